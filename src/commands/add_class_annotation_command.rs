@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::add_class_annotation_service::run,
+  common::types::annotation_attribute::AnnotationAttribute,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  class_file_b64_src: &str,
+  class_file_path: &Path,
+  annotation_fqcn: &str,
+  attributes: &[AnnotationAttribute],
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("add-class-annotation");
+  match run(class_file_b64_src, class_file_path, annotation_fqcn, attributes) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
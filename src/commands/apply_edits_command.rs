@@ -0,0 +1,12 @@
+use crate::{
+  commands::services::apply_edits_service::run,
+  responses::{apply_edits_response::ApplyEditsResponse, response::Response},
+};
+
+pub fn execute(b64_source_code: &str, edits: &str) -> Response<ApplyEditsResponse> {
+  let cmd_name = String::from("apply-edits");
+  match run(b64_source_code, edits) {
+    Ok(response) => Response::success(cmd_name, String::from("N/A"), response),
+    Err(error_msg) => Response::error(cmd_name, String::from("N/A"), error_msg),
+  }
+}
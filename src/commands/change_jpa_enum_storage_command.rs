@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::change_jpa_enum_storage_service::run,
+  common::types::{java_enum_type::JavaEnumType, persistence_namespace::PersistenceNamespace},
+  responses::{change_jpa_enum_storage_response::ChangeJPAEnumStorageResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  enum_type_storage: &JavaEnumType,
+  field_length: Option<u16>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Response<ChangeJPAEnumStorageResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("change-jpa-enum-storage");
+  // Note: Like the field creation commands, the entity file path is trusted as it comes from
+  // the user's editor context and is not validated against cwd.
+  match run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+    enum_type_storage,
+    field_length,
+    persistence_namespace,
+  ) {
+    Ok(response) => {
+      let warnings = response.warnings.clone();
+      Response::success(cmd_name, cwd_string, response).with_warnings(warnings)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::change_jpa_field_type_service::run,
+  common::types::persistence_namespace::PersistenceNamespace,
+  responses::{change_jpa_field_type_response::ChangeJPAFieldTypeResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  field_type: &str,
+  field_type_package_name: Option<&str>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Response<ChangeJPAFieldTypeResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("change-jpa-field-type");
+  // Note: Like the field creation commands, the entity file path is trusted as it comes from
+  // the user's editor context and is not validated against cwd.
+  match run(
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+    field_type,
+    field_type_package_name,
+    persistence_namespace,
+  ) {
+    Ok(response) => {
+      let warnings = response.warnings.clone();
+      Response::success(cmd_name, cwd_string, response).with_warnings(warnings)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,13 @@
+use crate::{
+  commands::services::convert_name_service::run,
+  common::types::name_case::NameCase,
+  responses::{convert_name_response::ConvertNameResponse, response::Response},
+};
+
+pub fn execute(name: &str, to_case: &NameCase) -> Response<ConvertNameResponse> {
+  let cmd_name = String::from("convert-name");
+  match run(name, to_case) {
+    Ok(response) => Response::success(cmd_name, String::from("N/A"), response),
+    Err(error_msg) => Response::error(cmd_name, String::from("N/A"), error_msg),
+  }
+}
@@ -0,0 +1,20 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::create_java_interface_service::run,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  method_specs: &[String],
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-java-interface");
+  match run(cwd, package_name, file_name, method_specs) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::create_jpa_converter_service::run,
+  common::types::persistence_namespace::PersistenceNamespace,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  attribute_type_fqcn: &str,
+  column_type_fqcn: &str,
+  auto_apply: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-jpa-converter");
+  match run(
+    cwd,
+    package_name,
+    file_name,
+    attribute_type_fqcn,
+    column_type_fqcn,
+    auto_apply,
+    persistence_namespace,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
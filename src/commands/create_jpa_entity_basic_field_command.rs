@@ -1,7 +1,9 @@
 use std::path::Path;
 
 use crate::{
-  commands::services::create_jpa_entity_basic_field_service::run,
+  commands::services::create_jpa_entity_basic_field_service::{
+    build_column_definition_warnings, build_precision_scale_warnings, build_temporal_warnings, run,
+  },
   common::types::basic_field_config::BasicFieldConfig,
   responses::{file_response::FileResponse, response::Response},
 };
@@ -21,7 +23,12 @@ pub fn execute(
   // the user's editor context.
 
   match run(entity_file_b64_src, entity_file_path, field_config) {
-    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Ok(response) => {
+      let mut warnings = build_precision_scale_warnings(field_config);
+      warnings.extend(build_temporal_warnings(field_config));
+      warnings.extend(build_column_definition_warnings(field_config));
+      Response::success(cmd_name, cwd_string, response).with_warnings(warnings)
+    }
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
 }
@@ -2,19 +2,42 @@ use std::path::Path;
 
 use crate::{
   commands::services::create_jpa_entity_service::run,
+  common::types::{
+    cache_concurrency_strategy::CacheConcurrencyStrategy,
+    persistence_namespace::PersistenceNamespace,
+  },
   responses::{file_response::FileResponse, response::Response},
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute(
   cwd: &Path,
   package_name: &str,
   file_name: &str,
   superclass_type: Option<&str>,
   superclass_package_name: Option<&str>,
+  cache_concurrency: Option<&CacheConcurrencyStrategy>,
+  cache_region: Option<&str>,
+  dynamic_insert: bool,
+  dynamic_update: bool,
+  immutable: bool,
+  persistence_namespace: &PersistenceNamespace,
 ) -> Response<FileResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("create-jpa-entity");
-  match run(cwd, package_name, file_name, superclass_type, superclass_package_name) {
+  match run(
+    cwd,
+    package_name,
+    file_name,
+    superclass_type,
+    superclass_package_name,
+    cache_concurrency,
+    cache_region,
+    dynamic_insert,
+    dynamic_update,
+    immutable,
+    persistence_namespace,
+  ) {
     Ok(response) => Response::success(cmd_name, cwd_string, response),
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::create_jpa_mapped_superclass_service::run,
+  common::types::persistence_namespace::PersistenceNamespace,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  generic_id: bool,
+  with_auditing: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-jpa-mapped-superclass");
+  match run(cwd, package_name, file_name, generic_id, with_auditing, persistence_namespace) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -30,7 +30,10 @@ pub fn execute(
   }
 
   match run(cwd, entity_file_b64_src, entity_file_path, b64_superclass_source) {
-    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Ok(response) => {
+      let warnings = response.warnings.clone();
+      Response::success(cmd_name, cwd_string, response).with_warnings(warnings)
+    }
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
 }
@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::create_package_info_service::run,
+  common::types::java_source_directory_type::JavaSourceDirectoryType,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  javadoc: Option<&str>,
+  annotations: &[String],
+  source_directory: &JavaSourceDirectoryType,
+  force: bool,
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-package-info");
+  match run(cwd, package_name, javadoc, annotations, source_directory, force) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
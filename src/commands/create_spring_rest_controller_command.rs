@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::create_spring_rest_controller_service::run,
+  common::utils::naming_util,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  base_path: &str,
+  entity_fqcn: &str,
+  service_fqcn: &str,
+  id_type_fqcn: Option<&str>,
+) -> Response<FileResponse> {
+  let normalized_file_name = naming_util::to_pascal_case(file_name);
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-spring-rest-controller");
+  match run(
+    cwd,
+    package_name,
+    &normalized_file_name,
+    base_path,
+    entity_fqcn,
+    service_fqcn,
+    id_type_fqcn,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,19 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::delete_java_file_service::run,
+  responses::{delete_java_file_response::DeleteJavaFileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  file_path: &Path,
+  check_usages: bool,
+) -> Response<DeleteJavaFileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("delete-java-file");
+  match run(cwd, file_path, check_usages) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::dump_ast_service::run,
+  responses::{dump_ast_response::DumpAstResponse, response::Response},
+};
+
+pub fn execute(cwd: &Path, b64_source_code: &str, include_text: bool) -> Response<DumpAstResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("dump-ast");
+  match run(b64_source_code, include_text) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
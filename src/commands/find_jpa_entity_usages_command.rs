@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::find_jpa_entity_usages_service::run,
+  responses::{find_jpa_entity_usages_response::FindJpaEntityUsagesResponse, response::Response},
+};
+
+pub fn execute(cwd: &Path, entity_type: &str) -> Response<FindJpaEntityUsagesResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("find-jpa-entity-usages");
+  match run(cwd, entity_type) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::generate_accessors_service::run,
+  common::types::fluent_setter_naming::FluentSetterNaming,
+  responses::{generate_accessors_response::GenerateAccessorsResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_names: &[String],
+  fluent_accessors: bool,
+  fluent_setter_naming: &FluentSetterNaming,
+) -> Response<GenerateAccessorsResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-accessors");
+  match run(
+    entity_file_b64_src,
+    entity_file_path,
+    field_names,
+    fluent_accessors,
+    fluent_setter_naming,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::generate_constructors_service::run,
+  responses::{generate_constructors_response::GenerateConstructorsResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  no_args: bool,
+  all_args: bool,
+) -> Response<GenerateConstructorsResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-constructors");
+  match run(entity_file_b64_src, entity_file_path, no_args, all_args) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
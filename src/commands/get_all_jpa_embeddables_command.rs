@@ -0,0 +1,19 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::get_all_jpa_embeddables_service::run,
+  responses::{get_jpa_embeddables_response::GetJpaEmbeddablesResponse, response::Response},
+};
+
+pub fn execute(cwd: &Path, module: Option<&str>) -> Response<GetJpaEmbeddablesResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-all-jpa-embeddables");
+  match run(cwd, module) {
+    Ok(embeddables) => {
+      let embeddables_count = embeddables.len();
+      let response = GetJpaEmbeddablesResponse { embeddables, embeddables_count };
+      Response::success(cmd_name, cwd_string, response)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
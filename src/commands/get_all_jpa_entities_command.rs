@@ -1,14 +1,15 @@
+use std::io::Write;
 use std::path::Path;
 
 use crate::{
-  commands::services::get_all_jpa_entities_service::run,
+  commands::services::get_all_jpa_entities_service::{for_each_entity, run},
   responses::{get_files_response::GetFilesResponse, response::Response},
 };
 
-pub fn execute(cwd: &Path) -> Response<GetFilesResponse> {
+pub fn execute(cwd: &Path, module: Option<&str>) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-all-jpa-entities");
-  match run(cwd) {
+  match run(cwd, module) {
     Ok(files) => {
       let files_count = files.len();
       let response = GetFilesResponse { files, files_count };
@@ -17,3 +18,30 @@ pub fn execute(cwd: &Path) -> Response<GetFilesResponse> {
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
 }
+
+/// Writes each entity to `out` as one compact JSON line while the project is scanned.
+///
+/// The returned Response is the terminating line of the stream: on success it carries the
+/// `filesCount` with an empty `files` list, on failure the error reason.
+pub fn execute_stream<W: Write>(
+  cwd: &Path,
+  module: Option<&str>,
+  out: &mut W,
+) -> Response<GetFilesResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-all-jpa-entities");
+  let result = for_each_entity(cwd, module, |entity| {
+    let line = serde_json::to_string(&entity)
+      .map_err(|e| format!("Unable to serialize entity {}: {}", entity.file_type, e))?;
+    writeln!(out, "{}", line)
+      .and_then(|_| out.flush())
+      .map_err(|e| format!("Unable to write entity {}: {}", entity.file_type, e))
+  });
+  match result {
+    Ok(files_count) => {
+      let response = GetFilesResponse { files: Vec::new(), files_count };
+      Response::success(cmd_name, cwd_string, response)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,19 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::get_all_jpa_enums_service::run,
+  responses::{get_jpa_enums_response::GetJpaEnumsResponse, response::Response},
+};
+
+pub fn execute(cwd: &Path, module: Option<&str>) -> Response<GetJpaEnumsResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-all-jpa-enums");
+  match run(cwd, module) {
+    Ok(enums) => {
+      let enums_count = enums.len();
+      let response = GetJpaEnumsResponse { enums, enums_count };
+      Response::success(cmd_name, cwd_string, response)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
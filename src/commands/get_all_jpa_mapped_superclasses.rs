@@ -5,10 +5,10 @@ use crate::{
   responses::{get_files_response::GetFilesResponse, response::Response},
 };
 
-pub fn execute(cwd: &Path) -> Response<GetFilesResponse> {
+pub fn execute(cwd: &Path, module: Option<&str>) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-all-jpa-mapped-superclasses");
-  match run(cwd) {
+  match run(cwd, module) {
     Ok(files) => {
       let files_count = files.len();
       let response = GetFilesResponse { files, files_count };
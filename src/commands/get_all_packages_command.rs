@@ -9,10 +9,11 @@ use crate::{
 pub fn execute(
   cwd: &Path,
   source_directory: &JavaSourceDirectoryType,
+  module: Option<&str>,
 ) -> Response<GetPackagesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-all-packages");
-  match run(cwd, source_directory) {
+  match run(cwd, source_directory, module) {
     Ok(packages) => {
       let packages_count = packages.len();
       let root_package_name = packages
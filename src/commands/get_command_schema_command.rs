@@ -0,0 +1,9 @@
+use serde_json::Value;
+
+use crate::commands::services::get_command_schema_service::run;
+
+/// Returns the JSON Schema of every command's inputs. Unlike other commands the schema is not
+/// wrapped in a `Response`, so it can be consumed directly by schema tooling.
+pub fn execute() -> Value {
+  run()
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use crate::{
+  commands::{
+    services::get_entity_table_name_service::run,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+  common::types::column_naming_strategy::ColumnNamingStrategy,
+  responses::{get_entity_table_name_response::GetEntityTableNameResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  naming_strategy: &ColumnNamingStrategy,
+) -> Response<GetEntityTableNameResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-entity-table-name");
+  // Security validation: ensure entity file path (if provided) is within the cwd
+  if let Some(file_path) = entity_file_path {
+    let file_path_str = file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("Entity file path security validation failed: {}", error_msg),
+      );
+    }
+  }
+
+  match run(cwd, entity_file_path, b64_source_code, naming_strategy) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -6,10 +6,14 @@ use crate::{
   responses::{get_files_response::GetFilesResponse, response::Response},
 };
 
-pub fn execute(cwd: &Path, file_type: &JavaFileType) -> Response<GetFilesResponse> {
+pub fn execute(
+  cwd: &Path,
+  file_type: &JavaFileType,
+  module: Option<&str>,
+) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-java-files");
-  match run(cwd, file_type) {
+  match run(cwd, file_type, module) {
     Ok(files) => {
       let files_count = files.len();
       let response = GetFilesResponse { files, files_count };
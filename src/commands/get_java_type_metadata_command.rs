@@ -0,0 +1,12 @@
+use crate::{
+  commands::services::get_java_type_metadata_service::run,
+  responses::{java_type_metadata_response::JavaTypeMetadataResponse, response::Response},
+};
+
+pub fn execute() -> Response<Vec<JavaTypeMetadataResponse>> {
+  let cmd_name = String::from("get-java-type-metadata");
+  match run() {
+    Ok(types) => Response::success(cmd_name, String::from("N/A"), types),
+    Err(error_msg) => Response::error(cmd_name, String::from("N/A"), error_msg),
+  }
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::get_packages_tree_service::run,
+  common::types::java_source_directory_type::JavaSourceDirectoryType,
+  responses::{
+    get_packages_tree_response::{GetPackagesTreeResponse, PackageTreeNodeResponse},
+    response::Response,
+  },
+};
+
+fn count_packages(nodes: &[PackageTreeNodeResponse]) -> usize {
+  nodes.iter().map(|node| 1 + count_packages(&node.children)).sum()
+}
+
+pub fn execute(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  module: Option<&str>,
+) -> Response<GetPackagesTreeResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-packages-tree");
+  match run(cwd, source_directory, module) {
+    Ok(packages) => {
+      let packages_count = count_packages(&packages);
+      let response = GetPackagesTreeResponse { packages, packages_count };
+      Response::success(cmd_name, cwd_string, response)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::get_project_info_service::run,
+  responses::{get_project_info_response::GetProjectInfoResponse, response::Response},
+};
+
+pub fn execute(cwd: &Path) -> Response<GetProjectInfoResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-project-info");
+  match run(cwd) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
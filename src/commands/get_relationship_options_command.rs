@@ -0,0 +1,14 @@
+use crate::{
+  commands::services::get_relationship_options_service::run,
+  responses::{
+    get_relationship_options_response::GetRelationshipOptionsResponse, response::Response,
+  },
+};
+
+pub fn execute() -> Response<GetRelationshipOptionsResponse> {
+  let cmd_name = String::from("get-relationship-options");
+  match run() {
+    Ok(options) => Response::success(cmd_name, String::from("N/A"), options),
+    Err(error_msg) => Response::error(cmd_name, String::from("N/A"), error_msg),
+  }
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::implement_interface_service::run,
+  responses::{implement_interface_response::ImplementInterfaceResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  class_file_b64_src: &str,
+  class_file_path: &Path,
+  interface_fqcn: &str,
+  stub_methods: bool,
+) -> Response<ImplementInterfaceResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("implement-interface");
+  match run(cwd, class_file_b64_src, class_file_path, interface_fqcn, stub_methods) {
+    Ok(response) => {
+      let warnings = response.warnings.clone();
+      Response::success(cmd_name, cwd_string, response).with_warnings(warnings)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,446 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+  commands::{
+    source_args::{EntitySourceArgs, OwningSideSourceArgs},
+    validators::{
+      attribute_override_validator::validate_attribute_override,
+      directory_validator::validate_directory_unrestricted,
+      java_fqcn_validator::validate_java_fully_qualified_class_name,
+      java_type_validator::validate_java_type, package_name_validator::validate_package_name,
+    },
+  },
+  common::types::{
+    cascade_type::CascadeType, collection_type::CollectionType,
+    column_naming_strategy::ColumnNamingStrategy, embedded_field_config::AttributeOverrideConfig,
+    fetch_type::FetchType, field_access::FieldAccess, generated_style::GeneratedStyle,
+    id_field_naming::IdFieldNaming, java_enum_type::JavaEnumType,
+    java_field_temporal::JavaFieldTemporal, java_field_time_zone_storage::JavaFieldTimeZoneStorage,
+    java_id_generation::JavaIdGeneration, java_id_generation_type::JavaIdGenerationType,
+    mapping_type::MappingType, other_type::OtherType, persistence_namespace::PersistenceNamespace,
+  },
+};
+
+// The commands with the most arguments declare them in their own `Args` structs, so clap builds
+// each of them in a separate stack frame instead of growing the single frame that augments the
+// whole `Commands` tree.
+
+/// Arguments of `create-jpa-entity-basic-field`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAEntityBasicFieldArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[arg(long, required = true)]
+  pub entity_file_path: PathBuf,
+
+  #[command(flatten)]
+  pub source: EntitySourceArgs,
+
+  #[arg(long, required = true)]
+  pub field_name: String,
+
+  #[arg(long, value_parser = validate_java_type, required = true)]
+  pub field_type: String,
+
+  #[arg(long, required = false)]
+  pub field_type_package_name: Option<String>,
+
+  #[arg(long, required = false)]
+  pub field_length: Option<u16>,
+
+  #[arg(long, required = false)]
+  pub field_precision: Option<u16>,
+
+  #[arg(long, required = false)]
+  pub field_scale: Option<u16>,
+
+  #[arg(long, required = false)]
+  pub field_temporal: Option<JavaFieldTemporal>,
+
+  #[arg(long, required = false)]
+  pub field_timezone_storage: Option<JavaFieldTimeZoneStorage>,
+
+  #[arg(long)]
+  pub field_unique: bool,
+
+  #[arg(long)]
+  pub field_nullable: bool,
+
+  #[arg(long)]
+  pub field_large_object: bool,
+
+  #[arg(long, required = false)]
+  pub basic_fetch: Option<FetchType>,
+
+  #[arg(long)]
+  pub field_natural_id: bool,
+
+  #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = false)]
+  pub converter_class: Option<String>,
+
+  #[arg(long, default_value = "snake_case")]
+  pub naming_strategy: ColumnNamingStrategy,
+
+  #[arg(long)]
+  pub explicit_column_name: bool,
+
+  #[arg(long, default_value = "private")]
+  pub field_access: FieldAccess,
+
+  #[arg(long, required = false, conflicts_with = "at_top")]
+  pub after_field: Option<String>,
+
+  #[arg(long)]
+  pub at_top: bool,
+
+  #[arg(long, required = false)]
+  pub javadoc: Option<String>,
+
+  #[arg(long)]
+  pub mark_generated: bool,
+
+  #[arg(long, default_value = "annotation", requires = "mark_generated")]
+  pub generated_style: GeneratedStyle,
+
+  #[arg(long, required = false)]
+  pub default_value: Option<String>,
+
+  #[arg(long, required = false)]
+  pub column_definition: Option<String>,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
+
+/// Arguments of `create-jpa-entity-id-field`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAEntityIdFieldArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[command(flatten)]
+  pub source: EntitySourceArgs,
+
+  #[arg(long, required = true)]
+  pub entity_file_path: PathBuf,
+
+  #[arg(long, required = false)]
+  pub field_name: Option<String>,
+
+  /// Names the field when `--field-name` is not given
+  #[arg(long, default_value = "id")]
+  pub id_field_naming: IdFieldNaming,
+
+  #[arg(long, required = true)]
+  pub field_type: String,
+
+  #[arg(long, required = false)]
+  pub field_type_package_name: Option<String>,
+
+  #[arg(long, required = true)]
+  pub field_id_generation: JavaIdGeneration,
+
+  #[arg(long, required = true)]
+  pub field_id_generation_type: JavaIdGenerationType,
+
+  #[arg(long, required = false)]
+  pub field_generator_name: Option<String>,
+
+  #[arg(long, required = false)]
+  pub field_sequence_name: Option<String>,
+
+  #[arg(long, required = false)]
+  pub field_initial_value: Option<i64>,
+
+  #[arg(long, required = false)]
+  pub field_allocation_size: Option<i64>,
+
+  #[arg(long)]
+  pub field_nullable: bool,
+
+  #[arg(long, default_value = "snake_case")]
+  pub naming_strategy: ColumnNamingStrategy,
+
+  #[arg(long, default_value = "private")]
+  pub field_access: FieldAccess,
+
+  #[arg(long, required = false, conflicts_with = "at_top")]
+  pub after_field: Option<String>,
+
+  #[arg(long)]
+  pub at_top: bool,
+
+  #[arg(long, required = false)]
+  pub javadoc: Option<String>,
+
+  #[arg(long)]
+  pub mark_generated: bool,
+
+  #[arg(long, default_value = "annotation", requires = "mark_generated")]
+  pub generated_style: GeneratedStyle,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
+
+/// Arguments of `create-jpa-entity-embedded-field`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAEntityEmbeddedFieldArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[command(flatten)]
+  pub source: EntitySourceArgs,
+
+  #[arg(long, required = true)]
+  pub entity_file_path: PathBuf,
+
+  #[arg(long, required = true)]
+  pub field_name: String,
+
+  #[arg(long, required = true)]
+  pub embeddable_type: String,
+
+  #[arg(long, required = true)]
+  pub embeddable_package_name: String,
+
+  #[arg(long = "attribute-override", value_parser = validate_attribute_override)]
+  pub attribute_overrides: Vec<AttributeOverrideConfig>,
+
+  #[arg(long, default_value = "private")]
+  pub field_access: FieldAccess,
+
+  #[arg(long, required = false, conflicts_with = "at_top")]
+  pub after_field: Option<String>,
+
+  #[arg(long)]
+  pub at_top: bool,
+
+  #[arg(long, required = false)]
+  pub javadoc: Option<String>,
+
+  #[arg(long)]
+  pub mark_generated: bool,
+
+  #[arg(long, default_value = "annotation", requires = "mark_generated")]
+  pub generated_style: GeneratedStyle,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
+
+/// Arguments of `create-jpa-entity-enum-field`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAEntityEnumFieldArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[command(flatten)]
+  pub source: EntitySourceArgs,
+
+  #[arg(long, required = true)]
+  pub entity_file_path: PathBuf,
+
+  #[arg(long, required = true)]
+  pub field_name: String,
+
+  #[arg(long, required = true)]
+  pub enum_type: String,
+
+  #[arg(long, required = true)]
+  pub enum_package_name: String,
+
+  #[arg(long, required = true)]
+  pub enum_type_storage: JavaEnumType,
+
+  #[arg(long, required = false)]
+  pub field_length: Option<u16>,
+
+  #[arg(long)]
+  pub field_nullable: bool,
+
+  #[arg(long)]
+  pub field_unique: bool,
+
+  #[arg(long, default_value = "snake_case")]
+  pub naming_strategy: ColumnNamingStrategy,
+
+  #[arg(long)]
+  pub explicit_column_name: bool,
+
+  #[arg(long, default_value = "private")]
+  pub field_access: FieldAccess,
+
+  #[arg(long, required = false, conflicts_with = "at_top")]
+  pub after_field: Option<String>,
+
+  #[arg(long)]
+  pub at_top: bool,
+
+  #[arg(long, required = false)]
+  pub javadoc: Option<String>,
+
+  #[arg(long)]
+  pub mark_generated: bool,
+
+  #[arg(long, default_value = "annotation", requires = "mark_generated")]
+  pub generated_style: GeneratedStyle,
+
+  #[arg(long, required = false)]
+  pub default_value: Option<String>,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
+
+/// Arguments of `create-jpa-one-to-one-relationship`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAOneToOneRelationshipArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[command(flatten)]
+  pub source: OwningSideSourceArgs,
+
+  #[arg(long, required = true)]
+  pub owning_side_entity_file_path: PathBuf,
+
+  #[arg(long, required = true)]
+  pub owning_side_field_name: String,
+
+  #[arg(long, required = true)]
+  pub inverse_side_field_name: String,
+
+  #[arg(long, required = true)]
+  pub inverse_field_type: String,
+
+  #[arg(long, value_parser = validate_package_name, required = false)]
+  pub inverse_field_package: Option<String>,
+
+  #[arg(long, required = false)]
+  pub mapping_type: Option<MappingType>,
+
+  #[arg(long, required = false)]
+  pub fetch_type: Option<FetchType>,
+
+  #[arg(long, required = false)]
+  pub owning_side_cascades: Vec<CascadeType>,
+
+  #[arg(long, required = false)]
+  pub inverse_side_cascades: Vec<CascadeType>,
+
+  #[arg(long, required = false)]
+  pub owning_side_other: Vec<OtherType>,
+
+  #[arg(long, required = false)]
+  pub inverse_side_other: Vec<OtherType>,
+
+  #[arg(long, required = false)]
+  pub join_column_name: Option<String>,
+
+  #[arg(long, required = false)]
+  pub referenced_column_name: Option<String>,
+
+  #[arg(long, required = false, num_args = 0..=1)]
+  pub maps_id: Option<Option<String>>,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
+
+/// Arguments of `create-jpa-many-to-one-relationship`.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct CreateJPAManyToOneRelationshipArgs {
+  #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+  pub cwd: PathBuf,
+
+  #[command(flatten)]
+  pub source: OwningSideSourceArgs,
+
+  #[arg(long, required = true)]
+  pub owning_side_entity_file_path: PathBuf,
+
+  #[arg(long, required = true)]
+  pub owning_side_field_name: String,
+
+  #[arg(long, required = true)]
+  pub inverse_side_field_name: String,
+
+  #[arg(long, required = true)]
+  pub inverse_field_type: String,
+
+  #[arg(long, value_parser = validate_package_name, required = false)]
+  pub inverse_field_package: Option<String>,
+
+  #[arg(long, required = true)]
+  pub fetch_type: FetchType,
+
+  #[arg(long, required = true)]
+  pub collection_type: CollectionType,
+
+  #[arg(long, required = false)]
+  pub declared_collection_type: Option<CollectionType>,
+
+  #[arg(long, required = false)]
+  pub mapping_type: Option<MappingType>,
+
+  #[arg(long, required = false)]
+  pub owning_side_cascades: Vec<CascadeType>,
+
+  #[arg(long, required = false)]
+  pub inverse_side_cascades: Vec<CascadeType>,
+
+  #[arg(long, required = false)]
+  pub owning_side_other: Vec<OtherType>,
+
+  #[arg(long, required = false)]
+  pub inverse_side_other: Vec<OtherType>,
+
+  #[arg(long, required = false)]
+  pub join_column_name: Option<String>,
+
+  #[arg(long, required = false)]
+  pub referenced_column_name: Option<String>,
+
+  #[arg(long, required = false, num_args = 0..=1)]
+  pub maps_id: Option<Option<String>>,
+
+  #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+  pub initialize_collection: bool,
+
+  #[arg(long, required = false)]
+  pub order_by: Option<String>,
+
+  #[arg(long, required = false, conflicts_with = "order_by")]
+  pub order_column: Option<String>,
+
+  #[arg(long, default_value = "jakarta")]
+  pub persistence_namespace: PersistenceNamespace,
+
+  #[arg(long)]
+  pub format_java: bool,
+}
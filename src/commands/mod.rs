@@ -1,43 +1,98 @@
+pub mod add_class_annotation_command;
+pub mod apply_edits_command;
+pub mod change_jpa_enum_storage_command;
+pub mod change_jpa_field_type_command;
+pub mod convert_name_command;
 pub mod create_java_file_command;
+pub mod create_java_interface_command;
+pub mod create_jpa_converter_command;
 pub mod create_jpa_entity_basic_field_command;
 pub mod create_jpa_entity_command;
+pub mod create_jpa_entity_embedded_field_command;
 pub mod create_jpa_entity_enum_field_command;
+pub mod create_jpa_entity_fields_command;
 pub mod create_jpa_entity_id_field_command;
 pub mod create_jpa_many_to_one_relationship_command;
+pub mod create_jpa_mapped_superclass_command;
 pub mod create_jpa_one_to_one_relationship_command;
 pub mod create_jpa_repository_command;
+pub mod create_package_info_command;
+pub mod create_spring_rest_controller_command;
+pub mod delete_java_file_command;
+pub mod dump_ast_command;
+pub mod find_jpa_entity_usages_command;
+pub mod generate_accessors_command;
+pub mod generate_constructors_command;
+pub mod get_all_jpa_embeddables_command;
 pub mod get_all_jpa_entities_command;
+pub mod get_all_jpa_enums_command;
 pub mod get_all_jpa_mapped_superclasses;
 pub mod get_all_packages_command;
+pub mod get_command_schema_command;
+pub mod get_entity_table_name_command;
+pub mod get_enum_constants_command;
+pub mod get_field_annotations_command;
+pub mod get_fqcn_command;
+pub mod get_import_status_command;
 pub mod get_java_basic_types_command;
+pub mod get_java_class_structure_command;
+pub mod get_java_file_type_command;
 pub mod get_java_files_command;
+pub mod get_java_type_metadata_command;
 pub mod get_jpa_entity_info_command;
+pub mod get_jpa_relationships_command;
+pub mod get_packages_tree_command;
+pub mod get_project_info_command;
+pub mod get_relationship_options_command;
+pub mod implement_interface_command;
+pub mod jpa_field_args;
+pub mod modify_jpa_field_constraints_command;
+pub mod move_jpa_field_command;
+pub mod remove_jpa_field_command;
+pub mod remove_unused_imports_command;
+pub mod rename_jpa_entity_command;
 pub mod services;
+pub mod source_args;
+pub mod validate_identifier_command;
+pub mod validate_jpa_entity_command;
 mod validators;
 
 use std::path::PathBuf;
 
-use clap::Subcommand;
+use clap::{ArgGroup, Subcommand};
 
 use crate::{
-  commands::validators::{
-    directory_validator::validate_directory_unrestricted,
-    java_class_name_validator::validate_java_class_name,
-    package_name_validator::validate_package_name,
+  commands::{
+    jpa_field_args::{
+      CreateJPAEntityBasicFieldArgs, CreateJPAEntityEmbeddedFieldArgs,
+      CreateJPAEntityEnumFieldArgs, CreateJPAEntityIdFieldArgs, CreateJPAManyToOneRelationshipArgs,
+      CreateJPAOneToOneRelationshipArgs,
+    },
+    source_args::{ClassSourceArgs, EntitySourceArgs, SourceArgs},
+    validators::{
+      annotation_attribute_validator::validate_annotation_attribute,
+      directory_validator::validate_directory_unrestricted,
+      java_class_name_validator::validate_java_class_name,
+      java_fqcn_validator::validate_java_fully_qualified_class_name,
+      java_method_spec_validator::validate_java_method_spec,
+      package_name_validator::validate_package_name,
+    },
   },
   common::types::{
-    basic_field_config::BasicFieldConfig, cascade_type::CascadeType,
-    collection_type::CollectionType, enum_field_config::EnumFieldConfig, fetch_type::FetchType,
+    annotation_attribute::AnnotationAttribute, basic_field_config::BasicFieldConfig,
+    cache_concurrency_strategy::CacheConcurrencyStrategy,
+    column_naming_strategy::ColumnNamingStrategy, embedded_field_config::EmbeddedFieldConfig,
+    enum_field_config::EnumFieldConfig, fluent_setter_naming::FluentSetterNaming,
     id_field_config::IdFieldConfig, java_basic_types::JavaBasicType, java_enum_type::JavaEnumType,
-    java_field_temporal::JavaFieldTemporal, java_field_time_zone_storage::JavaFieldTimeZoneStorage,
-    java_file_type::JavaFileType, java_id_generation::JavaIdGeneration,
-    java_id_generation_type::JavaIdGenerationType,
+    java_file_type::JavaFileType, java_identifier_kind::JavaIdentifierKind,
     java_source_directory_type::JavaSourceDirectoryType,
-    many_to_one_field_config::ManyToOneFieldConfig, mapping_type::MappingType,
-    one_to_one_field_config::OneToOneFieldConfig, other_type::OtherType,
+    many_to_one_field_config::ManyToOneFieldConfig, name_case::NameCase,
+    one_to_one_field_config::OneToOneFieldConfig, persistence_namespace::PersistenceNamespace,
   },
 };
 
+#[cfg(feature = "ui")]
+use crate::common::types::id_field_naming::IdFieldNaming;
 #[cfg(feature = "ui")]
 use crate::ui::{
   forms::{
@@ -71,6 +126,10 @@ pub enum UiCommands {
 
     #[arg(long, required = true)]
     entity_file_path: PathBuf,
+
+    /// Default name of the field in the id field form
+    #[arg(long, default_value = "id")]
+    id_field_naming: IdFieldNaming,
   },
   #[command(name = "create-jpa-one-to-one-relationship")]
   CreateJpaOneToOneRelationship {
@@ -110,11 +169,17 @@ impl UiCommands {
         run_ui_command(form)?;
         Ok(())
       }
-      UiCommands::CreateJpaEntityBasicField { cwd, entity_file_b64_src, entity_file_path } => {
+      UiCommands::CreateJpaEntityBasicField {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        id_field_naming,
+      } => {
         let form = CreateEntityFieldForm::new(
           cwd.clone(),
           entity_file_b64_src.clone(),
           entity_file_path.clone(),
+          id_field_naming.clone(),
         );
         run_ui_command(form)?;
         Ok(())
@@ -150,20 +215,136 @@ pub enum Commands {
   GetAllJPAEntities {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
+
+    #[arg(long)]
+    stream: bool,
+  },
+  GetAllJPAEnums {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
+  },
+  GetEnumConstants {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    enum_file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  /// Tell whether a type can be used by its simple name in a file or needs an import
+  GetImportStatus {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    type_fqcn: String,
   },
   GetAllJPAMappedSuperclasses {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
   },
-  GetJPAEntityInfo {
+  GetAllJPAEmbeddables {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = false)]
+    module: Option<String>,
+  },
+  GetJPAEntityInfo {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
     entity_file_path: Option<PathBuf>,
 
-    #[arg(long, required = false)]
-    b64_source_code: Option<String>,
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  GetEntityTableName {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    entity_file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+
+    #[arg(long, default_value = "snake_case")]
+    naming_strategy: ColumnNamingStrategy,
+  },
+  /// Dump the Tree-sitter tree of a source for debugging generators
+  #[command(group(ArgGroup::new("source_code").args(["b64_source_code", "stdin"]).required(true)))]
+  DumpAst {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[command(flatten)]
+    source: SourceArgs,
+
+    #[arg(long)]
+    include_text: bool,
+  },
+  GetFieldAnnotations {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    entity_file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+
+    #[arg(long, required = true)]
+    field_name: String,
+  },
+  FindJPAEntityUsages {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    entity_type: String,
+  },
+  GetJPARelationships {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    entity_file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  ValidateJPAEntity {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    entity_file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  GetProjectInfo {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
   },
   GetAllPackages {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
@@ -171,17 +352,107 @@ pub enum Commands {
 
     #[arg(long, default_value = "main")]
     source_directory: JavaSourceDirectoryType,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
+  },
+  GetPackagesTree {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, default_value = "main")]
+    source_directory: JavaSourceDirectoryType,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
   },
+  /// Print a JSON Schema describing the inputs of every command
+  Schema,
   GetJavaBasicTypes {
     #[arg(long, default_value = "all-types")]
     basic_type_kind: JavaBasicType,
   },
+  /// List every Java basic type with the column, temporal, time zone storage and lob options it
+  /// supports
+  GetJavaTypeMetadata,
+  /// List the cascade, fetch, collection and other options each relationship kind accepts
+  GetRelationshipOptions,
+  /// Apply client computed edits to a source, returning the result and the reverse edits
+  #[command(group(ArgGroup::new("source_code").args(["b64_source_code", "stdin"]).required(true)))]
+  ApplyEdits {
+    #[command(flatten)]
+    source: SourceArgs,
+
+    /// JSON array of `{"startByte", "oldEndByte", "newText"}` against the original source
+    #[arg(long, required = true)]
+    edits: String,
+  },
+  /// Check a class, package, field or method name without running a command with it
+  ValidateIdentifier {
+    #[arg(long, required = true)]
+    kind: JavaIdentifierKind,
+
+    #[arg(long, required = true)]
+    value: String,
+  },
+  /// Convert a name between camel, Pascal, snake, screaming snake and kebab case
+  ConvertName {
+    #[arg(long, required = true)]
+    name: String,
+
+    #[arg(long, required = true)]
+    to: NameCase,
+  },
   GetJavaFiles {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = true)]
     file_type: JavaFileType,
+
+    #[arg(long, required = false)]
+    module: Option<String>,
+  },
+  GetJavaFileType {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  /// Return the fully-qualified name of the top-level type of a Java file
+  GetFqcn {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  GetJavaClassStructure {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
+  },
+  RemoveUnusedImports {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false, conflicts_with = "stdin")]
+    file_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    source: SourceArgs,
   },
   CreateJavaFile {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
@@ -198,8 +469,12 @@ pub enum Commands {
 
     #[arg(long, default_value = "main")]
     source_directory: JavaSourceDirectoryType,
+
+    #[arg(long)]
+    force: bool,
   },
-  CreateJPAEntity {
+  /// Create an interface declaring the given method signatures
+  CreateJavaInterface {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
@@ -209,126 +484,163 @@ pub enum Commands {
     #[arg(long, value_parser = validate_java_class_name, required = true)]
     file_name: String,
 
-    #[arg(long, required = false)]
-    superclass_type: Option<String>,
-
-    #[arg(long, required = false)]
-    superclass_package_name: Option<String>,
+    #[arg(long = "method", value_parser = validate_java_method_spec, required = true)]
+    method_specs: Vec<String>,
   },
-  CreateJPARepository {
+  DeleteJavaFile {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = true)]
-    entity_file_b64_src: String,
-
-    #[arg(long, required = true)]
-    entity_file_path: PathBuf,
+    file_path: PathBuf,
 
-    #[arg(long, required = false)]
-    b64_superclass_source: Option<String>,
+    #[arg(long)]
+    check_usages: bool,
   },
-  CreateJPAEntityBasicField {
+  CreatePackageInfo {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
-    #[arg(long, required = true)]
-    entity_file_path: PathBuf,
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
 
-    #[arg(long, required = true)]
-    entity_file_b64_src: String,
+    #[arg(long, required = false)]
+    javadoc: Option<String>,
 
-    #[arg(long, required = true)]
-    field_name: String,
+    #[arg(long, required = false)]
+    annotations: Vec<String>,
 
-    #[arg(long, required = true)]
-    field_type: String,
+    #[arg(long, default_value = "main")]
+    source_directory: JavaSourceDirectoryType,
 
-    #[arg(long, required = false)]
-    field_type_package_name: Option<String>,
+    #[arg(long)]
+    force: bool,
+  },
+  CreateJPAEntity {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
-    #[arg(long, required = false)]
-    field_length: Option<u16>,
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
 
-    #[arg(long, required = false)]
-    field_precision: Option<u16>,
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    file_name: String,
 
     #[arg(long, required = false)]
-    field_scale: Option<u16>,
+    superclass_type: Option<String>,
 
     #[arg(long, required = false)]
-    field_temporal: Option<JavaFieldTemporal>,
+    superclass_package_name: Option<String>,
 
-    #[arg(long, required = false)]
-    field_timezone_storage: Option<JavaFieldTimeZoneStorage>,
+    #[arg(long)]
+    cacheable: bool,
+
+    #[arg(long, default_value = "read_write", requires = "cacheable")]
+    cache_concurrency: CacheConcurrencyStrategy,
+
+    #[arg(long, required = false, requires = "cacheable")]
+    cache_region: Option<String>,
 
     #[arg(long)]
-    field_unique: bool,
+    dynamic_insert: bool,
 
     #[arg(long)]
-    field_nullable: bool,
+    dynamic_update: bool,
 
     #[arg(long)]
-    field_large_object: bool,
+    immutable: bool,
+
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
   },
-  CreateJPAEntityIdField {
+  CreateJPAMappedSuperclass {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
-    #[arg(long, required = true)]
-    entity_file_b64_src: String,
-
-    #[arg(long, required = true)]
-    entity_file_path: PathBuf,
-
-    #[arg(long, required = true)]
-    field_name: String,
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
 
-    #[arg(long, required = true)]
-    field_type: String,
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    file_name: String,
 
-    #[arg(long, required = false)]
-    field_type_package_name: Option<String>,
+    #[arg(long)]
+    generic_id: bool,
 
-    #[arg(long, required = true)]
-    field_id_generation: JavaIdGeneration,
+    #[arg(long)]
+    with_auditing: bool,
 
-    #[arg(long, required = true)]
-    field_id_generation_type: JavaIdGenerationType,
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
+  },
+  CreateJPAConverter {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
-    #[arg(long, required = false)]
-    field_generator_name: Option<String>,
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
 
-    #[arg(long, required = false)]
-    field_sequence_name: Option<String>,
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    file_name: String,
 
-    #[arg(long, required = false)]
-    field_initial_value: Option<i64>,
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    attribute_type_fqcn: String,
 
-    #[arg(long, required = false)]
-    field_allocation_size: Option<i64>,
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    column_type_fqcn: String,
 
     #[arg(long)]
-    field_nullable: bool,
+    auto_apply: bool,
+
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
   },
-  CreateJPAEntityEnumField {
+  CreateJPARepository {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
-    #[arg(long, required = true)]
-    entity_file_b64_src: String,
+    #[command(flatten)]
+    source: EntitySourceArgs,
 
     #[arg(long, required = true)]
     entity_file_path: PathBuf,
 
+    #[arg(long, required = false)]
+    b64_superclass_source: Option<String>,
+  },
+  CreateSpringRestController {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
+
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    file_name: String,
+
     #[arg(long, required = true)]
-    field_name: String,
+    base_path: String,
+
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    entity_fqcn: String,
+
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    service_fqcn: String,
+
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = false)]
+    id_type_fqcn: Option<String>,
+  },
+  ChangeJPAEnumStorage {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
     #[arg(long, required = true)]
-    enum_type: String,
+    entity_file_path: PathBuf,
+
+    #[command(flatten)]
+    source: EntitySourceArgs,
 
     #[arg(long, required = true)]
-    enum_package_name: String,
+    field_name: String,
 
     #[arg(long, required = true)]
     enum_type_storage: JavaEnumType,
@@ -336,86 +648,197 @@ pub enum Commands {
     #[arg(long, required = false)]
     field_length: Option<u16>,
 
-    #[arg(long)]
-    field_nullable: bool,
-
-    #[arg(long)]
-    field_unique: bool,
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
   },
-  CreateJPAOneToOneRelationship {
+  ChangeJPAFieldType {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = true)]
-    owning_side_entity_file_b64_src: String,
+    entity_file_path: PathBuf,
 
-    #[arg(long, required = true)]
-    owning_side_entity_file_path: PathBuf,
+    #[command(flatten)]
+    source: EntitySourceArgs,
 
     #[arg(long, required = true)]
-    owning_side_field_name: String,
+    field_name: String,
 
     #[arg(long, required = true)]
-    inverse_side_field_name: String,
+    field_type: String,
+
+    #[arg(long, required = false)]
+    field_type_package_name: Option<String>,
+
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
+  },
+  ModifyJPAFieldConstraints {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
     #[arg(long, required = true)]
-    inverse_field_type: String,
+    entity_file_path: PathBuf,
 
-    #[arg(long, required = false)]
-    mapping_type: Option<MappingType>,
+    #[command(flatten)]
+    source: EntitySourceArgs,
 
-    #[arg(long, required = false)]
-    owning_side_cascades: Vec<CascadeType>,
+    #[arg(long, required = true)]
+    field_name: String,
 
     #[arg(long, required = false)]
-    inverse_side_cascades: Vec<CascadeType>,
+    nullable: Option<bool>,
 
     #[arg(long, required = false)]
-    owning_side_other: Vec<OtherType>,
+    unique: Option<bool>,
 
-    #[arg(long, required = false)]
-    inverse_side_other: Vec<OtherType>,
+    #[arg(long, default_value = "jakarta")]
+    persistence_namespace: PersistenceNamespace,
   },
-  CreateJPAManyToOneRelationship {
+  /// Move a field with its accessors from an entity into the mapped superclass it extends
+  MoveJPAField {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = true)]
-    owning_side_entity_file_b64_src: String,
+    entity_file_path: PathBuf,
 
-    #[arg(long, required = true)]
-    owning_side_entity_file_path: PathBuf,
+    #[command(flatten)]
+    source: EntitySourceArgs,
 
     #[arg(long, required = true)]
-    owning_side_field_name: String,
+    superclass_file_path: PathBuf,
 
     #[arg(long, required = true)]
-    inverse_side_field_name: String,
+    field_name: String,
 
-    #[arg(long, required = true)]
-    inverse_field_type: String,
+    #[arg(long)]
+    format_java: bool,
+  },
+  /// Add an interface to the implements clause of a class, optionally stubbing its methods
+  ImplementInterface {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
     #[arg(long, required = true)]
-    fetch_type: FetchType,
+    class_file_path: PathBuf,
 
-    #[arg(long, required = true)]
-    collection_type: CollectionType,
+    #[command(flatten)]
+    source: ClassSourceArgs,
 
-    #[arg(long, required = false)]
-    mapping_type: Option<MappingType>,
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    interface_fqcn: String,
 
-    #[arg(long, required = false)]
-    owning_side_cascades: Vec<CascadeType>,
+    #[arg(long)]
+    stub_methods: bool,
+  },
+  /// Add an annotation, with optional key=value attributes, to a class and import it
+  AddClassAnnotation {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
-    #[arg(long, required = false)]
-    inverse_side_cascades: Vec<CascadeType>,
+    #[arg(long, required = true)]
+    class_file_path: PathBuf,
 
-    #[arg(long, required = false)]
-    owning_side_other: Vec<OtherType>,
+    #[command(flatten)]
+    source: ClassSourceArgs,
 
-    #[arg(long, required = false)]
-    inverse_side_other: Vec<OtherType>,
+    #[arg(long, value_parser = validate_java_fully_qualified_class_name, required = true)]
+    annotation_fqcn: String,
+
+    #[arg(long = "attribute", value_parser = validate_annotation_attribute)]
+    attributes: Vec<AnnotationAttribute>,
   },
+  /// Generate getters and setters for the fields of an entity
+  GenerateAccessors {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[command(flatten)]
+    source: EntitySourceArgs,
+
+    #[arg(long = "field-name", required = false)]
+    field_names: Vec<String>,
+
+    #[arg(long)]
+    fluent_accessors: bool,
+
+    #[arg(long, default_value = "field", requires = "fluent_accessors")]
+    fluent_setter_naming: FluentSetterNaming,
+  },
+  /// Generate a no-args and/or an all-args constructor
+  GenerateConstructors {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[command(flatten)]
+    source: EntitySourceArgs,
+
+    #[arg(long, required_unless_present = "all_args")]
+    no_args: bool,
+
+    #[arg(long)]
+    all_args: bool,
+  },
+  RemoveJPAField {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[command(flatten)]
+    source: EntitySourceArgs,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long)]
+    cleanup_inverse: bool,
+
+    #[arg(long)]
+    format_java: bool,
+  },
+  RenameJPAEntity {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    new_name: String,
+
+    #[arg(long)]
+    rename_repository: bool,
+  },
+  CreateJPAEntityBasicField(CreateJPAEntityBasicFieldArgs),
+  /// Add several basic, id and enum fields to an entity at once, writing it a single time
+  CreateJPAEntityFields {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[command(flatten)]
+    source: EntitySourceArgs,
+
+    /// JSON array of field configs, each tagged with `"kind": "basic" | "id" | "enum"`
+    #[arg(long, required = true)]
+    fields: String,
+  },
+  CreateJPAEntityIdField(CreateJPAEntityIdFieldArgs),
+  CreateJPAEntityEmbeddedField(CreateJPAEntityEmbeddedFieldArgs),
+  CreateJPAEntityEnumField(CreateJPAEntityEnumFieldArgs),
+  CreateJPAOneToOneRelationship(CreateJPAOneToOneRelationshipArgs),
+  CreateJPAManyToOneRelationship(CreateJPAManyToOneRelationshipArgs),
 }
 
 impl Commands {
@@ -426,15 +849,51 @@ impl Commands {
         ui_command.execute()?;
         Ok(String::new())
       }
-      Commands::GetAllJPAEntities { cwd } => {
-        let response = get_all_jpa_entities_command::execute(cwd.as_path());
+      Commands::GetAllJPAEntities { cwd, module, stream } => {
+        if *stream {
+          let response = get_all_jpa_entities_command::execute_stream(
+            cwd.as_path(),
+            module.as_deref(),
+            &mut std::io::stdout(),
+          );
+          return response.to_json().map_err(|e| e.into());
+        }
+        let response = get_all_jpa_entities_command::execute(cwd.as_path(), module.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetAllJPAEnums { cwd, module } => {
+        let response = get_all_jpa_enums_command::execute(cwd.as_path(), module.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetEnumConstants { cwd, enum_file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_enum_constants_command::execute(
+          cwd.as_path(),
+          enum_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetImportStatus { cwd, file_path, source, type_fqcn } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_import_status_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+          type_fqcn,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetAllJPAMappedSuperclasses { cwd, module } => {
+        let response = get_all_jpa_mapped_superclasses::execute(cwd.as_path(), module.as_deref());
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::GetAllJPAMappedSuperclasses { cwd } => {
-        let response = get_all_jpa_mapped_superclasses::execute(cwd.as_path());
+      Commands::GetAllJPAEmbeddables { cwd, module } => {
+        let response = get_all_jpa_embeddables_command::execute(cwd.as_path(), module.as_deref());
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::GetJPAEntityInfo { cwd, entity_file_path, b64_source_code } => {
+      Commands::GetJPAEntityInfo { cwd, entity_file_path, source } => {
+        let b64_source_code = source.resolve()?;
         let response = get_jpa_entity_info_command::execute(
           cwd.as_path(),
           entity_file_path.as_deref(),
@@ -442,25 +901,182 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::GetAllPackages { cwd, source_directory } => {
-        let response = get_all_packages_command::execute(cwd.as_path(), source_directory);
+      Commands::GetEntityTableName { cwd, entity_file_path, source, naming_strategy } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_entity_table_name_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+          naming_strategy,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::DumpAst { cwd, source, include_text } => {
+        let b64_source_code = &source.require()?;
+        let response = dump_ast_command::execute(cwd.as_path(), b64_source_code, *include_text);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetFieldAnnotations { cwd, entity_file_path, source, field_name } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_field_annotations_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+          field_name,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::FindJPAEntityUsages { cwd, entity_type } => {
+        let response = find_jpa_entity_usages_command::execute(cwd.as_path(), entity_type);
         response.to_json_pretty().map_err(|e| e.into())
       }
+      Commands::GetJPARelationships { cwd, entity_file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_jpa_relationships_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ValidateJPAEntity { cwd, entity_file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = validate_jpa_entity_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetProjectInfo { cwd } => {
+        let response = get_project_info_command::execute(cwd.as_path());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetAllPackages { cwd, source_directory, module } => {
+        let response =
+          get_all_packages_command::execute(cwd.as_path(), source_directory, module.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetPackagesTree { cwd, source_directory, module } => {
+        let response =
+          get_packages_tree_command::execute(cwd.as_path(), source_directory, module.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::Schema => {
+        serde_json::to_string_pretty(&get_command_schema_command::execute()).map_err(|e| e.into())
+      }
       Commands::GetJavaBasicTypes { basic_type_kind } => {
         let response = get_java_basic_types_command::execute(basic_type_kind);
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::GetJavaFiles { cwd, file_type } => {
-        let response = get_java_files_command::execute(cwd.as_path(), file_type);
+      Commands::GetJavaTypeMetadata => {
+        let response = get_java_type_metadata_command::execute();
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJavaFile { cwd, package_name, file_name, file_type, source_directory } => {
+      Commands::GetRelationshipOptions => {
+        let response = get_relationship_options_command::execute();
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ApplyEdits { source, edits } => {
+        let b64_source_code = &source.require()?;
+        let response = apply_edits_command::execute(b64_source_code, edits);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ValidateIdentifier { kind, value } => {
+        let response = validate_identifier_command::execute(kind, value);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ConvertName { name, to } => {
+        let response = convert_name_command::execute(name, to);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetJavaFiles { cwd, file_type, module } => {
+        let response = get_java_files_command::execute(cwd.as_path(), file_type, module.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetJavaFileType { cwd, file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_java_file_type_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetFqcn { cwd, file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_fqcn_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GetJavaClassStructure { cwd, file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = get_java_class_structure_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::RemoveUnusedImports { cwd, file_path, source } => {
+        let b64_source_code = source.resolve()?;
+        let response = remove_unused_imports_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJavaFile {
+        cwd,
+        package_name,
+        file_name,
+        file_type,
+        source_directory,
+        force,
+      } => {
         let response = create_java_file_command::execute(
           cwd.as_path(),
           package_name,
           file_name,
           file_type,
           source_directory,
+          *force,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJavaInterface { cwd, package_name, file_name, method_specs } => {
+        let response = create_java_interface_command::execute(
+          cwd.as_path(),
+          package_name,
+          file_name,
+          method_specs,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::DeleteJavaFile { cwd, file_path, check_usages } => {
+        let response =
+          delete_java_file_command::execute(cwd.as_path(), file_path.as_path(), *check_usages);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreatePackageInfo {
+        cwd,
+        package_name,
+        javadoc,
+        annotations,
+        source_directory,
+        force,
+      } => {
+        let response = create_package_info_command::execute(
+          cwd.as_path(),
+          package_name,
+          javadoc.as_deref(),
+          annotations,
+          source_directory,
+          *force,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
@@ -470,6 +1086,13 @@ impl Commands {
         file_name,
         superclass_type,
         superclass_package_name,
+        cacheable,
+        cache_concurrency,
+        cache_region,
+        dynamic_insert,
+        dynamic_update,
+        immutable,
+        persistence_namespace,
       } => {
         let response = create_jpa_entity_command::execute(
           cwd.as_path(),
@@ -477,15 +1100,55 @@ impl Commands {
           file_name,
           superclass_type.as_deref(),
           superclass_package_name.as_deref(),
+          cacheable.then_some(cache_concurrency),
+          cache_region.as_deref(),
+          *dynamic_insert,
+          *dynamic_update,
+          *immutable,
+          persistence_namespace,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPARepository {
+      Commands::CreateJPAMappedSuperclass {
         cwd,
-        entity_file_b64_src,
-        entity_file_path,
-        b64_superclass_source,
+        package_name,
+        file_name,
+        generic_id,
+        with_auditing,
+        persistence_namespace,
       } => {
+        let response = create_jpa_mapped_superclass_command::execute(
+          cwd.as_path(),
+          package_name,
+          file_name,
+          *generic_id,
+          *with_auditing,
+          persistence_namespace,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJPAConverter {
+        cwd,
+        package_name,
+        file_name,
+        attribute_type_fqcn,
+        column_type_fqcn,
+        auto_apply,
+        persistence_namespace,
+      } => {
+        let response = create_jpa_converter_command::execute(
+          cwd.as_path(),
+          package_name,
+          file_name,
+          attribute_type_fqcn,
+          column_type_fqcn,
+          *auto_apply,
+          persistence_namespace,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJPARepository { cwd, source, entity_file_path, b64_superclass_source } => {
+        let entity_file_b64_src = &source.resolve()?;
         let response = create_jpa_repository_command::execute(
           cwd.as_path(),
           entity_file_b64_src,
@@ -494,10 +1157,204 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPAEntityBasicField {
+      Commands::CreateSpringRestController {
+        cwd,
+        package_name,
+        file_name,
+        base_path,
+        entity_fqcn,
+        service_fqcn,
+        id_type_fqcn,
+      } => {
+        let response = create_spring_rest_controller_command::execute(
+          cwd.as_path(),
+          package_name,
+          file_name,
+          base_path,
+          entity_fqcn,
+          service_fqcn,
+          id_type_fqcn.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ChangeJPAEnumStorage {
         cwd,
         entity_file_path,
-        entity_file_b64_src,
+        source,
+        field_name,
+        enum_type_storage,
+        field_length,
+        persistence_namespace,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = change_jpa_enum_storage_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          enum_type_storage,
+          *field_length,
+          persistence_namespace,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ChangeJPAFieldType {
+        cwd,
+        entity_file_path,
+        source,
+        field_name,
+        field_type,
+        field_type_package_name,
+        persistence_namespace,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = change_jpa_field_type_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          field_type,
+          field_type_package_name.as_deref(),
+          persistence_namespace,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ModifyJPAFieldConstraints {
+        cwd,
+        entity_file_path,
+        source,
+        field_name,
+        nullable,
+        unique,
+        persistence_namespace,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = modify_jpa_field_constraints_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          *nullable,
+          *unique,
+          persistence_namespace,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::MoveJPAField {
+        cwd,
+        entity_file_path,
+        source,
+        superclass_file_path,
+        field_name,
+        format_java,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = move_jpa_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          superclass_file_path.as_path(),
+          field_name,
+          *format_java,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::ImplementInterface {
+        cwd,
+        class_file_path,
+        source,
+        interface_fqcn,
+        stub_methods,
+      } => {
+        let class_file_b64_src = &source.resolve()?;
+        let response = implement_interface_command::execute(
+          cwd.as_path(),
+          class_file_b64_src,
+          class_file_path.as_path(),
+          interface_fqcn,
+          *stub_methods,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::AddClassAnnotation {
+        cwd,
+        class_file_path,
+        source,
+        annotation_fqcn,
+        attributes,
+      } => {
+        let class_file_b64_src = &source.resolve()?;
+        let response = add_class_annotation_command::execute(
+          cwd.as_path(),
+          class_file_b64_src,
+          class_file_path.as_path(),
+          annotation_fqcn,
+          attributes,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GenerateAccessors {
+        cwd,
+        entity_file_path,
+        source,
+        field_names,
+        fluent_accessors,
+        fluent_setter_naming,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = generate_accessors_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_names,
+          *fluent_accessors,
+          fluent_setter_naming,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::GenerateConstructors { cwd, entity_file_path, source, no_args, all_args } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = generate_constructors_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          *no_args,
+          *all_args,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::RemoveJPAField {
+        cwd,
+        entity_file_path,
+        source,
+        field_name,
+        cleanup_inverse,
+        format_java,
+      } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = remove_jpa_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          *cleanup_inverse,
+          *format_java,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::RenameJPAEntity { cwd, entity_file_path, new_name, rename_repository } => {
+        let response = rename_jpa_entity_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_path(),
+          new_name,
+          *rename_repository,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJPAEntityBasicField(CreateJPAEntityBasicFieldArgs {
+        cwd,
+        entity_file_path,
+        source,
         field_name,
         field_type,
         field_type_package_name,
@@ -509,7 +1366,23 @@ impl Commands {
         field_unique,
         field_nullable,
         field_large_object,
-      } => {
+        basic_fetch,
+        field_natural_id,
+        converter_class,
+        naming_strategy,
+        explicit_column_name,
+        field_access,
+        after_field,
+        at_top,
+        javadoc,
+        mark_generated,
+        generated_style,
+        default_value,
+        column_definition,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let entity_file_b64_src = &source.resolve()?;
         let field_config = BasicFieldConfig {
           field_name: field_name.clone(),
           field_type: field_type.clone(),
@@ -522,6 +1395,20 @@ impl Commands {
           field_unique: *field_unique,
           field_nullable: *field_nullable,
           field_large_object: *field_large_object,
+          basic_fetch: basic_fetch.clone(),
+          field_natural_id: *field_natural_id,
+          field_converter_class: converter_class.clone(),
+          column_naming_strategy: naming_strategy.clone(),
+          explicit_column_name: *explicit_column_name,
+          field_access: field_access.clone(),
+          after_field: after_field.clone(),
+          at_top: *at_top,
+          javadoc: javadoc.clone(),
+          mark_generated: mark_generated.then(|| generated_style.clone()),
+          default_value: default_value.clone(),
+          column_definition: column_definition.clone(),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
         };
         let response = create_jpa_entity_basic_field_command::execute(
           cwd.as_path(),
@@ -531,11 +1418,22 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPAEntityIdField {
+      Commands::CreateJPAEntityFields { cwd, entity_file_path, source, fields } => {
+        let entity_file_b64_src = &source.resolve()?;
+        let response = create_jpa_entity_fields_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          fields,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJPAEntityIdField(CreateJPAEntityIdFieldArgs {
         cwd,
-        entity_file_b64_src,
+        source,
         entity_file_path,
         field_name,
+        id_field_naming,
         field_type,
         field_type_package_name,
         field_id_generation,
@@ -545,9 +1443,23 @@ impl Commands {
         field_initial_value,
         field_allocation_size,
         field_nullable,
-      } => {
+        naming_strategy,
+        field_access,
+        after_field,
+        at_top,
+        javadoc,
+        mark_generated,
+        generated_style,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let entity_file_b64_src = &source.resolve()?;
+        let entity_type =
+          entity_file_path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
         let field_config = IdFieldConfig {
-          field_name: field_name.clone(),
+          field_name: field_name
+            .clone()
+            .unwrap_or_else(|| id_field_naming.field_name(&entity_type)),
           field_type: field_type.clone(),
           field_type_package_name: field_type_package_name.clone(),
           field_id_generation: field_id_generation.clone(),
@@ -557,6 +1469,14 @@ impl Commands {
           field_initial_value: *field_initial_value,
           field_allocation_size: *field_allocation_size,
           field_nullable: *field_nullable,
+          column_naming_strategy: naming_strategy.clone(),
+          field_access: field_access.clone(),
+          after_field: after_field.clone(),
+          at_top: *at_top,
+          javadoc: javadoc.clone(),
+          mark_generated: mark_generated.then(|| generated_style.clone()),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
         };
         let response = create_jpa_entity_id_field_command::execute(
           cwd.as_path(),
@@ -566,9 +1486,48 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPAEntityEnumField {
+      Commands::CreateJPAEntityEmbeddedField(CreateJPAEntityEmbeddedFieldArgs {
         cwd,
-        entity_file_b64_src,
+        source,
+        entity_file_path,
+        field_name,
+        embeddable_type,
+        embeddable_package_name,
+        attribute_overrides,
+        field_access,
+        after_field,
+        at_top,
+        javadoc,
+        mark_generated,
+        generated_style,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let entity_file_b64_src = &source.resolve()?;
+        let field_config = EmbeddedFieldConfig {
+          field_name: field_name.clone(),
+          embeddable_type: embeddable_type.clone(),
+          embeddable_package_name: embeddable_package_name.clone(),
+          attribute_overrides: attribute_overrides.clone(),
+          field_access: field_access.clone(),
+          after_field: after_field.clone(),
+          at_top: *at_top,
+          javadoc: javadoc.clone(),
+          mark_generated: mark_generated.then(|| generated_style.clone()),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
+        };
+        let response = create_jpa_entity_embedded_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      Commands::CreateJPAEntityEnumField(CreateJPAEntityEnumFieldArgs {
+        cwd,
+        source,
         entity_file_path,
         field_name,
         enum_type,
@@ -577,7 +1536,19 @@ impl Commands {
         field_length,
         field_nullable,
         field_unique,
-      } => {
+        naming_strategy,
+        explicit_column_name,
+        field_access,
+        after_field,
+        at_top,
+        javadoc,
+        mark_generated,
+        generated_style,
+        default_value,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let entity_file_b64_src = &source.resolve()?;
         let field_config = EnumFieldConfig {
           field_name: field_name.clone(),
           enum_type: enum_type.clone(),
@@ -586,6 +1557,16 @@ impl Commands {
           field_length: *field_length,
           field_nullable: *field_nullable,
           field_unique: *field_unique,
+          column_naming_strategy: naming_strategy.clone(),
+          explicit_column_name: *explicit_column_name,
+          field_access: field_access.clone(),
+          after_field: after_field.clone(),
+          at_top: *at_top,
+          javadoc: javadoc.clone(),
+          mark_generated: mark_generated.then(|| generated_style.clone()),
+          default_value: default_value.clone(),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
         };
         let response = create_jpa_entity_enum_field_command::execute(
           cwd.as_path(),
@@ -595,26 +1576,41 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPAOneToOneRelationship {
+      Commands::CreateJPAOneToOneRelationship(CreateJPAOneToOneRelationshipArgs {
         cwd,
-        owning_side_entity_file_b64_src,
+        source,
         owning_side_entity_file_path,
         owning_side_field_name,
         inverse_side_field_name,
         inverse_field_type,
+        inverse_field_package,
         mapping_type,
+        fetch_type,
         owning_side_cascades,
         inverse_side_cascades,
         owning_side_other,
         inverse_side_other,
-      } => {
+        join_column_name,
+        referenced_column_name,
+        maps_id,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let owning_side_entity_file_b64_src = &source.resolve()?;
         let config = OneToOneFieldConfig {
           inverse_field_type: inverse_field_type.clone(),
+          inverse_field_package: inverse_field_package.clone(),
           mapping_type: mapping_type.clone(),
+          fetch_type: fetch_type.clone(),
           owning_side_cascades: owning_side_cascades.clone(),
           inverse_side_cascades: inverse_side_cascades.clone(),
           owning_side_other: owning_side_other.clone(),
           inverse_side_other: inverse_side_other.clone(),
+          join_column_name: join_column_name.clone(),
+          referenced_column_name: referenced_column_name.clone(),
+          maps_id: maps_id.clone(),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
         };
         let response = create_jpa_one_to_one_relationship_command::execute(
           cwd.as_path(),
@@ -626,30 +1622,51 @@ impl Commands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      Commands::CreateJPAManyToOneRelationship {
+      Commands::CreateJPAManyToOneRelationship(CreateJPAManyToOneRelationshipArgs {
         cwd,
-        owning_side_entity_file_b64_src,
+        source,
         owning_side_entity_file_path,
         owning_side_field_name,
         inverse_side_field_name,
         inverse_field_type,
+        inverse_field_package,
         fetch_type,
         collection_type,
+        declared_collection_type,
         mapping_type,
         owning_side_cascades,
         inverse_side_cascades,
         owning_side_other,
         inverse_side_other,
-      } => {
+        join_column_name,
+        referenced_column_name,
+        maps_id,
+        initialize_collection,
+        order_by,
+        order_column,
+        persistence_namespace,
+        format_java,
+      }) => {
+        let owning_side_entity_file_b64_src = &source.resolve()?;
         let config = ManyToOneFieldConfig {
           inverse_field_type: inverse_field_type.clone(),
+          inverse_field_package: inverse_field_package.clone(),
           fetch_type: fetch_type.clone(),
           collection_type: collection_type.clone(),
+          declared_collection_type: declared_collection_type.clone(),
           mapping_type: mapping_type.clone(),
           owning_side_cascades: owning_side_cascades.clone(),
           inverse_side_cascades: inverse_side_cascades.clone(),
           owning_side_other: owning_side_other.clone(),
           inverse_side_other: inverse_side_other.clone(),
+          join_column_name: join_column_name.clone(),
+          referenced_column_name: referenced_column_name.clone(),
+          maps_id: maps_id.clone(),
+          initialize_collection: *initialize_collection,
+          order_by: order_by.clone(),
+          order_column: order_column.clone(),
+          persistence_namespace: persistence_namespace.clone(),
+          format_java: *format_java,
         };
         let response = create_jpa_many_to_one_relationship_command::execute(
           cwd.as_path(),
@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::modify_jpa_field_constraints_service::run,
+  common::types::persistence_namespace::PersistenceNamespace,
+  responses::{file_response::FileResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  nullable: Option<bool>,
+  unique: Option<bool>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("modify-jpa-field-constraints");
+  // Note: Like the field creation commands, the entity file path is trusted as it comes from
+  // the user's editor context and is not validated against cwd.
+  match run(
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+    nullable,
+    unique,
+    persistence_namespace,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
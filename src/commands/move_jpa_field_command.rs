@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use crate::{
+  commands::{
+    services::move_jpa_field_service::run,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+  responses::{move_jpa_field_response::MoveJPAFieldResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  superclass_file_path: &Path,
+  field_name: &str,
+  format_java: bool,
+) -> Response<MoveJPAFieldResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("move-jpa-field");
+  // Note: The entity file path is trusted as it comes from the user's editor context, while the
+  // superclass is read from disk and must be inside cwd.
+  let file_path_str = superclass_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Superclass file path security validation failed: {}", error_msg),
+    );
+  }
+  match run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    superclass_file_path,
+    field_name,
+    format_java,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use crate::{
+  commands::services::remove_jpa_field_service::run,
+  responses::{remove_jpa_field_response::RemoveJPAFieldResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  cleanup_inverse: bool,
+  format_java: bool,
+) -> Response<RemoveJPAFieldResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("remove-jpa-field");
+  // Note: The entity file path is trusted as it comes from the user's editor context. The
+  // inverse entity is looked up inside cwd and saved with path security validation.
+  match run(cwd, entity_file_b64_src, entity_file_path, field_name, cleanup_inverse, format_java) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crate::{
+  commands::{
+    services::rename_jpa_entity_service::run,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+  common::utils::naming_util,
+  responses::{rename_jpa_entity_response::RenameJPAEntityResponse, response::Response},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_path: &Path,
+  new_name: &str,
+  rename_repository: bool,
+) -> Response<RenameJPAEntityResponse> {
+  let normalized_new_name = naming_util::to_pascal_case(new_name);
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("rename-jpa-entity");
+  // Security validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path security validation failed: {}", error_msg),
+    );
+  }
+  match run(cwd, entity_file_path, &normalized_new_name, rename_repository) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
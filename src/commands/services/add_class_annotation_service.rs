@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::annotation_service::add_annotation;
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::ensure_class_has_no_syntax_errors;
+use crate::common::services::import_declaration_service::add_import;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::annotation_attribute::AnnotationAttribute;
+use crate::common::types::annotation_types::AnnotationInsertionPosition;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::responses::file_response::FileResponse;
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Names of the annotations placed directly on the class, as written, e.g. `Entity` or
+/// `jakarta.persistence.Entity`.
+fn get_class_annotation_names(ts_file: &TSFile, class_node: Node) -> Vec<String> {
+  let mut cursor = class_node.walk();
+  let Some(modifiers_node) = class_node.children(&mut cursor).find(|c| c.kind() == "modifiers")
+  else {
+    return Vec::new();
+  };
+  let mut modifiers_cursor = modifiers_node.walk();
+  modifiers_node
+    .children(&mut modifiers_cursor)
+    .filter(|child| matches!(child.kind(), "annotation" | "marker_annotation"))
+    .filter_map(|annotation_node| annotation_node.child_by_field_name("name"))
+    .filter_map(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(str::to_string)
+    .collect()
+}
+
+/// Renders the annotation with its attributes in the given order, e.g. `@Table(name = "users")`.
+fn render_annotation(
+  annotation_name: &str,
+  attributes: &[AnnotationAttribute],
+) -> Result<String, String> {
+  for (index, attribute) in attributes.iter().enumerate() {
+    if attributes[..index].iter().any(|previous| previous.name == attribute.name) {
+      return Err(format!("Attribute '{}' is given twice", attribute.name));
+    }
+  }
+  if attributes.is_empty() {
+    return Ok(format!("@{}", annotation_name));
+  }
+  let arguments: Vec<String> = attributes
+    .iter()
+    .map(|attribute| format!("{} = {}", attribute.name, attribute.value))
+    .collect();
+  Ok(format!("@{}({})", annotation_name, arguments.join(", ")))
+}
+
+/// Adds the annotation `annotation_fqcn`, with the given attributes, right above the public class
+/// declaration and imports it unless it is in `java.lang` or the class's own package. Fails when
+/// the class already carries an annotation of that name.
+pub fn run(
+  class_file_b64_src: &str,
+  class_file_path: &Path,
+  annotation_fqcn: &str,
+  attributes: &[AnnotationAttribute],
+) -> Result<FileResponse, String> {
+  // Step 1: Parse the class and validate it
+  let mut ts_file = TSFile::from_base64_source_code(class_file_b64_src);
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  ensure_class_has_no_syntax_errors(&ts_file, class_node.start_byte())?;
+  let class_byte_position = class_node.start_byte();
+  let class_name = get_class_declaration_name_node(&ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .ok_or_else(|| "Unable to get public class name".to_string())?
+    .to_string();
+  let (annotation_package, annotation_name) =
+    annotation_fqcn.rsplit_once('.').unwrap_or(("", annotation_fqcn));
+  // Step 2: Refuse a second annotation of the same name
+  if get_class_annotation_names(&ts_file, class_node)
+    .iter()
+    .any(|name| name == annotation_name || name == annotation_fqcn)
+  {
+    return Err(format!("{} is already annotated with @{}", class_name, annotation_name));
+  }
+  // Step 3: Add the annotation
+  let annotation_text = render_annotation(annotation_name, attributes)?;
+  add_annotation(
+    &mut ts_file,
+    class_byte_position,
+    &AnnotationInsertionPosition::AboveScopeDeclaration,
+    &annotation_text,
+  )
+  .ok_or_else(|| format!("Failed to add @{} annotation", annotation_name))?;
+  // Step 4: Add the import
+  if !annotation_package.is_empty()
+    && annotation_package != "java.lang"
+    && annotation_package != get_package_name(&ts_file)
+  {
+    add_import(
+      &mut ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      annotation_package,
+      annotation_name,
+    );
+  }
+  // Step 5: Save the class
+  ts_file
+    .save_to_existing_file(class_file_path)
+    .map_err(|e| format!("Unable to save class file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(build_file_response(&ts_file))
+}
@@ -0,0 +1,52 @@
+use crate::common::ts_file::TSFile;
+use crate::common::types::text_edit::TextEdit;
+use crate::responses::apply_edits_response::ApplyEditsResponse;
+use crate::responses::edit_record_response::EditRecord;
+
+/// Sorts the edits by position and rejects overlapping ones. Two insertions at the same byte
+/// are rejected too, as the order they should end up in is ambiguous.
+fn sort_and_check_overlaps(mut edits: Vec<TextEdit>) -> Result<Vec<TextEdit>, String> {
+  edits.sort_by_key(|edit| (edit.start_byte, edit.old_end_byte));
+  for pair in edits.windows(2) {
+    let (previous, next) = (&pair[0], &pair[1]);
+    if next.start_byte < previous.old_end_byte || next.start_byte == previous.start_byte {
+      return Err(format!(
+        "Edits {}..{} and {}..{} overlap",
+        previous.start_byte, previous.old_end_byte, next.start_byte, next.old_end_byte
+      ));
+    }
+  }
+  Ok(edits)
+}
+
+/// Applies edits whose byte ranges all refer to the original source, returning the edited
+/// source and the edits reversing them.
+pub fn run(b64_source_code: &str, edits_json: &str) -> Result<ApplyEditsResponse, String> {
+  // Step 1: Parse the source and the edits
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code);
+  let edits: Vec<TextEdit> =
+    serde_json::from_str(edits_json).map_err(|e| format!("Invalid edits JSON: {}", e))?;
+  // Step 2: Order the edits and reject overlapping ones
+  let edits = sort_and_check_overlaps(edits)?;
+  // Step 3: Apply the edits from last to first so earlier offsets stay valid
+  let original_source = ts_file.source_code.clone();
+  for edit in edits.iter().rev() {
+    ts_file.apply_edit(edit.start_byte, edit.old_end_byte, &edit.new_text)?;
+  }
+  ts_file.reparse();
+  // Step 4: Build the reverse edits, shifting each one by the size changes before it
+  let mut shift: isize = 0;
+  let mut reverse_edits = Vec::with_capacity(edits.len());
+  for edit in &edits {
+    let start_byte = edit.start_byte.saturating_add_signed(shift);
+    let old_text = original_source[edit.start_byte..edit.old_end_byte].to_string();
+    reverse_edits.push(EditRecord {
+      start_byte,
+      old_end_byte: start_byte + edit.new_text.len(),
+      new_text: old_text.clone(),
+      old_text: edit.new_text.clone(),
+    });
+    shift += edit.new_text.len() as isize - old_text.len() as isize;
+  }
+  Ok(ApplyEditsResponse { source_code: ts_file.source_code, reverse_edits })
+}
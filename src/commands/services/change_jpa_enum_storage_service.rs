@@ -0,0 +1,254 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_all_jpa_enums_service;
+use crate::common::services::annotation_service::{
+  add_annotation, add_annotation_argument, find_annotation_node_by_name,
+  find_annotation_value_node_by_key, get_annotation_argument_pair_nodes, remove_annotation,
+  remove_annotation_argument,
+};
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::common::services::import_declaration_service::{
+  add_import, find_imported_package_scope, is_class_name_referenced, remove_import,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::annotation_types::AnnotationInsertionPosition;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::types::java_enum_type::JavaEnumType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::responses::change_jpa_enum_storage_response::ChangeJPAEnumStorageResponse;
+use crate::responses::file_response::FileResponse;
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field not found: {}", field_name))
+}
+
+/// Returns the start byte of the field annotation named `annotation_name`, if present.
+fn find_field_annotation(
+  ts_file: &TSFile,
+  field_name: &str,
+  annotation_name: &str,
+) -> Option<usize> {
+  let field_node = find_field_node(ts_file, field_name).ok()?;
+  find_annotation_node_by_name(ts_file, field_node, annotation_name).map(|node| node.start_byte())
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+/// Whether the field type resolves, through its import or the entity package, to one of the
+/// enums declared in the project.
+fn is_project_enum_type(cwd: &Path, ts_file: &TSFile, field_type: &str) -> bool {
+  let Ok(enums) = get_all_jpa_enums_service::run(cwd, None) else {
+    return false;
+  };
+  let package_name =
+    find_imported_package_scope(ts_file, field_type).unwrap_or_else(|| get_package_name(ts_file));
+  enums.iter().any(|e| e.enum_type == field_type && e.enum_package_name == package_name)
+}
+
+/// The node holding the `@Enumerated` value, either `value = EnumType.X` or the single
+/// `EnumType.X` argument.
+fn find_enumerated_value_node<'a>(
+  ts_file: &'a TSFile,
+  annotation_node: Node<'a>,
+) -> Option<Node<'a>> {
+  if let Some(value_node) = find_annotation_value_node_by_key(ts_file, annotation_node, "value") {
+    return Some(value_node);
+  }
+  let arguments_node = annotation_node.child_by_field_name("arguments")?;
+  let mut cursor = arguments_node.walk();
+  let mut arguments = arguments_node.named_children(&mut cursor);
+  let argument = arguments.next()?;
+  if argument.kind() == "element_value_pair" || arguments.next().is_some() {
+    return None;
+  }
+  Some(argument)
+}
+
+/// Reads the declared storage, `ORDINAL` being the JPA default when `@Enumerated` has no value.
+fn get_current_storage(ts_file: &TSFile, field_name: &str) -> String {
+  find_field_annotation(ts_file, field_name, "Enumerated")
+    .and_then(|byte| ts_file.get_named_node_at_byte_position(byte))
+    .and_then(|annotation_node| find_enumerated_value_node(ts_file, annotation_node))
+    .and_then(|value_node| ts_file.get_text_from_node(&value_node))
+    .map(|value| value.trim().rsplit('.').next().unwrap_or(value).to_string())
+    .unwrap_or_else(|| JavaEnumType::Ordinal.as_str().to_string())
+}
+
+fn set_enumerated_storage(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  target_storage: &JavaEnumType,
+) -> Result<(), String> {
+  let new_value = format!("EnumType.{}", target_storage.as_str());
+  let value_range = find_field_annotation(ts_file, field_name, "Enumerated")
+    .and_then(|byte| ts_file.get_named_node_at_byte_position(byte))
+    .and_then(|annotation_node| find_enumerated_value_node(ts_file, annotation_node))
+    .map(|value_node| (value_node.start_byte(), value_node.end_byte()));
+  if let Some((start_byte, end_byte)) = value_range {
+    ts_file.replace_text_by_range(start_byte, end_byte, &new_value);
+    return Ok(());
+  }
+  // Bare or missing `@Enumerated`: write it the way the enum field creator does
+  if let Some(annotation_byte) = find_field_annotation(ts_file, field_name, "Enumerated") {
+    remove_annotation(ts_file, annotation_byte)
+      .ok_or_else(|| "Unable to update @Enumerated annotation".to_string())?;
+  }
+  let field_byte = find_field_node(ts_file, field_name)?.start_byte();
+  add_annotation(
+    ts_file,
+    field_byte,
+    &AnnotationInsertionPosition::BeforeFirstAnnotation,
+    &format!("@Enumerated(value = {})", new_value),
+  )
+  .ok_or_else(|| "Unable to add @Enumerated annotation".to_string())?;
+  Ok(())
+}
+
+/// `length` only applies to `STRING` storage: it is set when given and dropped for `ORDINAL`,
+/// together with a `@Column` left without arguments.
+fn adjust_column_length(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  target_storage: &JavaEnumType,
+  field_length: Option<u16>,
+) -> Result<(), String> {
+  let column_byte = find_field_annotation(ts_file, field_name, "Column");
+  let length_range = column_byte
+    .and_then(|byte| ts_file.get_named_node_at_byte_position(byte))
+    .and_then(|column_node| find_annotation_value_node_by_key(ts_file, column_node, "length"))
+    .map(|value_node| (value_node.start_byte(), value_node.end_byte()));
+  match (target_storage, field_length) {
+    (JavaEnumType::String, Some(length)) => match (column_byte, length_range) {
+      (_, Some((start_byte, end_byte))) => {
+        ts_file.replace_text_by_range(start_byte, end_byte, &length.to_string());
+      }
+      (Some(column_byte), None) => {
+        add_annotation_argument(ts_file, column_byte, "length", &length.to_string())
+          .ok_or_else(|| "Unable to add @Column(length)".to_string())?;
+      }
+      (None, None) => {
+        let field_byte = find_field_node(ts_file, field_name)?.start_byte();
+        add_annotation(
+          ts_file,
+          field_byte,
+          &AnnotationInsertionPosition::AboveScopeDeclaration,
+          &format!("@Column(length = {})", length),
+        )
+        .ok_or_else(|| "Unable to add @Column annotation".to_string())?;
+      }
+    },
+    (JavaEnumType::Ordinal, _) => {
+      if let (Some(column_byte), Some(_)) = (column_byte, length_range) {
+        remove_annotation_argument(ts_file, column_byte, "length")
+          .ok_or_else(|| "Unable to remove @Column(length)".to_string())?;
+        let is_empty =
+          ts_file.get_named_node_at_byte_position(column_byte).is_some_and(|column_node| {
+            get_annotation_argument_pair_nodes(ts_file, column_node).is_empty()
+          });
+        if is_empty {
+          remove_annotation(ts_file, column_byte);
+        }
+      }
+    }
+    (JavaEnumType::String, None) => {}
+  }
+  Ok(())
+}
+
+fn update_imports(ts_file: &mut TSFile, persistence_namespace: &PersistenceNamespace) {
+  let persistence_package = persistence_namespace.package();
+  for class_name in ["Enumerated", "EnumType", "Column"] {
+    let is_referenced = is_class_name_referenced(ts_file, class_name);
+    if is_referenced {
+      add_import(
+        ts_file,
+        &ImportInsertionPosition::AfterLastImport,
+        persistence_package,
+        class_name,
+      );
+    } else {
+      remove_import(ts_file, persistence_package, class_name);
+    }
+  }
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Switches an enum field between `EnumType.STRING` and `EnumType.ORDINAL` storage. The field
+/// must carry `@Enumerated` or be typed by an enum declared in the project.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  target_storage: &JavaEnumType,
+  field_length: Option<u16>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<ChangeJPAEnumStorageResponse, String> {
+  // Step 1: Parse entity file and check the field is an enum field
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let field_type = {
+    let field_node = find_field_node(&entity_ts_file, field_name)?;
+    field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| entity_ts_file.get_text_from_node(&type_node))
+      .unwrap_or_default()
+      .to_string()
+  };
+  if find_field_annotation(&entity_ts_file, field_name, "Enumerated").is_none()
+    && !is_project_enum_type(cwd, &entity_ts_file, &field_type)
+  {
+    return Err(format!("Field '{}' is not an enum field", field_name));
+  }
+  if matches!(target_storage, JavaEnumType::Ordinal) && field_length.is_some() {
+    return Err("Length only applies to STRING storage".to_string());
+  }
+  let old_storage = get_current_storage(&entity_ts_file, field_name);
+  // Step 2: Update @Enumerated and @Column(length)
+  set_enumerated_storage(&mut entity_ts_file, field_name, target_storage)?;
+  adjust_column_length(&mut entity_ts_file, field_name, target_storage, field_length)?;
+  // Step 3: Add the imports now needed and drop the unused ones
+  update_imports(&mut entity_ts_file, persistence_namespace);
+  // Step 4: Save file (use save_to_existing_file since we're modifying an existing entity file)
+  entity_ts_file
+    .save_to_existing_file(entity_file_path)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 5: Build and return response
+  let new_storage = target_storage.as_str().to_string();
+  let mut warnings = Vec::new();
+  if old_storage != new_storage {
+    warnings.push(format!(
+      "Existing rows store {} values; migrate the column data to {}",
+      old_storage, new_storage
+    ));
+  }
+  Ok(ChangeJPAEnumStorageResponse {
+    entity: build_file_response(&entity_ts_file),
+    old_storage,
+    new_storage,
+    warnings,
+  })
+}
@@ -0,0 +1,281 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::annotation_service::{
+  find_annotation_node_by_name, find_annotation_value_node_by_key, remove_annotation,
+  remove_annotation_argument,
+};
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::common::services::import_declaration_service::{
+  add_import, find_imported_package_scope, is_class_name_referenced, remove_import,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::types::java_basic_types::JavaBasicType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::responses::basic_java_type_response::JavaBasicTypeResponse;
+use crate::responses::change_jpa_field_type_response::ChangeJPAFieldTypeResponse;
+use crate::responses::file_response::FileResponse;
+
+/// Types that map to a `TemporalType` through `@Temporal`.
+const TEMPORAL_AWARE_TYPES: [&str; 2] = ["java.util.Date", "java.util.Calendar"];
+
+/// JPA annotation imports that may become unused once an annotation is removed.
+const PERSISTENCE_ANNOTATION_IMPORTS: [&str; 3] = ["Temporal", "TemporalType", "Lob"];
+
+/// Hibernate annotation imports that may become unused once an annotation is removed.
+const HIBERNATE_ANNOTATION_IMPORTS: [(&str, &str); 2] = [
+  ("org.hibernate.annotations", "TimeZoneStorage"),
+  ("org.hibernate.annotations", "TimeZoneStorageType"),
+];
+
+/// The JPA mapping capabilities of the new field type.
+struct FieldTypeCapabilities {
+  pub supports_length: bool,
+  pub supports_precision_and_scale: bool,
+  pub supports_temporal: bool,
+  pub supports_time_zone_storage: bool,
+  pub supports_lob: bool,
+}
+
+/// Matches the type against a basic type list by its fully qualified name, or by its simple
+/// name when no package was provided.
+fn matches_any_type(types: &[JavaBasicTypeResponse], field_type: &str, full_type: &str) -> bool {
+  types.iter().any(|t| t.id == full_type || (field_type == full_type && t.name == field_type))
+}
+
+fn get_field_type_capabilities(field_type: &str, full_type: &str) -> FieldTypeCapabilities {
+  let temporal_types: Vec<JavaBasicTypeResponse> = TEMPORAL_AWARE_TYPES
+    .iter()
+    .map(|id| JavaBasicTypeResponse {
+      id: id.to_string(),
+      name: id.rsplit('.').next().unwrap_or(id).to_string(),
+      package_path: id.rsplit_once('.').map(|(package, _)| package.to_string()),
+    })
+    .collect();
+  FieldTypeCapabilities {
+    supports_length: matches_any_type(
+      &JavaBasicType::TypesWithLength.get_types_with_length(),
+      field_type,
+      full_type,
+    ),
+    supports_precision_and_scale: matches_any_type(
+      &JavaBasicType::TypesWithPrecisionAndScale.get_types_with_precision_scale(),
+      field_type,
+      full_type,
+    ),
+    supports_temporal: matches_any_type(&temporal_types, field_type, full_type),
+    supports_time_zone_storage: matches_any_type(
+      &JavaBasicType::TypesWithTimeZoneStorage.get_types_with_time_zone_storage(),
+      field_type,
+      full_type,
+    ),
+    supports_lob: matches_any_type(
+      &JavaBasicType::TypesWithExtraOther.get_types_with_extra_other(),
+      field_type,
+      full_type,
+    ),
+  }
+}
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field not found: {}", field_name))
+}
+
+/// Returns the start byte of the field annotation named `annotation_name`, if present.
+fn find_field_annotation(
+  ts_file: &TSFile,
+  field_name: &str,
+  annotation_name: &str,
+) -> Option<usize> {
+  let field_node = find_field_node(ts_file, field_name).ok()?;
+  find_annotation_node_by_name(ts_file, field_node, annotation_name).map(|node| node.start_byte())
+}
+
+fn remove_column_argument_if_present(ts_file: &mut TSFile, field_name: &str, key: &str) -> bool {
+  let Some(column_byte) = find_field_annotation(ts_file, field_name, "Column") else {
+    return false;
+  };
+  let has_argument = ts_file
+    .get_named_node_at_byte_position(column_byte)
+    .and_then(|column_node| find_annotation_value_node_by_key(ts_file, column_node, key))
+    .is_some();
+  has_argument && remove_annotation_argument(ts_file, column_byte, key).is_some()
+}
+
+fn remove_field_annotation_if_present(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  annotation_name: &str,
+) -> bool {
+  find_field_annotation(ts_file, field_name, annotation_name)
+    .and_then(|annotation_byte| remove_annotation(ts_file, annotation_byte))
+    .is_some()
+}
+
+/// Drops the annotations and `@Column` arguments that no longer apply to the new type and
+/// returns a warning for every adjustment made or suggested.
+fn adjust_annotations(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  new_field_type: &str,
+  capabilities: &FieldTypeCapabilities,
+) -> Vec<String> {
+  let mut warnings = Vec::new();
+  if !capabilities.supports_length
+    && remove_column_argument_if_present(ts_file, field_name, "length")
+  {
+    warnings.push(format!("Removed @Column(length) because {} has no length", new_field_type));
+  }
+  if capabilities.supports_precision_and_scale {
+    let has_precision = find_field_annotation(ts_file, field_name, "Column")
+      .and_then(|byte| ts_file.get_named_node_at_byte_position(byte))
+      .and_then(|column_node| find_annotation_value_node_by_key(ts_file, column_node, "precision"))
+      .is_some();
+    if !has_precision {
+      warnings.push(format!(
+        "@Column(precision, scale) now apply to {}; defaults are precision = 19 and scale = 2",
+        new_field_type
+      ));
+    }
+  } else {
+    for key in ["precision", "scale"] {
+      if remove_column_argument_if_present(ts_file, field_name, key) {
+        warnings.push(format!(
+          "Removed @Column({}) because {} has no precision or scale",
+          key, new_field_type
+        ));
+      }
+    }
+  }
+  if capabilities.supports_temporal {
+    if find_field_annotation(ts_file, field_name, "Temporal").is_none() {
+      warnings.push(format!("{} may need @Temporal to set its TemporalType", new_field_type));
+    }
+  } else if remove_field_annotation_if_present(ts_file, field_name, "Temporal") {
+    warnings
+      .push(format!("Removed @Temporal because {} is not a legacy date type", new_field_type));
+  }
+  if !capabilities.supports_time_zone_storage
+    && remove_field_annotation_if_present(ts_file, field_name, "TimeZoneStorage")
+  {
+    warnings.push(format!(
+      "Removed @TimeZoneStorage because {} does not store a time zone",
+      new_field_type
+    ));
+  }
+  if !capabilities.supports_lob && remove_field_annotation_if_present(ts_file, field_name, "Lob") {
+    warnings.push(format!("Removed @Lob because {} cannot be a large object", new_field_type));
+  }
+  warnings
+}
+
+/// Strips generic arguments and array dimensions from a type, e.g. `List<String>` -> `List`.
+fn get_simple_type_name(field_type: &str) -> &str {
+  field_type.split(['<', '[']).next().unwrap_or(field_type).trim()
+}
+
+fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
+  let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+  let file_package_node = get_package_declaration_node(ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity's package node".to_string())?;
+  let file_package_scope_node = get_package_scope_node(ts_file, file_package_node);
+  let file_package_name = file_package_scope_node
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or("")
+    .to_string();
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+pub fn run(
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  field_type: &str,
+  field_type_package_name: Option<&str>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<ChangeJPAFieldTypeResponse, String> {
+  // Step 1: Parse entity file and find the field
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let old_field_type = {
+    let field_node = find_field_node(&entity_ts_file, field_name)?;
+    let type_node = field_node
+      .child_by_field_name("type")
+      .ok_or_else(|| "Unable to get field type node".to_string())?;
+    entity_ts_file.get_text_from_node(&type_node).unwrap_or_default().to_string()
+  };
+  let old_simple_type = get_simple_type_name(&old_field_type).to_string();
+  let old_type_package_name = find_imported_package_scope(&entity_ts_file, &old_simple_type);
+  // Step 2: Re-evaluate JPA annotations for the new type
+  let full_type = match field_type_package_name {
+    Some(package_name) => format!("{}.{}", package_name, field_type),
+    None => field_type.to_string(),
+  };
+  let capabilities = get_field_type_capabilities(field_type, &full_type);
+  let mut warnings = adjust_annotations(&mut entity_ts_file, field_name, field_type, &capabilities);
+  // Step 3: Replace the field type
+  let (type_start_byte, type_end_byte) = {
+    let field_node = find_field_node(&entity_ts_file, field_name)?;
+    let type_node = field_node
+      .child_by_field_name("type")
+      .ok_or_else(|| "Unable to get field type node".to_string())?;
+    (type_node.start_byte(), type_node.end_byte())
+  };
+  entity_ts_file.replace_text_by_range(type_start_byte, type_end_byte, field_type);
+  // Step 4: Swap imports, removing the ones no longer referenced
+  if let Some(package_name) = old_type_package_name {
+    if is_class_name_referenced(&entity_ts_file, &old_simple_type) {
+      warnings.push(format!(
+        "Kept import of {}.{} because it is still referenced",
+        package_name, old_simple_type
+      ));
+    } else {
+      remove_import(&mut entity_ts_file, &package_name, &old_simple_type);
+    }
+  }
+  let persistence_imports = PERSISTENCE_ANNOTATION_IMPORTS
+    .into_iter()
+    .map(|class_name| (persistence_namespace.package(), class_name));
+  for (package_name, class_name) in persistence_imports.chain(HIBERNATE_ANNOTATION_IMPORTS) {
+    if !is_class_name_referenced(&entity_ts_file, class_name) {
+      remove_import(&mut entity_ts_file, package_name, class_name);
+    }
+  }
+  if let Some(package_name) = field_type_package_name
+    && package_name != "java.lang"
+  {
+    let simple_type = get_simple_type_name(field_type);
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      package_name,
+      simple_type,
+    );
+  }
+  // Step 5: Save file (use save_to_existing_file since we're modifying an existing entity file)
+  entity_ts_file
+    .save_to_existing_file(entity_file_path)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(ChangeJPAFieldTypeResponse {
+    entity: build_file_response(&entity_ts_file)?,
+    old_field_type,
+    new_field_type: field_type.to_string(),
+    warnings,
+  })
+}
@@ -0,0 +1,22 @@
+use crate::common::types::name_case::NameCase;
+use crate::common::utils::naming_util::{
+  split_words, to_camel_case, to_kebab_case, to_pascal_case, to_screaming_snake_case, to_snake_case,
+};
+use crate::responses::convert_name_response::ConvertNameResponse;
+
+/// Converts `name` to the target case with the same word splitting the generators use, so
+/// editors can preview derived field, column and table names.
+pub fn run(name: &str, to_case: &NameCase) -> Result<ConvertNameResponse, String> {
+  let words = split_words(name);
+  if words.is_empty() {
+    return Err("Name must contain at least one word".to_string());
+  }
+  let converted_name = match to_case {
+    NameCase::Camel => to_camel_case(name),
+    NameCase::Pascal => to_pascal_case(name),
+    NameCase::Snake => to_snake_case(name),
+    NameCase::ScreamingSnake => to_screaming_snake_case(name),
+    NameCase::Kebab => to_kebab_case(name),
+  };
+  Ok(ConvertNameResponse { converted_name, words })
+}
@@ -4,7 +4,10 @@ use crate::{
   common::{
     ts_file::TSFile,
     types::{java_file_type::JavaFileType, java_source_directory_type::JavaSourceDirectoryType},
-    utils::{case_util, path_security_util::PathSecurityValidator},
+    utils::{
+      naming_util, path_security_util::PathSecurityValidator,
+      project_layout_util::resolve_source_root,
+    },
   },
   responses::file_response::FileResponse,
 };
@@ -26,10 +29,7 @@ pub fn correct_java_file_name(file_name: &str) -> String {
     Some(ext) if ext == "java" => file_name.to_string(),
     _ => match std::path::Path::new(file_name).file_stem() {
       Some(stem) => {
-        format!(
-          "{}.java",
-          case_util::auto_convert_case(&stem.to_string_lossy(), case_util::CaseType::Pascal)
-        )
+        format!("{}.java", naming_util::ensure_pascal_case(&stem.to_string_lossy()))
       }
       None => "Unknown.java".to_string(),
     },
@@ -44,8 +44,10 @@ pub fn build_save_path(
 ) -> Result<std::path::PathBuf, String> {
   // Create path security validator for the current working directory
   let validator = PathSecurityValidator::new(cwd)?;
-  // Build the intended path using the standard Java directory structure
-  let intended_path = source_directory.get_full_path(cwd, package_name).join(corrected_file_name);
+  // Build the intended path using the project's resolved source root
+  let intended_path = resolve_source_root(cwd, source_directory)
+    .join(package_name.replace('.', "/"))
+    .join(corrected_file_name);
   // Validate that the path is contained within the working directory
   validator
     .validate_path_containment(&intended_path)
@@ -68,15 +70,24 @@ fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileRespo
     .map(|p| p.to_string_lossy().to_string())
     .ok_or("Failed to get file path")?;
   let file_package_name = package_name.to_string();
-  Ok(FileResponse { file_type: file_type_str, file_path, file_package_name })
+  Ok(FileResponse {
+    file_type: file_type_str,
+    file_path,
+    file_package_name,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
 }
 
+/// Creates a Java file from the template of `file_type`. An existing file is an error unless
+/// `force` is set, in which case it is overwritten.
 pub fn run(
   cwd: &Path,
   package_name: &str,
   file_name: &str,
   file_type: &JavaFileType,
   source_directory: &JavaSourceDirectoryType,
+  force: bool,
 ) -> Result<FileResponse, String> {
   // Step 1: Generate file template
   let file_template = generate_file_template(file_type, package_name, file_name);
@@ -86,8 +97,8 @@ pub fn run(
   let corrected_file_name = correct_java_file_name(file_name);
   // Step 4: Build save path with security validation
   let save_path = build_save_path(source_directory, cwd, package_name, &corrected_file_name)?;
-  // Step 5: Check if file exists before saving
-  if save_path.exists() {
+  // Step 5: Check if file exists before saving, unless it should be overwritten
+  if save_path.exists() && !force {
     return Err(format!("File already exists: {}", save_path.display()));
   }
   save_ts_file(&mut ts_file, &save_path, cwd)?;
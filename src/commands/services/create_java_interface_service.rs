@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use crate::commands::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file,
+};
+use crate::commands::validators::java_method_spec_validator::{
+  JavaMethodSpec, parse_java_method_spec,
+};
+use crate::commands::validators::java_type_validator::JavaTypeReference;
+use crate::common::services::formatting_service::member_separator;
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_basic_types::JavaBasicType;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::indent_util::apply_indent_override;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+
+/// Resolves the package of a type written by simple name from the basic types and the
+/// `java.util` collections, leaving any other type to the interface's own package.
+fn resolve_type_package_name(java_type: &JavaTypeReference) -> Option<String> {
+  if java_type.package_name.is_some() {
+    return java_type.package_name.clone();
+  }
+  if ["Collection", "List", "Set", "SortedSet", "Map", "SortedMap", "Optional"]
+    .contains(&java_type.top_level_name())
+  {
+    return Some("java.util".to_string());
+  }
+  JavaBasicType::AllTypes
+    .get_all_types()
+    .into_iter()
+    .find(|t| t.name == java_type.name)
+    .and_then(|t| t.package_path)
+}
+
+/// Collects the imports of the return and parameter types, including their type arguments,
+/// skipping primitives, `java.lang` and same-package types.
+fn collect_imports(package_name: &str, method_specs: &[JavaMethodSpec]) -> Vec<String> {
+  let mut pending_types: Vec<&JavaTypeReference> = method_specs
+    .iter()
+    .flat_map(|method_spec| {
+      method_spec
+        .return_type
+        .iter()
+        .chain(method_spec.parameters.iter().map(|(parameter_type, _)| parameter_type))
+    })
+    .collect();
+  let mut imports = Vec::new();
+  while let Some(java_type) = pending_types.pop() {
+    if !java_type.is_primitive()
+      && let Some(type_package) = resolve_type_package_name(java_type)
+      && type_package != "java.lang"
+      && type_package != package_name
+    {
+      imports.push(format!("{}.{}", type_package, java_type.top_level_name()));
+    }
+    pending_types.extend(&java_type.type_arguments);
+  }
+  imports.sort();
+  imports.dedup();
+  imports
+}
+
+/// Parses the method specs, rejecting two methods with the same name and parameter types.
+fn parse_method_specs(method_specs: &[String]) -> Result<Vec<JavaMethodSpec>, String> {
+  let mut parsed_specs: Vec<JavaMethodSpec> = Vec::new();
+  for method_spec in method_specs {
+    let parsed_spec = parse_java_method_spec(method_spec)?;
+    let same_signature = |other: &JavaMethodSpec| {
+      other.name == parsed_spec.name
+        && other.parameters.len() == parsed_spec.parameters.len()
+        && other
+          .parameters
+          .iter()
+          .zip(&parsed_spec.parameters)
+          .all(|((other_type, _), (parsed_type, _))| other_type.render() == parsed_type.render())
+    };
+    if parsed_specs.iter().any(same_signature) {
+      return Err(format!("Method '{}' is declared twice", method_spec.trim()));
+    }
+    parsed_specs.push(parsed_spec);
+  }
+  Ok(parsed_specs)
+}
+
+fn generate_interface_template(
+  package_name: &str,
+  interface_name: &str,
+  method_specs: &[JavaMethodSpec],
+) -> String {
+  let mut template = format!("package {};\n\n", package_name);
+  let imports = collect_imports(package_name, method_specs);
+  for import in &imports {
+    template.push_str(&format!("import {};\n", import));
+  }
+  if !imports.is_empty() {
+    template.push('\n');
+  }
+  let methods = method_specs
+    .iter()
+    .map(|method_spec| format!("  {}", method_spec.render()))
+    .collect::<Vec<_>>()
+    .join(&member_separator());
+  template.push_str(&format!("public interface {} {{\n\n{}\n}}\n", interface_name, methods));
+  apply_indent_override(template)
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .ok_or("Failed to get file path")?;
+  Ok(FileResponse {
+    file_type,
+    file_path,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Scaffolds an interface declaring one abstract method per `ReturnType name(ParamType p)` spec,
+/// importing the types they use. Types written by simple name are looked up in the basic types
+/// and `java.util` collections, and otherwise taken to be in the interface's package.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  method_specs: &[String],
+) -> Result<FileResponse, String> {
+  // Normalize the interface name to PascalCase
+  let normalized_interface_name = naming_util::to_pascal_case(file_name);
+  // Step 1: Validate the method specs
+  let method_specs = parse_method_specs(method_specs)?;
+  // Step 2: Build save path with security validation
+  let corrected_file_name = correct_java_file_name(&normalized_interface_name);
+  let save_path =
+    build_save_path(&JavaSourceDirectoryType::Main, cwd, package_name, &corrected_file_name)?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 3: Generate the interface and save it
+  let file_template =
+    generate_interface_template(package_name, &normalized_interface_name, &method_specs);
+  let mut ts_file = create_ts_file(&file_template);
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 4: Build and return the final file response
+  build_file_response(&ts_file, package_name)
+}
@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use crate::commands::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file,
+};
+use crate::commands::services::create_spring_rest_controller_service::split_fqcn;
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::indent_util::apply_indent_override;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+
+/// Collects the imports of the converter, skipping `java.lang` and same-package types.
+fn collect_imports(
+  package_name: &str,
+  type_fqcns: &[&str],
+  persistence_namespace: &PersistenceNamespace,
+) -> Vec<String> {
+  let mut imports: Vec<String> = ["AttributeConverter", "Converter"]
+    .iter()
+    .map(|class_name| format!("{}.{}", persistence_namespace.package(), class_name))
+    .collect();
+  for fqcn in type_fqcns {
+    let (type_package, _) = split_fqcn(fqcn);
+    if !type_package.is_empty() && type_package != "java.lang" && type_package != package_name {
+      imports.push(fqcn.to_string());
+    }
+  }
+  imports.sort();
+  imports.dedup();
+  imports
+}
+
+struct ConverterTemplateParams<'a> {
+  package_name: &'a str,
+  class_name: &'a str,
+  attribute_type_fqcn: &'a str,
+  column_type_fqcn: &'a str,
+  auto_apply: bool,
+  persistence_namespace: &'a PersistenceNamespace,
+}
+
+/// Builds the converter source. `autoApply` is only written out when enabled, as `false` is the
+/// JPA default.
+fn generate_converter_template(params: &ConverterTemplateParams) -> String {
+  let (_, attribute_type) = split_fqcn(params.attribute_type_fqcn);
+  let (_, column_type) = split_fqcn(params.column_type_fqcn);
+  let class_name = params.class_name;
+  let converter_annotation =
+    if params.auto_apply { "@Converter(autoApply = true)" } else { "@Converter" };
+  let mut template = format!("package {};\n\n", params.package_name);
+  for import in collect_imports(
+    params.package_name,
+    &[params.attribute_type_fqcn, params.column_type_fqcn],
+    params.persistence_namespace,
+  ) {
+    template.push_str(&format!("import {};\n", import));
+  }
+  template.push_str(&format!(
+    r#"
+{converter_annotation}
+public class {class_name} implements AttributeConverter<{attribute_type}, {column_type}> {{
+
+  @Override
+  public {column_type} convertToDatabaseColumn({attribute_type} attribute) {{
+    throw new UnsupportedOperationException("Not implemented yet");
+  }}
+
+  @Override
+  public {attribute_type} convertToEntityAttribute({column_type} dbData) {{
+    throw new UnsupportedOperationException("Not implemented yet");
+  }}
+}}
+"#
+  ));
+  apply_indent_override(template)
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .ok_or("Failed to get file path")?;
+  Ok(FileResponse {
+    file_type,
+    file_path,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Scaffolds an `AttributeConverter` between `attribute_type_fqcn` and `column_type_fqcn`, to be
+/// referenced by the `--converter-class` option of the basic field command or applied to every
+/// attribute of that type with `auto_apply`.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  attribute_type_fqcn: &str,
+  column_type_fqcn: &str,
+  auto_apply: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<FileResponse, String> {
+  // Normalize the class name to PascalCase
+  let normalized_class_name = naming_util::to_pascal_case(file_name);
+  // Step 1: Build save path with security validation
+  let corrected_file_name = correct_java_file_name(&normalized_class_name);
+  let save_path =
+    build_save_path(&JavaSourceDirectoryType::Main, cwd, package_name, &corrected_file_name)?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 2: Generate the converter and save it
+  let file_template = generate_converter_template(&ConverterTemplateParams {
+    package_name,
+    class_name: &normalized_class_name,
+    attribute_type_fqcn,
+    column_type_fqcn,
+    auto_apply,
+    persistence_namespace,
+  });
+  let mut ts_file = create_ts_file(&file_template);
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 3: Build and return the final file response
+  build_file_response(&ts_file, package_name)
+}
@@ -1,19 +1,27 @@
+use crate::commands::validators::java_fqcn_validator::validate_java_fully_qualified_class_name;
+use crate::commands::validators::java_type_validator::{JavaTypeReference, parse_java_type};
 use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::field_declaration_service::{
-  AddFieldDeclarationParams, add_field_declaration,
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
+  prepare_field_insertion,
 };
+use crate::common::services::formatting_service::normalize_blank_lines;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::package_declaration_service::{
   get_package_class_scope_node, get_package_declaration_node,
 };
 use crate::common::ts_file::TSFile;
 use crate::common::types::basic_field_config::BasicFieldConfig;
+use crate::common::types::fetch_type::FetchType;
+use crate::common::types::generated_style::GeneratedStyle;
 use crate::common::types::import_types::ImportInsertionPosition;
-use crate::common::types::java_basic_types::FieldInsertionPosition;
+use crate::common::types::java_basic_types::JavaBasicType;
 use crate::common::types::java_field_temporal::JavaFieldTemporal;
 use crate::common::types::java_field_time_zone_storage::JavaFieldTimeZoneStorage;
-use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
-use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::column_naming_util::to_column_name;
+use crate::common::utils::naming_util;
+use crate::responses::basic_java_type_response::JavaBasicTypeResponse;
 use crate::responses::file_response::FileResponse;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -22,6 +30,183 @@ struct ProcessedFieldConfig {
   pub should_add_timezone_storage_annotation: bool,
   pub should_add_temporal_annotation: bool,
   pub should_add_lob_annotation: bool,
+  pub should_add_convert_annotation: bool,
+  pub field_type: JavaTypeReference,
+  pub field_type_package_name: Option<String>,
+  pub field_initialization: Option<String>,
+  pub column_arguments: Vec<(&'static str, String)>,
+  pub basic_arguments: Vec<(&'static str, String)>,
+}
+
+/// Resolves the package of the field type, preferring one written in the type itself and
+/// defaulting JDBC large object locators to `java.sql`.
+fn resolve_field_type_package_name(
+  field_config: &BasicFieldConfig,
+  field_type: &JavaTypeReference,
+) -> Option<String> {
+  match (&field_type.package_name, &field_config.field_type_package_name) {
+    (Some(package_name), _) | (None, Some(package_name)) => Some(package_name.clone()),
+    (None, None) if ["Blob", "Clob", "NClob"].contains(&field_type.name.as_str()) => {
+      Some("java.sql".to_string())
+    }
+    (None, None) => None,
+  }
+}
+
+/// Resolves the package of a type argument written by simple name from the basic types and the
+/// `java.util` collections, leaving any other type to the entity's own package.
+fn resolve_type_argument_package_name(type_argument: &JavaTypeReference) -> Option<String> {
+  if type_argument.package_name.is_some() {
+    return type_argument.package_name.clone();
+  }
+  if ["Collection", "List", "Set", "SortedSet", "Map", "SortedMap"]
+    .contains(&type_argument.top_level_name())
+  {
+    return Some("java.util".to_string());
+  }
+  JavaBasicType::AllTypes
+    .get_all_types()
+    .into_iter()
+    .find(|t| t.name == type_argument.name)
+    .and_then(|t| t.package_path)
+}
+
+/// The field type with its package, as the basic type lists identify it, e.g. `java.lang.Byte[]`.
+fn full_field_type(field_type: &JavaTypeReference, package_name: Option<&str>) -> String {
+  match package_name {
+    Some(package_name) => format!("{}.{}", package_name, field_type.render()),
+    None => field_type.render(),
+  }
+}
+
+/// Whether `@Temporal` applies to the fully qualified type: only `java.util.Date` and
+/// `java.util.Calendar`, as the `java.time` and `java.sql` types map to a column type by
+/// themselves.
+fn is_temporal_type(full_type: &str) -> bool {
+  JavaBasicType::TypesWithTemporal
+    .get_types_with_temporal()
+    .iter()
+    .any(|temporal_type| temporal_type.id == full_type)
+}
+
+/// Precision and scale of a `BigDecimal` column the providers use when none is given, which are
+/// left out of `@Column`.
+const DEFAULT_DECIMAL_PRECISION: u16 = 19;
+const DEFAULT_DECIMAL_SCALE: u16 = 2;
+
+/// Rejects a column size the field type cannot have, e.g. a length on an `Integer`, using the
+/// same type lists the UI uses to show the length, precision and scale inputs, or a scale larger
+/// than its precision. Large objects are exempt from the length check as their length is
+/// dropped anyway.
+fn validate_column_sizes(
+  field_config: &BasicFieldConfig,
+  field_type: &JavaTypeReference,
+) -> Result<(), String> {
+  let simple_type = field_type.render();
+  let full_type = full_field_type(
+    field_type,
+    resolve_field_type_package_name(field_config, field_type).as_deref(),
+  );
+  let supports = |types: Vec<JavaBasicTypeResponse>| {
+    types.iter().any(|t| t.id == full_type || t.id == simple_type)
+  };
+  if field_config.field_length.is_some()
+    && !field_config.field_large_object
+    && !supports(JavaBasicType::TypesWithLength.get_types_with_length())
+  {
+    return Err(format!("Length is not supported for field type {}", full_type));
+  }
+  if (field_config.field_precision.is_some() || field_config.field_scale.is_some())
+    && !supports(JavaBasicType::TypesWithPrecisionAndScale.get_types_with_precision_scale())
+  {
+    return Err(format!("Precision and scale are not supported for field type {}", full_type));
+  }
+  if let Some((precision, scale)) = resolve_precision_and_scale(field_config)
+    && scale > precision
+  {
+    return Err(format!("Scale {} cannot exceed precision {}", scale, precision));
+  }
+  Ok(())
+}
+
+fn escape_java_char(c: char, quote: char) -> String {
+  match c {
+    '\\' => "\\\\".to_string(),
+    '\n' => "\\n".to_string(),
+    '\r' => "\\r".to_string(),
+    '\t' => "\\t".to_string(),
+    c if c == quote => format!("\\{}", c),
+    c => c.to_string(),
+  }
+}
+
+/// Renders `default_value` as the field initializer. Strings and characters are quoted, numeric
+/// and boolean values must parse for the field type, and any other type takes the value verbatim
+/// as a Java expression, e.g. `LocalDate.now()`.
+fn build_field_initialization(
+  field_type: &JavaTypeReference,
+  package_name: Option<&str>,
+  default_value: &str,
+) -> Result<String, String> {
+  let invalid = || {
+    format!(
+      "'{}' is not a valid default value for field type {}",
+      default_value,
+      full_field_type(field_type, package_name)
+    )
+  };
+  let value = default_value.trim();
+  if field_type.array_dimensions > 0 || !field_type.type_arguments.is_empty() {
+    return Ok(value.to_string());
+  }
+  let is_java_lang = matches!(package_name, None | Some("java.lang"));
+  let is_java_math = package_name == Some("java.math");
+  match field_type.name.as_str() {
+    "String" if is_java_lang => Ok(format!(
+      "\"{}\"",
+      default_value.chars().map(|c| escape_java_char(c, '"')).collect::<String>()
+    )),
+    "char" | "Character" if is_java_lang => {
+      let mut chars = default_value.chars();
+      match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(format!("'{}'", escape_java_char(c, '\''))),
+        _ => Err(invalid()),
+      }
+    }
+    "boolean" | "Boolean" if is_java_lang => match value {
+      "true" | "false" => Ok(value.to_string()),
+      _ => Err(invalid()),
+    },
+    "byte" | "Byte" if is_java_lang => {
+      value.parse::<i8>().map(|_| value.to_string()).map_err(|_| invalid())
+    }
+    "short" | "Short" if is_java_lang => {
+      value.parse::<i16>().map(|_| value.to_string()).map_err(|_| invalid())
+    }
+    "int" | "Integer" if is_java_lang => {
+      value.parse::<i32>().map(|_| value.to_string()).map_err(|_| invalid())
+    }
+    "long" | "Long" if is_java_lang => {
+      value.parse::<i64>().map(|_| format!("{}L", value)).map_err(|_| invalid())
+    }
+    "float" | "Float" if is_java_lang => match value.parse::<f32>() {
+      Ok(number) if number.is_finite() => Ok(format!("{}f", value)),
+      _ => Err(invalid()),
+    },
+    "double" | "Double" if is_java_lang => match value.parse::<f64>() {
+      Ok(number) if number.is_finite() && value.contains(['.', 'e', 'E']) => Ok(value.to_string()),
+      Ok(number) if number.is_finite() => Ok(format!("{}.0", value)),
+      _ => Err(invalid()),
+    },
+    "BigDecimal" if is_java_math => match value.parse::<f64>() {
+      Ok(number) if number.is_finite() => Ok(format!("new BigDecimal(\"{}\")", value)),
+      _ => Err(invalid()),
+    },
+    "BigInteger" if is_java_math => {
+      value.parse::<i128>().map(|_| format!("new BigInteger(\"{}\")", value)).map_err(|_| invalid())
+    }
+    _ => Ok(value.to_string()),
+  }
 }
 
 fn add_to_import_map(
@@ -46,61 +231,243 @@ fn process_imports(
   processed_field_config: &ProcessedFieldConfig,
   field_config: &BasicFieldConfig,
 ) {
-  if let Some(ref package_name) = field_config.field_type_package_name {
-    add_to_import_map(import_map, package_name, &field_config.field_type);
+  let persistence_package = field_config.persistence_namespace.package();
+  if field_config.mark_generated == Some(GeneratedStyle::Annotation) {
+    add_to_import_map(import_map, GeneratedStyle::ANNOTATION_PACKAGE, "Generated");
+  }
+  let field_type = &processed_field_config.field_type;
+  if !field_type.is_primitive()
+    && let Some(ref package_name) = processed_field_config.field_type_package_name
+  {
+    add_to_import_map(import_map, package_name, field_type.top_level_name());
   };
-  add_to_import_map(import_map, "jakarta.persistence", "Column");
+  let mut type_arguments: Vec<&JavaTypeReference> = field_type.type_arguments.iter().collect();
+  while let Some(type_argument) = type_arguments.pop() {
+    if !type_argument.is_primitive()
+      && let Some(package_name) = resolve_type_argument_package_name(type_argument)
+    {
+      add_to_import_map(import_map, &package_name, type_argument.top_level_name());
+    }
+    type_arguments.extend(&type_argument.type_arguments);
+  }
+  if !processed_field_config.column_arguments.is_empty() {
+    add_to_import_map(import_map, persistence_package, "Column");
+  }
+  if !processed_field_config.basic_arguments.is_empty() {
+    add_to_import_map(import_map, persistence_package, "Basic");
+  }
+  if processed_field_config.basic_arguments.iter().any(|(key, _)| *key == "fetch") {
+    add_to_import_map(import_map, persistence_package, "FetchType");
+  }
+  if field_config.field_natural_id {
+    add_to_import_map(import_map, "org.hibernate.annotations", "NaturalId");
+  }
   if processed_field_config.should_add_timezone_storage_annotation {
     add_to_import_map(import_map, "org.hibernate.annotations", "TimeZoneStorage");
     add_to_import_map(import_map, "org.hibernate.annotations", "TimeZoneStorageType");
   }
   if processed_field_config.should_add_temporal_annotation {
-    add_to_import_map(import_map, "jakarta.persistence", "Temporal");
-    add_to_import_map(import_map, "jakarta.persistence", "TemporalType");
+    add_to_import_map(import_map, persistence_package, "Temporal");
+    add_to_import_map(import_map, persistence_package, "TemporalType");
   }
   if processed_field_config.should_add_lob_annotation {
-    add_to_import_map(import_map, "jakarta.persistence", "Lob");
+    add_to_import_map(import_map, persistence_package, "Lob");
+  }
+  if processed_field_config.should_add_convert_annotation
+    && let Some((converter_package, converter_class)) =
+      field_config.field_converter_class.as_deref().and_then(|converter| converter.rsplit_once('.'))
+  {
+    add_to_import_map(import_map, persistence_package, "Convert");
+    add_to_import_map(import_map, converter_package, converter_class);
   }
 }
 
-fn process_field_config(field_config: &BasicFieldConfig) -> ProcessedFieldConfig {
+fn process_field_config(
+  field_config: &BasicFieldConfig,
+  field_type: JavaTypeReference,
+  field_initialization: Option<String>,
+) -> Result<ProcessedFieldConfig, String> {
   let mut should_add_timezone_storage_annotation = false;
   let mut should_add_temporal_annotation = false;
   let mut should_add_lob_annotation = false;
+  let should_add_convert_annotation = field_config.field_converter_class.is_some();
   let time_zone_aware_types: HashSet<&str> =
     ["java.time.OffsetDateTime", "java.time.ZonedDateTime", "java.time.OffsetTime"]
       .iter()
       .cloned()
       .collect();
-  let temporal_aware_types: HashSet<&str> =
-    ["java.util.Date", "java.util.Calendar", "java.sql.Date"].iter().cloned().collect();
-  let lob_aware_types: HashSet<&str> =
-    ["java.lang.String", "byte[]", "java.lang.Byte[]", "char[]", "java.lang.Character[]"]
-      .iter()
-      .cloned()
-      .collect();
-  let full_type = field_config
-    .field_type_package_name
-    .as_ref()
-    .map(|pkg| format!("{}.{}", pkg, field_config.field_type))
-    .unwrap_or_else(|| field_config.field_type.clone());
+  let lob_aware_types: HashSet<&str> = [
+    "java.lang.String",
+    "byte[]",
+    "java.lang.Byte[]",
+    "char[]",
+    "java.lang.Character[]",
+    "java.sql.Blob",
+    "java.sql.Clob",
+    "java.sql.NClob",
+  ]
+  .iter()
+  .cloned()
+  .collect();
+  let length_aware_types: HashSet<String> =
+    JavaBasicType::TypesWithLength.get_types_with_length().into_iter().map(|t| t.id).collect();
+  let field_type_package_name = resolve_field_type_package_name(field_config, &field_type);
+  let simple_type = field_type.render();
+  let full_type = full_field_type(&field_type, field_type_package_name.as_deref());
   if time_zone_aware_types.contains(full_type.as_str()) {
     should_add_timezone_storage_annotation = true;
   }
-  if temporal_aware_types.contains(full_type.as_str()) && field_config.field_temporal.is_some() {
+  // The converter owns the column mapping, so @Temporal would conflict with it
+  if is_temporal_type(&full_type)
+    && field_config.field_temporal.is_some()
+    && !should_add_convert_annotation
+  {
     should_add_temporal_annotation = true;
   }
   if field_config.field_large_object
     && (lob_aware_types.contains(full_type.as_str())
-      || lob_aware_types.contains(field_config.field_type.as_str()))
+      || lob_aware_types.contains(simple_type.as_str()))
   {
     should_add_lob_annotation = true;
   }
-  ProcessedFieldConfig {
+  // A large object has no meaningful length, so only plain columns get one
+  let should_add_length_argument = !should_add_lob_annotation
+    && field_config.field_length.is_some_and(|length| length != 255)
+    && (length_aware_types.contains(&full_type) || length_aware_types.contains(&simple_type));
+  let column_arguments =
+    build_column_arguments(field_config, should_add_length_argument, &full_type)?;
+  let basic_arguments = build_basic_arguments(field_config);
+  Ok(ProcessedFieldConfig {
     should_add_timezone_storage_annotation,
     should_add_temporal_annotation,
     should_add_lob_annotation,
+    should_add_convert_annotation,
+    field_type,
+    field_type_package_name,
+    field_initialization,
+    column_arguments,
+    basic_arguments,
+  })
+}
+
+/// Builds the `@Column` arguments. With `explicit_column_name` the column name and the
+/// `unique`/`nullable` flags are always written out; otherwise only values that differ from the
+/// JPA defaults are, leaving the name to the provider's implicit naming strategy. An empty list
+/// means the `@Column` annotation is omitted.
+fn build_column_arguments(
+  field_config: &BasicFieldConfig,
+  should_add_length_argument: bool,
+  full_type: &str,
+) -> Result<Vec<(&'static str, String)>, String> {
+  let explicit = field_config.explicit_column_name;
+  let mut arguments = Vec::new();
+  if explicit {
+    let column_name =
+      to_column_name(&field_config.field_name, &field_config.column_naming_strategy);
+    arguments.push(("name", render_string_value(&column_name)?));
+  }
+  if should_add_length_argument && let Some(length) = field_config.field_length {
+    arguments.push(("length", length.to_string()));
+  }
+  if explicit || field_config.field_unique {
+    arguments.push(("unique", field_config.field_unique.to_string()));
+  }
+  if explicit || !field_config.field_nullable {
+    arguments.push(("nullable", field_config.field_nullable.to_string()));
+  }
+  if let Some(column_definition) = &field_config.column_definition {
+    arguments.push(("columnDefinition", render_string_value(column_definition)?));
+  }
+  // Written as a pair, so `scale` never appears without the `precision` it applies to
+  if full_type == "java.math.BigDecimal"
+    && let Some((precision, scale)) = resolve_precision_and_scale(field_config)
+    && (precision, scale) != (DEFAULT_DECIMAL_PRECISION, DEFAULT_DECIMAL_SCALE)
+  {
+    arguments.push(("precision", precision.to_string()));
+    arguments.push(("scale", scale.to_string()));
   }
+  Ok(arguments)
+}
+
+/// Completes a precision or scale given alone: a precision alone keeps the JPA default scale of
+/// 0, and a scale alone gets the default `BigDecimal` precision.
+fn resolve_precision_and_scale(field_config: &BasicFieldConfig) -> Option<(u16, u16)> {
+  match (field_config.field_precision, field_config.field_scale) {
+    (None, None) => None,
+    (Some(precision), None) => Some((precision, 0)),
+    (None, Some(scale)) => Some((DEFAULT_DECIMAL_PRECISION, scale)),
+    (Some(precision), Some(scale)) => Some((precision, scale)),
+  }
+}
+
+/// Warns about the precision or scale defaulted because only the other one was given.
+pub fn build_precision_scale_warnings(field_config: &BasicFieldConfig) -> Vec<String> {
+  match (field_config.field_precision, field_config.field_scale) {
+    (Some(precision), None) => {
+      vec![format!("@Column(scale) defaults to 0 as only precision {} was given", precision)]
+    }
+    (None, Some(scale)) => vec![format!(
+      "@Column(precision) defaults to {} as only scale {} was given",
+      DEFAULT_DECIMAL_PRECISION, scale
+    )],
+    _ => Vec::new(),
+  }
+}
+
+/// Warns that `--field-temporal` was left out because the field type is not one of the legacy
+/// date types `@Temporal` applies to, e.g. a `LocalDate`.
+pub fn build_temporal_warnings(field_config: &BasicFieldConfig) -> Vec<String> {
+  if field_config.field_temporal.is_none() {
+    return Vec::new();
+  }
+  let Ok(field_type) = parse_java_type(&field_config.field_type) else {
+    return Vec::new();
+  };
+  let package_name = resolve_field_type_package_name(field_config, &field_type);
+  let full_type = full_field_type(&field_type, package_name.as_deref());
+  if is_temporal_type(&full_type) {
+    return Vec::new();
+  }
+  vec![format!(
+    "@Temporal only applies to java.util.Date and java.util.Calendar, so it was left out for {}",
+    full_type
+  )]
+}
+
+/// Warns about the sizing options a provider may ignore once `columnDefinition` replaces the
+/// generated column DDL.
+pub fn build_column_definition_warnings(field_config: &BasicFieldConfig) -> Vec<String> {
+  if field_config.column_definition.is_none() {
+    return Vec::new();
+  }
+  [
+    ("length", field_config.field_length.is_some()),
+    ("precision", field_config.field_precision.is_some()),
+    ("scale", field_config.field_scale.is_some()),
+  ]
+  .into_iter()
+  .filter(|(_, is_set)| *is_set)
+  .map(|(argument, _)| {
+    format!("@Column({}) may be ignored by the provider when columnDefinition is set", argument)
+  })
+  .collect()
+}
+
+/// Builds the `@Basic` arguments for a field created with a basic fetch type. `EAGER` fetching
+/// is the JPA default and is left out, while `optional = false` mirrors a non-nullable column.
+/// An empty list means the `@Basic` annotation is omitted.
+fn build_basic_arguments(field_config: &BasicFieldConfig) -> Vec<(&'static str, String)> {
+  let Some(basic_fetch) = &field_config.basic_fetch else {
+    return Vec::new();
+  };
+  let mut arguments = Vec::new();
+  if *basic_fetch == FetchType::Lazy {
+    arguments.push(("fetch", format!("FetchType.{}", basic_fetch.as_str())));
+  }
+  if !field_config.field_nullable {
+    arguments.push(("optional", "false".to_string()));
+  }
+  arguments
 }
 
 fn add_field_and_annotations(
@@ -108,50 +475,48 @@ fn add_field_and_annotations(
   field_config: &BasicFieldConfig,
   processed_field_config: &ProcessedFieldConfig,
 ) -> Result<(), String> {
-  let field_name_camel_case =
-    case_util::auto_convert_case(&field_config.field_name, CaseType::Camel);
-  let column_name_snake_case =
-    case_util::auto_convert_case(&field_config.field_name, CaseType::Snake);
+  let field_name_camel_case = naming_util::ensure_camel_case(&field_config.field_name);
 
   let public_class_node = get_public_class_node(ts_file)
     .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
   let public_class_node_start_byte = public_class_node.start_byte();
+  let field_type = processed_field_config.field_type.render();
+  let insertion_position = prepare_field_insertion(
+    ts_file,
+    public_class_node_start_byte,
+    &field_name_camel_case,
+    field_config.after_field.as_deref(),
+    field_config.at_top,
+    field_config.mark_generated.as_ref(),
+  )?;
   let params = AddFieldDeclarationParams {
-    insertion_position: FieldInsertionPosition::EndOfClassBody,
-    visibility_modifier: JavaVisibilityModifier::Private,
+    insertion_position,
+    visibility_modifier: field_config.field_access.visibility_modifier(),
     field_modifiers: vec![],
-    field_type: &field_config.field_type,
+    field_type: &field_type,
     field_name: &field_name_camel_case,
-    field_initialization: None,
+    field_initialization: processed_field_config.field_initialization.as_deref(),
+    javadoc: field_config.javadoc.as_deref(),
+    generated_marker: field_config.mark_generated.as_ref(),
   };
   let timezone_storage_type =
     field_config.field_timezone_storage.clone().unwrap_or(JavaFieldTimeZoneStorage::Auto);
   let temporal_type = field_config.field_temporal.clone().unwrap_or(JavaFieldTemporal::Timestamp);
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
-    builder.add_annotation("@Column")?.with_argument(
-      "@Column",
-      "name",
-      &format!("\"{}\"", &column_name_snake_case),
-    )?;
-    if field_config.field_unique {
-      builder.with_argument("@Column", "unique", "true")?;
-    } else {
-      builder.with_argument("@Column", "unique", "false")?;
-    }
-    if field_config.field_nullable {
-      builder.with_argument("@Column", "nullable", "true")?;
-    } else {
-      builder.with_argument("@Column", "nullable", "false")?;
-    }
-    if field_config.field_type == "BigDecimal"
-      && field_config.field_type_package_name.as_deref() == Some("java.math")
-    {
-      if let Some(precision) = field_config.field_precision.filter(|&p| p != 19) {
-        builder.with_argument("@Column", "precision", &precision.to_string())?;
+    if field_config.field_natural_id {
+      builder.add_annotation("@NaturalId")?;
+    }
+    if !processed_field_config.basic_arguments.is_empty() {
+      builder.add_annotation("@Basic")?;
+      for (key, value) in &processed_field_config.basic_arguments {
+        builder.with_argument("@Basic", key, value)?;
       }
-
-      if let Some(scale) = field_config.field_scale.filter(|&s| s != 2) {
-        builder.with_argument("@Column", "scale", &scale.to_string())?;
+    }
+    if !processed_field_config.column_arguments.is_empty() {
+      builder.add_annotation("@Column")?;
+      for (key, value) in &processed_field_config.column_arguments {
+        builder.with_argument("@Column", key, value)?;
       }
     }
     if processed_field_config.should_add_timezone_storage_annotation
@@ -173,6 +538,18 @@ fn add_field_and_annotations(
     if processed_field_config.should_add_lob_annotation {
       builder.add_annotation("@Lob")?;
     }
+    if processed_field_config.should_add_convert_annotation
+      && let Some(converter_class) = field_config
+        .field_converter_class
+        .as_deref()
+        .and_then(|converter| converter.rsplit('.').next())
+    {
+      builder.add_annotation("@Convert")?.with_argument(
+        "@Convert",
+        "converter",
+        &format!("{}.class", converter_class),
+      )?;
+    }
     builder.build()
   })
   .ok_or_else(|| "Unable to add new field to the JPA Entity".to_string())?
@@ -190,7 +567,49 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Adds the field with its annotations and imports to an already parsed entity, without saving
+/// it, so several fields can be added to the same buffer.
+pub fn add_field_to_entity(
+  entity_ts_file: &mut TSFile,
+  field_config: &BasicFieldConfig,
+) -> Result<(), String> {
+  // Step 1: Validate and process field config
+  if let Some(ref converter_class) = field_config.field_converter_class {
+    validate_java_fully_qualified_class_name(converter_class)?;
+  }
+  let field_type = parse_java_type(&field_config.field_type)?;
+  validate_column_sizes(field_config, &field_type)?;
+  let field_initialization = match field_config.default_value.as_deref() {
+    Some(default_value) => Some(build_field_initialization(
+      &field_type,
+      resolve_field_type_package_name(field_config, &field_type).as_deref(),
+      default_value,
+    )?),
+    None => None,
+  };
+  let processed_field_config =
+    process_field_config(field_config, field_type, field_initialization)?;
+  // Step 2: Process imports
+  let mut import_map: HashMap<String, String> = HashMap::new();
+  process_imports(&mut import_map, &processed_field_config, field_config);
+  // Step 3: Add field and annotations
+  add_field_and_annotations(entity_ts_file, field_config, &processed_field_config)?;
+  // Step 4: Add imports
+  add_imports(entity_ts_file, &import_map);
+  // Step 5: Normalize blank lines when requested
+  if field_config.format_java {
+    normalize_blank_lines(entity_ts_file);
+  }
+  Ok(())
 }
 
 pub fn run(
@@ -198,21 +617,14 @@ pub fn run(
   entity_file_path: &Path,
   field_config: &BasicFieldConfig,
 ) -> Result<FileResponse, String> {
-  // Step 1: Process field config
-  let processed_field_config = process_field_config(field_config);
-  // Step 2: Parse entity file
+  // Step 1: Parse entity file
   let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
-  // Step 3: Process imports
-  let mut import_map: HashMap<String, String> = HashMap::new();
-  process_imports(&mut import_map, &processed_field_config, field_config);
-  // Step 4: Add field and annotations
-  add_field_and_annotations(&mut entity_ts_file, field_config, &processed_field_config)?;
-  // Step 5: Add imports
-  add_imports(&mut entity_ts_file, &import_map);
-  // Step 6: Save file (use save_to_existing_file since we're modifying an existing entity file)
+  // Step 2: Add the field, its annotations and imports
+  add_field_to_entity(&mut entity_ts_file, field_config)?;
+  // Step 3: Save file (use save_to_existing_file since we're modifying an existing entity file)
   entity_ts_file
     .save_to_existing_file(entity_file_path)
     .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
-  // Step 7: Build and return response
+  // Step 4: Build and return response
   build_file_response(&entity_ts_file)
 }
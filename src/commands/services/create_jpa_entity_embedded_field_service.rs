@@ -0,0 +1,160 @@
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::{
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
+  prepare_field_insertion,
+};
+use crate::common::services::formatting_service::normalize_blank_lines;
+use crate::common::services::import_declaration_service::add_import;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::embedded_field_config::{AttributeOverrideConfig, EmbeddedFieldConfig};
+use crate::common::types::generated_style::GeneratedStyle;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::responses::file_response::FileResponse;
+use std::collections::HashMap;
+use std::path::Path;
+
+fn add_to_import_map(
+  import_map: &mut HashMap<String, String>,
+  package_name: &str,
+  class_name: &str,
+) {
+  if !import_map.contains_key(class_name) {
+    import_map.insert(class_name.to_string(), package_name.to_string());
+  }
+}
+
+fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  for (class_name, package_name) in import_map {
+    add_import(ts_file, &import_position, package_name, class_name);
+  }
+}
+
+/// Builds the `{@AttributeOverride(...), ...}` value of `@AttributeOverrides`.
+fn build_attribute_overrides_value(
+  attribute_overrides: &[AttributeOverrideConfig],
+) -> Result<String, String> {
+  let overrides = attribute_overrides
+    .iter()
+    .map(|attribute_override| {
+      Ok(format!(
+        "@AttributeOverride(name = {}, column = @Column(name = {}))",
+        render_string_value(&attribute_override.attribute_name)?,
+        render_string_value(&attribute_override.column_name)?
+      ))
+    })
+    .collect::<Result<Vec<_>, String>>()?
+    .join(", ");
+  Ok(format!("{{{}}}", overrides))
+}
+
+fn add_field_and_annotations(
+  ts_file: &mut TSFile,
+  field_config: &EmbeddedFieldConfig,
+  import_map: &mut HashMap<String, String>,
+) -> Result<(), String> {
+  let persistence_package = field_config.persistence_namespace.package();
+  let public_class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  let public_class_node_start_byte = public_class_node.start_byte();
+  // Add required imports based on field configuration
+  add_to_import_map(import_map, persistence_package, "Embedded");
+  if !field_config.attribute_overrides.is_empty() {
+    add_to_import_map(import_map, persistence_package, "AttributeOverride");
+    add_to_import_map(import_map, persistence_package, "AttributeOverrides");
+    add_to_import_map(import_map, persistence_package, "Column");
+  }
+  // Add embeddable type import
+  add_to_import_map(
+    import_map,
+    &field_config.embeddable_package_name,
+    &field_config.embeddable_type,
+  );
+  if field_config.mark_generated == Some(GeneratedStyle::Annotation) {
+    add_to_import_map(import_map, GeneratedStyle::ANNOTATION_PACKAGE, "Generated");
+  }
+  let insertion_position = prepare_field_insertion(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.field_name,
+    field_config.after_field.as_deref(),
+    field_config.at_top,
+    field_config.mark_generated.as_ref(),
+  )?;
+  let params = AddFieldDeclarationParams {
+    insertion_position,
+    visibility_modifier: field_config.field_access.visibility_modifier(),
+    field_modifiers: vec![],
+    field_type: &field_config.embeddable_type,
+    field_name: &field_config.field_name,
+    field_initialization: None,
+    javadoc: field_config.javadoc.as_deref(),
+    generated_marker: field_config.mark_generated.as_ref(),
+  };
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
+  add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
+    builder.add_annotation("@Embedded")?;
+    // Add @AttributeOverrides annotation when any column is remapped
+    if !field_config.attribute_overrides.is_empty() {
+      builder.add_annotation("@AttributeOverrides")?.with_value(
+        "@AttributeOverrides",
+        &build_attribute_overrides_value(&field_config.attribute_overrides)?,
+      )?;
+    }
+    builder.build()
+  })
+  .ok_or_else(|| "Unable to add new field to the JPA Entity".to_string())?
+  .map_err(|e| format!("Unable to add annotations: {}", e))?;
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
+  let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+  let file_package_node = get_package_declaration_node(ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity's package node".to_string())?;
+  let file_package_scope_node = get_package_scope_node(ts_file, file_package_node);
+  let file_package_name = file_package_scope_node
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or("")
+    .to_string();
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Adds an `@Embedded` field typed by an `@Embeddable` value object, e.g. an `Address`, with
+/// optional `@AttributeOverrides` renaming the columns of its attributes.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_config: EmbeddedFieldConfig,
+) -> Result<FileResponse, String> {
+  // Step 1: Parse the entity file
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  // Step 2: Prepare import map for required imports
+  let mut import_map = HashMap::new();
+  // Step 3: Add field and annotations to the entity
+  add_field_and_annotations(&mut entity_ts_file, &field_config, &mut import_map)?;
+  // Step 4: Add all required imports to the file
+  add_imports(&mut entity_ts_file, &import_map);
+  // Step 5: Normalize blank lines when requested
+  if field_config.format_java {
+    normalize_blank_lines(&mut entity_ts_file);
+  }
+  // Step 6: Write the modified file back to disk
+  entity_ts_file
+    .save_as(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 7: Build and return response
+  build_file_response(&entity_ts_file)
+}
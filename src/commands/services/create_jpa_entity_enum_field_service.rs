@@ -1,18 +1,23 @@
+use crate::commands::services::get_enum_constants_service;
 use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::field_declaration_service::{
-  AddFieldDeclarationParams, add_field_declaration,
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
+  prepare_field_insertion,
 };
+use crate::common::services::formatting_service::normalize_blank_lines;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::package_declaration_service::{
   get_package_class_scope_node, get_package_declaration_node,
 };
 use crate::common::ts_file::TSFile;
 use crate::common::types::enum_field_config::EnumFieldConfig;
+use crate::common::types::generated_style::GeneratedStyle;
 use crate::common::types::import_types::ImportInsertionPosition;
-use crate::common::types::java_basic_types::FieldInsertionPosition;
 use crate::common::types::java_enum_type::JavaEnumType;
-use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
-use crate::common::utils::case_util::{self, CaseType};
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::column_naming_util::to_column_name;
+use crate::common::utils::project_layout_util::{resolve_source_root, select_project_modules};
 use crate::responses::file_response::FileResponse;
 use std::collections::HashMap;
 use std::path::Path;
@@ -34,30 +39,115 @@ fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
   }
 }
 
+/// Builds the `@Column` arguments. With `explicit_column_name` the column name and `nullable`
+/// flag are always written out; otherwise only values that differ from the JPA defaults are.
+/// An empty list means the `@Column` annotation is omitted.
+fn build_column_arguments(
+  field_config: &EnumFieldConfig,
+) -> Result<Vec<(&'static str, String)>, String> {
+  let explicit = field_config.explicit_column_name;
+  let mut arguments = Vec::new();
+  if explicit {
+    let column_name =
+      to_column_name(&field_config.field_name, &field_config.column_naming_strategy);
+    arguments.push(("name", render_string_value(&column_name)?));
+  }
+  // Add length for STRING type if specified and different from default (255)
+  if matches!(field_config.enum_type_storage, JavaEnumType::String)
+    && let Some(length) = field_config.field_length
+    && length != 255
+  {
+    arguments.push(("length", length.to_string()));
+  }
+  if explicit || !field_config.field_nullable {
+    arguments.push(("nullable", field_config.field_nullable.to_string()));
+  }
+  if field_config.field_unique {
+    arguments.push(("unique", "true".to_string()));
+  }
+  Ok(arguments)
+}
+
+/// Renders `default_value`, written as `ACTIVE` or `Status.ACTIVE`, as the field initializer
+/// after checking it is one of the constants of the enum declared in the project.
+fn build_field_initialization(
+  cwd: &Path,
+  field_config: &EnumFieldConfig,
+  default_value: &str,
+) -> Result<String, String> {
+  let enum_type = &field_config.enum_type;
+  let constant = default_value.trim();
+  let constant = constant
+    .strip_prefix(enum_type.as_str())
+    .and_then(|rest| rest.strip_prefix('.'))
+    .unwrap_or(constant);
+  let enum_file_path = select_project_modules(cwd, None)?
+    .iter()
+    .map(|module| {
+      resolve_source_root(&module.path, &JavaSourceDirectoryType::Main)
+        .join(field_config.enum_package_name.replace('.', "/"))
+        .join(format!("{}.java", enum_type))
+    })
+    .find(|path| path.is_file())
+    .ok_or_else(|| {
+      format!(
+        "Unable to find enum {}.{} in the project to validate the default value",
+        field_config.enum_package_name, enum_type
+      )
+    })?;
+  let enum_constants = get_enum_constants_service::run(Some(&enum_file_path), None)?;
+  if !enum_constants.constants.iter().any(|name| name == constant) {
+    return Err(format!(
+      "'{}' is not a constant of enum {} (expected one of: {})",
+      constant,
+      enum_type,
+      enum_constants.constants.join(", ")
+    ));
+  }
+  Ok(format!("{}.{}", enum_type, constant))
+}
+
 fn add_field_and_annotations(
   ts_file: &mut TSFile,
   field_config: &EnumFieldConfig,
+  field_initialization: Option<&str>,
   import_map: &mut HashMap<String, String>,
 ) -> Result<(), String> {
-  let column_name_snake_case =
-    case_util::auto_convert_case(&field_config.field_name, CaseType::Snake);
+  let column_arguments = build_column_arguments(field_config)?;
+  let persistence_package = field_config.persistence_namespace.package();
   let public_class_node = get_public_class_node(ts_file)
     .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
   let public_class_node_start_byte = public_class_node.start_byte();
   // Add required imports based on field configuration
-  add_to_import_map(import_map, "jakarta.persistence", "Column");
-  add_to_import_map(import_map, "jakarta.persistence", "Enumerated");
-  add_to_import_map(import_map, "jakarta.persistence", "EnumType");
+  if !column_arguments.is_empty() {
+    add_to_import_map(import_map, persistence_package, "Column");
+  }
+  add_to_import_map(import_map, persistence_package, "Enumerated");
+  add_to_import_map(import_map, persistence_package, "EnumType");
   // Add enum type import
   add_to_import_map(import_map, &field_config.enum_package_name, &field_config.enum_type);
+  if field_config.mark_generated == Some(GeneratedStyle::Annotation) {
+    add_to_import_map(import_map, GeneratedStyle::ANNOTATION_PACKAGE, "Generated");
+  }
+  let insertion_position = prepare_field_insertion(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.field_name,
+    field_config.after_field.as_deref(),
+    field_config.at_top,
+    field_config.mark_generated.as_ref(),
+  )?;
   let params = AddFieldDeclarationParams {
-    insertion_position: FieldInsertionPosition::EndOfClassBody,
-    visibility_modifier: JavaVisibilityModifier::Private,
+    insertion_position,
+    visibility_modifier: field_config.field_access.visibility_modifier(),
     field_modifiers: vec![],
     field_type: &field_config.enum_type,
     field_name: &field_config.field_name,
-    field_initialization: None,
+    field_initialization,
+    javadoc: field_config.javadoc.as_deref(),
+    generated_marker: field_config.mark_generated.as_ref(),
   };
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
     // Add @Enumerated annotation
     builder.add_annotation("@Enumerated")?.with_argument(
@@ -65,28 +155,12 @@ fn add_field_and_annotations(
       "value",
       &format!("EnumType.{}", field_config.enum_type_storage.as_str()),
     )?;
-    // Add @Column annotation
-    builder.add_annotation("@Column")?.with_argument(
-      "@Column",
-      "name",
-      &format!("\"{}\"", &column_name_snake_case),
-    )?;
-    // Add length for STRING type if specified and different from default (255)
-    if matches!(field_config.enum_type_storage, JavaEnumType::String)
-      && let Some(length) = field_config.field_length
-      && length != 255
-    {
-      builder.with_argument("@Column", "length", &length.to_string())?;
-    }
-    // Set nullable based on field_nullable (fixed bug from Python code)
-    if field_config.field_nullable {
-      builder.with_argument("@Column", "nullable", "true")?;
-    } else {
-      builder.with_argument("@Column", "nullable", "false")?;
-    }
-    // Set unique if true
-    if field_config.field_unique {
-      builder.with_argument("@Column", "unique", "true")?;
+    // Add @Column annotation when any argument needs to be written out
+    if !column_arguments.is_empty() {
+      builder.add_annotation("@Column")?;
+      for (key, value) in &column_arguments {
+        builder.with_argument("@Column", key, value)?;
+      }
     }
     builder.build()
   })
@@ -105,7 +179,44 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Adds the enum field with its annotations and imports to an already parsed entity, without
+/// saving it, so several fields can be added to the same buffer. `cwd` is searched for the enum
+/// when validating the default value.
+pub fn add_field_to_entity(
+  cwd: &Path,
+  entity_ts_file: &mut TSFile,
+  field_config: &EnumFieldConfig,
+) -> Result<(), String> {
+  // Step 1: Validate the default value against the enum constants
+  let field_initialization = match field_config.default_value.as_deref() {
+    Some(default_value) => Some(build_field_initialization(cwd, field_config, default_value)?),
+    None => None,
+  };
+  // Step 2: Prepare import map for required imports
+  let mut import_map = HashMap::new();
+  // Step 3: Add field and annotations to the entity
+  add_field_and_annotations(
+    entity_ts_file,
+    field_config,
+    field_initialization.as_deref(),
+    &mut import_map,
+  )?;
+  // Step 4: Add all required imports to the file
+  add_imports(entity_ts_file, &import_map);
+  // Step 5: Normalize blank lines when requested
+  if field_config.format_java {
+    normalize_blank_lines(entity_ts_file);
+  }
+  Ok(())
 }
 
 pub fn run(
@@ -116,16 +227,12 @@ pub fn run(
 ) -> Result<FileResponse, String> {
   // Step 1: Parse the entity file
   let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
-  // Step 2: Prepare import map for required imports
-  let mut import_map = HashMap::new();
-  // Step 3: Add field and annotations to the entity
-  add_field_and_annotations(&mut entity_ts_file, &field_config, &mut import_map)?;
-  // Step 4: Add all required imports to the file
-  add_imports(&mut entity_ts_file, &import_map);
-  // Step 5: Write the modified file back to disk
+  // Step 2: Add the field, its annotations and imports
+  add_field_to_entity(cwd, &mut entity_ts_file, &field_config)?;
+  // Step 3: Write the modified file back to disk
   entity_ts_file
     .save_as(entity_file_path, cwd)
     .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
-  // Step 6: Build and return response
+  // Step 4: Build and return response
   build_file_response(&entity_ts_file)
 }
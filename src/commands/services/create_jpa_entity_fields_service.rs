@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use crate::commands::services::{
+  create_jpa_entity_basic_field_service, create_jpa_entity_enum_field_service,
+  create_jpa_entity_id_field_service,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::entity_field_config::EntityFieldConfig;
+use crate::responses::create_jpa_entity_fields_response::{
+  CreateJpaEntityFieldsResponse, FieldCreationStatusResponse,
+};
+use crate::responses::file_response::FileResponse;
+
+fn add_field_to_entity(
+  cwd: &Path,
+  entity_ts_file: &mut TSFile,
+  field_config: &EntityFieldConfig,
+) -> Result<(), String> {
+  match field_config {
+    EntityFieldConfig::Basic(field_config) => {
+      create_jpa_entity_basic_field_service::add_field_to_entity(entity_ts_file, field_config)
+    }
+    EntityFieldConfig::Id(field_config) => {
+      create_jpa_entity_id_field_service::add_field_to_entity(entity_ts_file, field_config)
+    }
+    EntityFieldConfig::Enum(field_config) => {
+      create_jpa_entity_enum_field_service::add_field_to_entity(cwd, entity_ts_file, field_config)
+    }
+  }
+}
+
+/// The entity is described from `entity_file_path`, as it is not saved when every field failed.
+fn build_file_response(ts_file: &TSFile, entity_file_path: &Path) -> Result<FileResponse, String> {
+  let file_type =
+    entity_file_path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+  let file_path = entity_file_path.to_string_lossy().to_string();
+  let file_package_node = get_package_declaration_node(ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity's package node".to_string())?;
+  let file_package_scope_node = get_package_scope_node(ts_file, file_package_node);
+  let file_package_name = file_package_scope_node
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or("")
+    .to_string();
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Adds every field of `fields_json`, a JSON array of field configs tagged by `kind` (`basic`,
+/// `id` or `enum`), to one entity buffer in order and writes the entity once. A field that fails
+/// is rolled back and reported in its status while the following fields are still added.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  fields_json: &str,
+) -> Result<CreateJpaEntityFieldsResponse, String> {
+  // Step 1: Parse the field configs
+  let field_configs: Vec<EntityFieldConfig> =
+    serde_json::from_str(fields_json).map_err(|e| format!("Invalid fields JSON: {}", e))?;
+  if field_configs.is_empty() {
+    return Err("At least one field is required".to_string());
+  }
+  // Step 2: Parse the entity file
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  // Step 3: Add the fields one by one, restoring the buffer when a field fails
+  let mut fields = Vec::with_capacity(field_configs.len());
+  for field_config in &field_configs {
+    let source_before = entity_ts_file.source_code.clone();
+    let result = add_field_to_entity(cwd, &mut entity_ts_file, field_config);
+    if result.is_err() {
+      entity_ts_file.update_source_code(&source_before);
+    }
+    fields.push(FieldCreationStatusResponse {
+      field_name: field_config.field_name().to_string(),
+      kind: field_config.kind().to_string(),
+      succeed: result.is_ok(),
+      error: result.err(),
+    });
+  }
+  let created_count = fields.iter().filter(|field| field.succeed).count();
+  // Step 4: Write the entity once, unless no field could be added
+  if created_count > 0 {
+    entity_ts_file
+      .save_as(entity_file_path, cwd)
+      .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  let file = build_file_response(&entity_ts_file, entity_file_path)?;
+  Ok(CreateJpaEntityFieldsResponse { file, fields, created_count })
+}
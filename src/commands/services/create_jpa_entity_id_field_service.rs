@@ -1,19 +1,21 @@
 use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::field_declaration_service::{
-  AddFieldDeclarationParams, add_field_declaration,
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
+  prepare_field_insertion,
 };
+use crate::common::services::formatting_service::normalize_blank_lines;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::package_declaration_service::{
   get_package_class_scope_node, get_package_declaration_node,
 };
 use crate::common::ts_file::TSFile;
+use crate::common::types::generated_style::GeneratedStyle;
 use crate::common::types::id_field_config::IdFieldConfig;
 use crate::common::types::import_types::ImportInsertionPosition;
-use crate::common::types::java_basic_types::FieldInsertionPosition;
 use crate::common::types::java_id_generation::JavaIdGeneration;
 use crate::common::types::java_id_generation_type::JavaIdGenerationType;
-use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
-use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::column_naming_util::to_column_name;
+use crate::common::utils::naming_util;
 use crate::responses::file_response::FileResponse;
 use std::collections::HashMap;
 use std::path::Path;
@@ -40,35 +42,82 @@ fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
   }
 }
 
+/// Settings of the `@SequenceGenerator` emitted for entity exclusive sequence generation
+struct SequenceGeneratorConfig<'a> {
+  generator_name: &'a str,
+  sequence_name: &'a str,
+  initial_value: i64,
+  allocation_size: i64,
+}
+
+/// Resolves the `@SequenceGenerator` settings for `Sequence` + `EntityExclusiveGeneration`.
+/// The generator name is required; the sequence name defaults to the generator name (the
+/// provider default), and `initialValue`/`allocationSize` default to the JPA defaults of 1 and 50.
+fn resolve_sequence_generator(
+  field_config: &IdFieldConfig,
+) -> Result<Option<SequenceGeneratorConfig<'_>>, String> {
+  if field_config.field_id_generation.ne(&JavaIdGeneration::Sequence)
+    || field_config.field_id_generation_type.ne(&JavaIdGenerationType::EntityExclusiveGeneration)
+  {
+    return Ok(None);
+  }
+  let generator_name = optional_string(&field_config.field_generator_name)
+    .ok_or_else(|| "Generator name is required for entity exclusive generation".to_string())?;
+  let allocation_size = field_config.field_allocation_size.unwrap_or(50);
+  if allocation_size < 1 {
+    return Err("Allocation size must be greater than zero".to_string());
+  }
+  Ok(Some(SequenceGeneratorConfig {
+    generator_name,
+    sequence_name: optional_string(&field_config.field_sequence_name).unwrap_or(generator_name),
+    initial_value: field_config.field_initial_value.unwrap_or(1),
+    allocation_size,
+  }))
+}
+
 fn add_field_and_annotations(
   ts_file: &mut TSFile,
   field_config: &IdFieldConfig,
   import_map: &mut HashMap<String, String>,
 ) -> Result<(), String> {
-  let column_name_snake_case =
-    case_util::auto_convert_case(&field_config.field_name, CaseType::Snake);
-  let _field_name_pascal_case =
-    case_util::auto_convert_case(&field_config.field_name, CaseType::Pascal);
+  let sequence_generator = resolve_sequence_generator(field_config)?;
+  let persistence_package = field_config.persistence_namespace.package();
+  let column_name = to_column_name(&field_config.field_name, &field_config.column_naming_strategy);
+  let _field_name_pascal_case = naming_util::ensure_pascal_case(&field_config.field_name);
   let public_class_node = get_public_class_node(ts_file)
     .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
   let public_class_node_start_byte = public_class_node.start_byte();
   // Add required imports based on field configuration
-  add_to_import_map(import_map, "jakarta.persistence", "Column");
-  add_to_import_map(import_map, "jakarta.persistence", "Id");
-  add_to_import_map(import_map, "jakarta.persistence", "GeneratedValue");
-  add_to_import_map(import_map, "jakarta.persistence", "GenerationType");
+  add_to_import_map(import_map, persistence_package, "Column");
+  add_to_import_map(import_map, persistence_package, "Id");
+  add_to_import_map(import_map, persistence_package, "GeneratedValue");
+  add_to_import_map(import_map, persistence_package, "GenerationType");
   // Add field type import if it has a package
   if let Some(ref package_name) = field_config.field_type_package_name {
     add_to_import_map(import_map, package_name, &field_config.field_type);
   }
+  if field_config.mark_generated == Some(GeneratedStyle::Annotation) {
+    add_to_import_map(import_map, GeneratedStyle::ANNOTATION_PACKAGE, "Generated");
+  }
+  let insertion_position = prepare_field_insertion(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.field_name,
+    field_config.after_field.as_deref(),
+    field_config.at_top,
+    field_config.mark_generated.as_ref(),
+  )?;
   let params = AddFieldDeclarationParams {
-    insertion_position: FieldInsertionPosition::EndOfClassBody,
-    visibility_modifier: JavaVisibilityModifier::Private,
+    insertion_position,
+    visibility_modifier: field_config.field_access.visibility_modifier(),
     field_modifiers: vec![],
     field_type: &field_config.field_type,
     field_name: &field_config.field_name,
     field_initialization: None,
+    javadoc: field_config.javadoc.as_deref(),
+    generated_marker: field_config.mark_generated.as_ref(),
   };
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
     // Always add @Id annotation
     builder.add_annotation("@Id")?;
@@ -80,62 +129,23 @@ fn add_field_and_annotations(
         &format!("GenerationType.{}", field_config.field_id_generation.as_str()),
       )?;
       // Handle sequence generation with entity exclusive generation
-      if field_config.field_id_generation.eq(&JavaIdGeneration::Sequence)
-        && field_config
-          .field_id_generation_type
-          .eq(&JavaIdGenerationType::EntityExclusiveGeneration)
-      {
-        add_to_import_map(import_map, "jakarta.persistence", "SequenceGenerator");
-        // generator_name is required for entity_exclusive_generation
-        if let Some(generator_name) = optional_string(&field_config.field_generator_name) {
-          builder.with_argument(
-            "@GeneratedValue",
-            "generator",
-            &format!("\"{}\"", generator_name),
-          )?;
-          // Add @SequenceGenerator annotation
-          builder.add_annotation("@SequenceGenerator")?.with_argument(
+      if let Some(sequence) = sequence_generator {
+        add_to_import_map(import_map, persistence_package, "SequenceGenerator");
+        builder.with_string_argument("@GeneratedValue", "generator", sequence.generator_name)?;
+        builder
+          .add_annotation("@SequenceGenerator")?
+          .with_string_argument("@SequenceGenerator", "name", sequence.generator_name)?
+          .with_string_argument("@SequenceGenerator", "sequenceName", sequence.sequence_name)?
+          .with_argument("@SequenceGenerator", "initialValue", &sequence.initial_value.to_string())?
+          .with_argument(
             "@SequenceGenerator",
-            "name",
-            &format!("\"{}\"", generator_name),
+            "allocationSize",
+            &sequence.allocation_size.to_string(),
           )?;
-          // sequenceName is optional - only add if provided and not empty
-          if let Some(sequence_name) = optional_string(&field_config.field_sequence_name) {
-            builder.with_argument(
-              "@SequenceGenerator",
-              "sequenceName",
-              &format!("\"{}\"", sequence_name),
-            )?;
-          }
-          // Add initialValue if different from default (1)
-          if let Some(initial_value) = field_config.field_initial_value
-            && initial_value != 1
-          {
-            builder.with_argument(
-              "@SequenceGenerator",
-              "initialValue",
-              &initial_value.to_string(),
-            )?;
-          }
-          // Add allocationSize if different from default (50)
-          if let Some(allocation_size) = field_config.field_allocation_size
-            && allocation_size != 50
-          {
-            builder.with_argument(
-              "@SequenceGenerator",
-              "allocationSize",
-              &allocation_size.to_string(),
-            )?;
-          }
-        }
       }
     }
     // Add @Column annotation
-    builder.add_annotation("@Column")?.with_argument(
-      "@Column",
-      "name",
-      &format!("\"{}\"", &column_name_snake_case),
-    )?;
+    builder.add_annotation("@Column")?.with_string_argument("@Column", "name", &column_name)?;
     // Set nullable based on field_nullable
     if field_config.field_nullable {
       builder.with_argument("@Column", "nullable", "true")?;
@@ -159,7 +169,32 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Adds the id field with its annotations and imports to an already parsed entity, without
+/// saving it, so several fields can be added to the same buffer.
+pub fn add_field_to_entity(
+  entity_ts_file: &mut TSFile,
+  field_config: &IdFieldConfig,
+) -> Result<(), String> {
+  // Step 1: Prepare import map for required imports
+  let mut import_map = HashMap::new();
+  // Step 2: Add field and annotations to the entity
+  add_field_and_annotations(entity_ts_file, field_config, &mut import_map)?;
+  // Step 3: Add all required imports to the file
+  add_imports(entity_ts_file, &import_map);
+  // Step 4: Normalize blank lines when requested
+  if field_config.format_java {
+    normalize_blank_lines(entity_ts_file);
+  }
+  Ok(())
 }
 
 pub fn run(
@@ -170,16 +205,12 @@ pub fn run(
 ) -> Result<FileResponse, String> {
   // Step 1: Parse the entity file
   let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
-  // Step 2: Prepare import map for required imports
-  let mut import_map = HashMap::new();
-  // Step 3: Add field and annotations to the entity
-  add_field_and_annotations(&mut entity_ts_file, &field_config, &mut import_map)?;
-  // Step 4: Add all required imports to the file
-  add_imports(&mut entity_ts_file, &import_map);
-  // Step 5: Write the modified file back to disk
+  // Step 2: Add the field, its annotations and imports
+  add_field_to_entity(&mut entity_ts_file, &field_config)?;
+  // Step 3: Write the modified file back to disk
   entity_ts_file
     .save_as(entity_file_path, cwd)
     .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
-  // Step 6: Build and return response
+  // Step 4: Build and return response
   build_file_response(&entity_ts_file)
 }
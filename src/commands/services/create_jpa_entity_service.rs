@@ -10,30 +10,38 @@ use crate::common::services::class_declaration_service::{
 use crate::common::services::import_declaration_service::{self, add_import};
 use crate::common::ts_file::TSFile;
 use crate::common::types::annotation_types::AnnotationInsertionPosition;
+use crate::common::types::cache_concurrency_strategy::CacheConcurrencyStrategy;
 use crate::common::types::import_types::ImportInsertionPosition;
 use crate::common::types::java_file_type::JavaFileType;
 use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
-use crate::common::utils::case_util;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::naming_util;
+use crate::common::utils::naming_util::table_name_for_entity;
 use crate::responses::file_response::FileResponse;
 
-fn add_jpa_imports(ts_file: &mut TSFile) -> Result<(), String> {
+fn add_jpa_imports(
+  ts_file: &mut TSFile,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<(), String> {
+  let persistence_package = persistence_namespace.package();
   let entity_import_result = import_declaration_service::add_import(
     ts_file,
     &ImportInsertionPosition::AfterLastImport,
-    "jakarta.persistence",
+    persistence_package,
     "Entity",
   );
   if entity_import_result.is_none() {
-    return Err("Failed to add import for jakarta.persistence.Entity".to_string());
+    return Err(format!("Failed to add import for {}.Entity", persistence_package));
   }
   let table_import_result = import_declaration_service::add_import(
     ts_file,
     &ImportInsertionPosition::AfterLastImport,
-    "jakarta.persistence",
+    persistence_package,
     "Table",
   );
   if table_import_result.is_none() {
-    return Err("Failed to add import for jakarta.persistence.Table".to_string());
+    return Err(format!("Failed to add import for {}.Table", persistence_package));
   }
   Ok(())
 }
@@ -46,7 +54,13 @@ fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileRespo
     .map(|p| p.to_string_lossy().to_string())
     .ok_or("Failed to get file path")?;
   let file_package_name = package_name.to_string();
-  Ok(FileResponse { file_type: file_type_str, file_path, file_package_name })
+  Ok(FileResponse {
+    file_type: file_type_str,
+    file_path,
+    file_package_name,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
 }
 
 fn create_java_file_and_get_response(
@@ -88,8 +102,8 @@ fn add_table_name_argument(ts_file: &mut TSFile, class_name: &str) -> Result<(),
   let table_node = annotation_service::find_annotation_node_by_name(ts_file, class_node, "Table")
     .ok_or("@Table annotation not found".to_string())?;
   let table_byte_position = table_node.start_byte();
-  let table_name = case_util::to_snake_case(class_name);
-  let table_name_value = format!("\"{}\"", table_name);
+  let table_name = table_name_for_entity(class_name);
+  let table_name_value = render_string_value(&table_name)?;
   let result = annotation_service::add_annotation_argument(
     ts_file,
     table_byte_position,
@@ -103,6 +117,99 @@ fn add_table_name_argument(ts_file: &mut TSFile, class_name: &str) -> Result<(),
   }
 }
 
+/// Adds `@Cacheable` and Hibernate's `@Cache` below `@Table`, together with their imports. The
+/// region defaults to the fully qualified entity name, which is also Hibernate's default.
+fn add_cache_annotations(
+  ts_file: &mut TSFile,
+  package_name: &str,
+  class_name: &str,
+  cache_concurrency: &CacheConcurrencyStrategy,
+  cache_region: Option<&str>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<(), String> {
+  let imports = [
+    (persistence_namespace.package(), "Cacheable"),
+    ("org.hibernate.annotations", "Cache"),
+    ("org.hibernate.annotations", "CacheConcurrencyStrategy"),
+  ];
+  for (package, class) in imports {
+    if add_import(ts_file, &ImportInsertionPosition::AfterLastImport, package, class).is_none() {
+      return Err(format!("Failed to add import for {}.{}", package, class));
+    }
+  }
+  let default_region = format!("{}.{}", package_name, class_name);
+  let region = cache_region.unwrap_or(&default_region);
+  let cache_annotation = format!(
+    "@Cache(usage = CacheConcurrencyStrategy.{}, region = {})",
+    cache_concurrency.as_str(),
+    render_string_value(region)?
+  );
+  let position = AnnotationInsertionPosition::AboveScopeDeclaration;
+  for annotation in ["@Cacheable", cache_annotation.as_str()] {
+    let class_byte_position = get_class_byte_position(ts_file)?;
+    if annotation_service::add_annotation(ts_file, class_byte_position, &position, annotation)
+      .is_none()
+    {
+      return Err(format!("Failed to add {} annotation", annotation));
+    }
+  }
+  Ok(())
+}
+
+/// Adds Hibernate's `@DynamicInsert` and/or `@DynamicUpdate` below the existing class
+/// annotations, together with their imports.
+fn add_dynamic_sql_annotations(
+  ts_file: &mut TSFile,
+  dynamic_insert: bool,
+  dynamic_update: bool,
+) -> Result<(), String> {
+  let requested = [(dynamic_insert, "DynamicInsert"), (dynamic_update, "DynamicUpdate")];
+  let position = AnnotationInsertionPosition::AboveScopeDeclaration;
+  for (_, class) in requested.iter().filter(|(enabled, _)| *enabled) {
+    if add_import(
+      ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      "org.hibernate.annotations",
+      class,
+    )
+    .is_none()
+    {
+      return Err(format!("Failed to add import for org.hibernate.annotations.{}", class));
+    }
+    let class_byte_position = get_class_byte_position(ts_file)?;
+    let annotation = format!("@{}", class);
+    if annotation_service::add_annotation(ts_file, class_byte_position, &position, &annotation)
+      .is_none()
+    {
+      return Err(format!("Failed to add {} annotation", annotation));
+    }
+  }
+  Ok(())
+}
+
+/// Adds Hibernate's `@Immutable` below the existing class annotations, together with its import,
+/// for read-only entities such as the ones mapped to database views.
+fn add_immutable_annotation(ts_file: &mut TSFile) -> Result<(), String> {
+  if add_import(
+    ts_file,
+    &ImportInsertionPosition::AfterLastImport,
+    "org.hibernate.annotations",
+    "Immutable",
+  )
+  .is_none()
+  {
+    return Err("Failed to add import for org.hibernate.annotations.Immutable".to_string());
+  }
+  let class_byte_position = get_class_byte_position(ts_file)?;
+  let position = AnnotationInsertionPosition::AboveScopeDeclaration;
+  if annotation_service::add_annotation(ts_file, class_byte_position, &position, "@Immutable")
+    .is_none()
+  {
+    return Err("Failed to add @Immutable annotation".to_string());
+  }
+  Ok(())
+}
+
 fn add_superclass_heritage(
   ts_file: &mut TSFile,
   superclass_type_opt: Option<&str>,
@@ -142,19 +249,26 @@ fn save_ts_file(
   ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
   cwd: &Path,
   package_name: &str,
   file_name: &str,
   superclass_type: Option<&str>,
   superclass_package_name: Option<&str>,
+  cache_concurrency: Option<&CacheConcurrencyStrategy>,
+  cache_region: Option<&str>,
+  dynamic_insert: bool,
+  dynamic_update: bool,
+  immutable: bool,
+  persistence_namespace: &PersistenceNamespace,
 ) -> Result<FileResponse, String> {
   // Normalize the class name to PascalCase
-  let normalized_class_name = case_util::to_pascal_case(file_name);
+  let normalized_class_name = naming_util::to_pascal_case(file_name);
   // Step 1: Create the Java file
   let mut ts_file = create_java_file_and_get_response(package_name, &normalized_class_name)?;
   // Step 2: Add required imports for JPA annotations
-  add_jpa_imports(&mut ts_file)?;
+  add_jpa_imports(&mut ts_file, persistence_namespace)?;
   // Step 3: Get the public class node byte position after imports are added
   let class_byte_position = get_class_byte_position(&ts_file)?;
   // Step 4: Add @Entity annotation above the class declaration
@@ -165,10 +279,27 @@ pub fn run(
   add_table_annotation(&mut ts_file, updated_class_position)?;
   // Step 7: Add table name argument with snake_case conversion
   add_table_name_argument(&mut ts_file, &normalized_class_name)?;
-  // Step 8: Add superclass heritage
+  // Step 8: Add second-level cache annotations when the entity is cacheable
+  if let Some(cache_concurrency) = cache_concurrency {
+    add_cache_annotations(
+      &mut ts_file,
+      package_name,
+      &normalized_class_name,
+      cache_concurrency,
+      cache_region,
+      persistence_namespace,
+    )?;
+  }
+  // Step 9: Add @DynamicInsert/@DynamicUpdate when requested
+  add_dynamic_sql_annotations(&mut ts_file, dynamic_insert, dynamic_update)?;
+  // Step 10: Add @Immutable for read-only entities
+  if immutable {
+    add_immutable_annotation(&mut ts_file)?;
+  }
+  // Step 11: Add superclass heritage
   add_superclass_heritage(&mut ts_file, superclass_type, superclass_package_name)?;
-  // Step 9: Save the updated TSFile to disk
+  // Step 12: Save the updated TSFile to disk
   save_ts_file(&mut ts_file, cwd, file_name, package_name)?;
-  // Step 10: Build and return the final file response
+  // Step 13: Build and return the final file response
   build_file_response(&ts_file, package_name)
 }
@@ -1,14 +1,17 @@
 use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::field_declaration_service::{
-  AddFieldDeclarationParams, add_field_declaration,
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
 };
+use crate::common::services::formatting_service::normalize_blank_lines;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::package_declaration_service::{
   get_package_declaration_node, get_package_scope_node,
 };
+use crate::common::services::relationship_field_service::ensure_relationship_fields_available;
 use crate::common::ts_file::TSFile;
 use crate::common::types::annotation_config::AnnotationConfig;
 use crate::common::types::cascade_type::CascadeType;
+use crate::common::types::collection_type::CollectionType;
 use crate::common::types::entity_side::EntitySide;
 use crate::common::types::fetch_type::FetchType;
 use crate::common::types::import_types::ImportInsertionPosition;
@@ -18,7 +21,9 @@ use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
 use crate::common::types::many_to_one_field_config::ManyToOneFieldConfig;
 use crate::common::types::mapping_type::MappingType;
 use crate::common::types::other_type::OtherType;
-use crate::common::utils::case_util::{self, CaseType};
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::naming_util;
 use crate::common::utils::path_util::parse_all_files;
 use crate::responses::file_response::FileResponse;
 use std::collections::HashMap;
@@ -41,22 +46,50 @@ fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
   }
 }
 
-fn find_inverse_entity(cwd: &Path, class_name: &str) -> Result<PathBuf, String> {
+/// Finds the inverse entity by class name, restricted to `package_name` when given. Fails
+/// rather than guessing when several entities of the project share the class name.
+fn find_inverse_entity(
+  cwd: &Path,
+  class_name: &str,
+  package_name: Option<&str>,
+) -> Result<PathBuf, String> {
+  use crate::common::services::annotation_service::find_annotation_node_by_name;
   let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
-  for ts_file in ts_files {
-    if let Some(file_name) = ts_file.get_file_name_without_ext()
-      && file_name == class_name
-      && let Some(public_class_node) = get_public_class_node(&ts_file)
-    {
-      use crate::common::services::annotation_service::find_annotation_node_by_name;
-      if find_annotation_node_by_name(&ts_file, public_class_node, "Entity").is_some()
-        && let Some(file_path) = ts_file.file_path()
-      {
-        return Ok(file_path.to_path_buf());
+  let mut candidates: Vec<(String, PathBuf)> = ts_files
+    .iter()
+    .filter(|ts_file| ts_file.get_file_name_without_ext().as_deref() == Some(class_name))
+    .filter(|ts_file| {
+      get_public_class_node(ts_file)
+        .is_some_and(|node| find_annotation_node_by_name(ts_file, node, "Entity").is_some())
+    })
+    .filter_map(|ts_file| {
+      let entity_package = get_package_declaration_node(ts_file)
+        .and_then(|node| get_package_scope_node(ts_file, node))
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .unwrap_or_default()
+        .to_string();
+      Some((entity_package, ts_file.file_path()?.to_path_buf()))
+    })
+    .filter(|(entity_package, _)| package_name.is_none_or(|package| package == entity_package))
+    .collect();
+  candidates.sort();
+  match candidates.as_slice() {
+    [] => match package_name {
+      Some(package) => {
+        Err(format!("Entity class '{}.{}' not found in project", package, class_name))
       }
+      None => Err(format!("Unable to find entity with class name: {}", class_name)),
+    },
+    [(_, file_path)] => Ok(file_path.clone()),
+    _ => {
+      let packages: Vec<&str> = candidates.iter().map(|(package, _)| package.as_str()).collect();
+      Err(format!(
+        "Entity class '{}' is ambiguous, found in packages: {}; use --inverse-field-package to pick one",
+        class_name,
+        packages.join(", ")
+      ))
     }
   }
-  Err(format!("Unable to find entity with class name: {}", class_name))
 }
 
 fn extract_owning_entity_class_name(file_path: &Path) -> Result<String, String> {
@@ -116,42 +149,62 @@ fn build_annotation_config(
     field_config.fetch_type.clone(),
     field_config.collection_type.clone(),
   )
+  .with_join_column_names(
+    field_config.join_column_name.clone(),
+    field_config.referenced_column_name.clone(),
+  )
+  .with_maps_id(if is_owning_side { field_config.maps_id.clone() } else { None })
+  .with_declared_collection_type(field_config.declared_collection_type.clone())
+  .with_collection_initializer(field_config.initialize_collection)
+  .with_collection_ordering(field_config.order_by.clone(), field_config.order_column.clone())
 }
 
 fn build_import_map(
   target_entity_type: &str,
   target_entity_file_path: &Path,
   annotation_config: &AnnotationConfig,
+  persistence_namespace: &PersistenceNamespace,
 ) -> Result<HashMap<String, String>, String> {
   let mut import_map = HashMap::new();
+  let persistence_package = persistence_namespace.package();
 
   // Add JPA imports based on side
   if annotation_config.is_owning_side {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "ManyToOne");
+    add_to_import_map(&mut import_map, persistence_package, "ManyToOne");
   } else {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "OneToMany");
-    add_to_import_map(
-      &mut import_map,
-      "java.util",
-      annotation_config.get_collection_type().unwrap().as_java_type(),
-    );
+    add_to_import_map(&mut import_map, persistence_package, "OneToMany");
+    let collection_type = annotation_config.get_collection_type().unwrap();
+    let declared_collection_type = annotation_config.get_declared_collection_type().unwrap();
+    add_to_import_map(&mut import_map, "java.util", declared_collection_type.as_java_type());
+    if annotation_config.initialize_collection {
+      add_to_import_map(&mut import_map, "java.util", collection_type.as_java_implementation());
+    }
+    if annotation_config.order_by.is_some() {
+      add_to_import_map(&mut import_map, persistence_package, "OrderBy");
+    }
+    if annotation_config.order_column.is_some() {
+      add_to_import_map(&mut import_map, persistence_package, "OrderColumn");
+    }
   }
 
   // Add FetchType import if needed
   if let Some(fetch_type) = annotation_config.get_fetch_type()
     && *fetch_type != FetchType::None
   {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "FetchType");
+    add_to_import_map(&mut import_map, persistence_package, "FetchType");
   }
 
   // Add JoinColumn import if needed
   if annotation_config.needs_join_column {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "JoinColumn");
+    add_to_import_map(&mut import_map, persistence_package, "JoinColumn");
+  }
+  if annotation_config.maps_id.is_some() {
+    add_to_import_map(&mut import_map, persistence_package, "MapsId");
   }
 
   // Add CascadeType import if needed
   if !annotation_config.cascades.is_empty() {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "CascadeType");
+    add_to_import_map(&mut import_map, persistence_package, "CascadeType");
   }
 
   // Add target entity import
@@ -184,18 +237,29 @@ fn add_relationship_field_and_annotations(
   } else {
     format!(
       "{}<{}>",
-      annotation_config.get_collection_type().unwrap().as_java_type(),
+      annotation_config.get_declared_collection_type().unwrap().as_java_type(),
       target_entity_type
     )
   };
+  let field_initialization = match annotation_config.get_collection_type() {
+    Some(collection_type)
+      if !annotation_config.is_owning_side && annotation_config.initialize_collection =>
+    {
+      Some(format!("new {}<>()", collection_type.as_java_implementation()))
+    }
+    _ => None,
+  };
   let params = AddFieldDeclarationParams {
     insertion_position: FieldInsertionPosition::EndOfClassBody,
     visibility_modifier: JavaVisibilityModifier::Private,
     field_modifiers: vec![],
     field_type: &field_type,
     field_name,
-    field_initialization: None,
+    field_initialization: field_initialization.as_deref(),
+    javadoc: None,
+    generated_marker: None,
   };
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
     if annotation_config.is_owning_side {
       builder.add_annotation("@ManyToOne")?;
@@ -220,7 +284,7 @@ fn add_relationship_field_and_annotations(
     } else {
       builder.add_annotation("@OneToMany")?;
       if let Some(ref mapped_by_field) = annotation_config.mapped_by_field {
-        builder.with_argument("@OneToMany", "mappedBy", &format!("\"{}\"", mapped_by_field))?;
+        builder.with_string_argument("@OneToMany", "mappedBy", mapped_by_field)?;
       }
       if let Some(cascade_param) = build_cascade_param(&annotation_config.cascades) {
         builder.with_argument("@OneToMany", "cascade", &cascade_param)?;
@@ -228,12 +292,34 @@ fn add_relationship_field_and_annotations(
       if annotation_config.other_options.contains(&OtherType::OrphanRemoval) {
         builder.with_argument("@OneToMany", "orphanRemoval", "true")?;
       }
+      if let Some(ref order_by) = annotation_config.order_by {
+        builder.add_annotation(&format!("@OrderBy({})", render_string_value(order_by)?))?;
+      }
+      if let Some(ref order_column) = annotation_config.order_column {
+        builder.add_annotation("@OrderColumn")?;
+        builder.with_string_argument("@OrderColumn", "name", order_column)?;
+      }
+    }
+    if let Some(ref maps_id) = annotation_config.maps_id {
+      builder.add_annotation("@MapsId")?;
+      if let Some(attribute) = maps_id {
+        builder.with_value("@MapsId", &render_string_value(attribute)?)?;
+      }
     }
     if annotation_config.needs_join_column {
       builder.add_annotation("@JoinColumn")?;
-      let column_name_snake_case = case_util::auto_convert_case(field_name, CaseType::Snake);
-      let column_name = format!("{}_id", column_name_snake_case);
-      builder.with_argument("@JoinColumn", "name", &format!("\"{}\"", column_name))?;
+      let column_name = annotation_config
+        .join_column_name
+        .clone()
+        .unwrap_or_else(|| format!("{}_id", naming_util::to_snake_case(field_name)));
+      builder.with_string_argument("@JoinColumn", "name", &column_name)?;
+      if let Some(ref referenced_column_name) = annotation_config.referenced_column_name {
+        builder.with_string_argument(
+          "@JoinColumn",
+          "referencedColumnName",
+          referenced_column_name,
+        )?;
+      }
       let is_mandatory = annotation_config.other_options.contains(&OtherType::Mandatory);
       if is_mandatory {
         builder.with_argument("@JoinColumn", "nullable", "false")?;
@@ -280,7 +366,13 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
 }
 
 fn is_bidirectional_mapping(field_config: &ManyToOneFieldConfig) -> bool {
@@ -360,6 +452,7 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
     params.target_entity_type,
     params.target_entity_file_path,
     &annotation_config,
+    &params.field_config.persistence_namespace,
   )?;
   // Step 4: Add relationship field and annotations
   add_relationship_field_and_annotations(
@@ -370,12 +463,67 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
   )?;
   // Step 5: Add imports
   add_imports(&mut entity_ts_file, &import_map);
-  // Step 6: Save file
+  // Step 6: Normalize blank lines when requested
+  if params.field_config.format_java {
+    normalize_blank_lines(&mut entity_ts_file);
+  }
+  // Step 7: Save file
   save_entity_file(&mut entity_ts_file, params.save_path, params.cwd)?;
-  // Step 7: Build and return response
+  // Step 8: Build and return response
   build_file_response(&entity_ts_file)
 }
 
+/// Checks that the declared type of the inverse side collection can hold its initializer: a
+/// `Collection` holds any of them, a `List` or `Set` only its own kind.
+fn validate_declared_collection_type(field_config: &ManyToOneFieldConfig) -> Result<(), String> {
+  match &field_config.declared_collection_type {
+    Some(declared_collection_type)
+      if *declared_collection_type != CollectionType::Collection
+        && *declared_collection_type != field_config.collection_type =>
+    {
+      Err(format!(
+        "Declared collection type {} cannot hold a {} collection",
+        declared_collection_type.as_java_type(),
+        field_config.collection_type.as_java_type()
+      ))
+    }
+    _ => Ok(()),
+  }
+}
+
+/// Checks the ordering of the inverse side collection: `@OrderBy` and `@OrderColumn` exclude
+/// each other, and `@OrderColumn` persists positions, which only a declared `List` has.
+fn validate_collection_ordering(field_config: &ManyToOneFieldConfig) -> Result<(), String> {
+  match (&field_config.order_by, &field_config.order_column) {
+    (None, None) => return Ok(()),
+    (Some(_), Some(_)) => {
+      return Err("@OrderBy and @OrderColumn cannot be combined".to_string());
+    }
+    (Some(order_by), None) if order_by.trim().is_empty() => {
+      return Err("@OrderBy clause cannot be empty".to_string());
+    }
+    (None, Some(order_column)) if order_column.trim().is_empty() => {
+      return Err("@OrderColumn name cannot be empty".to_string());
+    }
+    _ => {}
+  }
+  if !is_bidirectional_mapping(field_config) {
+    return Err(
+      "Collection ordering requires a bidirectional mapping, as only its inverse side declares a collection"
+        .to_string(),
+    );
+  }
+  let declared_collection_type =
+    field_config.declared_collection_type.as_ref().unwrap_or(&field_config.collection_type);
+  if field_config.order_column.is_some() && *declared_collection_type != CollectionType::List {
+    return Err(format!(
+      "@OrderColumn requires a List collection type, not {}",
+      declared_collection_type.as_java_type()
+    ));
+  }
+  Ok(())
+}
+
 pub fn run(
   cwd: &Path,
   owning_side_entity_file_b64_src: &str,
@@ -384,11 +532,28 @@ pub fn run(
   inverse_side_field_name: &str,
   field_config: &ManyToOneFieldConfig,
 ) -> Result<Vec<FileResponse>, String> {
-  // Step 1: Find inverse entity by class name
-  let inverse_entity_file_path = find_inverse_entity(cwd, &field_config.inverse_field_type)?;
-  // Step 2: Extract owning entity class name for inverse side
+  // Step 1: Validate the declared type and ordering of the inverse side collection
+  validate_declared_collection_type(field_config)?;
+  validate_collection_ordering(field_config)?;
+  // Step 2: Find inverse entity by class name
+  let inverse_entity_file_path = find_inverse_entity(
+    cwd,
+    &field_config.inverse_field_type,
+    field_config.inverse_field_package.as_deref(),
+  )?;
+  // Step 3: Extract owning entity class name for inverse side
   let owning_entity_class_name = extract_owning_entity_class_name(owning_side_entity_file_path)?;
-  // Step 3: Process owning side entity (ManyToOne side)
+  // Step 4: Check that neither side already declares the field
+  let inverse_side = is_bidirectional_mapping(field_config)
+    .then_some((inverse_entity_file_path.as_path(), inverse_side_field_name));
+  ensure_relationship_fields_available(
+    owning_side_entity_file_b64_src,
+    owning_side_entity_file_path,
+    owning_side_field_name,
+    inverse_side,
+    field_config.maps_id.as_ref(),
+  )?;
+  // Step 5: Process owning side entity (ManyToOne side)
   let owning_response = process_owning_side_entity(
     cwd,
     owning_side_entity_file_b64_src,
@@ -399,7 +564,7 @@ pub fn run(
     field_config,
   )?;
   let mut responses = vec![owning_response];
-  // Step 4: Process inverse side entity (OneToMany side) if bidirectional
+  // Step 6: Process inverse side entity (OneToMany side) if bidirectional
   if is_bidirectional_mapping(field_config) {
     let inverse_response = process_inverse_side_entity(
       cwd,
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use crate::commands::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::indent_util::apply_indent_override;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+
+fn collect_imports(
+  generic_id: bool,
+  with_auditing: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> Vec<String> {
+  let persistence_import =
+    |class_name: &str| format!("{}.{}", persistence_namespace.package(), class_name);
+  let mut imports = vec![persistence_import("MappedSuperclass")];
+  if generic_id {
+    imports.extend([persistence_import("Id"), "java.io.Serializable".to_string()]);
+  }
+  if with_auditing {
+    imports.push(persistence_import("Column"));
+    imports.extend(
+      [
+        "java.time.Instant",
+        "org.hibernate.annotations.CreationTimestamp",
+        "org.hibernate.annotations.UpdateTimestamp",
+      ]
+      .map(String::from),
+    );
+  }
+  imports.sort();
+  imports
+}
+
+/// Builds the superclass source. Fields are `protected` so subclasses can use them.
+fn generate_mapped_superclass_template(
+  package_name: &str,
+  class_name: &str,
+  generic_id: bool,
+  with_auditing: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> String {
+  let mut template = format!("package {};\n\n", package_name);
+  for import in collect_imports(generic_id, with_auditing, persistence_namespace) {
+    template.push_str(&format!("import {};\n", import));
+  }
+  let type_parameters = if generic_id { "<ID extends Serializable>" } else { "" };
+  template.push_str(&format!(
+    "\n@MappedSuperclass\npublic abstract class {}{} {{\n",
+    class_name, type_parameters
+  ));
+  let mut members = Vec::new();
+  if generic_id {
+    members.push("  @Id\n  protected ID id;\n".to_string());
+  }
+  if with_auditing {
+    members.push(
+      "  @CreationTimestamp\n  @Column(name = \"created_at\", nullable = false, updatable = false)\n  protected Instant createdAt;\n"
+        .to_string(),
+    );
+    members.push(
+      "  @UpdateTimestamp\n  @Column(name = \"updated_at\", nullable = false)\n  protected Instant updatedAt;\n"
+        .to_string(),
+    );
+  }
+  if !members.is_empty() {
+    template.push('\n');
+    template.push_str(&members.join("\n"));
+  }
+  template.push_str("}\n");
+  apply_indent_override(template)
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .ok_or("Failed to get file path")?;
+  Ok(FileResponse {
+    file_type,
+    file_path,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Scaffolds an abstract `@MappedSuperclass` for entities to extend. `generic_id` declares an
+/// `@Id` typed by the `ID` type parameter and `with_auditing` adds creation and update
+/// timestamps maintained by Hibernate.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  generic_id: bool,
+  with_auditing: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<FileResponse, String> {
+  // Normalize the class name to PascalCase
+  let normalized_class_name = naming_util::to_pascal_case(file_name);
+  // Step 1: Build save path with security validation
+  let corrected_file_name = correct_java_file_name(&normalized_class_name);
+  let save_path =
+    build_save_path(&JavaSourceDirectoryType::Main, cwd, package_name, &corrected_file_name)?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 2: Generate the superclass and save it
+  let file_template = generate_mapped_superclass_template(
+    package_name,
+    &normalized_class_name,
+    generic_id,
+    with_auditing,
+    persistence_namespace,
+  );
+  let mut ts_file = create_ts_file(&file_template);
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 3: Build and return the final file response
+  build_file_response(&ts_file, package_name)
+}
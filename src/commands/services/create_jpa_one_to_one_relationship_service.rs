@@ -1,15 +1,18 @@
 use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::field_declaration_service::{
-  AddFieldDeclarationParams, add_field_declaration,
+  AddFieldDeclarationParams, add_field_declaration, ensure_field_name_available,
 };
+use crate::common::services::formatting_service::normalize_blank_lines;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::package_declaration_service::{
   get_package_declaration_node, get_package_scope_node,
 };
+use crate::common::services::relationship_field_service::ensure_relationship_fields_available;
 use crate::common::ts_file::TSFile;
 use crate::common::types::annotation_config::AnnotationConfig;
 use crate::common::types::cascade_type::CascadeType;
 use crate::common::types::entity_side::EntitySide;
+use crate::common::types::fetch_type::FetchType;
 use crate::common::types::import_types::ImportInsertionPosition;
 use crate::common::types::java_basic_types::FieldInsertionPosition;
 use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
@@ -17,7 +20,9 @@ use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
 use crate::common::types::mapping_type::MappingType;
 use crate::common::types::one_to_one_field_config::OneToOneFieldConfig;
 use crate::common::types::other_type::OtherType;
-use crate::common::utils::case_util::{self, CaseType};
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::naming_util;
 use crate::common::utils::path_util::parse_all_files;
 use crate::responses::file_response::FileResponse;
 use std::collections::HashMap;
@@ -53,22 +58,50 @@ fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
   }
 }
 
-fn find_inverse_entity(cwd: &Path, class_name: &str) -> Result<PathBuf, String> {
+/// Finds the inverse entity by class name, restricted to `package_name` when given. Fails
+/// rather than guessing when several entities of the project share the class name.
+fn find_inverse_entity(
+  cwd: &Path,
+  class_name: &str,
+  package_name: Option<&str>,
+) -> Result<PathBuf, String> {
+  use crate::common::services::annotation_service::find_annotation_node_by_name;
   let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
-  for ts_file in ts_files {
-    if let Some(file_name) = ts_file.get_file_name_without_ext()
-      && file_name == class_name
-      && let Some(public_class_node) = get_public_class_node(&ts_file)
-    {
-      use crate::common::services::annotation_service::find_annotation_node_by_name;
-      if find_annotation_node_by_name(&ts_file, public_class_node, "Entity").is_some()
-        && let Some(file_path) = ts_file.file_path()
-      {
-        return Ok(file_path.to_path_buf());
+  let mut candidates: Vec<(String, PathBuf)> = ts_files
+    .iter()
+    .filter(|ts_file| ts_file.get_file_name_without_ext().as_deref() == Some(class_name))
+    .filter(|ts_file| {
+      get_public_class_node(ts_file)
+        .is_some_and(|node| find_annotation_node_by_name(ts_file, node, "Entity").is_some())
+    })
+    .filter_map(|ts_file| {
+      let entity_package = get_package_declaration_node(ts_file)
+        .and_then(|node| get_package_scope_node(ts_file, node))
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .unwrap_or_default()
+        .to_string();
+      Some((entity_package, ts_file.file_path()?.to_path_buf()))
+    })
+    .filter(|(entity_package, _)| package_name.is_none_or(|package| package == entity_package))
+    .collect();
+  candidates.sort();
+  match candidates.as_slice() {
+    [] => match package_name {
+      Some(package) => {
+        Err(format!("Entity class '{}.{}' not found in project", package, class_name))
       }
+      None => Err(format!("Entity class '{}' not found in project", class_name)),
+    },
+    [(_, file_path)] => Ok(file_path.clone()),
+    _ => {
+      let packages: Vec<&str> = candidates.iter().map(|(package, _)| package.as_str()).collect();
+      Err(format!(
+        "Entity class '{}' is ambiguous, found in packages: {}; use --inverse-field-package to pick one",
+        class_name,
+        packages.join(", ")
+      ))
     }
   }
-  Err(format!("Entity class '{}' not found in project", class_name))
 }
 
 fn extract_owning_entity_class_name(file_path: &Path) -> Result<String, String> {
@@ -125,23 +158,39 @@ fn build_annotation_config(
     other_options,
     if is_owning_side || is_unidirectional { None } else { mapped_by_field_name.clone() },
     is_owning_side || is_unidirectional,
+    if is_owning_side { field_config.fetch_type.clone() } else { None },
   )
+  .with_join_column_names(
+    field_config.join_column_name.clone(),
+    field_config.referenced_column_name.clone(),
+  )
+  .with_maps_id(if is_owning_side { field_config.maps_id.clone() } else { None })
 }
 
 fn build_import_map(
   target_entity_type: &str,
   target_entity_file_path: &Path,
   annotation_config: &AnnotationConfig,
+  persistence_namespace: &PersistenceNamespace,
 ) -> Result<HashMap<String, String>, String> {
   let mut import_map = HashMap::new();
+  let persistence_package = persistence_namespace.package();
 
   // Add JPA imports
-  add_to_import_map(&mut import_map, "jakarta.persistence", "OneToOne");
+  add_to_import_map(&mut import_map, persistence_package, "OneToOne");
   if annotation_config.needs_join_column {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "JoinColumn");
+    add_to_import_map(&mut import_map, persistence_package, "JoinColumn");
+  }
+  if annotation_config.maps_id.is_some() {
+    add_to_import_map(&mut import_map, persistence_package, "MapsId");
   }
   if !annotation_config.cascades.is_empty() {
-    add_to_import_map(&mut import_map, "jakarta.persistence", "CascadeType");
+    add_to_import_map(&mut import_map, persistence_package, "CascadeType");
+  }
+  if let Some(fetch_type) = annotation_config.get_fetch_type()
+    && *fetch_type != FetchType::None
+  {
+    add_to_import_map(&mut import_map, persistence_package, "FetchType");
   }
 
   // Add target entity import
@@ -175,7 +224,7 @@ fn add_relationship_field_and_annotations(
   let public_class_node = get_public_class_node(ts_file)
     .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
   let public_class_node_start_byte = public_class_node.start_byte();
-  let field_name_camel_case = case_util::auto_convert_case(field_name, CaseType::Camel);
+  let field_name_camel_case = naming_util::ensure_camel_case(field_name);
   let params = AddFieldDeclarationParams {
     insertion_position: FieldInsertionPosition::EndOfClassBody,
     visibility_modifier: JavaVisibilityModifier::Private,
@@ -183,9 +232,17 @@ fn add_relationship_field_and_annotations(
     field_type: target_entity_type,
     field_name: &field_name_camel_case,
     field_initialization: None,
+    javadoc: None,
+    generated_marker: None,
   };
+  ensure_field_name_available(ts_file, public_class_node_start_byte, params.field_name)?;
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
     builder.add_annotation("@OneToOne")?;
+    if let Some(fetch_type) = annotation_config.get_fetch_type()
+      && *fetch_type != FetchType::None
+    {
+      builder.with_argument("@OneToOne", "fetch", &format!("FetchType.{}", fetch_type.as_str()))?;
+    }
     if let Some(cascade_param) = build_cascade_param(&annotation_config.cascades) {
       builder.with_argument("@OneToOne", "cascade", &cascade_param)?;
     }
@@ -199,14 +256,28 @@ fn add_relationship_field_and_annotations(
       builder.with_argument("@OneToOne", "orphanRemoval", "true")?;
     }
     if let Some(ref mapped_by_field) = annotation_config.mapped_by_field {
-      builder.with_argument("@OneToOne", "mappedBy", &format!("\"{}\"", mapped_by_field))?;
+      builder.with_string_argument("@OneToOne", "mappedBy", mapped_by_field)?;
+    }
+    if let Some(ref maps_id) = annotation_config.maps_id {
+      builder.add_annotation("@MapsId")?;
+      if let Some(attribute) = maps_id {
+        builder.with_value("@MapsId", &render_string_value(attribute)?)?;
+      }
     }
     if annotation_config.needs_join_column {
       builder.add_annotation("@JoinColumn")?;
-      let field_name_snake_case =
-        case_util::auto_convert_case(&field_name_camel_case, CaseType::Snake);
-      let column_name = format!("{}_id", field_name_snake_case);
-      builder.with_argument("@JoinColumn", "name", &format!("\"{}\"", column_name))?;
+      let column_name = annotation_config
+        .join_column_name
+        .clone()
+        .unwrap_or_else(|| format!("{}_id", naming_util::to_snake_case(&field_name_camel_case)));
+      builder.with_string_argument("@JoinColumn", "name", &column_name)?;
+      if let Some(ref referenced_column_name) = annotation_config.referenced_column_name {
+        builder.with_string_argument(
+          "@JoinColumn",
+          "referencedColumnName",
+          referenced_column_name,
+        )?;
+      }
       if is_mandatory {
         builder.with_argument("@JoinColumn", "nullable", "false")?;
       } else {
@@ -252,7 +323,13 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
 }
 
 fn is_bidirectional_mapping(field_config: &OneToOneFieldConfig) -> bool {
@@ -319,6 +396,7 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
     params.target_entity_type,
     params.target_entity_file_path,
     &annotation_config,
+    &params.field_config.persistence_namespace,
   )?;
   // Step 4: Add relationship field and annotations
   add_relationship_field_and_annotations(
@@ -329,9 +407,13 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
   )?;
   // Step 5: Add imports
   add_imports(&mut entity_ts_file, &import_map);
-  // Step 6: Save file
+  // Step 6: Normalize blank lines when requested
+  if params.field_config.format_java {
+    normalize_blank_lines(&mut entity_ts_file);
+  }
+  // Step 7: Save file
   save_entity_file(&mut entity_ts_file, params.save_path, params.cwd)?;
-  // Step 7: Build and return response
+  // Step 8: Build and return response
   build_file_response(&entity_ts_file)
 }
 
@@ -344,10 +426,24 @@ pub fn run(
   field_config: &OneToOneFieldConfig,
 ) -> Result<Vec<FileResponse>, String> {
   // Step 1: Find inverse entity by class name
-  let inverse_entity_file_path = find_inverse_entity(cwd, &field_config.inverse_field_type)?;
+  let inverse_entity_file_path = find_inverse_entity(
+    cwd,
+    &field_config.inverse_field_type,
+    field_config.inverse_field_package.as_deref(),
+  )?;
   // Step 2: Extract owning entity class name for inverse side
   let owning_entity_class_name = extract_owning_entity_class_name(owning_side_entity_file_path)?;
-  // Step 3: Process owning side entity
+  // Step 3: Check that neither side already declares the field
+  let inverse_side = is_bidirectional_mapping(field_config)
+    .then_some((inverse_entity_file_path.as_path(), inverse_side_field_name));
+  ensure_relationship_fields_available(
+    owning_side_entity_file_b64_src,
+    owning_side_entity_file_path,
+    owning_side_field_name,
+    inverse_side,
+    field_config.maps_id.as_ref(),
+  )?;
+  // Step 4: Process owning side entity
   let owning_response = process_owning_side_entity(
     cwd,
     owning_side_entity_file_b64_src,
@@ -358,7 +454,7 @@ pub fn run(
     field_config,
   )?;
   let mut responses = vec![owning_response];
-  // Step 4: Process inverse side entity (if bidirectional)
+  // Step 5: Process inverse side entity (if bidirectional)
   if is_bidirectional_mapping(field_config) {
     let inverse_response = process_inverse_side_entity(
       cwd,
@@ -371,6 +467,6 @@ pub fn run(
     )?;
     responses.push(inverse_response);
   }
-  // Step 5: Return processed file responses
+  // Step 6: Return processed file responses
   Ok(responses)
 }
@@ -1,7 +1,12 @@
 use std::path::{Path, PathBuf};
 
+use tree_sitter::Node;
+
 use crate::commands::services::create_java_file_service::{self};
-use crate::commands::services::get_jpa_entity_info_service::{self};
+use crate::commands::services::get_jpa_entity_info_service::{
+  self, get_class_type_parameter_names, resolve_type_package,
+};
+use crate::common::services::class_declaration_service::get_public_class_node;
 use crate::common::services::import_declaration_service::add_import;
 use crate::common::services::interface_declaration_service::{
   get_interface_name_node, get_public_interface_node,
@@ -17,6 +22,15 @@ use crate::responses::create_jpa_repository_response::CreateJPARepositoryRespons
 use crate::responses::file_response::FileResponse;
 use crate::responses::get_jpa_entity_info_response::GetJpaEntityInfoResponse;
 
+/// Id type used when neither the entity nor its superclass declares an id.
+const DEFAULT_ID_TYPE: &str = "Long";
+
+/// Simple name and package of the repository's id type.
+struct IdType {
+  name: String,
+  package_name: String,
+}
+
 fn create_repository_file(
   cwd: &Path,
   entity_ts_file: &TSFile,
@@ -51,6 +65,7 @@ fn create_repository_file(
     &repository_file_name,
     &repository_file_type,
     &repository_source_dir_type,
+    false,
   )?;
   Ok(create_java_file_response)
 }
@@ -59,8 +74,14 @@ pub fn create_jpa_repository_response(
   id_field_found: bool,
   superclass_type: Option<String>,
   file_response: Option<FileResponse>,
+  warnings: Vec<String>,
 ) -> CreateJPARepositoryResponse {
-  CreateJPARepositoryResponse { id_field_found, superclass_type, repository: file_response }
+  CreateJPARepositoryResponse {
+    id_field_found,
+    superclass_type,
+    repository: file_response,
+    warnings,
+  }
 }
 
 fn create_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
@@ -77,7 +98,13 @@ fn create_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     })
     .and_then(|scope_node| ts_file.get_text_from_node(&scope_node).map(|s| s.to_string()))
     .unwrap_or_default();
-  Ok(FileResponse { file_path, file_type, file_package_name: package_name })
+  Ok(FileResponse {
+    file_path,
+    file_type,
+    file_package_name: package_name,
+    module: None,
+    edits: ts_file.edit_records(),
+  })
 }
 
 fn create_and_extend_jpa_repository(
@@ -85,18 +112,25 @@ fn create_and_extend_jpa_repository(
   entity_ts_file: &TSFile,
   entity_file_path: &Path,
   entity_type: &str,
-  jpa_entity_info: &GetJpaEntityInfoResponse,
+  id_type: &IdType,
 ) -> Result<TSFile, String> {
   let create_repository_file_response =
     create_repository_file(cwd, entity_ts_file, entity_file_path)?;
   let jpa_repository_path = PathBuf::from(&create_repository_file_response.file_path);
   let mut jpa_repository_ts_file = TSFile::from_file(jpa_repository_path.as_path())
     .map_err(|e| format!("Unable to parse newly created repository file: {}", e))?;
+  // The repository shares the entity's package, so an id type declared there needs no import
+  let id_field_package_name =
+    if create_repository_file_response.file_package_name == id_type.package_name {
+      ""
+    } else {
+      id_type.package_name.as_str()
+    };
   extend_jpa_repository(
     &mut jpa_repository_ts_file,
     entity_type,
-    jpa_entity_info.id_field_type.as_ref().unwrap().as_ref(),
-    jpa_entity_info.id_field_package_name.as_ref().unwrap().as_ref(),
+    &id_type.name,
+    id_field_package_name,
   );
   Ok(jpa_repository_ts_file)
 }
@@ -144,55 +178,74 @@ fn step_get_jpa_entity_info(
   get_jpa_entity_info_service::run(entity_file_path, b64_source_code)
 }
 
-fn step_process_entity_without_superclass(
-  cwd: &Path,
-  entity_ts_file: &TSFile,
-  entity_file_path: &Path,
-  entity_type: &str,
-  jpa_entity_info: &GetJpaEntityInfoResponse,
-) -> Result<CreateJPARepositoryResponse, String> {
-  if jpa_entity_info.is_jpa_entity
-    && jpa_entity_info.id_field_type.is_some()
-    && jpa_entity_info.id_field_package_name.is_some()
-  {
-    step_create_repository_and_save(
-      cwd,
-      entity_ts_file,
-      entity_file_path,
-      entity_type,
-      jpa_entity_info,
-    )
-  } else {
-    let superclass_type = jpa_entity_info.superclass_type.clone();
-    let response = create_jpa_repository_response(false, superclass_type, None);
-    Ok(response)
+fn found_id_type(jpa_entity_info: &GetJpaEntityInfoResponse) -> Option<IdType> {
+  match (&jpa_entity_info.id_field_type, &jpa_entity_info.id_field_package_name) {
+    (Some(name), Some(package_name)) => {
+      Some(IdType { name: name.clone(), package_name: package_name.clone() })
+    }
+    _ => None,
   }
 }
 
-fn step_process_entity_with_superclass(
-  cwd: &Path,
+/// Type arguments passed to the superclass in `extends`, e.g. `UUID` in `BaseEntity<UUID>`.
+fn get_superclass_type_arguments<'a>(class_node: &Node<'a>) -> Vec<Node<'a>> {
+  let Some(type_arguments_node) = class_node
+    .child_by_field_name("superclass")
+    .and_then(|superclass_node| superclass_node.named_child(0))
+    .filter(|type_node| type_node.kind() == "generic_type")
+    .and_then(|generic_type_node| {
+      let mut cursor = generic_type_node.walk();
+      generic_type_node.named_children(&mut cursor).find(|node| node.kind() == "type_arguments")
+    })
+  else {
+    return Vec::new();
+  };
+  let mut cursor = type_arguments_node.walk();
+  type_arguments_node.named_children(&mut cursor).collect()
+}
+
+/// Resolves an id inherited from a mapped superclass. When the superclass types its id by one of
+/// its type parameters, as in `BaseEntity<ID>`, the id type is the argument the entity passes to
+/// it in `extends`.
+fn resolve_inherited_id_type(
   entity_ts_file: &TSFile,
-  entity_file_path: &Path,
-  entity_type: &str,
-  jpa_entity_info: &GetJpaEntityInfoResponse,
-) -> Result<CreateJPARepositoryResponse, String> {
-  // If the superclass is not a JPA entity but has a superclass (like MappedSuperclass extending BaseEntity),
-  // return the superclass type instead of trying to create a repository
-  if !jpa_entity_info.is_jpa_entity && jpa_entity_info.superclass_type.is_some() {
-    let superclass_type = jpa_entity_info.superclass_type.clone();
-    let response = create_jpa_repository_response(false, superclass_type, None);
-    return Ok(response);
-  }
-  if jpa_entity_info.id_field_type.is_none() || jpa_entity_info.id_field_package_name.is_none() {
-    return Err("Unable to find ID field for this JPA Entity".to_string());
-  }
-  step_create_repository_and_save(
-    cwd,
-    entity_ts_file,
-    entity_file_path,
-    entity_type,
-    jpa_entity_info,
-  )
+  superclass_b64_src: &str,
+  inherited_id_type: IdType,
+) -> Result<IdType, String> {
+  let superclass_ts_file =
+    get_jpa_entity_info_service::create_ts_file(None, Some(superclass_b64_src))?;
+  let Some(superclass_node) = get_public_class_node(&superclass_ts_file) else {
+    return Ok(inherited_id_type);
+  };
+  let Some(type_parameter_index) =
+    get_class_type_parameter_names(&superclass_ts_file, &superclass_node)
+      .iter()
+      .position(|name| *name == inherited_id_type.name)
+  else {
+    return Ok(inherited_id_type);
+  };
+  let entity_class_node = get_public_class_node(entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  let type_argument = get_superclass_type_arguments(&entity_class_node)
+    .get(type_parameter_index)
+    .and_then(|type_argument_node| entity_ts_file.get_text_from_node(type_argument_node))
+    .map(|text| text.to_string())
+    .ok_or_else(|| {
+      format!(
+        "Unable to resolve the id type {} inherited from the superclass",
+        inherited_id_type.name
+      )
+    })?;
+  let package_name = resolve_type_package(entity_ts_file, &entity_class_node, &type_argument);
+  Ok(IdType { name: type_argument, package_name })
+}
+
+fn default_id_type(entity_type: &str, warnings: &mut Vec<String>) -> IdType {
+  warnings.push(format!(
+    "No @Id or @EmbeddedId field found for {}; using Long as the repository id type",
+    entity_type
+  ));
+  IdType { name: DEFAULT_ID_TYPE.to_string(), package_name: "java.lang".to_string() }
 }
 
 fn step_create_repository_and_save(
@@ -200,19 +253,15 @@ fn step_create_repository_and_save(
   entity_ts_file: &TSFile,
   entity_file_path: &Path,
   entity_type: &str,
-  jpa_entity_info: &GetJpaEntityInfoResponse,
+  id_type: &IdType,
+  warnings: Vec<String>,
 ) -> Result<CreateJPARepositoryResponse, String> {
-  let mut jpa_repository_ts_file = create_and_extend_jpa_repository(
-    cwd,
-    entity_ts_file,
-    entity_file_path,
-    entity_type,
-    jpa_entity_info,
-  )?;
+  let mut jpa_repository_ts_file =
+    create_and_extend_jpa_repository(cwd, entity_ts_file, entity_file_path, entity_type, id_type)?;
   match jpa_repository_ts_file.save() {
     Ok(_) => {
       let file_response = create_file_response(&jpa_repository_ts_file)?;
-      let response = create_jpa_repository_response(true, None, Some(file_response));
+      let response = create_jpa_repository_response(true, None, Some(file_response), warnings);
       Ok(response)
     }
     Err(_) => Err("Unable to create response".to_string()),
@@ -253,6 +302,11 @@ pub fn run_with_manual_id(
   create_file_response(&jpa_repository_ts_file)
 }
 
+/// Creates a `JpaRepository<Entity, IdType>` for the entity. The id type is the type of its `@Id`
+/// field, or the embeddable of its `@EmbeddedId`, looked up in the entity and then in the mapped
+/// superclass given as `b64_superclass_source`. When the entity extends a class that was not
+/// passed, no repository is created and `superclassType` asks for its source; when no id is
+/// declared anywhere, the repository falls back to `Long` with a warning.
 pub fn run(
   cwd: &Path,
   entity_file_b64_src: &str,
@@ -266,28 +320,43 @@ pub fn run(
     .file_stem()
     .and_then(|s| s.to_str())
     .ok_or_else(|| "Unable to get entity file name from path".to_string())?;
-
-  if b64_superclass_source.is_none() {
-    // Step 3: Get JPA entity info from entity file
-    let jpa_entity_info = step_get_jpa_entity_info(Some(entity_file_path), None)?;
-    // Step 4: Process entity without superclass
-    step_process_entity_without_superclass(
-      cwd,
-      &entity_ts_file,
-      entity_file_path,
-      entity_type,
-      &jpa_entity_info,
-    )
-  } else {
-    // Step 3: Get JPA entity info from superclass source
-    let jpa_entity_info = step_get_jpa_entity_info(None, b64_superclass_source)?;
-    // Step 4: Process entity with superclass
-    step_process_entity_with_superclass(
-      cwd,
-      &entity_ts_file,
-      entity_file_path,
-      entity_type,
-      &jpa_entity_info,
-    )
+  // Step 3: Get JPA entity info from the entity source
+  let entity_info = step_get_jpa_entity_info(None, Some(entity_file_b64_src))?;
+  if !entity_info.is_jpa_entity && b64_superclass_source.is_none() {
+    let response = create_jpa_repository_response(false, entity_info.superclass_type, None, vec![]);
+    return Ok(response);
   }
+  // Step 4: Resolve the id type from the entity, then from its superclass
+  let mut warnings = Vec::new();
+  let id_type = match (found_id_type(&entity_info), b64_superclass_source) {
+    (Some(id_type), _) => id_type,
+    (None, Some(superclass_b64_src)) => {
+      let superclass_info = step_get_jpa_entity_info(None, Some(superclass_b64_src))?;
+      match found_id_type(&superclass_info) {
+        Some(id_type) => resolve_inherited_id_type(&entity_ts_file, superclass_b64_src, id_type)?,
+        // The superclass extends another class that may declare the id
+        None if superclass_info.superclass_type.is_some() => {
+          let response =
+            create_jpa_repository_response(false, superclass_info.superclass_type, None, vec![]);
+          return Ok(response);
+        }
+        None => default_id_type(entity_type, &mut warnings),
+      }
+    }
+    (None, None) if entity_info.superclass_type.is_some() => {
+      let response =
+        create_jpa_repository_response(false, entity_info.superclass_type, None, vec![]);
+      return Ok(response);
+    }
+    (None, None) => default_id_type(entity_type, &mut warnings),
+  };
+  // Step 5: Create the repository and save it
+  step_create_repository_and_save(
+    cwd,
+    &entity_ts_file,
+    entity_file_path,
+    entity_type,
+    &id_type,
+    warnings,
+  )
 }
@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use crate::commands::services::create_java_file_service::{build_save_path, create_ts_file};
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::responses::file_response::FileResponse;
+
+const PACKAGE_INFO_FILE_NAME: &str = "package-info.java";
+
+struct PackageAnnotation {
+  import: Option<String>,
+  annotation_text: String,
+}
+
+/// Parses a package-level annotation given either as a simple name (`@NonNullApi`) or as a
+/// fully qualified name (`org.springframework.lang.NonNullApi`), optionally with arguments.
+fn parse_package_annotation(annotation: &str) -> Result<PackageAnnotation, String> {
+  let annotation = annotation.trim().trim_start_matches('@');
+  let (name, arguments) = match annotation.find('(') {
+    Some(index) => (&annotation[..index], &annotation[index..]),
+    None => (annotation, ""),
+  };
+  let name = name.trim();
+  if name.is_empty()
+    || name.starts_with('.')
+    || name.ends_with('.')
+    || !name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '$')
+  {
+    return Err(format!("Invalid package annotation: @{}", annotation));
+  }
+  match name.rsplit_once('.') {
+    Some((_, simple_name)) => Ok(PackageAnnotation {
+      import: Some(name.to_string()),
+      annotation_text: format!("@{}{}", simple_name, arguments),
+    }),
+    None => {
+      Ok(PackageAnnotation { import: None, annotation_text: format!("@{}{}", name, arguments) })
+    }
+  }
+}
+
+/// Renders `javadoc` as a Javadoc block. A `*/` in the text is escaped so it cannot end the
+/// comment early.
+fn build_javadoc(javadoc: &str) -> String {
+  let mut comment = String::from("/**\n");
+  for line in javadoc.trim().replace("*/", "*&#47;").lines() {
+    let line = line.trim_end();
+    if line.is_empty() {
+      comment.push_str(" *\n");
+    } else {
+      comment.push_str(&format!(" * {}\n", line));
+    }
+  }
+  comment.push_str(" */\n");
+  comment
+}
+
+fn generate_package_info_template(
+  package_name: &str,
+  javadoc: Option<&str>,
+  annotations: &[PackageAnnotation],
+) -> String {
+  let mut template = String::new();
+  if let Some(javadoc) = javadoc.filter(|j| !j.trim().is_empty()) {
+    template.push_str(&build_javadoc(javadoc));
+  }
+  for annotation in annotations {
+    template.push_str(&annotation.annotation_text);
+    template.push('\n');
+  }
+  template.push_str(&format!("package {};\n", package_name));
+  let mut imports: Vec<&str> = annotations.iter().filter_map(|a| a.import.as_deref()).collect();
+  imports.sort();
+  imports.dedup();
+  if !imports.is_empty() {
+    template.push('\n');
+    for import in imports {
+      template.push_str(&format!("import {};\n", import));
+    }
+  }
+  template
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .ok_or("Failed to get file path")?;
+  Ok(FileResponse {
+    file_type,
+    file_path,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  javadoc: Option<&str>,
+  annotations: &[String],
+  source_directory: &JavaSourceDirectoryType,
+  force: bool,
+) -> Result<FileResponse, String> {
+  // Step 1: Parse package-level annotations
+  let annotations =
+    annotations.iter().map(|a| parse_package_annotation(a)).collect::<Result<Vec<_>, _>>()?;
+  // Step 2: Build save path with security validation
+  let save_path = build_save_path(source_directory, cwd, package_name, PACKAGE_INFO_FILE_NAME)?;
+  // Step 3: Refuse to overwrite an existing package-info.java unless forced
+  if save_path.exists() && !force {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 4: Generate the file content and save it
+  let file_template = generate_package_info_template(package_name, javadoc, &annotations);
+  let mut ts_file = create_ts_file(&file_template);
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 5: Build response
+  build_file_response(&ts_file, package_name)
+}
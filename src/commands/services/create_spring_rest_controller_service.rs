@@ -0,0 +1,196 @@
+use std::path::Path;
+
+use crate::commands::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file,
+};
+use crate::commands::services::get_jpa_entity_info_service;
+use crate::commands::services::remove_jpa_field_service::find_entity_file;
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::indent_util::apply_indent_override;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+
+const SPRING_WEB_IMPORTS: [&str; 11] = [
+  "org.springframework.http.HttpStatus",
+  "org.springframework.http.ResponseEntity",
+  "org.springframework.web.bind.annotation.DeleteMapping",
+  "org.springframework.web.bind.annotation.GetMapping",
+  "org.springframework.web.bind.annotation.PathVariable",
+  "org.springframework.web.bind.annotation.PostMapping",
+  "org.springframework.web.bind.annotation.PutMapping",
+  "org.springframework.web.bind.annotation.RequestBody",
+  "org.springframework.web.bind.annotation.RequestMapping",
+  "org.springframework.web.bind.annotation.RestController",
+  "java.util.List",
+];
+
+/// Splits a fully qualified class name into its package and simple name.
+pub(crate) fn split_fqcn(fqcn: &str) -> (&str, &str) {
+  fqcn.rsplit_once('.').unwrap_or(("", fqcn))
+}
+
+/// Normalizes the `@RequestMapping` path to a single leading slash and no trailing slash.
+fn normalize_base_path(base_path: &str) -> Result<String, String> {
+  let trimmed = base_path.trim().trim_matches('/');
+  if trimmed.chars().any(|c| c.is_whitespace() || c == '"' || c == '\\') {
+    return Err(format!("Invalid base path: {}", base_path));
+  }
+  Ok(format!("/{}", trimmed))
+}
+
+/// Resolves the id type of the entity, either from `id_type_fqcn` or from the entity's `@Id`
+/// field when the entity is declared in the project.
+fn resolve_id_type(
+  cwd: &Path,
+  entity_fqcn: &str,
+  id_type_fqcn: Option<&str>,
+) -> Result<String, String> {
+  if let Some(id_type_fqcn) = id_type_fqcn {
+    return Ok(id_type_fqcn.to_string());
+  }
+  let (entity_package, entity_name) = split_fqcn(entity_fqcn);
+  let entity_ts_file = find_entity_file(cwd, entity_package, entity_name)
+    .ok_or_else(|| format!("Unable to find JPA Entity {} in the project", entity_fqcn))?;
+  let entity_path = entity_ts_file.file_path().cloned();
+  let entity_info = get_jpa_entity_info_service::run(entity_path.as_deref(), None)?;
+  match (entity_info.id_field_package_name, entity_info.id_field_type) {
+    (Some(package_name), Some(field_type)) if !package_name.is_empty() => {
+      Ok(format!("{}.{}", package_name, field_type))
+    }
+    (_, Some(field_type)) => Ok(field_type),
+    _ => Err(format!("Unable to find ID field for {}; pass --id-type-fqcn instead", entity_name)),
+  }
+}
+
+/// Collects the imports of the controller, skipping `java.lang` and same-package types.
+fn collect_imports(package_name: &str, type_fqcns: &[&str]) -> Vec<String> {
+  let mut imports: Vec<String> = SPRING_WEB_IMPORTS.iter().map(|i| i.to_string()).collect();
+  for fqcn in type_fqcns {
+    let (type_package, _) = split_fqcn(fqcn);
+    if !type_package.is_empty() && type_package != "java.lang" && type_package != package_name {
+      imports.push(fqcn.to_string());
+    }
+  }
+  imports.sort();
+  imports.dedup();
+  imports
+}
+
+struct ControllerTemplateParams<'a> {
+  package_name: &'a str,
+  class_name: &'a str,
+  base_path: &'a str,
+  entity_fqcn: &'a str,
+  service_fqcn: &'a str,
+  id_type_fqcn: &'a str,
+}
+
+fn generate_controller_template(params: &ControllerTemplateParams) -> String {
+  let (_, entity) = split_fqcn(params.entity_fqcn);
+  let (_, service) = split_fqcn(params.service_fqcn);
+  let (_, id) = split_fqcn(params.id_type_fqcn);
+  let class_name = params.class_name;
+  let service_field = naming_util::to_camel_case(service);
+  let entity_variable = naming_util::to_camel_case(entity);
+  let mut template = format!("package {};\n\n", params.package_name);
+  for import in collect_imports(
+    params.package_name,
+    &[params.entity_fqcn, params.service_fqcn, params.id_type_fqcn],
+  ) {
+    template.push_str(&format!("import {};\n", import));
+  }
+  template.push_str(&format!(
+    r#"
+@RestController
+@RequestMapping("{base_path}")
+public class {class_name} {{
+
+  private final {service} {service_field};
+
+  public {class_name}({service} {service_field}) {{
+    this.{service_field} = {service_field};
+  }}
+
+  @GetMapping
+  public ResponseEntity<List<{entity}>> findAll() {{
+    return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+  }}
+
+  @GetMapping("/{{id}}")
+  public ResponseEntity<{entity}> findById(@PathVariable {id} id) {{
+    return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+  }}
+
+  @PostMapping
+  public ResponseEntity<{entity}> create(@RequestBody {entity} {entity_variable}) {{
+    return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+  }}
+
+  @PutMapping("/{{id}}")
+  public ResponseEntity<{entity}> update(@PathVariable {id} id, @RequestBody {entity} {entity_variable}) {{
+    return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+  }}
+
+  @DeleteMapping("/{{id}}")
+  public ResponseEntity<Void> delete(@PathVariable {id} id) {{
+    return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+  }}
+}}
+"#,
+    base_path = params.base_path,
+  ));
+  apply_indent_override(template)
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .ok_or("Failed to get file path")?;
+  Ok(FileResponse {
+    file_type,
+    file_path,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Generates a `@RestController` exposing stub CRUD endpoints for `entity_fqcn` under
+/// `base_path`, with `service_fqcn` injected through the constructor.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  base_path: &str,
+  entity_fqcn: &str,
+  service_fqcn: &str,
+  id_type_fqcn: Option<&str>,
+) -> Result<FileResponse, String> {
+  // Step 1: Validate inputs and resolve the entity id type
+  let base_path = normalize_base_path(base_path)?;
+  let id_type_fqcn = resolve_id_type(cwd, entity_fqcn, id_type_fqcn)?;
+  // Step 2: Build save path with security validation
+  let corrected_file_name = correct_java_file_name(file_name);
+  let save_path =
+    build_save_path(&JavaSourceDirectoryType::Main, cwd, package_name, &corrected_file_name)?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 3: Generate the controller and save it
+  let class_name = corrected_file_name.trim_end_matches(".java");
+  let file_template = generate_controller_template(&ControllerTemplateParams {
+    package_name,
+    class_name,
+    base_path: &base_path,
+    entity_fqcn,
+    service_fqcn,
+    id_type_fqcn: &id_type_fqcn,
+  });
+  let mut ts_file = create_ts_file(&file_template);
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 4: Build response
+  build_file_response(&ts_file, package_name)
+}
@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+
+use crate::commands::services::find_jpa_entity_usages_service;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::utils::file_util;
+use crate::common::utils::path_security_util::PathSecurityValidator;
+use crate::responses::delete_java_file_response::DeleteJavaFileResponse;
+
+/// Resolves `file_path` inside `cwd` and ensures it is an existing `.java` file.
+fn validate_java_file(cwd: &Path, file_path: &Path) -> Result<PathBuf, String> {
+  let validator = PathSecurityValidator::new(cwd)?;
+  let file_path = validator.validate_path_containment(file_path)?;
+  if !file_path.is_file() {
+    return Err(format!("File not found: {}", file_path.display()));
+  }
+  if file_path.extension().is_none_or(|extension| extension != "java") {
+    return Err(format!("Not a Java file: {}", file_path.display()));
+  }
+  Ok(file_path)
+}
+
+/// Lists the other files referencing the type declared by the file, using the same scanner as
+/// `find-jpa-entity-usages`.
+fn find_referencing_files(cwd: &Path, file_path: &Path) -> Result<Vec<String>, String> {
+  let ts_file = TSFile::from_file(file_path).map_err(|e| e.to_string())?;
+  let package_name = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .ok_or_else(|| "Unable to check usages of a class in the default package".to_string())?;
+  let type_name =
+    ts_file.get_file_name_without_ext().ok_or_else(|| "Unable to get type name".to_string())?;
+  let type_fqcn = format!("{}.{}", package_name, type_name);
+  let usages = find_jpa_entity_usages_service::run(cwd, &type_fqcn)?.usages;
+  let own_path = file_path.to_string_lossy();
+  let mut referencing_files: Vec<String> = usages
+    .into_iter()
+    .map(|usage| usage.file_path)
+    .filter(|usage_path| *usage_path != own_path)
+    .collect();
+  referencing_files.dedup();
+  Ok(referencing_files)
+}
+
+/// Deletes a Java file of the project. With `check_usages` the file is kept when its type is
+/// still referenced elsewhere, and the referencing files are returned instead.
+pub fn run(
+  cwd: &Path,
+  file_path: &Path,
+  check_usages: bool,
+) -> Result<DeleteJavaFileResponse, String> {
+  // Step 1: Validate the file is a Java file within the project
+  let file_path = validate_java_file(cwd, file_path)?;
+  let file_path_string = file_path.to_string_lossy().to_string();
+  // Step 2: Refuse the deletion while the type is still referenced
+  if check_usages {
+    let referencing_files = find_referencing_files(cwd, &file_path)?;
+    if !referencing_files.is_empty() {
+      return Ok(DeleteJavaFileResponse {
+        file_path: file_path_string,
+        deleted: false,
+        referencing_files,
+      });
+    }
+  }
+  // Step 3: Delete the file
+  file_util::remove_file(&file_path).map_err(|e| format!("Unable to delete file: {}", e))?;
+  Ok(DeleteJavaFileResponse {
+    file_path: file_path_string,
+    deleted: true,
+    referencing_files: Vec::new(),
+  })
+}
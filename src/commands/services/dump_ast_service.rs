@@ -0,0 +1,53 @@
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::ts_file::TSFile;
+use crate::responses::dump_ast_response::{AstNodeResponse, DumpAstResponse};
+
+/// Converts `node` and its named descendants. `field_name` is the grammar field the node fills in
+/// its parent, e.g. `name` or `body`.
+fn build_node_response(
+  ts_file: &TSFile,
+  node: Node,
+  field_name: Option<&str>,
+  include_text: bool,
+) -> AstNodeResponse {
+  let mut cursor = node.walk();
+  let mut children = Vec::new();
+  if cursor.goto_first_child() {
+    loop {
+      let child = cursor.node();
+      if child.is_named() {
+        children.push(build_node_response(ts_file, child, cursor.field_name(), include_text));
+      }
+      if !cursor.goto_next_sibling() {
+        break;
+      }
+    }
+  }
+  AstNodeResponse {
+    kind: node.kind().to_string(),
+    field_name: field_name.map(|name| name.to_string()),
+    start_byte: node.start_byte(),
+    end_byte: node.end_byte(),
+    text: include_text
+      .then(|| ts_file.get_text_from_node(&node).map(|text| text.to_string()))
+      .flatten(),
+    children,
+  }
+}
+
+/// Dumps the Tree-sitter tree of the source as an S-expression and as a JSON tree of its named
+/// nodes with their byte ranges. With `include_text` every node carries its source text.
+pub fn run(b64_source_code: &str, include_text: bool) -> Result<DumpAstResponse, String> {
+  // Step 1: Parse the source
+  let ts_file = create_ts_file(None, Some(b64_source_code))?;
+  let tree = ts_file.tree.as_ref().ok_or_else(|| "Unable to parse the source code".to_string())?;
+  // Step 2: Build and return response
+  let root_node = tree.root_node();
+  Ok(DumpAstResponse {
+    s_expression: root_node.to_sexp(),
+    has_errors: ts_file.has_errors(),
+    root: build_node_response(&ts_file, root_node, None, include_text),
+  })
+}
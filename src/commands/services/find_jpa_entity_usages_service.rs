@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::validators::java_fqcn_validator::validate_java_fully_qualified_class_name;
+use crate::common::services::class_declaration_service::find_class_node_by_name;
+use crate::common::services::import_declaration_service::{
+  get_all_import_declaration_nodes, get_import_declaration_full_import_scope_node,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::path_util::parse_all_files_in_directory;
+use crate::common::utils::project_layout_util::{resolve_source_root, select_project_modules};
+use crate::responses::find_jpa_entity_usages_response::{
+  EntityUsageResponse, FindJpaEntityUsagesResponse,
+};
+
+/// How a file can refer to the entity by its simple name.
+enum SimpleNameVisibility {
+  /// The file declares the entity itself, its self references are not usages
+  Declaration,
+  /// Same package or explicit/wildcard import
+  Visible,
+  /// The simple name refers to something else in this file
+  Hidden,
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn get_simple_name_visibility(
+  ts_file: &TSFile,
+  entity_package: &str,
+  entity_name: &str,
+) -> SimpleNameVisibility {
+  if get_package_name(ts_file) == entity_package {
+    if find_class_node_by_name(ts_file, entity_name).is_some()
+      && ts_file.get_file_name_without_ext().as_deref() == Some(entity_name)
+    {
+      return SimpleNameVisibility::Declaration;
+    }
+    return SimpleNameVisibility::Visible;
+  }
+  let entity_fqcn = format!("{}.{}", entity_package, entity_name);
+  let wildcard_import = format!("import{}.*;", entity_package);
+  let is_imported = get_all_import_declaration_nodes(ts_file).into_iter().any(|import_node| {
+    let is_single_type_import = get_import_declaration_full_import_scope_node(ts_file, import_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))
+      .is_some_and(|text| text == entity_fqcn);
+    let is_wildcard_import = ts_file
+      .get_text_from_node(&import_node)
+      .map(|text| text.split_whitespace().collect::<String>())
+      .is_some_and(|text| text == wildcard_import);
+    is_single_type_import || is_wildcard_import
+  });
+  if is_imported { SimpleNameVisibility::Visible } else { SimpleNameVisibility::Hidden }
+}
+
+/// Classifies a type reference by the declaration it belongs to.
+fn get_usage_kind(node: &Node) -> &'static str {
+  let mut child = *node;
+  let mut current = node.parent();
+  while let Some(parent) = current {
+    match parent.kind() {
+      "import_declaration" => return "import",
+      "field_declaration" => return "field-type",
+      "formal_parameter" | "spread_parameter" => return "method-parameter",
+      "method_declaration" => {
+        let is_return_type =
+          parent.child_by_field_name("type").is_some_and(|t| t.id() == child.id());
+        return if is_return_type { "method-return-type" } else { "type-reference" };
+      }
+      "class_body" | "interface_body" | "block" => return "type-reference",
+      _ => {}
+    }
+    child = parent;
+    current = parent.parent();
+  }
+  "type-reference"
+}
+
+fn find_file_usages(
+  ts_file: &TSFile,
+  entity_package: &str,
+  entity_name: &str,
+) -> Vec<(&'static str, usize, usize)> {
+  let entity_fqcn = format!("{}.{}", entity_package, entity_name);
+  let visibility = get_simple_name_visibility(ts_file, entity_package, entity_name);
+  if matches!(visibility, SimpleNameVisibility::Declaration) {
+    return Vec::new();
+  }
+  let mut usages = Vec::new();
+  // Fully qualified references and imports are unambiguous
+  let query_string = r#"
+        [
+          (scoped_type_identifier) @reference
+          (import_declaration (scoped_identifier) @reference)
+        ]
+    "#;
+  if let Ok(result) = ts_file.query_builder(query_string).returning("reference").execute() {
+    for node in result.nodes() {
+      if ts_file.get_text_from_node(&node).is_some_and(|text| text == entity_fqcn) {
+        usages.push((get_usage_kind(&node), node.start_byte(), node.end_byte()));
+      }
+    }
+  }
+  if matches!(visibility, SimpleNameVisibility::Visible) {
+    let query_string = format!(
+      r#"
+        ((type_identifier) @reference
+        (#eq? @reference "{}"))
+        "#,
+      entity_name
+    );
+    if let Ok(result) = ts_file.query_builder(&query_string).returning("reference").execute() {
+      for node in result.nodes() {
+        // Skip the trailing segment of a fully qualified name, already reported above
+        if node.parent().is_some_and(|p| p.kind() == "scoped_type_identifier") {
+          continue;
+        }
+        usages.push((get_usage_kind(&node), node.start_byte(), node.end_byte()));
+      }
+    }
+  }
+  usages.sort_by_key(|(_, start, _)| *start);
+  usages
+}
+
+/// Scans the main and test sources of every project module for references to the entity:
+/// imports, field types, method return and parameter types and any other type reference.
+/// References by simple name only count when the entity is imported or in the same package.
+pub fn run(cwd: &Path, entity_fqcn: &str) -> Result<FindJpaEntityUsagesResponse, String> {
+  // Step 1: Validate and split the fully qualified entity name
+  let entity_fqcn = validate_java_fully_qualified_class_name(entity_fqcn)?;
+  let (entity_package, entity_name) =
+    entity_fqcn.rsplit_once('.').ok_or_else(|| "Invalid entity name".to_string())?;
+  let (entity_package, entity_name) = (entity_package.to_string(), entity_name.to_string());
+  // Step 2: Scan every Java file of every module
+  let mut usages = Vec::new();
+  for module in select_project_modules(cwd, None)? {
+    let mut source_roots: Vec<_> = [JavaSourceDirectoryType::Main, JavaSourceDirectoryType::Test]
+      .iter()
+      .map(|source_directory| resolve_source_root(&module.path, source_directory))
+      .collect();
+    source_roots.dedup();
+    for source_root in source_roots {
+      for ts_file in parse_all_files_in_directory(&source_root) {
+        let file_path =
+          ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+        for (kind, start_byte, end_byte) in
+          find_file_usages(&ts_file, &entity_package, &entity_name)
+        {
+          usages.push(EntityUsageResponse {
+            file_path: file_path.clone(),
+            kind: kind.to_string(),
+            start_byte,
+            end_byte,
+            module: module.name.clone(),
+          });
+        }
+      }
+    }
+  }
+  // Step 3: Sort for a stable output
+  usages.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.start_byte.cmp(&b.start_byte)));
+  Ok(FindJpaEntityUsagesResponse { entity_type: entity_fqcn, usages })
+}
@@ -0,0 +1,229 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  ensure_class_has_no_syntax_errors, get_all_field_declaration_nodes,
+  get_all_method_declaration_nodes, get_class_body_node,
+};
+use crate::common::services::formatting_service::member_separator;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::fluent_setter_naming::FluentSetterNaming;
+use crate::common::utils::indent_util::indent_unit;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+use crate::responses::generate_accessors_response::GenerateAccessorsResponse;
+
+/// A field declarator of the class, with the type and modifiers of its declaration.
+struct AccessorField {
+  name: String,
+  field_type: String,
+  is_final: bool,
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+fn has_modifier(ts_file: &TSFile, declaration_node: &Node, modifier: &str) -> bool {
+  let mut cursor = declaration_node.walk();
+  declaration_node.children(&mut cursor).any(|child| {
+    child.kind() == "modifiers"
+      && ts_file
+        .get_text_from_node(&child)
+        .is_some_and(|modifiers| modifiers.split_whitespace().any(|m| m == modifier))
+  })
+}
+
+/// Collects the instance fields of the class, one per declarator, skipping `static` fields.
+fn collect_fields(ts_file: &TSFile, class_node: Node) -> Vec<AccessorField> {
+  let mut fields = Vec::new();
+  for field_node in get_all_field_declaration_nodes(ts_file, class_node) {
+    if has_modifier(ts_file, &field_node, "static") {
+      continue;
+    }
+    let Some(field_type) = field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+    else {
+      continue;
+    };
+    let is_final = has_modifier(ts_file, &field_node, "final");
+    let mut cursor = field_node.walk();
+    for declarator_node in field_node.children_by_field_name("declarator", &mut cursor) {
+      if let Some(name) = declarator_node
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      {
+        fields.push(AccessorField {
+          name: name.to_string(),
+          field_type: field_type.to_string(),
+          is_final,
+        });
+      }
+    }
+  }
+  fields
+}
+
+/// Name and parameter count of every method the class declares.
+fn collect_method_signatures(ts_file: &TSFile, class_node: Node) -> Vec<(String, usize)> {
+  get_all_method_declaration_nodes(ts_file, class_node)
+    .iter()
+    .filter_map(|method_node| {
+      let name_node = method_node.child_by_field_name("name")?;
+      let name = ts_file.get_text_from_node(&name_node)?.to_string();
+      let parameter_count = method_node
+        .child_by_field_name("parameters")
+        .map_or(0, |parameters| parameters.named_child_count());
+      Some((name, parameter_count))
+    })
+    .collect()
+}
+
+/// The class as a type, with its type parameters, e.g. `BaseEntity<ID>`.
+fn get_class_type(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  let name_node = get_class_declaration_name_node(ts_file, class_node)?;
+  let class_name = ts_file.get_text_from_node(&name_node)?;
+  let Some(type_parameters_node) = class_node.child_by_field_name("type_parameters") else {
+    return Some(class_name.to_string());
+  };
+  let mut cursor = type_parameters_node.walk();
+  let type_parameter_names: Vec<&str> = type_parameters_node
+    .named_children(&mut cursor)
+    .filter_map(|type_parameter_node| type_parameter_node.named_child(0))
+    .filter_map(|type_name_node| ts_file.get_text_from_node(&type_name_node))
+    .collect();
+  Some(format!("{}<{}>", class_name, type_parameter_names.join(", ")))
+}
+
+fn build_getter(field: &AccessorField, getter_name: &str) -> String {
+  let i = indent_unit();
+  format!(
+    "{i}public {} {}() {{\n{i}{i}return {};\n{i}}}",
+    field.field_type, getter_name, field.name
+  )
+}
+
+/// Builds a setter; a fluent one returns `this` typed as `class_type` instead of `void`.
+fn build_setter(field: &AccessorField, setter_name: &str, class_type: Option<&str>) -> String {
+  let i = indent_unit();
+  let return_type = class_type.unwrap_or("void");
+  let return_statement =
+    if class_type.is_some() { format!("{i}{i}return this;\n") } else { String::new() };
+  format!(
+    "{i}public {} {}({} {}) {{\n{i}{i}this.{} = {};\n{}{i}}}",
+    return_type,
+    setter_name,
+    field.field_type,
+    field.name,
+    field.name,
+    field.name,
+    return_statement
+  )
+}
+
+/// Appends the accessors at the end of the class body, separated by `--member-spacing` blank
+/// lines.
+fn insert_accessors(ts_file: &mut TSFile, accessor_texts: &[String]) -> Result<(), String> {
+  let (body_start_byte, body_end_byte, body_text) = {
+    let class_node = get_public_class_node(ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let body_node = get_class_body_node(ts_file, class_node)
+      .ok_or_else(|| "Unable to get class body".to_string())?;
+    let body_text = ts_file.get_text_from_node(&body_node).unwrap_or_default().to_string();
+    (body_node.start_byte(), body_node.end_byte(), body_text)
+  };
+  let separator = member_separator();
+  let last_member = body_text.strip_suffix('}').unwrap_or(&body_text).trim_end();
+  let leading = if last_member == "{" { "\n" } else { separator.as_str() };
+  let body = format!("{}{}{}\n}}", last_member, leading, accessor_texts.join(&separator));
+  ts_file.replace_text_by_range(body_start_byte, body_end_byte, &body);
+  Ok(())
+}
+
+/// Generates a getter and a setter for the given fields of the class, or for all its instance
+/// fields when none are given. Accessors the class already declares are kept, and `final`
+/// fields get no setter. Setters return `void` unless `fluent_accessors` is set, in which case
+/// they return `this` and are named after `fluent_setter_naming`.
+pub fn run(
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_names: &[String],
+  fluent_accessors: bool,
+  fluent_setter_naming: &FluentSetterNaming,
+) -> Result<GenerateAccessorsResponse, String> {
+  // Step 1: Parse the class and validate it
+  let mut ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  ensure_class_has_no_syntax_errors(&ts_file, class_node.start_byte())?;
+  let class_type = get_class_type(&ts_file, class_node)
+    .ok_or_else(|| "Unable to get public class name".to_string())?;
+  // Step 2: Select the fields
+  let mut fields = collect_fields(&ts_file, class_node);
+  if !field_names.is_empty() {
+    if let Some(missing_field_name) =
+      field_names.iter().find(|field_name| !fields.iter().any(|field| field.name == **field_name))
+    {
+      return Err(format!("Field '{}' not found in {}", missing_field_name, class_type));
+    }
+    fields.retain(|field| field_names.contains(&field.name));
+  }
+  // Step 3: Build the accessors the class does not declare yet
+  let mut method_signatures = collect_method_signatures(&ts_file, class_node);
+  let mut generated_method_names = Vec::new();
+  let mut accessor_texts = Vec::new();
+  for field in &fields {
+    let capitalized = naming_util::to_pascal_case(&field.name);
+    let getter_prefix = if field.field_type == "boolean" { "is" } else { "get" };
+    let getter_name = format!("{}{}", getter_prefix, capitalized);
+    if !method_signatures.contains(&(getter_name.clone(), 0)) {
+      accessor_texts.push(build_getter(field, &getter_name));
+      method_signatures.push((getter_name.clone(), 0));
+      generated_method_names.push(getter_name);
+    }
+    if field.is_final {
+      continue;
+    }
+    let setter_name = match (fluent_accessors, fluent_setter_naming) {
+      (true, FluentSetterNaming::Field) => field.name.clone(),
+      _ => format!("set{}", capitalized),
+    };
+    if !method_signatures.contains(&(setter_name.clone(), 1)) {
+      let fluent_return_type = fluent_accessors.then_some(class_type.as_str());
+      accessor_texts.push(build_setter(field, &setter_name, fluent_return_type));
+      method_signatures.push((setter_name.clone(), 1));
+      generated_method_names.push(setter_name);
+    }
+  }
+  // Step 4: Insert the accessors and save the class
+  if !accessor_texts.is_empty() {
+    insert_accessors(&mut ts_file, &accessor_texts)?;
+    ts_file
+      .save_to_existing_file(entity_file_path)
+      .map_err(|e| format!("Unable to save entity file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  Ok(GenerateAccessorsResponse { entity: build_file_response(&ts_file), generated_method_names })
+}
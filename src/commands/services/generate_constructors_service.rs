@@ -0,0 +1,241 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  ensure_class_has_no_syntax_errors, get_class_body_node,
+};
+use crate::common::services::formatting_service::member_separator;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::utils::indent_util::indent_unit;
+use crate::responses::file_response::FileResponse;
+use crate::responses::generate_constructors_response::GenerateConstructorsResponse;
+
+/// An instance field declarator the all-args constructor assigns.
+struct ConstructorField {
+  name: String,
+  field_type: String,
+  is_final: bool,
+  is_initialized: bool,
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+fn get_modifiers_node<'a>(declaration_node: &Node<'a>) -> Option<Node<'a>> {
+  let mut cursor = declaration_node.walk();
+  declaration_node.children(&mut cursor).find(|child| child.kind() == "modifiers")
+}
+
+fn has_modifier(ts_file: &TSFile, declaration_node: &Node, modifier: &str) -> bool {
+  get_modifiers_node(declaration_node).is_some_and(|modifiers_node| {
+    let mut cursor = modifiers_node.walk();
+    modifiers_node.children(&mut cursor).any(|child| {
+      !child.kind().contains("annotation") && ts_file.get_text_from_node(&child) == Some(modifier)
+    })
+  })
+}
+
+/// Whether the declaration is annotated `@Transient` (simple or fully qualified).
+fn is_annotated_transient(ts_file: &TSFile, declaration_node: &Node) -> bool {
+  get_modifiers_node(declaration_node).is_some_and(|modifiers_node| {
+    let mut cursor = modifiers_node.walk();
+    modifiers_node.children(&mut cursor).any(|child| {
+      child.kind().contains("annotation")
+        && child
+          .child_by_field_name("name")
+          .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+          .is_some_and(|name| name == "Transient" || name.ends_with(".Transient"))
+    })
+  })
+}
+
+/// Collects the instance fields declared directly in the class body, one per declarator,
+/// skipping `static`, `transient` and `@Transient` fields.
+fn collect_fields(ts_file: &TSFile, body_node: Node) -> Vec<ConstructorField> {
+  let mut fields = Vec::new();
+  let mut body_cursor = body_node.walk();
+  for field_node in body_node.named_children(&mut body_cursor) {
+    if field_node.kind() != "field_declaration"
+      || has_modifier(ts_file, &field_node, "static")
+      || has_modifier(ts_file, &field_node, "transient")
+      || is_annotated_transient(ts_file, &field_node)
+    {
+      continue;
+    }
+    let Some(field_type) = field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+    else {
+      continue;
+    };
+    let is_final = has_modifier(ts_file, &field_node, "final");
+    let mut cursor = field_node.walk();
+    for declarator_node in field_node.children_by_field_name("declarator", &mut cursor) {
+      if let Some(name) = declarator_node
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      {
+        fields.push(ConstructorField {
+          name: name.to_string(),
+          field_type: field_type.to_string(),
+          is_final,
+          is_initialized: declarator_node.child_by_field_name("value").is_some(),
+        });
+      }
+    }
+  }
+  fields
+}
+
+/// The parameter types of every constructor the class declares.
+fn collect_constructor_signatures(ts_file: &TSFile, body_node: Node) -> Vec<Vec<String>> {
+  let mut body_cursor = body_node.walk();
+  body_node
+    .named_children(&mut body_cursor)
+    .filter(|member_node| member_node.kind() == "constructor_declaration")
+    .filter_map(|constructor_node| {
+      let parameters_node = constructor_node.child_by_field_name("parameters")?;
+      let mut cursor = parameters_node.walk();
+      Some(
+        parameters_node
+          .named_children(&mut cursor)
+          .filter_map(|parameter_node| parameter_node.child_by_field_name("type"))
+          .filter_map(|type_node| ts_file.get_text_from_node(&type_node))
+          .map(str::to_string)
+          .collect(),
+      )
+    })
+    .collect()
+}
+
+fn build_no_args_constructor(class_name: &str) -> String {
+  let i = indent_unit();
+  format!("{i}protected {}() {{\n{i}}}", class_name)
+}
+
+fn build_all_args_constructor(class_name: &str, fields: &[&ConstructorField]) -> String {
+  let i = indent_unit();
+  let parameters: Vec<String> =
+    fields.iter().map(|field| format!("{} {}", field.field_type, field.name)).collect();
+  let assignments: String =
+    fields.iter().map(|field| format!("{i}{i}this.{} = {};\n", field.name, field.name)).collect();
+  format!("{i}public {}({}) {{\n{}{i}}}", class_name, parameters.join(", "), assignments)
+}
+
+/// Inserts the constructors after the last field of the class, or at the top of its body when
+/// it has no fields, separated by `--member-spacing` blank lines.
+fn insert_constructors(ts_file: &mut TSFile, constructor_texts: &[String]) -> Result<(), String> {
+  let (body_start_byte, body_end_byte, last_field_end_byte, has_members) = {
+    let class_node = get_public_class_node(ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let body_node = get_class_body_node(ts_file, class_node)
+      .ok_or_else(|| "Unable to get class body".to_string())?;
+    let mut cursor = body_node.walk();
+    let members: Vec<Node> = body_node.named_children(&mut cursor).collect();
+    let last_field_end_byte = members
+      .iter()
+      .filter(|member_node| member_node.kind() == "field_declaration")
+      .map(|field_node| field_node.end_byte())
+      .max();
+    (body_node.start_byte(), body_node.end_byte(), last_field_end_byte, !members.is_empty())
+  };
+  let separator = member_separator();
+  let constructors = constructor_texts.join(&separator);
+  if let Some(last_field_end_byte) = last_field_end_byte {
+    ts_file.insert_text(last_field_end_byte, &format!("{}{}", separator, constructors));
+  } else if has_members {
+    ts_file.insert_text(body_start_byte + 1, &format!("\n{}{}", constructors, &separator[1..]));
+  } else {
+    ts_file.replace_text_by_range(
+      body_start_byte,
+      body_end_byte,
+      &format!("{{\n{}\n}}", constructors),
+    );
+  }
+  Ok(())
+}
+
+/// Generates a `protected` no-args constructor, as JPA requires for entities, and/or a `public`
+/// all-args constructor over the non-static, non-transient fields. Constructors the class
+/// already declares with the same parameter types are kept. The parameter types are the field
+/// types, so the class already imports them.
+pub fn run(
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  no_args: bool,
+  all_args: bool,
+) -> Result<GenerateConstructorsResponse, String> {
+  if !no_args && !all_args {
+    return Err("Nothing to generate: pass --no-args and/or --all-args".to_string());
+  }
+  // Step 1: Parse the class and validate it
+  let mut ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  ensure_class_has_no_syntax_errors(&ts_file, class_node.start_byte())?;
+  let class_name = get_class_declaration_name_node(&ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .ok_or_else(|| "Unable to get public class name".to_string())?
+    .to_string();
+  let body_node = get_class_body_node(&ts_file, class_node)
+    .ok_or_else(|| "Unable to get class body".to_string())?;
+  // Step 2: Collect the fields and the existing constructors
+  let fields = collect_fields(&ts_file, body_node);
+  let mut constructor_signatures = collect_constructor_signatures(&ts_file, body_node);
+  // Step 3: Build the constructors the class does not declare yet
+  let mut generated_constructors = Vec::new();
+  let mut constructor_texts = Vec::new();
+  if no_args && !constructor_signatures.contains(&Vec::new()) {
+    if let Some(final_field) = fields.iter().find(|field| field.is_final && !field.is_initialized) {
+      return Err(format!(
+        "Cannot generate a no-args constructor for {}: final field '{}' is not initialized",
+        class_name, final_field.name
+      ));
+    }
+    constructor_texts.push(build_no_args_constructor(&class_name));
+    constructor_signatures.push(Vec::new());
+    generated_constructors.push(format!("{}()", class_name));
+  }
+  if all_args {
+    let assigned_fields: Vec<&ConstructorField> =
+      fields.iter().filter(|field| !(field.is_final && field.is_initialized)).collect();
+    let signature: Vec<String> =
+      assigned_fields.iter().map(|field| field.field_type.clone()).collect();
+    if !constructor_signatures.contains(&signature) {
+      constructor_texts.push(build_all_args_constructor(&class_name, &assigned_fields));
+      generated_constructors.push(format!("{}({})", class_name, signature.join(", ")));
+      constructor_signatures.push(signature);
+    }
+  }
+  // Step 4: Insert the constructors and save the class
+  if !constructor_texts.is_empty() {
+    insert_constructors(&mut ts_file, &constructor_texts)?;
+    ts_file
+      .save_to_existing_file(entity_file_path)
+      .map_err(|e| format!("Unable to save entity file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  Ok(GenerateConstructorsResponse { entity: build_file_response(&ts_file), generated_constructors })
+}
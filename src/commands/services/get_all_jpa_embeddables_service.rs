@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::{
+  common::{
+    services::{
+      annotation_service::find_annotation_node_by_name,
+      class_declaration_service::{get_class_declaration_name_node, get_public_class_node},
+      package_declaration_service::{get_package_declaration_node, get_package_scope_node},
+    },
+    ts_file::TSFile,
+    types::java_source_directory_type::JavaSourceDirectoryType,
+    utils::{
+      path_util::parse_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+    },
+  },
+  responses::get_jpa_embeddables_response::{EmbeddableFieldResponse, JpaEmbeddableResponse},
+};
+
+/// Whether a field is left out of the embeddable's columns: `static` and `transient` fields, and
+/// fields annotated with `@Transient`.
+fn is_non_persistent_field(ts_file: &TSFile, field_node: &Node) -> bool {
+  let mut cursor = field_node.walk();
+  let Some(modifiers) = field_node.children(&mut cursor).find(|child| child.kind() == "modifiers")
+  else {
+    return false;
+  };
+  let mut modifiers_cursor = modifiers.walk();
+  modifiers.children(&mut modifiers_cursor).any(|modifier| match modifier.kind() {
+    "static" | "transient" => true,
+    "annotation" | "marker_annotation" => modifier
+      .child_by_field_name("name")
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .is_some_and(|name| name.rsplit('.').next() == Some("Transient")),
+    _ => false,
+  })
+}
+
+/// Lists the persistent fields declared directly in the class body, one per declarator.
+fn get_embeddable_fields(ts_file: &TSFile, class_node: &Node) -> Vec<EmbeddableFieldResponse> {
+  let Some(class_body_node) = class_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = class_body_node.walk();
+  class_body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "field_declaration")
+    .filter(|field_node| !is_non_persistent_field(ts_file, field_node))
+    .flat_map(|field_node| {
+      let field_type = field_node
+        .child_by_field_name("type")
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .unwrap_or_default()
+        .to_string();
+      let mut field_cursor = field_node.walk();
+      field_node
+        .children_by_field_name("declarator", &mut field_cursor)
+        .filter_map(|declarator| declarator.child_by_field_name("name"))
+        .filter_map(|name_node| ts_file.get_text_from_node(&name_node))
+        .map(|name| EmbeddableFieldResponse {
+          name: name.to_string(),
+          field_type: field_type.clone(),
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect()
+}
+
+/// Builds the response for the file's public class when it is annotated with `@Embeddable`.
+fn create_embeddable_response(
+  ts_file: &TSFile,
+  module: Option<&str>,
+) -> Option<JpaEmbeddableResponse> {
+  let class_node = get_public_class_node(ts_file)?;
+  find_annotation_node_by_name(ts_file, class_node, "Embeddable")?;
+  let embeddable_type = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let embeddable_package_name = get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+  Some(JpaEmbeddableResponse {
+    embeddable_type,
+    embeddable_package_name,
+    file_path,
+    fields: get_embeddable_fields(ts_file, &class_node),
+    module: module.map(|m| m.to_string()),
+  })
+}
+
+pub fn run(cwd: &Path, module_filter: Option<&str>) -> Result<Vec<JpaEmbeddableResponse>, String> {
+  let mut embeddables: Vec<JpaEmbeddableResponse> = Vec::new();
+  for module in select_project_modules(cwd, module_filter)? {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    for ts_file in parse_all_files_in_directory(&source_root) {
+      if let Some(embeddable) = create_embeddable_response(&ts_file, module.name.as_deref()) {
+        embeddables.push(embeddable);
+      }
+    }
+  }
+  Ok(embeddables)
+}
@@ -6,43 +6,78 @@ use crate::{
       annotation_service::find_annotation_node_by_name,
       class_declaration_service::get_public_class_node,
       package_declaration_service::{get_package_declaration_node, get_package_scope_node},
+      record_declaration_service::is_record_file,
     },
+    ts_file::TSFile,
     types::java_source_directory_type::JavaSourceDirectoryType,
-    utils::path_util::parse_all_files,
+    utils::{
+      path_util::iter_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+      scan_cache_util::cached_scan,
+    },
   },
   responses::file_response::FileResponse,
 };
 
-pub fn run(cwd: &Path) -> Result<Vec<FileResponse>, String> {
-  let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
-  for ts_file in ts_files {
-    match get_public_class_node(&ts_file) {
-      Some(public_class_node) => {
-        let mapped_superclass_annotation_node =
-          find_annotation_node_by_name(&ts_file, public_class_node, "Entity");
-        if mapped_superclass_annotation_node.is_some() {
-          let file_type =
-            ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
-          let file_path = ts_file
-            .file_path()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| "Unknown path".to_string());
-          let file_package_name = if let Some(package_node) = get_package_declaration_node(&ts_file)
-          {
-            get_package_scope_node(&ts_file, package_node)
-              .and_then(|name_node| ts_file.get_text_from_node(&name_node))
-              .map(|s| s.to_string())
-              .unwrap_or_else(|| "No package".to_string())
-          } else {
-            continue;
-          };
-          let found_file = FileResponse { file_type, file_package_name, file_path };
-          files.push(found_file);
-        }
+fn build_entity_file_response(ts_file: &TSFile, module: Option<&str>) -> Option<FileResponse> {
+  if is_record_file(ts_file) {
+    return None;
+  }
+  let public_class_node = get_public_class_node(ts_file)?;
+  find_annotation_node_by_name(ts_file, public_class_node, "Entity")?;
+  let package_node = get_package_declaration_node(ts_file)?;
+  let file_type = ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
+  let file_path = ts_file
+    .file_path()
+    .map(|p| p.to_string_lossy().to_string())
+    .unwrap_or_else(|| "Unknown path".to_string());
+  let file_package_name = get_package_scope_node(ts_file, package_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|s| s.to_string())
+    .unwrap_or_else(|| "No package".to_string());
+  Some(FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    module: module.map(|m| m.to_string()),
+    edits: Vec::new(),
+  })
+}
+
+/// Hands each `@Entity` class of the selected modules to `on_entity` as soon as its file is
+/// parsed, stopping at the first error the callback returns.
+///
+/// # Returns
+/// The number of entities found
+pub fn for_each_entity<F>(
+  cwd: &Path,
+  module_filter: Option<&str>,
+  mut on_entity: F,
+) -> Result<usize, String>
+where
+  F: FnMut(FileResponse) -> Result<(), String>,
+{
+  let mut entities_count = 0;
+  for module in select_project_modules(cwd, module_filter)? {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    for ts_file in iter_all_files_in_directory(&source_root) {
+      if let Some(entity) = build_entity_file_response(&ts_file, module.name.as_deref()) {
+        on_entity(entity)?;
+        entities_count += 1;
       }
-      None => continue,
     }
   }
-  Ok(files)
+  Ok(entities_count)
+}
+
+pub fn run(cwd: &Path, module_filter: Option<&str>) -> Result<Vec<FileResponse>, String> {
+  let scan_key = format!("get-all-jpa-entities:{}", module_filter.unwrap_or(""));
+  cached_scan(cwd, &scan_key, || {
+    let mut files: Vec<FileResponse> = Vec::new();
+    for_each_entity(cwd, module_filter, |entity| {
+      files.push(entity);
+      Ok(())
+    })?;
+    Ok(files)
+  })
 }
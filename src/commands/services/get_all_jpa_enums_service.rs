@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use crate::{
+  common::{
+    services::{
+      enum_declaration_service::{
+        get_enum_constant_name_nodes, get_enum_name_node, get_public_enum_node,
+      },
+      package_declaration_service::{get_package_declaration_node, get_package_scope_node},
+    },
+    ts_file::TSFile,
+    types::java_source_directory_type::JavaSourceDirectoryType,
+    utils::{
+      path_util::parse_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+    },
+  },
+  responses::get_jpa_enums_response::JpaEnumResponse,
+};
+
+/// Builds the response for the file's enum when it can back a JPA enum field, that is a
+/// public top-level enum declared in a package.
+fn create_enum_response(ts_file: &TSFile, module: Option<&str>) -> Option<JpaEnumResponse> {
+  let enum_node = get_public_enum_node(ts_file)?;
+  if enum_node.parent().is_none_or(|parent| parent.kind() != "program") {
+    return None;
+  }
+  let is_public = {
+    let mut cursor = enum_node.walk();
+    enum_node
+      .children(&mut cursor)
+      .find(|child| child.kind() == "modifiers")
+      .and_then(|modifiers| ts_file.get_text_from_node(&modifiers))
+      .is_some_and(|text| text.split_whitespace().any(|modifier| modifier == "public"))
+  };
+  if !is_public {
+    return None;
+  }
+  let enum_type = get_enum_name_node(ts_file, enum_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let enum_package_name = get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let constants = get_enum_constant_name_nodes(ts_file, enum_node)
+    .iter()
+    .filter_map(|node| ts_file.get_text_from_node(node).map(|s| s.to_string()))
+    .collect();
+  let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+  Some(JpaEnumResponse {
+    enum_type,
+    enum_package_name,
+    file_path,
+    constants,
+    module: module.map(|m| m.to_string()),
+  })
+}
+
+pub fn run(cwd: &Path, module_filter: Option<&str>) -> Result<Vec<JpaEnumResponse>, String> {
+  let mut enums: Vec<JpaEnumResponse> = Vec::new();
+  for module in select_project_modules(cwd, module_filter)? {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    for ts_file in parse_all_files_in_directory(&source_root) {
+      if let Some(enum_response) = create_enum_response(&ts_file, module.name.as_deref()) {
+        enums.push(enum_response);
+      }
+    }
+  }
+  Ok(enums)
+}
@@ -8,40 +8,44 @@ use crate::{
       package_declaration_service::{get_package_declaration_node, get_package_scope_node},
     },
     types::java_source_directory_type::JavaSourceDirectoryType,
-    utils::path_util::parse_all_files,
+    utils::{
+      path_util::parse_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+    },
   },
   responses::file_response::FileResponse,
 };
 
-pub fn run(cwd: &Path) -> Result<Vec<FileResponse>, String> {
+pub fn run(cwd: &Path, module_filter: Option<&str>) -> Result<Vec<FileResponse>, String> {
   let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
-  for ts_file in ts_files {
-    match get_public_class_node(&ts_file) {
-      Some(public_class_node) => {
-        let mapped_superclass_annotation_node =
-          find_annotation_node_by_name(&ts_file, public_class_node, "MappedSuperclass");
-        if mapped_superclass_annotation_node.is_some() {
-          let file_type =
-            ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
-          let file_path = ts_file
-            .file_path()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| "Unknown path".to_string());
-          let file_package_name = if let Some(package_node) = get_package_declaration_node(&ts_file)
-          {
-            get_package_scope_node(&ts_file, package_node)
-              .and_then(|name_node| ts_file.get_text_from_node(&name_node))
-              .map(|s| s.to_string())
-              .unwrap_or_else(|| "No package".to_string())
-          } else {
-            continue;
-          };
-          let found_file = FileResponse { file_type, file_package_name, file_path };
-          files.push(found_file);
-        }
+  for module in select_project_modules(cwd, module_filter)? {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    for ts_file in parse_all_files_in_directory(&source_root) {
+      let Some(public_class_node) = get_public_class_node(&ts_file) else {
+        continue;
+      };
+      if find_annotation_node_by_name(&ts_file, public_class_node, "MappedSuperclass").is_none() {
+        continue;
       }
-      None => continue,
+      let Some(package_node) = get_package_declaration_node(&ts_file) else {
+        continue;
+      };
+      let file_type = ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
+      let file_path = ts_file
+        .file_path()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Unknown path".to_string());
+      let file_package_name = get_package_scope_node(&ts_file, package_node)
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "No package".to_string());
+      files.push(FileResponse {
+        file_type,
+        file_package_name,
+        file_path,
+        module: module.name.clone(),
+        edits: Vec::new(),
+      });
     }
   }
   Ok(files)
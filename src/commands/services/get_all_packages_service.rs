@@ -6,7 +6,11 @@ use crate::{
   common::{
     services::package_declaration_service::{get_package_declaration_node, get_package_scope_node},
     types::java_source_directory_type::JavaSourceDirectoryType,
-    utils::path_util::parse_all_files,
+    utils::{
+      path_util::parse_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+      scan_cache_util::cached_scan,
+    },
   },
   responses::package_response::PackageResponse,
 };
@@ -25,7 +29,7 @@ fn find_root_package_from_structure(
   cwd: &Path,
   source_directory: &JavaSourceDirectoryType,
 ) -> Option<String> {
-  let src_dir_path = cwd.join(source_directory.get_directory_path());
+  let src_dir_path = resolve_source_root(cwd, source_directory);
   if !src_dir_path.exists() {
     return None;
   }
@@ -63,13 +67,17 @@ fn find_root_package_from_structure(
   None
 }
 
-pub fn run(
+/// Adds the packages of a single module, starting from its root package, to `response`.
+pub fn collect_module_packages(
   cwd: &Path,
   source_directory: &JavaSourceDirectoryType,
-) -> Result<HashSet<PackageResponse>, String> {
-  let mut response: HashSet<PackageResponse> = HashSet::new();
+  module: Option<&str>,
+  response: &mut HashSet<PackageResponse>,
+) {
+  let module = module.map(|m| m.to_string());
+  let src_dir_path = resolve_source_root(cwd, source_directory);
   // Step 1: Try to find root package from existing Java files
-  let ts_files = parse_all_files(cwd, source_directory);
+  let ts_files = parse_all_files_in_directory(&src_dir_path);
   let root_package = if !ts_files.is_empty() {
     // Collect all packages from files
     let mut packages: Vec<String> = Vec::new();
@@ -89,11 +97,10 @@ pub fn run(
   };
   // If we found a root package, discover all packages from it
   if let Some(root_pkg) = root_package {
-    let src_dir_path = cwd.join(source_directory.get_directory_path());
     let root_pkg_path = root_pkg.replace('.', "/");
     let root_package_dir = src_dir_path.join(&root_pkg_path);
     // Add the root package itself
-    response.insert(PackageResponse { package_name: root_pkg.clone() });
+    response.insert(PackageResponse { package_name: root_pkg.clone(), module: module.clone() });
     // Traverse all subdirectories starting from root package
     if root_package_dir.exists() {
       for entry in WalkDir::new(&root_package_dir).min_depth(1).into_iter().flatten() {
@@ -101,10 +108,30 @@ pub fn run(
           && let Ok(relative_path) = entry.path().strip_prefix(&src_dir_path)
         {
           let package_name = relative_path.to_string_lossy().replace(['\\', '/'], ".");
-          response.insert(PackageResponse { package_name });
+          response.insert(PackageResponse { package_name, module: module.clone() });
         }
       }
     }
   }
-  Ok(response)
+}
+
+pub fn run(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  module_filter: Option<&str>,
+) -> Result<HashSet<PackageResponse>, String> {
+  let scan_key = format!("get-all-packages:{:?}:{}", source_directory, module_filter.unwrap_or(""));
+  cached_scan(cwd, &scan_key, || {
+    let mut response: HashSet<PackageResponse> = HashSet::new();
+    // Scan every selected module, tagging packages with the module they belong to
+    for module in select_project_modules(cwd, module_filter)? {
+      collect_module_packages(
+        &module.path,
+        source_directory,
+        module.name.as_deref(),
+        &mut response,
+      );
+    }
+    Ok(response)
+  })
 }
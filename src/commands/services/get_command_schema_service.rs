@@ -0,0 +1,137 @@
+use std::any::TypeId;
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, Subcommand};
+use schemars::{SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value, json};
+
+use crate::commands::Commands;
+use crate::common::types::{
+  basic_field_config::BasicFieldConfig, embedded_field_config::EmbeddedFieldConfig,
+  entity_field_config::EntityFieldConfig, enum_field_config::EnumFieldConfig,
+  id_field_config::IdFieldConfig, many_to_one_field_config::ManyToOneFieldConfig,
+  one_to_one_field_config::OneToOneFieldConfig,
+};
+use crate::common::utils::json_schema_util::value_enum_subschema_for;
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Subcommands that are not driven by JSON input and are left out of the schema.
+const EXCLUDED_COMMANDS: [&str; 2] = ["ui", "help"];
+
+/// The `$id` of the schema, versioned with the crate so clients can cache it per release.
+pub fn schema_id() -> String {
+  format!("urn:syntaxpresso-core:commands:{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Maps the value type of a clap argument to a schema, referencing `$defs` for value enums.
+fn value_schema(arg: &Arg, generator: &mut SchemaGenerator) -> Value {
+  let type_id = arg.get_value_parser().type_id();
+  if let Some(schema) = value_enum_subschema_for(&type_id, generator) {
+    return schema.to_value();
+  }
+  if type_id == TypeId::of::<bool>() {
+    json!({ "type": "boolean" })
+  } else if type_id == TypeId::of::<u16>() {
+    json!({ "type": "integer", "minimum": 0, "maximum": u16::MAX })
+  } else if type_id == TypeId::of::<i64>() {
+    json!({ "type": "integer" })
+  } else if type_id == TypeId::of::<PathBuf>() {
+    json!({ "type": "string", "format": "path" })
+  } else {
+    json!({ "type": "string" })
+  }
+}
+
+/// Converts a clap default value into a JSON value matching the argument's schema.
+fn default_value(arg: &Arg, schema: &Value) -> Option<Value> {
+  let default = arg.get_default_values().first()?.to_str()?;
+  match schema.get("type").and_then(Value::as_str) {
+    Some("integer") => default.parse::<i64>().ok().map(Value::from),
+    Some("boolean") => default.parse::<bool>().ok().map(Value::from),
+    _ => Some(Value::from(default)),
+  }
+}
+
+fn argument_schema(arg: &Arg, generator: &mut SchemaGenerator) -> Value {
+  let mut schema = match arg.get_action() {
+    ArgAction::SetTrue => json!({ "type": "boolean", "default": false }),
+    ArgAction::Append => json!({ "type": "array", "items": value_schema(arg, generator) }),
+    _ => {
+      let mut schema = value_schema(arg, generator);
+      if let Some(default) = default_value(arg, &schema) {
+        schema["default"] = default;
+      }
+      schema
+    }
+  };
+  if let Some(help) = arg.get_help() {
+    schema["description"] = Value::from(help.to_string());
+  }
+  schema
+}
+
+fn command_schema(command: &clap::Command, generator: &mut SchemaGenerator) -> Value {
+  let mut properties = Map::new();
+  let mut required = Vec::new();
+  for arg in command.get_arguments() {
+    let Some(long) = arg.get_long() else {
+      continue;
+    };
+    if matches!(arg.get_action(), ArgAction::Help | ArgAction::Version) {
+      continue;
+    }
+    properties.insert(long.to_string(), argument_schema(arg, generator));
+    if arg.is_required_set() {
+      required.push(Value::from(long));
+    }
+  }
+  let name = command.get_name();
+  json!({
+    "title": name,
+    "type": "object",
+    "properties": {
+      "command": { "const": name },
+      "arguments": {
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+      },
+    },
+    "required": ["command", "arguments"],
+  })
+}
+
+/// Builds a JSON Schema describing the arguments of every subcommand. Each command is one
+/// `oneOf` branch keyed by its `command` name, with arguments named after their `--long` flags.
+/// Value enums and the field config structs are collected under `$defs`.
+pub fn run() -> Value {
+  let mut generator = SchemaGenerator::new(SchemaSettings::draft2020_12());
+  let cli = Commands::augment_subcommands(clap::Command::new("syntaxpresso-core"));
+  let mut commands: Vec<&clap::Command> = cli
+    .get_subcommands()
+    .filter(|command| !EXCLUDED_COMMANDS.contains(&command.get_name()))
+    .collect();
+  commands.sort_by_key(|command| command.get_name());
+  let command_schemas: Vec<Value> =
+    commands.into_iter().map(|command| command_schema(command, &mut generator)).collect();
+  generator.subschema_for::<BasicFieldConfig>();
+  generator.subschema_for::<EmbeddedFieldConfig>();
+  generator.subschema_for::<EntityFieldConfig>();
+  generator.subschema_for::<EnumFieldConfig>();
+  generator.subschema_for::<IdFieldConfig>();
+  generator.subschema_for::<ManyToOneFieldConfig>();
+  generator.subschema_for::<OneToOneFieldConfig>();
+  let mut definitions: Vec<(String, Value)> =
+    generator.take_definitions(true).into_iter().collect();
+  definitions.sort_by(|(a, _), (b, _)| a.cmp(b));
+  json!({
+    "$schema": SCHEMA_DIALECT,
+    "$id": schema_id(),
+    "title": "syntaxpresso-core command inputs",
+    "version": env!("CARGO_PKG_VERSION"),
+    "oneOf": command_schemas,
+    "$defs": definitions.into_iter().collect::<Map<String, Value>>(),
+  })
+}
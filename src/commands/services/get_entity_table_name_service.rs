@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::services::remove_jpa_field_service::find_entity_file;
+use crate::common::services::annotation_service::{
+  find_annotation_node_by_name, find_annotation_value_node_by_key,
+};
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_class_superclass_name_node, get_public_class_node,
+};
+use crate::common::services::import_declaration_service::find_imported_package_scope;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::utils::column_naming_util::to_column_name;
+use crate::responses::get_entity_table_name_response::{
+  GetEntityTableNameResponse, TableNameSource,
+};
+
+/// Inheritance strategies giving every entity of the hierarchy its own table.
+const TABLE_PER_ENTITY_STRATEGIES: [&str; 2] = ["JOINED", "TABLE_PER_CLASS"];
+
+fn get_annotation_string_value(
+  ts_file: &TSFile,
+  class_node: Node,
+  annotation_name: &str,
+  key: &str,
+) -> Option<String> {
+  let annotation_node = find_annotation_node_by_name(ts_file, class_node, annotation_name)?;
+  let value_node = find_annotation_value_node_by_key(ts_file, annotation_node, key)?;
+  let value = ts_file.get_text_from_node(&value_node)?.trim_matches('"');
+  (!value.is_empty()).then(|| value.to_string())
+}
+
+fn get_class_name(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  let name_node = get_class_declaration_name_node(ts_file, class_node)?;
+  ts_file.get_text_from_node(&name_node).map(|s| s.to_string())
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+/// Loads the superclass of `ts_file` when it is an entity of the project, looking it up through
+/// its import or, without one, in the package of the subclass.
+fn find_superclass_entity_file(cwd: &Path, ts_file: &TSFile) -> Option<TSFile> {
+  let class_node = get_public_class_node(ts_file)?;
+  let superclass_node = get_class_superclass_name_node(ts_file, class_node)?;
+  let superclass_name = ts_file.get_text_from_node(&superclass_node)?;
+  let package_name = find_imported_package_scope(ts_file, superclass_name)
+    .unwrap_or_else(|| get_package_name(ts_file));
+  find_entity_file(cwd, &package_name, superclass_name)
+}
+
+/// The JPA entity name: `@Entity(name = ...)` when given, the class name otherwise.
+fn get_entity_name(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  get_annotation_string_value(ts_file, class_node, "Entity", "name")
+    .or_else(|| get_class_name(ts_file, class_node))
+}
+
+fn uses_table_per_entity_strategy(ts_file: &TSFile, class_node: Node) -> bool {
+  find_annotation_node_by_name(ts_file, class_node, "Inheritance")
+    .and_then(|node| find_annotation_value_node_by_key(ts_file, node, "strategy"))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .is_some_and(|strategy| {
+      TABLE_PER_ENTITY_STRATEGIES.iter().any(|candidate| strategy.ends_with(candidate))
+    })
+}
+
+/// Returns the table an entity is mapped to: its own `@Table(name)`, the table of the root of a
+/// single table hierarchy, or a name derived from the entity name with `naming_strategy`.
+pub fn run(
+  cwd: &Path,
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  naming_strategy: &ColumnNamingStrategy,
+) -> Result<GetEntityTableNameResponse, String> {
+  // Step 1: Parse the entity and check it is a JPA entity
+  let ts_file = create_ts_file(entity_file_path, b64_source_code)?;
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type =
+    get_class_name(&ts_file, class_node).ok_or_else(|| "Unable to get class name".to_string())?;
+  // Step 2: An explicit @Table name always wins
+  if let Some(table_name) = get_annotation_string_value(&ts_file, class_node, "Table", "name") {
+    return Ok(GetEntityTableNameResponse {
+      declaring_type: entity_type.clone(),
+      entity_type,
+      table_name,
+      source: TableNameSource::Explicit,
+    });
+  }
+  // Step 3: Walk up the entity superclasses to the root of the hierarchy
+  let mut root_ts_file = None;
+  let mut visited = vec![entity_type.clone()];
+  while let Some(superclass_ts_file) =
+    find_superclass_entity_file(cwd, root_ts_file.as_ref().unwrap_or(&ts_file))
+  {
+    let superclass_name = superclass_ts_file.get_file_name_without_ext().unwrap_or_default();
+    if visited.contains(&superclass_name) {
+      break;
+    }
+    visited.push(superclass_name);
+    root_ts_file = Some(superclass_ts_file);
+  }
+  // Step 4: Single table hierarchies share the table of the root entity
+  let (declaring_ts_file, inherited) = match &root_ts_file {
+    Some(root) => {
+      let root_class_node = get_public_class_node(root)
+        .ok_or_else(|| "Unable to get public class node of the root entity".to_string())?;
+      if uses_table_per_entity_strategy(root, root_class_node) {
+        (&ts_file, false)
+      } else {
+        (root, true)
+      }
+    }
+    None => (&ts_file, false),
+  };
+  let declaring_class_node = get_public_class_node(declaring_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  let declaring_type = get_class_name(declaring_ts_file, declaring_class_node)
+    .ok_or_else(|| "Unable to get class name".to_string())?;
+  if inherited
+    && let Some(table_name) =
+      get_annotation_string_value(declaring_ts_file, declaring_class_node, "Table", "name")
+  {
+    return Ok(GetEntityTableNameResponse {
+      entity_type,
+      table_name,
+      source: TableNameSource::Inherited,
+      declaring_type,
+    });
+  }
+  // Step 5: Derive the name from the entity name
+  let entity_name = get_entity_name(declaring_ts_file, declaring_class_node)
+    .ok_or_else(|| "Unable to get entity name".to_string())?;
+  Ok(GetEntityTableNameResponse {
+    entity_type,
+    table_name: to_column_name(&entity_name, naming_strategy),
+    source: TableNameSource::Derived,
+    declaring_type,
+  })
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::enum_declaration_service::{
+  get_enum_constant_name_nodes, get_enum_name_node, get_public_enum_node,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::responses::get_enum_constants_response::GetEnumConstantsResponse;
+
+/// Lists the constants of the public enum of a file in declaration order, without their
+/// constructor arguments or bodies.
+pub fn run(
+  enum_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<GetEnumConstantsResponse, String> {
+  // Step 1: Parse the enum file
+  let ts_file = create_ts_file(enum_file_path, b64_source_code)?;
+  // Step 2: Find the public enum and its name
+  let enum_node =
+    get_public_enum_node(&ts_file).ok_or_else(|| "Unable to find a public enum".to_string())?;
+  let enum_type = get_enum_name_node(&ts_file, enum_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .ok_or_else(|| "Unable to get enum name".to_string())?
+    .to_string();
+  let enum_package_name = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  // Step 3: Collect the constant names in order
+  let constants = get_enum_constant_name_nodes(&ts_file, enum_node)
+    .iter()
+    .filter_map(|node| ts_file.get_text_from_node(node))
+    .map(|name| name.to_string())
+    .collect();
+  Ok(GetEnumConstantsResponse { enum_type, enum_package_name, constants })
+}
@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use serde_json::{Map, Value};
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::common::ts_file::TSFile;
+use crate::responses::get_field_annotations_response::{
+  FieldAnnotationResponse, GetFieldAnnotationsResponse,
+};
+
+/// Resolves the Java escape sequences of a `"..."` literal.
+fn unescape_string_literal(literal: &str) -> String {
+  let content = literal.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(literal);
+  let mut value = String::with_capacity(content.len());
+  let mut chars = content.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      value.push(c);
+      continue;
+    }
+    match chars.next() {
+      Some('n') => value.push('\n'),
+      Some('t') => value.push('\t'),
+      Some('r') => value.push('\r'),
+      Some('b') => value.push('\u{8}'),
+      Some('f') => value.push('\u{c}'),
+      Some('s') => value.push(' '),
+      Some(escaped) => value.push(escaped),
+      None => value.push('\\'),
+    }
+  }
+  value
+}
+
+fn parse_number(text: &str) -> Option<Value> {
+  let digits = text.replace('_', "");
+  if let Ok(integer) = digits.trim_end_matches(['L', 'l']).parse::<i64>() {
+    return Some(Value::from(integer));
+  }
+  digits
+    .trim_end_matches(['F', 'f', 'D', 'd'])
+    .parse::<f64>()
+    .ok()
+    .filter(|number| number.is_finite())
+    .map(Value::from)
+}
+
+/// Converts an annotation element value to JSON, falling back to its source text.
+fn parse_value(ts_file: &TSFile, node: &Node) -> Value {
+  let text = ts_file.get_text_from_node(node).unwrap_or_default();
+  match node.kind() {
+    "true" => Value::Bool(true),
+    "false" => Value::Bool(false),
+    "string_literal" if text.starts_with('"') && !text.starts_with("\"\"\"") => {
+      Value::String(unescape_string_literal(text))
+    }
+    "decimal_integer_literal" | "decimal_floating_point_literal" | "unary_expression" => {
+      parse_number(text).unwrap_or_else(|| Value::String(text.to_string()))
+    }
+    "element_value_array_initializer" => {
+      let mut cursor = node.walk();
+      Value::Array(
+        node
+          .named_children(&mut cursor)
+          .filter(|child| child.kind() != "comment")
+          .map(|child| parse_value(ts_file, &child))
+          .collect(),
+      )
+    }
+    _ => Value::String(text.to_string()),
+  }
+}
+
+fn parse_annotation(ts_file: &TSFile, annotation_node: &Node) -> FieldAnnotationResponse {
+  let name = annotation_node
+    .child_by_field_name("name")
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  let mut attributes = Map::new();
+  if let Some(arguments_node) = annotation_node.child_by_field_name("arguments") {
+    let mut cursor = arguments_node.walk();
+    for argument in arguments_node.named_children(&mut cursor) {
+      if argument.kind() == "comment" {
+        continue;
+      }
+      if argument.kind() == "element_value_pair" {
+        let key =
+          argument.child_by_field_name("key").and_then(|key| ts_file.get_text_from_node(&key));
+        let value = argument.child_by_field_name("value");
+        if let (Some(key), Some(value)) = (key, value) {
+          attributes.insert(key.to_string(), parse_value(ts_file, &value));
+        }
+      } else {
+        attributes.insert("value".to_string(), parse_value(ts_file, &argument));
+      }
+    }
+  }
+  FieldAnnotationResponse { name, attributes }
+}
+
+/// Lists the annotations of a field of the file's public class, in source order, with their
+/// attributes so edit dialogs can start from the current mapping.
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  field_name: &str,
+) -> Result<GetFieldAnnotationsResponse, String> {
+  // Step 1: Parse the file
+  let ts_file = create_ts_file(entity_file_path, b64_source_code)?;
+  // Step 2: Find the field
+  let class_node = get_public_class_node(&ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  let field_node = find_field_declaration_node_by_name(&ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field not found: {}", field_name))?;
+  let field_type = field_node
+    .child_by_field_name("type")
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  // Step 3: Parse the annotations placed directly on the field
+  let mut cursor = field_node.walk();
+  let annotations = field_node
+    .children(&mut cursor)
+    .filter(|child| child.kind() == "modifiers")
+    .flat_map(|modifiers| {
+      let mut modifiers_cursor = modifiers.walk();
+      modifiers.children(&mut modifiers_cursor).collect::<Vec<_>>()
+    })
+    .filter(|modifier| modifier.kind().ends_with("annotation"))
+    .map(|annotation| parse_annotation(&ts_file, &annotation))
+    .collect();
+  Ok(GetFieldAnnotationsResponse { field_name: field_name.to_string(), field_type, annotations })
+}
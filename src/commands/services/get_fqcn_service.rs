@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use crate::commands::services::get_java_file_type_service::find_primary_type_declaration;
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::responses::get_fqcn_response::GetFqcnResponse;
+
+/// Returns the fully-qualified name of the top-level type of a file, combining its package
+/// declaration with the public type name (or the first type when none is public).
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<GetFqcnResponse, String> {
+  // Step 1: Parse the file
+  let ts_file = create_ts_file(file_path, b64_source_code)?;
+  if ts_file.tree.is_none() {
+    return Err("Unable to parse the file".to_string());
+  }
+  // Step 2: Find the primary top-level type name
+  let (declaration_node, _) = find_primary_type_declaration(&ts_file)
+    .ok_or_else(|| "Unable to find a top-level type declaration".to_string())?;
+  let type_name = declaration_node
+    .child_by_field_name("name")
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .ok_or_else(|| "Unable to get type name".to_string())?
+    .to_string();
+  // Step 3: Prefix it with the package, if any
+  let package_name = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  let fqcn = if package_name.is_empty() {
+    type_name.clone()
+  } else {
+    format!("{}.{}", package_name, type_name)
+  };
+  Ok(GetFqcnResponse { fqcn, package_name, type_name })
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::import_declaration_service::{
+  find_import_declaration_node, find_imported_package_scope,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::responses::get_import_status_response::{GetImportStatusResponse, ImportStatus};
+
+/// Reports whether `type_fqcn` can be used by its simple name in a file or needs an import.
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  type_fqcn: &str,
+) -> Result<GetImportStatusResponse, String> {
+  // Step 1: Split the type into its package and simple name
+  let (package_scope, class_name) = type_fqcn.rsplit_once('.').ok_or_else(|| {
+    format!("'{}' must include its package (e.g. com.example.{})", type_fqcn, type_fqcn)
+  })?;
+  // Step 2: Parse the file
+  let ts_file = create_ts_file(file_path, b64_source_code)?;
+  // Step 3: Read the package of the file
+  let file_package = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default();
+  // Step 4: Check the imports, single-type imports taking precedence over wildcards, then
+  // the types visible without an import
+  let status =
+    if find_imported_package_scope(&ts_file, class_name).as_deref() == Some(package_scope) {
+      ImportStatus::Imported
+    } else if find_import_declaration_node(&ts_file, package_scope, class_name).is_some() {
+      ImportStatus::Wildcard
+    } else if package_scope == file_package {
+      ImportStatus::SamePackage
+    } else if package_scope == "java.lang" {
+      ImportStatus::JavaLang
+    } else {
+      ImportStatus::NotImported
+    };
+  Ok(GetImportStatusResponse {
+    type_fqcn: type_fqcn.to_string(),
+    import_needed: status == ImportStatus::NotImported,
+    status,
+  })
+}
@@ -0,0 +1,213 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::responses::get_java_class_structure_response::{
+  GetJavaClassStructureResponse, JavaFieldStructureResponse, JavaMethodStructureResponse,
+  JavaParameterStructureResponse, JavaTypeStructureResponse,
+};
+
+/// Type declaration node kinds with the `kind` reported for them.
+const TYPE_DECLARATION_KINDS: [(&str, &str); 5] = [
+  ("class_declaration", "class"),
+  ("interface_declaration", "interface"),
+  ("enum_declaration", "enum"),
+  ("record_declaration", "record"),
+  ("annotation_type_declaration", "annotation"),
+];
+
+fn get_type_kind(node: &Node) -> Option<&'static str> {
+  TYPE_DECLARATION_KINDS.iter().find(|(kind, _)| *kind == node.kind()).map(|(_, name)| *name)
+}
+
+fn get_field_text(ts_file: &TSFile, node: &Node, field_name: &str) -> Option<String> {
+  node
+    .child_by_field_name(field_name)
+    .and_then(|child| ts_file.get_text_from_node(&child))
+    .map(|text| text.to_string())
+}
+
+/// Keyword modifiers of a declaration, e.g. `public`, `static`. Annotations are left out.
+fn get_modifiers(ts_file: &TSFile, node: &Node) -> Vec<String> {
+  let mut cursor = node.walk();
+  let Some(modifiers_node) = node.children(&mut cursor).find(|child| child.kind() == "modifiers")
+  else {
+    return Vec::new();
+  };
+  let mut cursor = modifiers_node.walk();
+  modifiers_node
+    .children(&mut cursor)
+    .filter(|child| !child.kind().ends_with("annotation"))
+    .filter_map(|child| ts_file.get_text_from_node(&child))
+    .map(|text| text.to_string())
+    .collect()
+}
+
+/// One entry per declarator, so `int x, y;` yields both `x` and `y` with the declaration range.
+fn get_fields(ts_file: &TSFile, field_node: &Node) -> Vec<JavaFieldStructureResponse> {
+  let field_type = get_field_text(ts_file, field_node, "type").unwrap_or_default();
+  let modifiers = get_modifiers(ts_file, field_node);
+  let mut cursor = field_node.walk();
+  field_node
+    .children_by_field_name("declarator", &mut cursor)
+    .filter_map(|declarator| get_field_text(ts_file, &declarator, "name"))
+    .map(|name| JavaFieldStructureResponse {
+      name,
+      field_type: field_type.clone(),
+      modifiers: modifiers.clone(),
+      start_byte: field_node.start_byte(),
+      end_byte: field_node.end_byte(),
+    })
+    .collect()
+}
+
+/// Record components are reported as the fields of the record.
+fn get_record_components(ts_file: &TSFile, record_node: &Node) -> Vec<JavaFieldStructureResponse> {
+  get_parameters_with_nodes(ts_file, record_node)
+    .into_iter()
+    .map(|(parameter, node)| JavaFieldStructureResponse {
+      name: parameter.name,
+      field_type: parameter.parameter_type,
+      modifiers: Vec::new(),
+      start_byte: node.start_byte(),
+      end_byte: node.end_byte(),
+    })
+    .collect()
+}
+
+fn get_parameters_with_nodes<'a>(
+  ts_file: &TSFile,
+  declaration_node: &Node<'a>,
+) -> Vec<(JavaParameterStructureResponse, Node<'a>)> {
+  let Some(parameters_node) = declaration_node.child_by_field_name("parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = parameters_node.walk();
+  parameters_node
+    .named_children(&mut cursor)
+    .filter_map(|parameter_node| {
+      let parameter = match parameter_node.kind() {
+        "formal_parameter" => JavaParameterStructureResponse {
+          name: get_field_text(ts_file, &parameter_node, "name")?,
+          parameter_type: get_field_text(ts_file, &parameter_node, "type")?,
+        },
+        "spread_parameter" => {
+          let mut cursor = parameter_node.walk();
+          let children: Vec<Node> = parameter_node.named_children(&mut cursor).collect();
+          let type_node = children.iter().find(|child| child.kind() != "modifiers")?;
+          let declarator = children.iter().find(|child| child.kind() == "variable_declarator")?;
+          JavaParameterStructureResponse {
+            name: get_field_text(ts_file, declarator, "name")?,
+            parameter_type: format!("{}...", ts_file.get_text_from_node(type_node)?),
+          }
+        }
+        _ => return None,
+      };
+      Some((parameter, parameter_node))
+    })
+    .collect()
+}
+
+fn get_method(ts_file: &TSFile, method_node: &Node) -> Option<JavaMethodStructureResponse> {
+  let kind = match method_node.kind() {
+    "method_declaration" | "annotation_type_element_declaration" => "method",
+    "constructor_declaration" | "compact_constructor_declaration" => "constructor",
+    _ => return None,
+  };
+  Some(JavaMethodStructureResponse {
+    name: get_field_text(ts_file, method_node, "name")?,
+    kind: kind.to_string(),
+    return_type: get_field_text(ts_file, method_node, "type"),
+    parameters: get_parameters_with_nodes(ts_file, method_node)
+      .into_iter()
+      .map(|(parameter, _)| parameter)
+      .collect(),
+    modifiers: get_modifiers(ts_file, method_node),
+    start_byte: method_node.start_byte(),
+    end_byte: method_node.end_byte(),
+  })
+}
+
+/// Members of a type body. Enum members live after the constants, in `enum_body_declarations`.
+fn get_member_nodes<'a>(type_node: &Node<'a>) -> Vec<Node<'a>> {
+  let Some(body_node) = type_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  let mut members = Vec::new();
+  for child in body_node.named_children(&mut cursor) {
+    if child.kind() == "enum_body_declarations" {
+      let mut cursor = child.walk();
+      members.extend(child.named_children(&mut cursor));
+    } else {
+      members.push(child);
+    }
+  }
+  members
+}
+
+fn get_type_structure(ts_file: &TSFile, type_node: &Node) -> Option<JavaTypeStructureResponse> {
+  let kind = get_type_kind(type_node)?;
+  let mut structure = JavaTypeStructureResponse {
+    name: get_field_text(ts_file, type_node, "name")?,
+    kind: kind.to_string(),
+    modifiers: get_modifiers(ts_file, type_node),
+    start_byte: type_node.start_byte(),
+    end_byte: type_node.end_byte(),
+    fields: Vec::new(),
+    methods: Vec::new(),
+    nested_types: Vec::new(),
+  };
+  if type_node.kind() == "record_declaration" {
+    structure.fields.extend(get_record_components(ts_file, type_node));
+  }
+  for member_node in get_member_nodes(type_node) {
+    match member_node.kind() {
+      "field_declaration" | "constant_declaration" => {
+        structure.fields.extend(get_fields(ts_file, &member_node));
+      }
+      _ if get_type_kind(&member_node).is_some() => {
+        structure.nested_types.extend(get_type_structure(ts_file, &member_node));
+      }
+      _ => structure.methods.extend(get_method(ts_file, &member_node)),
+    }
+  }
+  Some(structure)
+}
+
+/// Builds an outline of every type declared in a Java file: fields, methods and constructors,
+/// and nested types, each with its byte range. Works for any Java file, not only entities.
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<GetJavaClassStructureResponse, String> {
+  // Step 1: Parse the file
+  let ts_file = create_ts_file(file_path, b64_source_code)?;
+  let root_node = ts_file
+    .tree
+    .as_ref()
+    .map(|tree| tree.root_node())
+    .ok_or_else(|| "Unable to parse Java file".to_string())?;
+  // Step 2: Get package name
+  let package_name = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  // Step 3: Build the structure of every top-level type
+  let mut cursor = root_node.walk();
+  let types: Vec<JavaTypeStructureResponse> = root_node
+    .named_children(&mut cursor)
+    .filter_map(|node| get_type_structure(&ts_file, &node))
+    .collect();
+  if types.is_empty() {
+    return Err("No type declaration found in file".to_string());
+  }
+  // Step 4: Build and return response
+  Ok(GetJavaClassStructureResponse { package_name, types })
+}
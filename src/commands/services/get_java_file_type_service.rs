@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::responses::get_java_file_type_response::{GetJavaFileTypeResponse, JavaFileKind};
+
+/// JPA type annotations, checked in this order, with the kind reported for them.
+const JPA_TYPE_ANNOTATIONS: [(&str, JavaFileKind); 3] = [
+  ("Entity", JavaFileKind::Entity),
+  ("MappedSuperclass", JavaFileKind::MappedSuperclass),
+  ("Embeddable", JavaFileKind::Embeddable),
+];
+
+fn get_declaration_kind(node: &Node) -> Option<JavaFileKind> {
+  match node.kind() {
+    "class_declaration" => Some(JavaFileKind::Class),
+    "interface_declaration" => Some(JavaFileKind::Interface),
+    "enum_declaration" => Some(JavaFileKind::Enum),
+    "record_declaration" => Some(JavaFileKind::Record),
+    "annotation_type_declaration" => Some(JavaFileKind::Annotation),
+    _ => None,
+  }
+}
+
+fn get_modifiers_node<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+  let mut cursor = node.walk();
+  node.children(&mut cursor).find(|child| child.kind() == "modifiers")
+}
+
+fn is_public(ts_file: &TSFile, node: &Node) -> bool {
+  get_modifiers_node(node).is_some_and(|modifiers_node| {
+    let mut cursor = modifiers_node.walk();
+    modifiers_node
+      .children(&mut cursor)
+      .any(|child| ts_file.get_text_from_node(&child) == Some("public"))
+  })
+}
+
+/// Simple names of the annotations placed directly on a declaration, ignoring the ones on its
+/// members. Qualified annotations (e.g. `@jakarta.persistence.Entity`) keep their last segment.
+fn get_annotation_names(ts_file: &TSFile, node: &Node) -> Vec<String> {
+  let Some(modifiers_node) = get_modifiers_node(node) else {
+    return Vec::new();
+  };
+  let mut cursor = modifiers_node.walk();
+  modifiers_node
+    .children(&mut cursor)
+    .filter(|child| child.kind().ends_with("annotation"))
+    .filter_map(|annotation| annotation.child_by_field_name("name"))
+    .filter_map(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.rsplit('.').next().unwrap_or(name).to_string())
+    .collect()
+}
+
+/// Finds the top-level type declaration of a file, preferring the public one when the file
+/// declares several and falling back to the first otherwise.
+pub fn find_primary_type_declaration(ts_file: &TSFile) -> Option<(Node<'_>, JavaFileKind)> {
+  let root_node = ts_file.tree.as_ref()?.root_node();
+  let mut cursor = root_node.walk();
+  let declarations: Vec<(Node, JavaFileKind)> = root_node
+    .named_children(&mut cursor)
+    .filter_map(|node| get_declaration_kind(&node).map(|kind| (node, kind)))
+    .collect();
+  declarations
+    .iter()
+    .find(|(node, _)| is_public(ts_file, node))
+    .or_else(|| declarations.first())
+    .cloned()
+}
+
+/// Classifies the top-level type of a file, preferring the public one when the file declares
+/// several. JPA type annotations take precedence over the plain `class` kind.
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<GetJavaFileTypeResponse, String> {
+  // Step 1: Parse the file
+  let ts_file = create_ts_file(file_path, b64_source_code)?;
+  if ts_file.tree.is_none() {
+    return Err("Unable to parse the file".to_string());
+  }
+  // Step 2: Find the top-level type declaration
+  let (declaration_node, declaration_kind) = find_primary_type_declaration(&ts_file)
+    .ok_or_else(|| "Unable to find a top-level type declaration".to_string())?;
+  let type_name = declaration_node
+    .child_by_field_name("name")
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .ok_or_else(|| "Unable to get type name".to_string())?
+    .to_string();
+  let package_name = get_package_declaration_node(&ts_file)
+    .and_then(|node| get_package_scope_node(&ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  // Step 3: Refine classes by their JPA type annotation
+  let kind = if declaration_kind == JavaFileKind::Class {
+    let annotation_names = get_annotation_names(&ts_file, &declaration_node);
+    JPA_TYPE_ANNOTATIONS
+      .into_iter()
+      .find(|(name, _)| annotation_names.iter().any(|annotation| annotation == name))
+      .map_or(declaration_kind, |(_, kind)| kind)
+  } else {
+    declaration_kind
+  };
+  Ok(GetJavaFileTypeResponse { type_name, package_name, kind })
+}
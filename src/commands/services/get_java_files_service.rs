@@ -12,12 +12,15 @@ use crate::{
     },
     ts_file::TSFile,
     types::{java_file_type::JavaFileType, java_source_directory_type::JavaSourceDirectoryType},
-    utils::path_util::parse_all_files,
+    utils::{
+      path_util::parse_all_files_in_directory,
+      project_layout_util::{resolve_source_root, select_project_modules},
+    },
   },
   responses::file_response::FileResponse,
 };
 
-fn create_file_response(ts_file: &TSFile) -> Option<FileResponse> {
+fn create_file_response(ts_file: &TSFile, module: Option<&str>) -> Option<FileResponse> {
   let file_type = ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
   let file_path = ts_file
     .file_path()
@@ -31,55 +34,37 @@ fn create_file_response(ts_file: &TSFile) -> Option<FileResponse> {
   } else {
     return None;
   };
-  let found_file = FileResponse { file_type, file_package_name, file_path };
+  let module = module.map(|m| m.to_string());
+  let found_file =
+    FileResponse { file_type, file_package_name, file_path, module, edits: Vec::new() };
   Some(found_file)
 }
 
-pub fn run(cwd: &Path, java_file_type: &JavaFileType) -> Result<Vec<FileResponse>, String> {
+fn is_java_file_of_type(ts_file: &TSFile, java_file_type: &JavaFileType) -> bool {
+  match java_file_type {
+    JavaFileType::Class => get_public_class_node(ts_file).is_some(),
+    JavaFileType::Interface => get_public_interface_node(ts_file).is_some(),
+    JavaFileType::Enum => get_public_enum_node(ts_file).is_some(),
+    JavaFileType::Annotation => get_public_annotation_type_node(ts_file).is_some(),
+    JavaFileType::Record => get_public_record_node(ts_file).is_some(),
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  java_file_type: &JavaFileType,
+  module_filter: Option<&str>,
+) -> Result<Vec<FileResponse>, String> {
   let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
-  for ts_file in ts_files {
-    match java_file_type {
-      JavaFileType::Class => match get_public_class_node(&ts_file) {
-        Some(_) => {
-          if let Some(file_response) = create_file_response(&ts_file) {
-            files.push(file_response);
-          }
-        }
-        None => continue,
-      },
-      JavaFileType::Interface => match get_public_interface_node(&ts_file) {
-        Some(_) => {
-          if let Some(file_response) = create_file_response(&ts_file) {
-            files.push(file_response);
-          }
-        }
-        None => continue,
-      },
-      JavaFileType::Enum => match get_public_enum_node(&ts_file) {
-        Some(_) => {
-          if let Some(file_response) = create_file_response(&ts_file) {
-            files.push(file_response);
-          }
-        }
-        None => continue,
-      },
-      JavaFileType::Annotation => match get_public_annotation_type_node(&ts_file) {
-        Some(_) => {
-          if let Some(file_response) = create_file_response(&ts_file) {
-            files.push(file_response);
-          }
-        }
-        None => continue,
-      },
-      JavaFileType::Record => match get_public_record_node(&ts_file) {
-        Some(_) => {
-          if let Some(file_response) = create_file_response(&ts_file) {
-            files.push(file_response);
-          }
-        }
-        None => continue,
-      },
+  for module in select_project_modules(cwd, module_filter)? {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    let ts_files = parse_all_files_in_directory(&source_root);
+    for ts_file in ts_files {
+      if is_java_file_of_type(&ts_file, java_file_type)
+        && let Some(file_response) = create_file_response(&ts_file, module.name.as_deref())
+      {
+        files.push(file_response);
+      }
     }
   }
   Ok(files)
@@ -0,0 +1,38 @@
+use crate::{
+  common::types::java_basic_types::JavaBasicType,
+  responses::{
+    basic_java_type_response::JavaBasicTypeResponse,
+    java_type_metadata_response::JavaTypeMetadataResponse,
+  },
+};
+
+fn contains_type(types: &[JavaBasicTypeResponse], type_id: &str) -> bool {
+  types.iter().any(|java_type| java_type.id == type_id)
+}
+
+/// Returns every Java basic type with all its capability flags, replacing one
+/// `get-java-basic-types` call per category.
+pub fn run() -> Result<Vec<JavaTypeMetadataResponse>, String> {
+  let basic_types = JavaBasicType::AllTypes;
+  let types_with_length = basic_types.get_types_with_length();
+  let types_with_precision_scale = basic_types.get_types_with_precision_scale();
+  let types_with_temporal = basic_types.get_types_with_temporal();
+  let types_with_time_zone_storage = basic_types.get_types_with_time_zone_storage();
+  let types_with_lob = basic_types.get_types_with_extra_other();
+  Ok(
+    basic_types
+      .get_all_types()
+      .into_iter()
+      .map(|java_type| JavaTypeMetadataResponse {
+        supports_length: contains_type(&types_with_length, &java_type.id),
+        supports_precision_scale: contains_type(&types_with_precision_scale, &java_type.id),
+        supports_temporal: contains_type(&types_with_temporal, &java_type.id),
+        supports_time_zone_storage: contains_type(&types_with_time_zone_storage, &java_type.id),
+        supports_lob: contains_type(&types_with_lob, &java_type.id),
+        id: java_type.id,
+        name: java_type.name,
+        package_path: java_type.package_path,
+      })
+      .collect(),
+  )
+}
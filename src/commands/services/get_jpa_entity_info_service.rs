@@ -9,9 +9,11 @@ use crate::common::services::annotation_service::{
 use crate::common::services::class_declaration_service::{
   get_class_declaration_name_node, get_class_superclass_name_node,
 };
+use crate::common::services::import_declaration_service::find_imported_package_scope;
 use crate::common::services::package_declaration_service::{
   get_package_declaration_node, get_package_scope_node,
 };
+use crate::common::services::record_declaration_service::is_record_file;
 use crate::common::services::{
   annotation_service, class_declaration_service, field_declaration_service,
 };
@@ -97,13 +99,18 @@ fn find_id_field_recursive(
   let field_nodes =
     field_declaration_service::get_all_field_declaration_nodes(ts_file, *class_node);
   for field_node in field_nodes {
-    if annotation_service::find_annotation_node_by_name(ts_file, field_node, "Id").is_some() {
+    // An `@EmbeddedId` is typed by its embeddable, which is the id type of the entity
+    let is_id_field = ["Id", "EmbeddedId"].iter().any(|annotation_name| {
+      annotation_service::find_annotation_node_by_name(ts_file, field_node, annotation_name)
+        .is_some()
+    });
+    if is_id_field {
       let field_type_result =
         field_declaration_service::get_field_declaration_full_type_node(ts_file, field_node);
       if let Some(type_node) = field_type_result
         && let Some(field_type) = ts_file.get_text_from_node(&type_node)
       {
-        let package_name = get_package_for_type(field_type);
+        let package_name = resolve_type_package(ts_file, class_node, field_type);
         return Ok(IdFieldSearchResult::Found { field_type: field_type.to_string(), package_name });
       }
     }
@@ -210,7 +217,42 @@ fn get_package_for_type(type_name: &str) -> String {
   }
 }
 
-fn create_ts_file(
+/// Resolves the package of a type used in `ts_file`: its single-type import, the package of a
+/// well-known JDK type, or else the package of the file itself. Type parameters of the class
+/// have no package.
+pub(crate) fn resolve_type_package(ts_file: &TSFile, class_node: &Node, type_name: &str) -> String {
+  let simple_name = type_name.split('<').next().unwrap_or(type_name).trim();
+  if get_class_type_parameter_names(ts_file, class_node).iter().any(|name| name == simple_name) {
+    return String::new();
+  }
+  if let Some(package_name) = find_imported_package_scope(ts_file, simple_name) {
+    return package_name;
+  }
+  let package_name = get_package_for_type(simple_name);
+  if !package_name.is_empty() || is_primitive_type(simple_name) {
+    return package_name;
+  }
+  extract_entity_package_scope(ts_file).unwrap_or_default()
+}
+
+/// Names of the type parameters declared by the class, e.g. `ID` in `BaseEntity<ID>`.
+pub(crate) fn get_class_type_parameter_names(ts_file: &TSFile, class_node: &Node) -> Vec<String> {
+  let Some(type_parameters_node) = class_node.child_by_field_name("type_parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = type_parameters_node.walk();
+  type_parameters_node
+    .named_children(&mut cursor)
+    .filter_map(|type_parameter_node| type_parameter_node.named_child(0))
+    .filter_map(|name_node| ts_file.get_text_from_node(&name_node).map(|s| s.to_string()))
+    .collect()
+}
+
+fn is_primitive_type(type_name: &str) -> bool {
+  matches!(type_name, "byte" | "short" | "int" | "long" | "float" | "double" | "char" | "boolean")
+}
+
+pub(crate) fn create_ts_file(
   entity_file_path: Option<&Path>,
   b64_source_code: Option<&str>,
 ) -> Result<TSFile, String> {
@@ -230,7 +272,8 @@ fn get_superclass_name(ts_file: &TSFile, class_declaration_node: &Node) -> Optio
     get_class_superclass_name_node(ts_file, *class_declaration_node);
   match superclass_name_node_option {
     Some(node) => ts_file.get_text_from_node(&node).map(|s| s.to_string()),
-    None => None,
+    // Generic superclasses such as `BaseEntity<UUID>` are reported by their raw name
+    None => extract_superclass_name(ts_file, class_declaration_node).ok().flatten(),
   }
 }
 
@@ -251,7 +294,10 @@ pub fn run(
 ) -> Result<GetJpaEntityInfoResponse, String> {
   // Step 1: Create TSFile
   let ts_file = create_ts_file(entity_file_path, b64_source_code)?;
-  // Step 2: Get public class node
+  // Step 2: Get public class node, records can't be entities
+  if is_record_file(&ts_file) {
+    return Err("Records cannot be JPA entities".to_string());
+  }
   let public_class_node = get_public_class_node(&ts_file)?;
   // Step 3: Check if class is JPA entity
   let is_jpa_entity = check_is_jpa_entity(&ts_file, &public_class_node)?;
@@ -0,0 +1,123 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::{self, create_ts_file};
+use crate::common::services::annotation_service::{
+  find_annotation_node_by_name, find_annotation_value_node_by_key,
+};
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::{
+  get_all_field_declaration_nodes, get_field_declaration_name_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::responses::get_jpa_relationships_response::{
+  GetJpaRelationshipsResponse, JpaRelationshipResponse,
+};
+
+/// Relationship annotations with the fetch type JPA applies when none is declared.
+const RELATIONSHIP_KINDS: [(&str, &str); 4] =
+  [("OneToOne", "EAGER"), ("ManyToOne", "EAGER"), ("OneToMany", "LAZY"), ("ManyToMany", "LAZY")];
+
+fn get_value_text(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<String> {
+  find_annotation_value_node_by_key(ts_file, annotation_node, key)
+    .and_then(|value_node| ts_file.get_text_from_node(&value_node))
+    .map(|text| text.to_string())
+}
+
+/// Strips the enum qualifier from a value, e.g. `FetchType.LAZY` -> `LAZY`.
+fn get_enum_constant(value: &str) -> String {
+  value.trim().rsplit('.').next().unwrap_or(value).to_string()
+}
+
+/// Parses `cascade = CascadeType.ALL` or `cascade = {CascadeType.PERSIST, CascadeType.MERGE}`.
+fn get_cascades(ts_file: &TSFile, annotation_node: Node) -> Vec<String> {
+  get_value_text(ts_file, annotation_node, "cascade")
+    .map(|value| {
+      value
+        .trim_matches(['{', '}'])
+        .split(',')
+        .map(get_enum_constant)
+        .filter(|cascade| !cascade.is_empty())
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Resolves the target entity, preferring `targetEntity = X.class` over the field type and
+/// unwrapping collections such as `List<Line>` or `Map<String, Line>` to their element type.
+fn get_target_type(ts_file: &TSFile, field_node: Node, annotation_node: Node) -> String {
+  if let Some(target_entity) = get_value_text(ts_file, annotation_node, "targetEntity") {
+    return target_entity.trim_end_matches(".class").to_string();
+  }
+  let Some(type_node) = field_node.child_by_field_name("type") else {
+    return String::new();
+  };
+  if type_node.kind() == "generic_type" {
+    let mut cursor = type_node.walk();
+    let type_arguments = type_node.children(&mut cursor).find(|c| c.kind() == "type_arguments");
+    if let Some(type_arguments) = type_arguments {
+      let mut cursor = type_arguments.walk();
+      if let Some(last_argument) = type_arguments.named_children(&mut cursor).last()
+        && let Some(text) = ts_file.get_text_from_node(&last_argument)
+      {
+        return text.to_string();
+      }
+    }
+  }
+  ts_file.get_text_from_node(&type_node).unwrap_or_default().to_string()
+}
+
+pub(crate) fn get_relationship(
+  ts_file: &TSFile,
+  field_node: Node,
+) -> Option<JpaRelationshipResponse> {
+  let (kind, default_fetch_type, annotation_node) =
+    RELATIONSHIP_KINDS.iter().find_map(|(kind, default_fetch_type)| {
+      find_annotation_node_by_name(ts_file, field_node, kind)
+        .map(|node| (*kind, *default_fetch_type, node))
+    })?;
+  let field_name = get_field_declaration_name_node(ts_file, field_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let fetch_type = get_value_text(ts_file, annotation_node, "fetch")
+    .map(|value| get_enum_constant(&value))
+    .unwrap_or_else(|| default_fetch_type.to_string());
+  Some(JpaRelationshipResponse {
+    field_name,
+    kind: kind.to_string(),
+    target_type: get_target_type(ts_file, field_node, annotation_node),
+    mapped_by: get_value_text(ts_file, annotation_node, "mappedBy")
+      .map(|value| value.trim_matches('"').to_string()),
+    fetch_type,
+    cascades: get_cascades(ts_file, annotation_node),
+  })
+}
+
+/// Lists the relationship fields of an entity in declaration order. The fetch type is the one
+/// declared on the annotation or, when omitted, the JPA default for the relationship kind.
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<GetJpaRelationshipsResponse, String> {
+  // Step 1: Get entity info
+  let entity_info = get_jpa_entity_info_service::run(entity_file_path, b64_source_code)?;
+  if !entity_info.is_jpa_entity {
+    return Err("File is not a JPA entity".to_string());
+  }
+  // Step 2: Create TSFile and get public class node
+  let ts_file = create_ts_file(entity_file_path, b64_source_code)?;
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  // Step 3: Collect relationships from field annotations
+  let relationships = get_all_field_declaration_nodes(&ts_file, class_node)
+    .into_iter()
+    .filter_map(|field_node| get_relationship(&ts_file, field_node))
+    .collect();
+  // Step 4: Build and return response
+  Ok(GetJpaRelationshipsResponse {
+    entity_type: entity_info.entity_type,
+    entity_package_name: entity_info.entity_package_name,
+    relationships,
+  })
+}
@@ -0,0 +1,83 @@
+use std::{
+  collections::{BTreeSet, HashSet},
+  path::Path,
+};
+
+use crate::{
+  commands::services::get_all_packages_service::collect_module_packages,
+  common::{
+    types::java_source_directory_type::JavaSourceDirectoryType,
+    utils::project_layout_util::{resolve_source_root, select_project_modules},
+  },
+  responses::{
+    get_packages_tree_response::PackageTreeNodeResponse, package_response::PackageResponse,
+  },
+};
+
+fn get_parent_package_name(package_name: &str) -> Option<&str> {
+  package_name.rsplit_once('.').map(|(parent, _)| parent)
+}
+
+fn count_java_files(package_dir: &Path) -> usize {
+  let Ok(entries) = std::fs::read_dir(package_dir) else {
+    return 0;
+  };
+  entries
+    .flatten()
+    .filter(|entry| {
+      entry.path().is_file()
+        && entry
+          .path()
+          .extension()
+          .and_then(|ext| ext.to_str())
+          .is_some_and(|ext| ext.eq_ignore_ascii_case("java"))
+    })
+    .count()
+}
+
+fn build_node(
+  package_name: &str,
+  package_names: &BTreeSet<String>,
+  source_root: &Path,
+  module: Option<&str>,
+) -> PackageTreeNodeResponse {
+  let children = package_names
+    .iter()
+    .filter(|candidate| get_parent_package_name(candidate) == Some(package_name))
+    .map(|child| build_node(child, package_names, source_root, module))
+    .collect();
+  PackageTreeNodeResponse {
+    name: package_name.rsplit('.').next().unwrap_or(package_name).to_string(),
+    package_name: package_name.to_string(),
+    file_count: count_java_files(&source_root.join(package_name.replace('.', "/"))),
+    module: module.map(|m| m.to_string()),
+    children,
+  }
+}
+
+/// Lists the same packages as `get-all-packages`, nested under their parent package. Packages
+/// whose parent is not part of the project (e.g. the root package) are the top-level nodes, and
+/// each module gets its own trees.
+pub fn run(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  module_filter: Option<&str>,
+) -> Result<Vec<PackageTreeNodeResponse>, String> {
+  let mut trees = Vec::new();
+  for module in select_project_modules(cwd, module_filter)? {
+    // Step 1: Collect the flat package list of the module
+    let mut packages: HashSet<PackageResponse> = HashSet::new();
+    collect_module_packages(&module.path, source_directory, module.name.as_deref(), &mut packages);
+    let package_names: BTreeSet<String> =
+      packages.into_iter().map(|package| package.package_name).collect();
+    // Step 2: Nest every package under its parent, starting from the ones without a parent
+    let source_root = resolve_source_root(&module.path, source_directory);
+    for package_name in &package_names {
+      if get_parent_package_name(package_name).is_none_or(|parent| !package_names.contains(parent))
+      {
+        trees.push(build_node(package_name, &package_names, &source_root, module.name.as_deref()));
+      }
+    }
+  }
+  Ok(trees)
+}
@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::Path;
+
+use crate::common::types::build_tool_type::BuildToolType;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::types::project_module::ProjectModule;
+use crate::common::utils::project_layout_util::{
+  find_gradle_java_version, find_maven_java_version, find_persistence_namespace,
+  resolve_source_root, select_project_modules,
+};
+use crate::responses::get_project_info_response::{GetProjectInfoResponse, SourceRootResponse};
+
+/// Reads the build file of a project or module along with the build tool it belongs to.
+fn read_build_file(path: &Path) -> Option<(BuildToolType, String)> {
+  let build_tool = BuildToolType::detect(path);
+  let build_file_name = build_tool.get_build_file_name()?;
+  let content = fs::read_to_string(path.join(build_file_name)).ok()?;
+  Some((build_tool, content))
+}
+
+fn find_java_version(build_tool: &BuildToolType, build_file_content: &str) -> Option<String> {
+  match build_tool {
+    BuildToolType::Maven => find_maven_java_version(build_file_content),
+    BuildToolType::Gradle | BuildToolType::GradleKotlin => {
+      find_gradle_java_version(build_file_content)
+    }
+    BuildToolType::Unknown => None,
+  }
+}
+
+fn collect_source_roots(modules: &[ProjectModule]) -> Vec<SourceRootResponse> {
+  let mut source_roots = Vec::new();
+  for module in modules {
+    for source_directory_type in [JavaSourceDirectoryType::Main, JavaSourceDirectoryType::Test] {
+      let source_root = resolve_source_root(&module.path, &source_directory_type);
+      if source_root.is_dir() {
+        source_roots.push(SourceRootResponse {
+          path: source_root.to_string_lossy().to_string(),
+          source_set: source_directory_type.get_source_set_name().unwrap_or_default().to_string(),
+          module: module.name.clone(),
+        });
+      }
+    }
+  }
+  source_roots
+}
+
+/// Reports the build tool, Java version, JPA namespace and source roots of a project.
+///
+/// The root build file is inspected first, then the sub-module build files in order, so a
+/// multi-module project whose JPA dependency lives in one module is still detected.
+pub fn run(cwd: &Path) -> Result<GetProjectInfoResponse, String> {
+  // Step 1: Detect the build tool from the root build file
+  let build_tool = BuildToolType::detect(cwd);
+  // Step 2: Read the root and sub-module build files
+  let modules = select_project_modules(cwd, None)?;
+  let root_module = ProjectModule { name: None, path: cwd.to_path_buf() };
+  let build_files: Vec<(BuildToolType, String)> = std::iter::once(&root_module)
+    .chain(modules.iter().filter(|module| module.name.is_some()))
+    .filter_map(|module| read_build_file(&module.path))
+    .collect();
+  // Step 3: Infer the Java version and JPA namespace from the first build file declaring them
+  let java_version = build_files
+    .iter()
+    .find_map(|(module_build_tool, content)| find_java_version(module_build_tool, content));
+  let persistence_namespace =
+    build_files.iter().find_map(|(_, content)| find_persistence_namespace(content));
+  // Step 4: Build and return response
+  Ok(GetProjectInfoResponse {
+    build_tool: build_tool.as_str().to_string(),
+    java_version,
+    persistence_namespace: persistence_namespace.map(|namespace| namespace.as_str().to_string()),
+    source_roots: collect_source_roots(&modules),
+  })
+}
@@ -0,0 +1,34 @@
+use clap::ValueEnum;
+
+use crate::{
+  common::types::relationship_kind::RelationshipKind,
+  responses::get_relationship_options_response::{
+    GetRelationshipOptionsResponse, RelationshipOptionsResponse,
+  },
+};
+
+/// Names the values are passed on the command line with, e.g. `orphan_removal`
+fn value_names<T: ValueEnum>(values: &[T]) -> Vec<String> {
+  values
+    .iter()
+    .filter_map(|value| value.to_possible_value())
+    .map(|value| value.get_name().to_string())
+    .collect()
+}
+
+fn build_options(kind: RelationshipKind) -> RelationshipOptionsResponse {
+  RelationshipOptionsResponse {
+    cascades: value_names(&kind.cascade_options()),
+    fetch_types: value_names(&kind.fetch_type_options()),
+    collection_types: value_names(&kind.collection_type_options()),
+    owning_side_other: value_names(&kind.owning_side_other_options()),
+    inverse_side_other: value_names(&kind.inverse_side_other_options()),
+  }
+}
+
+pub fn run() -> Result<GetRelationshipOptionsResponse, String> {
+  Ok(GetRelationshipOptionsResponse {
+    one_to_one: build_options(RelationshipKind::OneToOne),
+    many_to_one: build_options(RelationshipKind::ManyToOne),
+  })
+}
@@ -0,0 +1,333 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  ensure_class_has_no_syntax_errors, get_all_method_declaration_nodes, get_class_body_node,
+};
+use crate::common::services::formatting_service::member_separator;
+use crate::common::services::import_declaration_service::{
+  add_import, find_imported_package_scope,
+};
+use crate::common::services::interface_declaration_service::get_public_interface_node;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::indent_util::indent_unit;
+use crate::common::utils::project_layout_util::{resolve_source_root, select_project_modules};
+use crate::responses::file_response::FileResponse;
+use crate::responses::implement_interface_response::ImplementInterfaceResponse;
+
+/// Primitive return types whose stubs return `false` or `0` instead of `null`.
+const NUMERIC_PRIMITIVE_TYPES: [&str; 7] =
+  ["byte", "short", "int", "long", "float", "double", "char"];
+
+/// An abstract interface method to implement, with the signature written in the stub.
+struct MethodStub {
+  name: String,
+  parameter_count: usize,
+  signature: String,
+  return_type: String,
+  referenced_names: HashSet<String>,
+}
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn get_class_name(ts_file: &TSFile) -> Option<String> {
+  let class_node = get_public_class_node(ts_file)?;
+  let name_node = get_class_declaration_name_node(ts_file, class_node)?;
+  ts_file.get_text_from_node(&name_node).map(|s| s.to_string())
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+fn parameter_count(method_node: &Node) -> usize {
+  method_node
+    .child_by_field_name("parameters")
+    .map_or(0, |parameters| parameters.named_child_count())
+}
+
+/// Names listed after `implements`, without type arguments or package.
+fn get_implemented_names(ts_file: &TSFile, class_node: Node) -> Vec<String> {
+  let Some(type_list) =
+    class_node.child_by_field_name("interfaces").and_then(|interfaces| interfaces.named_child(0))
+  else {
+    return Vec::new();
+  };
+  let mut cursor = type_list.walk();
+  type_list
+    .named_children(&mut cursor)
+    .filter_map(|type_node| ts_file.get_text_from_node(&type_node))
+    .filter_map(|text| text.split('<').next())
+    .map(|name| name.rsplit('.').next().unwrap_or(name).trim().to_string())
+    .collect()
+}
+
+/// Adds `interface_name` to the `implements` clause, creating the clause after the superclass,
+/// type parameters or class name when absent.
+fn add_to_implements_clause(ts_file: &mut TSFile, interface_name: &str) -> Result<(), String> {
+  let (insert_byte, text) = {
+    let class_node = get_public_class_node(ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    match class_node.child_by_field_name("interfaces") {
+      Some(interfaces_node) => (interfaces_node.end_byte(), format!(", {}", interface_name)),
+      None => {
+        let anchor_node = ["superclass", "type_parameters", "name"]
+          .iter()
+          .find_map(|field_name| class_node.child_by_field_name(field_name))
+          .ok_or_else(|| "Unable to get public class name node".to_string())?;
+        (anchor_node.end_byte(), format!(" implements {}", interface_name))
+      }
+    }
+  };
+  ts_file.insert_text(insert_byte, &text);
+  Ok(())
+}
+
+/// Loads the interface from the main sources of the project, if it is declared there.
+fn find_interface_file(cwd: &Path, package_name: &str, interface_name: &str) -> Option<TSFile> {
+  let modules = select_project_modules(cwd, None).ok()?;
+  modules.iter().find_map(|module| {
+    let interface_path = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main)
+      .join(package_name.replace('.', "/"))
+      .join(format!("{}.java", interface_name));
+    let ts_file = TSFile::from_file(&interface_path).ok()?;
+    get_public_interface_node(&ts_file)?;
+    Some(ts_file)
+  })
+}
+
+fn collect_referenced_names(ts_file: &TSFile, method_node: Node) -> HashSet<String> {
+  match ts_file
+    .query_builder("(type_identifier) @name")
+    .within(method_node)
+    .returning("name")
+    .execute()
+  {
+    Ok(result) => result
+      .nodes()
+      .iter()
+      .filter_map(|node| ts_file.get_text_from_node(node).map(|s| s.to_string()))
+      .collect(),
+    Err(_) => HashSet::new(),
+  }
+}
+
+/// Collects the abstract methods of the interface: the ones without a body that are neither
+/// `default`, `static` nor `private`.
+fn collect_abstract_methods(interface_ts_file: &TSFile) -> Vec<MethodStub> {
+  let Some(body_node) =
+    get_public_interface_node(interface_ts_file).and_then(|node| node.child_by_field_name("body"))
+  else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  body_node
+    .named_children(&mut cursor)
+    .filter(|node| {
+      node.kind() == "method_declaration" && node.child_by_field_name("body").is_none()
+    })
+    .filter(|method_node| {
+      let mut cursor = method_node.walk();
+      !method_node.children(&mut cursor).any(|child| {
+        child.kind() == "modifiers"
+          && interface_ts_file.get_text_from_node(&child).is_some_and(|modifiers| {
+            modifiers
+              .split_whitespace()
+              .any(|modifier| ["default", "static", "private"].contains(&modifier))
+          })
+      })
+    })
+    .filter_map(|method_node| {
+      let text_of = |field_name: &str| {
+        method_node
+          .child_by_field_name(field_name)
+          .and_then(|node| interface_ts_file.get_text_from_node(&node))
+      };
+      let name = text_of("name")?.to_string();
+      let return_type = text_of("type")?.to_string();
+      let type_parameters = text_of("type_parameters").map(|text| format!("{} ", text));
+      let mut cursor = method_node.walk();
+      let throws = method_node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "throws")
+        .and_then(|node| interface_ts_file.get_text_from_node(&node))
+        .map(|text| format!(" {}", text));
+      let signature = format!(
+        "{}{} {}{}{}",
+        type_parameters.unwrap_or_default(),
+        return_type,
+        name,
+        text_of("parameters")?,
+        throws.unwrap_or_default()
+      );
+      Some(MethodStub {
+        name,
+        parameter_count: parameter_count(&method_node),
+        signature,
+        return_type,
+        referenced_names: collect_referenced_names(interface_ts_file, method_node),
+      })
+    })
+    .collect()
+}
+
+fn build_stub_text(stub: &MethodStub) -> String {
+  let indentation = indent_unit();
+  let return_statement = match stub.return_type.as_str() {
+    "void" => None,
+    "boolean" => Some("return false;"),
+    return_type if NUMERIC_PRIMITIVE_TYPES.contains(&return_type) => Some("return 0;"),
+    _ => Some("return null;"),
+  };
+  let body = match return_statement {
+    Some(statement) => format!("{}{}{}\n", indentation, indentation, statement),
+    None => String::new(),
+  };
+  format!("{i}@Override\n{i}public {} {{\n{}{i}}}", stub.signature, body, i = indentation)
+}
+
+/// Appends the stubs at the end of the class body, separated by `--member-spacing` blank lines.
+fn insert_stubs(ts_file: &mut TSFile, stub_texts: &[String]) -> Result<(), String> {
+  let (body_start_byte, body_end_byte, body_text) = {
+    let class_node = get_public_class_node(ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let body_node = get_class_body_node(ts_file, class_node)
+      .ok_or_else(|| "Unable to get class body".to_string())?;
+    let body_text = ts_file.get_text_from_node(&body_node).unwrap_or_default().to_string();
+    (body_node.start_byte(), body_node.end_byte(), body_text)
+  };
+  let separator = member_separator();
+  let last_member = body_text.strip_suffix('}').unwrap_or(&body_text).trim_end();
+  let leading = if last_member == "{" { "\n" } else { separator.as_str() };
+  let body = format!("{}{}{}\n}}", last_member, leading, stub_texts.join(&separator));
+  ts_file.replace_text_by_range(body_start_byte, body_end_byte, &body);
+  Ok(())
+}
+
+/// Makes a class implement an interface: adds it to the `implements` clause, creating the clause
+/// when absent, and imports it. With `stub_methods`, the abstract methods the class does not
+/// declare yet are stubbed with `@Override` and a default body, which needs the interface
+/// source to be part of the project.
+pub fn run(
+  cwd: &Path,
+  class_file_b64_src: &str,
+  class_file_path: &Path,
+  interface_fqcn: &str,
+  stub_methods: bool,
+) -> Result<ImplementInterfaceResponse, String> {
+  // Step 1: Parse the class and validate it
+  let mut ts_file = TSFile::from_base64_source_code(class_file_b64_src);
+  let class_name =
+    get_class_name(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let class_start_byte = get_public_class_node(&ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  ensure_class_has_no_syntax_errors(&ts_file, class_start_byte)?;
+  let (interface_package, interface_name) = interface_fqcn
+    .rsplit_once('.')
+    .ok_or_else(|| format!("'{}' must include its package", interface_fqcn))?;
+  let (implemented_names, declared_methods) = {
+    let class_node = get_public_class_node(&ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let declared_methods: Vec<(String, usize)> =
+      get_all_method_declaration_nodes(&ts_file, class_node)
+        .iter()
+        .filter_map(|method_node| {
+          let name_node = method_node.child_by_field_name("name")?;
+          let name = ts_file.get_text_from_node(&name_node)?.to_string();
+          Some((name, parameter_count(method_node)))
+        })
+        .collect();
+    (get_implemented_names(&ts_file, class_node), declared_methods)
+  };
+  if implemented_names.iter().any(|name| name == interface_name) {
+    return Err(format!("{} already implements {}", class_name, interface_name));
+  }
+  // Step 2: Collect the methods to stub
+  let mut warnings = Vec::new();
+  let mut stubs = Vec::new();
+  let mut stub_imports: Vec<(String, String)> = Vec::new();
+  if stub_methods {
+    match find_interface_file(cwd, interface_package, interface_name) {
+      Some(interface_ts_file) => {
+        stubs = collect_abstract_methods(&interface_ts_file)
+          .into_iter()
+          .filter(|stub| {
+            !declared_methods
+              .iter()
+              .any(|(name, count)| *name == stub.name && *count == stub.parameter_count)
+          })
+          .collect();
+        let referenced_names: HashSet<&String> =
+          stubs.iter().flat_map(|stub| stub.referenced_names.iter()).collect();
+        for name in referenced_names {
+          if let Some(package_name) = find_imported_package_scope(&interface_ts_file, name) {
+            stub_imports.push((package_name, name.clone()));
+          } else if interface_ts_file
+            .file_path()
+            .and_then(|path| path.parent())
+            .is_some_and(|directory| directory.join(format!("{}.java", name)).is_file())
+          {
+            stub_imports.push((interface_package.to_string(), name.clone()));
+          }
+        }
+        stub_imports.sort();
+      }
+      None => warnings.push(format!(
+        "Source of {} not found in the project; no methods were stubbed",
+        interface_fqcn
+      )),
+    }
+  }
+  // Step 3: Add the interface, the stubs and their imports
+  add_to_implements_clause(&mut ts_file, interface_name)?;
+  if !stubs.is_empty() {
+    let stub_texts: Vec<String> = stubs.iter().map(build_stub_text).collect();
+    insert_stubs(&mut ts_file, &stub_texts)?;
+  }
+  let class_package = get_package_name(&ts_file);
+  let imports = std::iter::once((interface_package.to_string(), interface_name.to_string()))
+    .chain(stub_imports)
+    .filter(|(package_name, _)| *package_name != class_package && package_name != "java.lang");
+  for (package_name, import_name) in imports {
+    add_import(
+      &mut ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      &package_name,
+      &import_name,
+    );
+  }
+  // Step 4: Save the class
+  ts_file
+    .save_to_existing_file(class_file_path)
+    .map_err(|e| format!("Unable to save class file: {}", e))?;
+  // Step 5: Build and return response
+  Ok(ImplementInterfaceResponse {
+    class: build_file_response(&ts_file),
+    interface_name: interface_name.to_string(),
+    stubbed_method_names: stubs.into_iter().map(|stub| stub.name).collect(),
+    warnings,
+  })
+}
@@ -1,14 +1,54 @@
+pub mod add_class_annotation_service;
+pub mod apply_edits_service;
+pub mod change_jpa_enum_storage_service;
+pub mod change_jpa_field_type_service;
+pub mod convert_name_service;
 pub mod create_java_file_service;
+pub mod create_java_interface_service;
+pub mod create_jpa_converter_service;
 pub mod create_jpa_entity_basic_field_service;
+pub mod create_jpa_entity_embedded_field_service;
 pub mod create_jpa_entity_enum_field_service;
+pub mod create_jpa_entity_fields_service;
 pub mod create_jpa_entity_id_field_service;
 pub mod create_jpa_entity_service;
 pub mod create_jpa_many_to_one_relationship_service;
+pub mod create_jpa_mapped_superclass_service;
 pub mod create_jpa_one_to_one_relationship_service;
 pub mod create_jpa_repository_service;
+pub mod create_package_info_service;
+pub mod create_spring_rest_controller_service;
+pub mod delete_java_file_service;
+pub mod dump_ast_service;
+pub mod find_jpa_entity_usages_service;
+pub mod generate_accessors_service;
+pub mod generate_constructors_service;
+pub mod get_all_jpa_embeddables_service;
 pub mod get_all_jpa_entities_service;
+pub mod get_all_jpa_enums_service;
 pub mod get_all_jpa_mapped_superclasses;
 pub mod get_all_packages_service;
+pub mod get_command_schema_service;
+pub mod get_entity_table_name_service;
+pub mod get_enum_constants_service;
+pub mod get_field_annotations_service;
+pub mod get_fqcn_service;
+pub mod get_import_status_service;
 pub mod get_java_basic_types_service;
+pub mod get_java_class_structure_service;
+pub mod get_java_file_type_service;
 pub mod get_java_files_service;
+pub mod get_java_type_metadata_service;
 pub mod get_jpa_entity_info_service;
+pub mod get_jpa_relationships_service;
+pub mod get_packages_tree_service;
+pub mod get_project_info_service;
+pub mod get_relationship_options_service;
+pub mod implement_interface_service;
+pub mod modify_jpa_field_constraints_service;
+pub mod move_jpa_field_service;
+pub mod remove_jpa_field_service;
+pub mod remove_unused_imports_service;
+pub mod rename_jpa_entity_service;
+pub mod validate_identifier_service;
+pub mod validate_jpa_entity_service;
@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::annotation_service::{
+  add_annotation, add_annotation_argument, find_annotation_node_by_name,
+  find_annotation_value_node_by_key,
+};
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::common::services::import_declaration_service::add_import;
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::annotation_types::AnnotationInsertionPosition;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::responses::file_response::FileResponse;
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field not found: {}", field_name))
+}
+
+/// Returns the start byte of the field's `@Column` annotation, if present.
+fn find_column_annotation(ts_file: &TSFile, field_name: &str) -> Option<usize> {
+  let field_node = find_field_node(ts_file, field_name).ok()?;
+  find_annotation_node_by_name(ts_file, field_node, "Column").map(|node| node.start_byte())
+}
+
+/// Sets `key = value` on the field's `@Column`, replacing the current value when the argument is
+/// already there.
+fn set_column_argument(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  key: &str,
+  value: &str,
+) -> Result<(), String> {
+  let column_byte = find_column_annotation(ts_file, field_name)
+    .ok_or_else(|| "Unable to find @Column annotation".to_string())?;
+  let value_range = ts_file
+    .get_named_node_at_byte_position(column_byte)
+    .and_then(|column_node| find_annotation_value_node_by_key(ts_file, column_node, key))
+    .map(|value_node| (value_node.start_byte(), value_node.end_byte()));
+  match value_range {
+    Some((start_byte, end_byte)) => {
+      ts_file.replace_text_by_range(start_byte, end_byte, value);
+    }
+    None => {
+      add_annotation_argument(ts_file, column_byte, key, value)
+        .ok_or_else(|| format!("Unable to add @Column({})", key))?;
+    }
+  }
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  let file_package_name = get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name,
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Updates the `nullable` and `unique` attributes of a field's `@Column` in place, adding the
+/// annotation when the field has none. Attributes left as `None` are not touched.
+pub fn run(
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  nullable: Option<bool>,
+  unique: Option<bool>,
+  persistence_namespace: &PersistenceNamespace,
+) -> Result<FileResponse, String> {
+  let constraints: Vec<(&str, bool)> = [("nullable", nullable), ("unique", unique)]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|value| (key, value)))
+    .collect();
+  if constraints.is_empty() {
+    return Err("At least one of nullable or unique must be given".to_string());
+  }
+  // Step 1: Parse entity file and find the field
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let field_byte = find_field_node(&entity_ts_file, field_name)?.start_byte();
+  // Step 2: Update the existing @Column or add a new one
+  if find_column_annotation(&entity_ts_file, field_name).is_some() {
+    for (key, value) in &constraints {
+      set_column_argument(&mut entity_ts_file, field_name, key, &value.to_string())?;
+    }
+  } else {
+    let arguments = constraints
+      .iter()
+      .map(|(key, value)| format!("{} = {}", key, value))
+      .collect::<Vec<_>>()
+      .join(", ");
+    add_annotation(
+      &mut entity_ts_file,
+      field_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      &format!("@Column({})", arguments),
+    )
+    .ok_or_else(|| "Unable to add @Column annotation".to_string())?;
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      persistence_namespace.package(),
+      "Column",
+    );
+  }
+  // Step 3: Save file (use save_to_existing_file since we're modifying an existing entity file)
+  entity_ts_file
+    .save_to_existing_file(entity_file_path)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 4: Build and return response
+  Ok(build_file_response(&entity_ts_file))
+}
@@ -0,0 +1,325 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  ensure_class_has_no_syntax_errors, ensure_field_name_available,
+  find_field_declaration_node_by_name, get_all_field_declaration_nodes,
+  get_all_method_declaration_nodes, get_class_body_node, take_member_declaration,
+};
+use crate::common::services::formatting_service::{member_separator, normalize_blank_lines};
+use crate::common::services::import_declaration_service::{
+  add_import, find_import_declaration_node, find_imported_package_scope, is_class_name_referenced,
+  remove_import,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::import_types::ImportInsertionPosition;
+use crate::common::utils::naming_util;
+use crate::responses::file_response::FileResponse;
+use crate::responses::move_jpa_field_response::MoveJPAFieldResponse;
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn get_class_name(ts_file: &TSFile) -> Option<String> {
+  let class_node = get_public_class_node(ts_file)?;
+  let name_node = get_class_declaration_name_node(ts_file, class_node)?;
+  ts_file.get_text_from_node(&name_node).map(|s| s.to_string())
+}
+
+/// Name written after `extends`, without type arguments, e.g. `com.example.BaseEntity` for
+/// `extends com.example.BaseEntity<Long>`.
+fn get_superclass_name(ts_file: &TSFile) -> Option<String> {
+  let class_node = get_public_class_node(ts_file)?;
+  let superclass_node = class_node.child_by_field_name("superclass")?;
+  let text = ts_file.get_text_from_node(&superclass_node)?;
+  let name = text.trim().strip_prefix("extends")?.split('<').next()?.trim();
+  Some(name.split_whitespace().collect())
+}
+
+/// Fails unless the entity extends the superclass, matching it by simple name and by the package
+/// it is imported from, written in, or shares with the entity.
+fn ensure_extends(
+  entity_ts_file: &TSFile,
+  superclass_ts_file: &TSFile,
+  entity_name: &str,
+) -> Result<(), String> {
+  let superclass_name = get_class_name(superclass_ts_file)
+    .ok_or_else(|| "Unable to get public class node from superclass".to_string())?;
+  let superclass_package_name = get_package_name(superclass_ts_file);
+  let extends_superclass =
+    get_superclass_name(entity_ts_file).is_some_and(|name| match name.rsplit_once('.') {
+      Some((package_name, simple_name)) => {
+        simple_name == superclass_name && package_name == superclass_package_name
+      }
+      None => {
+        name == superclass_name
+          && find_imported_package_scope(entity_ts_file, &name)
+            .unwrap_or_else(|| get_package_name(entity_ts_file))
+            == superclass_package_name
+      }
+    });
+  if !extends_superclass {
+    return Err(format!("{} does not extend {}", entity_name, superclass_name));
+  }
+  Ok(())
+}
+
+/// Finds the getter and setter of `field_name` declared in the class: `get<Field>()` or
+/// `is<Field>()` without parameters and `set<Field>(...)` with exactly one.
+fn find_accessor_nodes<'a>(
+  ts_file: &'a TSFile,
+  class_node: Node<'a>,
+  field_name: &str,
+) -> Vec<Node<'a>> {
+  let capitalized = naming_util::to_pascal_case(field_name);
+  let getter_names = [format!("get{}", capitalized), format!("is{}", capitalized)];
+  let setter_name = format!("set{}", capitalized);
+  get_all_method_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter(|method_node| {
+      let Some(name) = method_node
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      else {
+        return false;
+      };
+      let parameter_count = method_node
+        .child_by_field_name("parameters")
+        .map_or(0, |parameters| parameters.named_child_count());
+      (getter_names.iter().any(|getter| getter == name) && parameter_count == 0)
+        || (name == setter_name && parameter_count == 1)
+    })
+    .collect()
+}
+
+/// Collects the simple names referenced by the given members, i.e. their types, annotation names
+/// and identifiers.
+fn collect_referenced_names(ts_file: &TSFile, member_nodes: &[Node]) -> HashSet<String> {
+  let query_string = r#"
+        [
+          (identifier) @name
+          (type_identifier) @name
+        ]
+    "#;
+  member_nodes
+    .iter()
+    .flat_map(|member_node| {
+      match ts_file.query_builder(query_string).within(*member_node).returning("name").execute() {
+        Ok(result) => result
+          .nodes()
+          .iter()
+          .filter_map(|node| ts_file.get_text_from_node(node).map(|s| s.to_string()))
+          .collect(),
+        Err(_) => Vec::new(),
+      }
+    })
+    .collect()
+}
+
+/// Resolves the imports the moved members need in the superclass: every single-type import of
+/// the entity they reference, plus the classes of the entity's own package they reference when
+/// the superclass lives in another package.
+fn resolve_required_imports(
+  entity_ts_file: &TSFile,
+  entity_file_path: &Path,
+  superclass_package_name: &str,
+  referenced_names: &HashSet<String>,
+) -> Vec<(String, String)> {
+  let entity_package_name = get_package_name(entity_ts_file);
+  let mut imports: Vec<(String, String)> = referenced_names
+    .iter()
+    .filter_map(|class_name| {
+      if let Some(package_name) = find_imported_package_scope(entity_ts_file, class_name) {
+        return Some((package_name, class_name.clone()));
+      }
+      let is_sibling_class = entity_file_path
+        .parent()
+        .is_some_and(|directory| directory.join(format!("{}.java", class_name)).is_file());
+      (is_sibling_class && entity_package_name != superclass_package_name)
+        .then(|| (entity_package_name.clone(), class_name.clone()))
+    })
+    .filter(|(package_name, _)| package_name != superclass_package_name)
+    .collect();
+  imports.sort();
+  imports
+}
+
+/// Inserts the field after the last field of the superclass, or at the top of its body, and the
+/// methods at the end of the body. Members are separated by `--member-spacing` blank lines.
+fn insert_members(
+  ts_file: &mut TSFile,
+  field_text: &str,
+  method_texts: &[String],
+) -> Result<(), String> {
+  let (body_start_byte, body_end_byte, last_field_end_byte, body_text) = {
+    let class_node = get_public_class_node(ts_file)
+      .ok_or_else(|| "Unable to get public class node from superclass".to_string())?;
+    let body_node = get_class_body_node(ts_file, class_node)
+      .ok_or_else(|| "Unable to get class body from superclass".to_string())?;
+    let body_text = ts_file.get_text_from_node(&body_node).unwrap_or_default().to_string();
+    let last_field_end_byte =
+      get_all_field_declaration_nodes(ts_file, class_node).last().map(|field| field.end_byte());
+    (body_node.start_byte(), body_node.end_byte(), last_field_end_byte, body_text)
+  };
+  let separator = member_separator();
+  let inner_text = body_text.trim_start_matches('{').trim_end_matches('}');
+  if inner_text.trim().is_empty() {
+    let members: Vec<&str> =
+      std::iter::once(field_text).chain(method_texts.iter().map(String::as_str)).collect();
+    let body = format!("{{\n{}\n}}", members.join(&separator));
+    ts_file.replace_text_by_range(body_start_byte, body_end_byte, &body);
+    return Ok(());
+  }
+  // The methods go first since inserting them leaves the byte positions of the field untouched
+  if !method_texts.is_empty() {
+    let last_member_end_byte = body_start_byte + 1 + inner_text.trim_end().len();
+    let methods: String =
+      method_texts.iter().map(|method| format!("{}{}", separator, method)).collect();
+    ts_file.replace_text_by_range(
+      last_member_end_byte,
+      body_end_byte - 1,
+      &format!("{}\n", methods),
+    );
+  }
+  match last_field_end_byte {
+    Some(last_field_end_byte) => {
+      ts_file.insert_text(last_field_end_byte, &format!("{}{}", separator, field_text))
+    }
+    None => {
+      // Replace the gap between `{` and the first member, keeping that member's indentation
+      let leading_gap = &inner_text[..inner_text.len() - inner_text.trim_start().len()];
+      let indentation = &leading_gap[leading_gap.rfind('\n').map_or(0, |pos| pos + 1)..];
+      ts_file.replace_text_by_range(
+        body_start_byte + 1,
+        body_start_byte + 1 + leading_gap.len(),
+        &format!("\n{}{}{}", field_text, separator, indentation),
+      );
+    }
+  }
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Moves a field, with its annotations, Javadoc, getter and setter, from an entity into the
+/// mapped superclass it extends. Imports the moved members need are added to the superclass and
+/// the ones left unused are removed from the entity. Both files are edited before either is
+/// saved, so a failed validation leaves them untouched.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  superclass_file_path: &Path,
+  field_name: &str,
+  format_java: bool,
+) -> Result<MoveJPAFieldResponse, String> {
+  // Step 1: Parse both files
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let mut superclass_ts_file = TSFile::from_file(superclass_file_path)
+    .map_err(|e| format!("Unable to read superclass file: {}", e))?;
+  // Step 2: Validate the hierarchy and both class bodies
+  let entity_name = get_class_name(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  ensure_extends(&entity_ts_file, &superclass_ts_file, &entity_name)?;
+  let entity_class_start_byte = get_public_class_node(&entity_ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  let superclass_class_start_byte = get_public_class_node(&superclass_ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "Unable to get public class node from superclass".to_string())?;
+  ensure_class_has_no_syntax_errors(&entity_ts_file, entity_class_start_byte)?;
+  ensure_class_has_no_syntax_errors(&superclass_ts_file, superclass_class_start_byte)?;
+  ensure_field_name_available(&superclass_ts_file, superclass_class_start_byte, field_name)?;
+  // Step 3: Locate the field, its accessors and the imports they need
+  let (field_start_byte, method_positions, required_imports) = {
+    let class_node = get_public_class_node(&entity_ts_file)
+      .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+    let field_node = find_field_declaration_node_by_name(&entity_ts_file, field_name, class_node)
+      .ok_or_else(|| format!("Field not found: {}", field_name))?;
+    let accessor_nodes = find_accessor_nodes(&entity_ts_file, class_node, field_name);
+    let member_nodes: Vec<Node> =
+      std::iter::once(field_node).chain(accessor_nodes.clone()).collect();
+    let method_positions: Vec<(usize, String)> = accessor_nodes
+      .iter()
+      .filter_map(|method_node| {
+        let name_node = method_node.child_by_field_name("name")?;
+        let name = entity_ts_file.get_text_from_node(&name_node)?;
+        Some((method_node.start_byte(), name.to_string()))
+      })
+      .collect();
+    let required_imports = resolve_required_imports(
+      &entity_ts_file,
+      entity_file_path,
+      &get_package_name(&superclass_ts_file),
+      &collect_referenced_names(&entity_ts_file, &member_nodes),
+    );
+    (field_node.start_byte(), method_positions, required_imports)
+  };
+  // Step 4: Take the members out of the entity, from the bottom up
+  let mut method_texts = Vec::new();
+  for (method_start_byte, method_name) in method_positions.iter().rev() {
+    let method_text =
+      take_member_declaration(&mut entity_ts_file, *method_start_byte, "method_declaration")
+        .ok_or_else(|| format!("Unable to remove method: {}", method_name))?;
+    method_texts.insert(0, method_text);
+  }
+  let field_text =
+    take_member_declaration(&mut entity_ts_file, field_start_byte, "field_declaration")
+      .ok_or_else(|| format!("Unable to remove field: {}", field_name))?;
+  for (package_name, class_name) in &required_imports {
+    if !is_class_name_referenced(&entity_ts_file, class_name) {
+      remove_import(&mut entity_ts_file, package_name, class_name);
+    }
+  }
+  // Step 5: Insert the members and their imports into the superclass
+  insert_members(&mut superclass_ts_file, &field_text, &method_texts)?;
+  for (package_name, class_name) in &required_imports {
+    if find_import_declaration_node(&superclass_ts_file, package_name, class_name).is_none() {
+      add_import(
+        &mut superclass_ts_file,
+        &ImportInsertionPosition::Alphabetical,
+        package_name,
+        class_name,
+      );
+    }
+  }
+  if format_java {
+    normalize_blank_lines(&mut entity_ts_file);
+    normalize_blank_lines(&mut superclass_ts_file);
+  }
+  // Step 6: Save both files
+  superclass_ts_file
+    .save_as(superclass_file_path, cwd)
+    .map_err(|e| format!("Unable to save superclass file: {}", e))?;
+  entity_ts_file
+    .save_to_existing_file(entity_file_path)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 7: Build and return response
+  Ok(MoveJPAFieldResponse {
+    entity: build_file_response(&entity_ts_file),
+    superclass: build_file_response(&superclass_ts_file),
+    moved_field_name: field_name.to_string(),
+    moved_method_names: method_positions.into_iter().map(|(_, name)| name).collect(),
+  })
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_relationships_service::get_relationship;
+use crate::common::services::annotation_service::find_annotation_node_by_name;
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  find_field_declaration_node_by_name, get_all_field_declaration_nodes, remove_field_declaration,
+};
+use crate::common::services::formatting_service::normalize_blank_lines;
+use crate::common::services::import_declaration_service::{
+  find_imported_package_scope, is_class_name_referenced, remove_import,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::project_layout_util::{resolve_source_root, select_project_modules};
+use crate::responses::file_response::FileResponse;
+use crate::responses::get_jpa_relationships_response::JpaRelationshipResponse;
+use crate::responses::remove_jpa_field_response::RemoveJPAFieldResponse;
+
+fn get_package_name(ts_file: &TSFile) -> String {
+  get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn get_class_name(ts_file: &TSFile) -> Option<String> {
+  let class_node = get_public_class_node(ts_file)?;
+  let name_node = get_class_declaration_name_node(ts_file, class_node)?;
+  ts_file.get_text_from_node(&name_node).map(|s| s.to_string())
+}
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field not found: {}", field_name))
+}
+
+/// Collects the simple names referenced by a field, i.e. its type and annotation names.
+fn collect_referenced_names(ts_file: &TSFile, field_node: Node) -> HashSet<String> {
+  let query_string = r#"
+        [
+          (identifier) @name
+          (type_identifier) @name
+        ]
+    "#;
+  match ts_file.query_builder(query_string).within(field_node).returning("name").execute() {
+    Ok(result) => result
+      .nodes()
+      .iter()
+      .filter_map(|node| ts_file.get_text_from_node(node).map(|s| s.to_string()))
+      .collect(),
+    Err(_) => HashSet::new(),
+  }
+}
+
+/// Removes a field from the file along with the imports only that field was using.
+fn remove_field(ts_file: &mut TSFile, field_name: &str) -> Result<(), String> {
+  let (field_start_byte, referenced_names) = {
+    let field_node = find_field_node(ts_file, field_name)?;
+    (field_node.start_byte(), collect_referenced_names(ts_file, field_node))
+  };
+  remove_field_declaration(ts_file, field_start_byte)
+    .ok_or_else(|| format!("Unable to remove field: {}", field_name))?;
+  for class_name in referenced_names {
+    if !is_class_name_referenced(ts_file, &class_name)
+      && let Some(package_name) = find_imported_package_scope(ts_file, &class_name)
+    {
+      remove_import(ts_file, &package_name, &class_name);
+    }
+  }
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name: get_package_name(ts_file),
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Loads the entity named `entity_name` from `package_name` in any project module.
+pub(crate) fn find_entity_file(
+  cwd: &Path,
+  package_name: &str,
+  entity_name: &str,
+) -> Option<TSFile> {
+  let modules = select_project_modules(cwd, None).ok()?;
+  modules.iter().find_map(|module| {
+    let entity_path = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main)
+      .join(package_name.replace('.', "/"))
+      .join(format!("{}.java", entity_name));
+    let ts_file = TSFile::from_file(&entity_path).ok()?;
+    let class_node = get_public_class_node(&ts_file)?;
+    find_annotation_node_by_name(&ts_file, class_node, "Entity")?;
+    Some(ts_file)
+  })
+}
+
+/// Finds the field on the other side of a bidirectional relationship. For an owning field the
+/// inverse declares `mappedBy = "<field>"`; for an inverse field the owner is named by its own
+/// `mappedBy`. In both cases the other field must target `entity_name`.
+fn find_inverse_field_name(
+  inverse_ts_file: &TSFile,
+  entity_name: &str,
+  field_name: &str,
+  relationship: &JpaRelationshipResponse,
+) -> Option<String> {
+  let class_node = get_public_class_node(inverse_ts_file)?;
+  get_all_field_declaration_nodes(inverse_ts_file, class_node)
+    .into_iter()
+    .filter_map(|field_node| get_relationship(inverse_ts_file, field_node))
+    .find(|candidate| {
+      candidate.target_type == entity_name
+        && match relationship.mapped_by.as_deref() {
+          Some(mapped_by) => candidate.field_name == mapped_by,
+          None => candidate.mapped_by.as_deref() == Some(field_name),
+        }
+    })
+    .map(|candidate| candidate.field_name)
+}
+
+/// Removes a field from an entity. With `cleanup_inverse`, when the field is one side of a
+/// bidirectional relationship, the matching field on the other entity is removed as well. With
+/// `format_java`, blank lines of every saved entity are normalized afterwards.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  cleanup_inverse: bool,
+  format_java: bool,
+) -> Result<RemoveJPAFieldResponse, String> {
+  // Step 1: Parse entity file and inspect the field
+  let mut entity_ts_file = TSFile::from_base64_source_code(entity_file_b64_src);
+  let relationship =
+    get_relationship(&entity_ts_file, find_field_node(&entity_ts_file, field_name)?);
+  let entity_name = get_class_name(&entity_ts_file)
+    .or_else(|| entity_file_path.file_stem().map(|s| s.to_string_lossy().to_string()))
+    .unwrap_or_default();
+  let entity_package_name = get_package_name(&entity_ts_file);
+  // Step 2: Locate the inverse side before any change so the operation stays all-or-nothing
+  let mut inverse = None;
+  if cleanup_inverse && let Some(ref relationship) = relationship {
+    let target_package_name =
+      find_imported_package_scope(&entity_ts_file, &relationship.target_type)
+        .unwrap_or_else(|| entity_package_name.clone());
+    if let Some(inverse_ts_file) =
+      find_entity_file(cwd, &target_package_name, &relationship.target_type)
+      && let Some(inverse_field_name) =
+        find_inverse_field_name(&inverse_ts_file, &entity_name, field_name, relationship)
+    {
+      inverse = Some((inverse_ts_file, inverse_field_name));
+    }
+  }
+  // Step 3: Remove the field and save the entity
+  remove_field(&mut entity_ts_file, field_name)?;
+  if format_java {
+    normalize_blank_lines(&mut entity_ts_file);
+  }
+  entity_ts_file
+    .save_to_existing_file(entity_file_path)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 4: Remove the inverse field and save the other entity
+  let mut inverse_entity = None;
+  let mut inverse_field_name = None;
+  if let Some((mut inverse_ts_file, field_name)) = inverse {
+    remove_field(&mut inverse_ts_file, &field_name)?;
+    if format_java {
+      normalize_blank_lines(&mut inverse_ts_file);
+    }
+    let inverse_path = inverse_ts_file
+      .file_path()
+      .cloned()
+      .ok_or_else(|| "Unable to get inverse entity file path".to_string())?;
+    inverse_ts_file
+      .save_as(&inverse_path, cwd)
+      .map_err(|e| format!("Unable to save inverse JPA Entity file: {}", e))?;
+    inverse_entity = Some(build_file_response(&inverse_ts_file));
+    inverse_field_name = Some(field_name);
+  }
+  // Step 5: Build and return response
+  Ok(RemoveJPAFieldResponse {
+    entity: build_file_response(&entity_ts_file),
+    removed_field_name: field_name.to_string(),
+    inverse_entity,
+    inverse_field_name,
+  })
+}
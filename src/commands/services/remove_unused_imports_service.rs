@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::import_declaration_service::{
+  get_all_import_declaration_nodes, get_import_declaration_class_name_node,
+  get_import_declaration_relative_import_scope_node, is_class_name_referenced,
+  is_static_or_wildcard_import, remove_import,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::responses::file_response::FileResponse;
+use crate::responses::remove_unused_imports_response::RemoveUnusedImportsResponse;
+
+/// Lists the `(package_scope, class_name)` of single-type imports whose simple name is not
+/// referenced outside the imports. Wildcard and static imports are kept, since which names they
+/// bring in cannot be told from the file alone. Comments are not parsed, so a name only
+/// mentioned in Javadoc, e.g. `{@link Foo}`, counts as unused.
+fn find_unused_imports(ts_file: &TSFile) -> Vec<(String, String)> {
+  get_all_import_declaration_nodes(ts_file)
+    .into_iter()
+    .filter(|import_node| !is_static_or_wildcard_import(*import_node))
+    .filter_map(|import_node| {
+      let class_name_node = get_import_declaration_class_name_node(ts_file, import_node)?;
+      let scope_node = get_import_declaration_relative_import_scope_node(ts_file, import_node)?;
+      let class_name = ts_file.get_text_from_node(&class_name_node)?;
+      let package_scope = ts_file.get_text_from_node(&scope_node)?;
+      Some((package_scope.to_string(), class_name.to_string()))
+    })
+    .filter(|(_, class_name)| !is_class_name_referenced(ts_file, class_name))
+    .collect()
+}
+
+fn build_file_response(ts_file: &TSFile) -> FileResponse {
+  let file_package_name = get_package_declaration_node(ts_file)
+    .and_then(|node| get_package_scope_node(ts_file, node))
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  FileResponse {
+    file_type: ts_file.get_file_name_without_ext().unwrap_or_default(),
+    file_package_name,
+    file_path: ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+    module: None,
+    edits: ts_file.edit_records(),
+  }
+}
+
+/// Removes the imports the file does not reference. A file given by path is saved in place; for
+/// base64 source only the edits are returned.
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<RemoveUnusedImportsResponse, String> {
+  // Step 1: Create TSFile
+  let mut ts_file = create_ts_file(file_path, b64_source_code)?;
+  // Step 2: Find and remove the unused imports
+  let unused_imports = find_unused_imports(&ts_file);
+  let mut removed_imports = Vec::new();
+  for (package_scope, class_name) in unused_imports {
+    if remove_import(&mut ts_file, &package_scope, &class_name).is_some() {
+      removed_imports.push(format!("{}.{}", package_scope, class_name));
+    }
+  }
+  // Step 3: Save file when it was read from disk and something changed
+  if let Some(path) = file_path
+    && !removed_imports.is_empty()
+  {
+    ts_file.save_to_existing_file(path).map_err(|e| format!("Unable to save file: {}", e))?;
+  }
+  // Step 4: Build and return response
+  Ok(RemoveUnusedImportsResponse { file: build_file_response(&ts_file), removed_imports })
+}
@@ -0,0 +1,274 @@
+use std::path::{Path, PathBuf};
+
+use crate::common::services::annotation_service::{
+  find_annotation_node_by_name, find_annotation_value_node_by_key,
+};
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::common::services::import_declaration_service::{
+  get_all_import_declaration_nodes, get_import_declaration_class_name_node,
+  get_import_declaration_full_import_scope_node,
+};
+use crate::common::services::interface_declaration_service::{
+  find_interface_node_by_name, get_interface_name_node,
+};
+use crate::common::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::utils::file_util;
+use crate::common::utils::naming_util::table_name_for_entity;
+use crate::common::utils::path_util::parse_all_files_in_directory;
+use crate::common::utils::project_layout_util::{resolve_source_root, select_project_modules};
+use crate::responses::file_response::FileResponse;
+use crate::responses::rename_jpa_entity_response::RenameJPAEntityResponse;
+
+/// A pending text replacement, expressed as a byte range of the original source.
+type TextEdit = (usize, usize, String);
+
+fn get_package_name(ts_file: &TSFile) -> Option<String> {
+  let package_declaration_node = get_package_declaration_node(ts_file)?;
+  let package_scope_node = get_package_scope_node(ts_file, package_declaration_node)?;
+  ts_file.get_text_from_node(&package_scope_node).map(|s| s.to_string())
+}
+
+fn get_java_file_path(file_path: &Path, class_name: &str) -> Result<PathBuf, String> {
+  let parent_dir =
+    file_path.parent().ok_or_else(|| "Unable to get parent directory of file".to_string())?;
+  Ok(parent_dir.join(format!("{}.java", class_name)))
+}
+
+/// Collects an edit for every type reference to `old_name` in the file, including
+/// `JpaRepository<Old, Id>` type arguments and `Old.class` literals.
+fn collect_type_reference_edits(ts_file: &TSFile, old_name: &str, new_name: &str) -> Vec<TextEdit> {
+  let query_string = format!(
+    r#"
+        ((type_identifier) @typeName
+        (#eq? @typeName "{}"))
+        "#,
+    old_name
+  );
+  let nodes = match ts_file.query_builder(&query_string).returning("typeName").execute() {
+    Ok(result) => result.nodes(),
+    Err(_) => Vec::new(),
+  };
+  nodes.iter().map(|node| (node.start_byte(), node.end_byte(), new_name.to_string())).collect()
+}
+
+/// Collects an edit for the name of every constructor declared as `old_name`.
+fn collect_constructor_edits(ts_file: &TSFile, old_name: &str, new_name: &str) -> Vec<TextEdit> {
+  let query_string = format!(
+    r#"
+        (constructor_declaration
+          name: (identifier) @constructorName
+        (#eq? @constructorName "{}"))
+        "#,
+    old_name
+  );
+  let nodes = match ts_file.query_builder(&query_string).returning("constructorName").execute() {
+    Ok(result) => result.nodes(),
+    Err(_) => Vec::new(),
+  };
+  nodes.iter().map(|node| (node.start_byte(), node.end_byte(), new_name.to_string())).collect()
+}
+
+/// Collects an edit for the `import <package>.<Old>;` declaration, if present.
+fn collect_import_edits(ts_file: &TSFile, old_full_name: &str, new_name: &str) -> Option<TextEdit> {
+  get_all_import_declaration_nodes(ts_file).into_iter().find_map(|import_node| {
+    let full_import_node = get_import_declaration_full_import_scope_node(ts_file, import_node)?;
+    if ts_file.get_text_from_node(&full_import_node)? != old_full_name {
+      return None;
+    }
+    let class_name_node = get_import_declaration_class_name_node(ts_file, import_node)?;
+    Some((class_name_node.start_byte(), class_name_node.end_byte(), new_name.to_string()))
+  })
+}
+
+/// Collects an edit for `@Table(name = "...")` when its value is the one derived from the old
+/// entity name, so tables named explicitly by the user are left untouched.
+fn collect_table_name_edit(ts_file: &TSFile, old_name: &str, new_name: &str) -> Option<TextEdit> {
+  let class_node = get_public_class_node(ts_file)?;
+  let table_node = find_annotation_node_by_name(ts_file, class_node, "Table")?;
+  let value_node = find_annotation_value_node_by_key(ts_file, table_node, "name")?;
+  let derived_table_name = format!("\"{}\"", table_name_for_entity(old_name));
+  if ts_file.get_text_from_node(&value_node)? != derived_table_name {
+    return None;
+  }
+  let new_table_name = format!("\"{}\"", table_name_for_entity(new_name));
+  Some((value_node.start_byte(), value_node.end_byte(), new_table_name))
+}
+
+/// Applies the edits from the end of the file backwards so earlier byte ranges stay valid.
+fn apply_edits(ts_file: &mut TSFile, mut edits: Vec<TextEdit>) {
+  edits.sort_by_key(|(start, _, _)| *start);
+  edits.dedup_by_key(|(start, _, _)| *start);
+  for (start, end, new_text) in edits.into_iter().rev() {
+    ts_file.replace_text_by_range(start, end, &new_text);
+  }
+}
+
+/// Saves `ts_file` to `new_path` (validated against `cwd`) and removes `old_path`.
+fn move_file(
+  ts_file: &mut TSFile,
+  cwd: &Path,
+  old_path: &Path,
+  new_path: &Path,
+) -> Result<(), String> {
+  ts_file.save_as(new_path, cwd).map_err(|e| format!("Failed to save renamed file: {}", e))?;
+  file_util::remove_file(old_path).map_err(|e| format!("Failed to remove old file: {}", e))
+}
+
+fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+  let file_path = ts_file
+    .file_path()
+    .and_then(|p| p.to_str().map(|s| s.to_string()))
+    .ok_or_else(|| "Unable to get file path from TSFile".to_string())?;
+  let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
+  Ok(FileResponse {
+    file_path,
+    file_type,
+    file_package_name: package_name.to_string(),
+    module: None,
+    edits: ts_file.edit_records(),
+  })
+}
+
+/// Finds the `<Entity>Repository` interface declared in the entity package in any module.
+fn find_repository_file(cwd: &Path, package_name: &str, repository_name: &str) -> Option<TSFile> {
+  let modules = select_project_modules(cwd, None).ok()?;
+  modules.iter().find_map(|module| {
+    let source_root = resolve_source_root(&module.path, &JavaSourceDirectoryType::Main);
+    let package_dir = source_root.join(package_name.replace('.', "/"));
+    parse_all_files_in_directory(&package_dir)
+      .into_iter()
+      .find(|ts_file| find_interface_node_by_name(ts_file, repository_name).is_some())
+  })
+}
+
+fn rename_repository(
+  cwd: &Path,
+  repository_ts_file: &mut TSFile,
+  package_name: &str,
+  old_name: &str,
+  new_name: &str,
+  should_rename_repository: bool,
+) -> Result<(), String> {
+  let old_repository_name = format!("{}Repository", old_name);
+  let new_repository_name = format!("{}Repository", new_name);
+  let old_repository_path = repository_ts_file
+    .file_path()
+    .cloned()
+    .ok_or_else(|| "Unable to get repository file path".to_string())?;
+  let mut edits = collect_type_reference_edits(repository_ts_file, old_name, new_name);
+  edits.extend(collect_import_edits(
+    repository_ts_file,
+    &format!("{}.{}", package_name, old_name),
+    new_name,
+  ));
+  if !should_rename_repository {
+    apply_edits(repository_ts_file, edits);
+    return repository_ts_file
+      .save_to_existing_file(&old_repository_path)
+      .map_err(|e| format!("Failed to save repository: {}", e));
+  }
+  let new_repository_path = get_java_file_path(&old_repository_path, &new_repository_name)?;
+  if new_repository_path.exists() {
+    return Err(format!("A class named {} already exists", new_repository_name));
+  }
+  let interface_node = find_interface_node_by_name(repository_ts_file, &old_repository_name)
+    .ok_or_else(|| "Unable to get repository interface node".to_string())?;
+  let interface_name_node = get_interface_name_node(repository_ts_file, interface_node)
+    .ok_or_else(|| "Unable to get repository interface name node".to_string())?;
+  edits.push((
+    interface_name_node.start_byte(),
+    interface_name_node.end_byte(),
+    new_repository_name.clone(),
+  ));
+  apply_edits(repository_ts_file, edits);
+  move_file(repository_ts_file, cwd, &old_repository_path, &new_repository_path)
+}
+
+/// Renames a JPA entity: its class declaration, constructors, file on disk and derived
+/// `@Table` name. The matching `<Entity>Repository` has its entity type argument and import
+/// updated, and is itself renamed when `should_rename_repository` is set.
+pub fn run(
+  cwd: &Path,
+  entity_file_path: &Path,
+  new_name: &str,
+  should_rename_repository: bool,
+) -> Result<RenameJPAEntityResponse, String> {
+  // Step 1: Parse the entity file and extract its current name
+  let mut entity_ts_file = TSFile::from_file(entity_file_path)
+    .map_err(|e| format!("Failed to read entity file: {}", e))?;
+  let class_node = get_public_class_node(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+    return Err("File is not a JPA entity".to_string());
+  }
+  let class_name_node = get_class_declaration_name_node(&entity_ts_file, class_node)
+    .ok_or_else(|| "Unable to get class name node".to_string())?;
+  let old_name = entity_ts_file
+    .get_text_from_node(&class_name_node)
+    .map(|s| s.to_string())
+    .ok_or_else(|| "Unable to get class name".to_string())?;
+  let package_name = get_package_name(&entity_ts_file).unwrap_or_default();
+  if old_name == new_name {
+    return Err(format!("Entity is already named {}", new_name));
+  }
+  // Step 2: Guard against collisions with an existing class in the same package
+  let new_entity_path = get_java_file_path(entity_file_path, new_name)?;
+  if new_entity_path.exists() {
+    return Err(format!("A class named {} already exists", new_name));
+  }
+  // Step 3: Rename the class, constructors, self references and derived table name
+  let mut edits =
+    vec![(class_name_node.start_byte(), class_name_node.end_byte(), new_name.to_string())];
+  edits.extend(collect_constructor_edits(&entity_ts_file, &old_name, new_name));
+  edits.extend(collect_type_reference_edits(&entity_ts_file, &old_name, new_name));
+  edits.extend(collect_table_name_edit(&entity_ts_file, &old_name, new_name));
+  // Step 4: Find the repository before touching the disk so a collision aborts the rename
+  let old_repository_name = format!("{}Repository", old_name);
+  let mut repository_ts_file = find_repository_file(cwd, &package_name, &old_repository_name);
+  if should_rename_repository
+    && let Some(repository) = &repository_ts_file
+    && let Some(repository_path) = repository.file_path()
+    && get_java_file_path(repository_path, &format!("{}Repository", new_name))?.exists()
+  {
+    return Err(format!("A class named {}Repository already exists", new_name));
+  }
+  // Step 5: Write the renamed entity to its new file
+  apply_edits(&mut entity_ts_file, edits);
+  move_file(&mut entity_ts_file, cwd, entity_file_path, &new_entity_path)?;
+  let entity = build_file_response(&entity_ts_file, &package_name)?;
+  let mut touched_files = vec![entity_file_path.display().to_string(), entity.file_path.clone()];
+  // Step 6: Update the repository
+  let mut repository = None;
+  if let Some(repository_ts_file) = repository_ts_file.as_mut() {
+    let old_repository_path = repository_ts_file.file_path().cloned();
+    rename_repository(
+      cwd,
+      repository_ts_file,
+      &package_name,
+      &old_name,
+      new_name,
+      should_rename_repository,
+    )?;
+    let repository_response = build_file_response(repository_ts_file, &package_name)?;
+    if let Some(old_repository_path) = old_repository_path
+      && old_repository_path.display().to_string() != repository_response.file_path
+    {
+      touched_files.push(old_repository_path.display().to_string());
+    }
+    touched_files.push(repository_response.file_path.clone());
+    repository = Some(repository_response);
+  }
+  Ok(RenameJPAEntityResponse {
+    old_entity_name: old_name,
+    new_entity_name: new_name.to_string(),
+    entity,
+    repository,
+    touched_files,
+  })
+}
@@ -0,0 +1,23 @@
+use crate::{
+  commands::validators::{
+    java_class_name_validator::validate_java_class_name,
+    java_identifier_validator::validate_java_identifier,
+    package_name_validator::validate_package_name,
+  },
+  common::types::java_identifier_kind::JavaIdentifierKind,
+  responses::validate_identifier_response::ValidateIdentifierResponse,
+};
+
+/// Checks `value` with the same validator the CLI applies to arguments of the given kind, so
+/// editors can reject a name before running a command with it.
+pub fn run(kind: &JavaIdentifierKind, value: &str) -> Result<ValidateIdentifierResponse, String> {
+  let result = match kind {
+    JavaIdentifierKind::Class => validate_java_class_name(value),
+    JavaIdentifierKind::Package => validate_package_name(value),
+    JavaIdentifierKind::Field | JavaIdentifierKind::Method => validate_java_identifier(value),
+  };
+  Ok(match result {
+    Ok(_) => ValidateIdentifierResponse { valid: true, reason: None },
+    Err(reason) => ValidateIdentifierResponse { valid: false, reason: Some(reason) },
+  })
+}
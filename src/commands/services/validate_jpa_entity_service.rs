@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::services::annotation_service::{
+  find_annotation_node_by_name, find_annotation_value_node_by_key,
+};
+use crate::common::services::class_declaration_service::{
+  get_class_declaration_name_node, get_class_superclass_name_node, get_public_class_node,
+};
+use crate::common::services::field_declaration_service::{
+  get_all_field_declaration_nodes, get_field_declaration_name_node,
+};
+use crate::common::ts_file::TSFile;
+use crate::responses::validate_jpa_entity_response::{
+  DiagnosticSeverity, JpaEntityDiagnostic, ValidateJpaEntityResponse,
+};
+
+/// Annotations that tell JPA how a relationship is joined.
+const JOIN_ANNOTATIONS: [&str; 4] = ["JoinColumn", "JoinColumns", "JoinTable", "MapsId"];
+
+fn create_diagnostic(
+  severity: DiagnosticSeverity,
+  code: &str,
+  message: String,
+  node: &Node,
+) -> JpaEntityDiagnostic {
+  JpaEntityDiagnostic {
+    severity,
+    code: code.to_string(),
+    message,
+    start_byte: node.start_byte(),
+    end_byte: node.end_byte(),
+  }
+}
+
+fn has_annotation(ts_file: &TSFile, node: Node, annotation_name: &str) -> bool {
+  find_annotation_node_by_name(ts_file, node, annotation_name).is_some()
+}
+
+fn has_join_annotation(ts_file: &TSFile, field_node: Node) -> bool {
+  JOIN_ANNOTATIONS.iter().any(|annotation| has_annotation(ts_file, field_node, annotation))
+}
+
+fn get_field_name(ts_file: &TSFile, field_node: Node) -> String {
+  get_field_declaration_name_node(ts_file, field_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn get_field_modifiers(ts_file: &TSFile, field_node: Node) -> Vec<String> {
+  let mut cursor = field_node.walk();
+  field_node
+    .children(&mut cursor)
+    .find(|child| child.kind() == "modifiers")
+    .and_then(|modifiers| ts_file.get_text_from_node(&modifiers))
+    .map(|text| {
+      text.split_whitespace().filter(|token| !token.starts_with('@')).map(String::from).collect()
+    })
+    .unwrap_or_default()
+}
+
+fn check_id_fields(
+  ts_file: &TSFile,
+  class_node: Node,
+  field_nodes: &[Node],
+  diagnostics: &mut Vec<JpaEntityDiagnostic>,
+) {
+  let id_fields: Vec<&Node> =
+    field_nodes.iter().filter(|field| has_annotation(ts_file, **field, "Id")).collect();
+  let has_embedded_id =
+    field_nodes.iter().any(|field| has_annotation(ts_file, *field, "EmbeddedId"));
+  if id_fields.is_empty() && !has_embedded_id {
+    // The identifier may be inherited from a @MappedSuperclass
+    if get_class_superclass_name_node(ts_file, class_node).is_some() {
+      return;
+    }
+    let name_node = get_class_declaration_name_node(ts_file, class_node).unwrap_or(class_node);
+    diagnostics.push(create_diagnostic(
+      DiagnosticSeverity::Error,
+      "missing-id",
+      "Entity has no field annotated with @Id or @EmbeddedId".to_string(),
+      &name_node,
+    ));
+    return;
+  }
+  if id_fields.len() > 1 && !has_embedded_id && !has_annotation(ts_file, class_node, "IdClass") {
+    for field_node in id_fields.iter().skip(1) {
+      diagnostics.push(create_diagnostic(
+        DiagnosticSeverity::Error,
+        "multiple-ids",
+        format!(
+          "Field '{}' is an additional @Id; composite keys require @IdClass or @EmbeddedId",
+          get_field_name(ts_file, **field_node)
+        ),
+        field_node,
+      ));
+    }
+  }
+}
+
+fn check_relationships(
+  ts_file: &TSFile,
+  field_nodes: &[Node],
+  diagnostics: &mut Vec<JpaEntityDiagnostic>,
+) {
+  for field_node in field_nodes {
+    let field_name = get_field_name(ts_file, *field_node);
+    if has_annotation(ts_file, *field_node, "ManyToOne")
+      && !has_join_annotation(ts_file, *field_node)
+    {
+      diagnostics.push(create_diagnostic(
+        DiagnosticSeverity::Warning,
+        "missing-join-strategy",
+        format!("@ManyToOne field '{}' has no @JoinColumn or @JoinTable", field_name),
+        field_node,
+      ));
+    }
+    // The inverse side of a bidirectional relationship must point to the owning field
+    for relationship in ["OneToMany", "ManyToMany"] {
+      let Some(annotation_node) = find_annotation_node_by_name(ts_file, *field_node, relationship)
+      else {
+        continue;
+      };
+      let has_mapped_by =
+        find_annotation_value_node_by_key(ts_file, annotation_node, "mappedBy").is_some();
+      if !has_mapped_by && !has_join_annotation(ts_file, *field_node) {
+        diagnostics.push(create_diagnostic(
+          DiagnosticSeverity::Warning,
+          "missing-mapped-by",
+          format!(
+            "@{} field '{}' has neither mappedBy nor a join annotation; a bidirectional \
+             relationship should set mappedBy",
+            relationship, field_name
+          ),
+          &annotation_node,
+        ));
+      }
+    }
+  }
+}
+
+fn check_public_mutable_fields(
+  ts_file: &TSFile,
+  field_nodes: &[Node],
+  diagnostics: &mut Vec<JpaEntityDiagnostic>,
+) {
+  for field_node in field_nodes {
+    let modifiers = get_field_modifiers(ts_file, *field_node);
+    let is_public = modifiers.iter().any(|m| m == "public");
+    let is_constant = modifiers.iter().any(|m| m == "final" || m == "static");
+    if is_public && !is_constant {
+      diagnostics.push(create_diagnostic(
+        DiagnosticSeverity::Warning,
+        "public-mutable-field",
+        format!(
+          "Field '{}' is public and mutable; entity state should be encapsulated",
+          get_field_name(ts_file, *field_node)
+        ),
+        field_node,
+      ));
+    }
+  }
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+) -> Result<ValidateJpaEntityResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(entity_file_path, b64_source_code)?;
+  // Step 2: Get public class node and ensure it is an entity
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  if !has_annotation(&ts_file, class_node, "Entity") {
+    return Err("File is not a JPA entity".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(&ts_file, class_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default()
+    .to_string();
+  // Step 3: Run the checks
+  let field_nodes = get_all_field_declaration_nodes(&ts_file, class_node);
+  let mut diagnostics = Vec::new();
+  check_id_fields(&ts_file, class_node, &field_nodes, &mut diagnostics);
+  check_relationships(&ts_file, &field_nodes, &mut diagnostics);
+  check_public_mutable_fields(&ts_file, &field_nodes, &mut diagnostics);
+  diagnostics.sort_by_key(|diagnostic| diagnostic.start_byte);
+  // Step 4: Build and return response
+  let is_valid = !diagnostics.iter().any(|d| d.severity == DiagnosticSeverity::Error);
+  Ok(ValidateJpaEntityResponse { entity_type, is_valid, diagnostics })
+}
@@ -0,0 +1,107 @@
+use std::io::Read;
+
+use base64::Engine;
+use clap::Args;
+
+/// Reads the Java source from standard input and encodes it, so commands handle it like any other
+/// in-memory base64 source.
+fn read_stdin_source() -> Result<String, String> {
+  let mut source = String::new();
+  std::io::stdin()
+    .read_to_string(&mut source)
+    .map_err(|e| format!("Unable to read source from stdin: {}", e))?;
+  Ok(base64::engine::general_purpose::STANDARD.encode(source))
+}
+
+/// In-memory source of the commands that read a Java file without editing it.
+///
+/// The source structs clear `about` so that their docs do not become the help summary of the
+/// commands flattening them.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct SourceArgs {
+  #[arg(long, required = false, conflicts_with = "stdin")]
+  pub b64_source_code: Option<String>,
+
+  /// Reads the Java source from standard input instead of `--b64-source-code`
+  #[arg(long)]
+  pub stdin: bool,
+}
+
+impl SourceArgs {
+  /// The base64 source given on the command line or read from stdin, if any.
+  pub fn resolve(&self) -> Result<Option<String>, String> {
+    if self.stdin { read_stdin_source().map(Some) } else { Ok(self.b64_source_code.clone()) }
+  }
+
+  /// Like [`SourceArgs::resolve`], for commands that have no file path to fall back to.
+  pub fn require(&self) -> Result<String, String> {
+    self.resolve()?.ok_or_else(|| "Either --b64-source-code or --stdin is required".to_string())
+  }
+}
+
+/// Source of the entity edited by a command.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct EntitySourceArgs {
+  #[arg(long, required_unless_present = "stdin", conflicts_with = "stdin")]
+  pub entity_file_b64_src: Option<String>,
+
+  /// Reads the Java source from standard input instead of `--entity-file-b64-src`
+  #[arg(long)]
+  pub stdin: bool,
+}
+
+impl EntitySourceArgs {
+  /// The base64 source given on the command line or read from stdin.
+  pub fn resolve(&self) -> Result<String, String> {
+    match &self.entity_file_b64_src {
+      Some(source) if !self.stdin => Ok(source.clone()),
+      _ => read_stdin_source(),
+    }
+  }
+}
+
+/// Source of the class edited by a command.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct ClassSourceArgs {
+  #[arg(long, required_unless_present = "stdin", conflicts_with = "stdin")]
+  pub class_file_b64_src: Option<String>,
+
+  /// Reads the Java source from standard input instead of `--class-file-b64-src`
+  #[arg(long)]
+  pub stdin: bool,
+}
+
+impl ClassSourceArgs {
+  /// The base64 source given on the command line or read from stdin.
+  pub fn resolve(&self) -> Result<String, String> {
+    match &self.class_file_b64_src {
+      Some(source) if !self.stdin => Ok(source.clone()),
+      _ => read_stdin_source(),
+    }
+  }
+}
+
+/// Source of the owning side entity of a relationship command.
+#[derive(Args)]
+#[command(about = None, long_about = None)]
+pub struct OwningSideSourceArgs {
+  #[arg(long, required_unless_present = "stdin", conflicts_with = "stdin")]
+  pub owning_side_entity_file_b64_src: Option<String>,
+
+  /// Reads the Java source from standard input instead of `--owning-side-entity-file-b64-src`
+  #[arg(long)]
+  pub stdin: bool,
+}
+
+impl OwningSideSourceArgs {
+  /// The base64 source given on the command line or read from stdin.
+  pub fn resolve(&self) -> Result<String, String> {
+    match &self.owning_side_entity_file_b64_src {
+      Some(source) if !self.stdin => Ok(source.clone()),
+      _ => read_stdin_source(),
+    }
+  }
+}
@@ -0,0 +1,13 @@
+use crate::{
+  commands::services::validate_identifier_service::run,
+  common::types::java_identifier_kind::JavaIdentifierKind,
+  responses::{response::Response, validate_identifier_response::ValidateIdentifierResponse},
+};
+
+pub fn execute(kind: &JavaIdentifierKind, value: &str) -> Response<ValidateIdentifierResponse> {
+  let cmd_name = String::from("validate-identifier");
+  match run(kind, value) {
+    Ok(response) => Response::success(cmd_name, String::from("N/A"), response),
+    Err(error_msg) => Response::error(cmd_name, String::from("N/A"), error_msg),
+  }
+}
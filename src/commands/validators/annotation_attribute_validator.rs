@@ -0,0 +1,23 @@
+use crate::common::types::annotation_attribute::AnnotationAttribute;
+
+fn is_java_identifier(s: &str) -> bool {
+  let mut chars = s.chars();
+  chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Parses a `key=value` pair, e.g. `name="users"` or `usage=CacheConcurrencyStrategy.READ_WRITE`.
+/// The value is written as given, so string values keep their quotes.
+pub fn validate_annotation_attribute(s: &str) -> Result<AnnotationAttribute, String> {
+  let (name, value) = s
+    .split_once('=')
+    .ok_or_else(|| format!("Annotation attribute '{}' must be in the form key=value", s))?;
+  let (name, value) = (name.trim(), value.trim());
+  if !is_java_identifier(name) {
+    return Err(format!("'{}' is not a valid annotation attribute name", name));
+  }
+  if value.is_empty() {
+    return Err(format!("Annotation attribute '{}' has no value", name));
+  }
+  Ok(AnnotationAttribute { name: name.to_string(), value: value.to_string() })
+}
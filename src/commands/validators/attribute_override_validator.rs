@@ -0,0 +1,25 @@
+use crate::common::types::embedded_field_config::AttributeOverrideConfig;
+
+fn is_java_identifier(s: &str) -> bool {
+  let mut chars = s.chars();
+  chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Parses a `name:column` pair, e.g. `street:home_street`.
+pub fn validate_attribute_override(s: &str) -> Result<AttributeOverrideConfig, String> {
+  let (attribute_name, column_name) = s
+    .split_once(':')
+    .ok_or_else(|| format!("Attribute override '{}' must be in the form name:column", s))?;
+  let (attribute_name, column_name) = (attribute_name.trim(), column_name.trim());
+  if !is_java_identifier(attribute_name) {
+    return Err(format!("'{}' is not a valid attribute name", attribute_name));
+  }
+  if column_name.is_empty() || column_name.contains('"') {
+    return Err(format!("'{}' is not a valid column name", column_name));
+  }
+  Ok(AttributeOverrideConfig {
+    attribute_name: attribute_name.to_string(),
+    column_name: column_name.to_string(),
+  })
+}
@@ -0,0 +1,34 @@
+use crate::commands::validators::java_identifier_validator::is_java_reserved_word;
+
+fn is_java_identifier(s: &str) -> bool {
+  let mut chars = s.chars();
+  match chars.next() {
+    Some(first) if first.is_alphabetic() || first == '_' || first == '$' => {
+      chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    }
+    _ => false,
+  }
+}
+
+/// Validates a fully qualified Java class name such as `com.example.converter.MoneyConverter`.
+///
+/// Every dot-separated segment must be a valid, non-reserved Java identifier, and the name must
+/// include a package so the class can be imported.
+pub fn validate_java_fully_qualified_class_name(s: &str) -> Result<String, String> {
+  let s = s.trim();
+  if s.is_empty() {
+    return Err("Fully qualified class name cannot be empty".to_string());
+  }
+  if !s.contains('.') {
+    return Err(format!("'{}' must include its package (e.g. com.example.{})", s, s));
+  }
+  for segment in s.split('.') {
+    if !is_java_identifier(segment) {
+      return Err(format!("'{}' is not a well-formed fully qualified class name", s));
+    }
+    if is_java_reserved_word(segment) {
+      return Err(format!("'{}' conflicts with a Java reserved word", segment));
+    }
+  }
+  Ok(s.to_string())
+}
@@ -0,0 +1,86 @@
+/// Java keywords and the `true`, `false` and `null` literals, none of which can name a class,
+/// package segment, field or method.
+pub const JAVA_RESERVED_WORDS: [&str; 53] = [
+  "abstract",
+  "assert",
+  "boolean",
+  "break",
+  "byte",
+  "case",
+  "catch",
+  "char",
+  "class",
+  "const",
+  "continue",
+  "default",
+  "do",
+  "double",
+  "else",
+  "enum",
+  "extends",
+  "final",
+  "finally",
+  "float",
+  "for",
+  "goto",
+  "if",
+  "implements",
+  "import",
+  "instanceof",
+  "int",
+  "interface",
+  "long",
+  "native",
+  "new",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "short",
+  "static",
+  "strictfp",
+  "super",
+  "switch",
+  "synchronized",
+  "this",
+  "throw",
+  "throws",
+  "transient",
+  "try",
+  "void",
+  "volatile",
+  "while",
+  "true",
+  "false",
+  "null",
+];
+
+pub fn is_java_reserved_word(s: &str) -> bool {
+  JAVA_RESERVED_WORDS.contains(&s)
+}
+
+/// Validates a field or method name: a letter, `_` or `$` followed by letters, digits, `_` or
+/// `$`, and not a reserved word. Unlike class names, it is used verbatim, so no case format
+/// conversion is allowed for.
+pub fn validate_java_identifier(s: &str) -> Result<String, String> {
+  let mut chars = s.chars();
+  let Some(first) = chars.next() else {
+    return Err("Identifier cannot be empty".to_string());
+  };
+  if !(first.is_alphabetic() || first == '_' || first == '$') {
+    return Err("Identifier must start with a letter, underscore or dollar sign".to_string());
+  }
+  if !chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+    return Err(
+      "Identifier can only contain letters, numbers, underscores and dollar signs".to_string(),
+    );
+  }
+  if s == "_" {
+    return Err("'_' is a Java keyword".to_string());
+  }
+  if is_java_reserved_word(s) {
+    return Err(format!("'{}' conflicts with a Java reserved word", s));
+  }
+  Ok(s.to_string())
+}
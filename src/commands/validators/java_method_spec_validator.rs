@@ -0,0 +1,95 @@
+use crate::commands::validators::{
+  java_identifier_validator::validate_java_identifier,
+  java_type_validator::{JavaTypeReference, parse_java_type},
+};
+
+/// A parsed method signature such as `List<Order> findByCustomer(UUID customerId)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JavaMethodSpec {
+  /// Return type, `None` for `void`.
+  pub return_type: Option<JavaTypeReference>,
+  pub name: String,
+  /// Parameter types and names, in declaration order.
+  pub parameters: Vec<(JavaTypeReference, String)>,
+}
+
+impl JavaMethodSpec {
+  /// Renders the abstract method declaration with simple type names, e.g.
+  /// `List<Order> findByCustomer(UUID customerId);`.
+  pub fn render(&self) -> String {
+    let return_type = self.return_type.as_ref().map_or("void".to_string(), |t| t.render());
+    let parameters = self
+      .parameters
+      .iter()
+      .map(|(parameter_type, parameter_name)| {
+        format!("{} {}", parameter_type.render(), parameter_name)
+      })
+      .collect::<Vec<_>>();
+    format!("{} {}({});", return_type, self.name, parameters.join(", "))
+  }
+}
+
+/// Splits `s` on the commas that are not nested inside type arguments.
+fn split_top_level_commas(s: &str) -> Vec<&str> {
+  let mut parts = Vec::new();
+  let (mut depth, mut start) = (0usize, 0);
+  for (index, c) in s.char_indices() {
+    match c {
+      '<' => depth += 1,
+      '>' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        parts.push(&s[start..index]);
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  parts.push(&s[start..]);
+  parts
+}
+
+/// Splits `Type name` at its last whitespace, the name being the trailing identifier.
+fn split_type_and_name(s: &str) -> Option<(&str, &str)> {
+  let s = s.trim();
+  let index = s.rfind(char::is_whitespace)?;
+  Some((s[..index].trim(), s[index..].trim()))
+}
+
+fn parse_return_type(s: &str) -> Result<Option<JavaTypeReference>, String> {
+  if s == "void" { Ok(None) } else { parse_java_type(s).map(Some) }
+}
+
+fn parse_method_spec(s: &str) -> Result<JavaMethodSpec, String> {
+  let s = s.trim().trim_end_matches(';').trim_end();
+  let open_index = s.find('(').ok_or("expected '(' after the method name")?;
+  let parameters_text =
+    s[open_index + 1..].strip_suffix(')').ok_or("expected the signature to end with ')'")?;
+  let (return_type, name) =
+    split_type_and_name(&s[..open_index]).ok_or("expected a return type before the method name")?;
+  let return_type = parse_return_type(return_type)?;
+  let name = validate_java_identifier(name)?;
+  let mut parameters: Vec<(JavaTypeReference, String)> = Vec::new();
+  if !parameters_text.trim().is_empty() {
+    for parameter in split_top_level_commas(parameters_text) {
+      let (parameter_type, parameter_name) = split_type_and_name(parameter)
+        .ok_or_else(|| format!("parameter '{}' must be in the form Type name", parameter.trim()))?;
+      let parameter_name = validate_java_identifier(parameter_name)?;
+      if parameters.iter().any(|(_, existing_name)| *existing_name == parameter_name) {
+        return Err(format!("parameter '{}' is declared twice", parameter_name));
+      }
+      parameters.push((parse_java_type(parameter_type)?, parameter_name));
+    }
+  }
+  Ok(JavaMethodSpec { return_type, name, parameters })
+}
+
+/// Parses a method signature `ReturnType name(ParamType p, ...)`, where every type is a simple
+/// or fully qualified name as accepted by `--field-type`, e.g.
+/// `List<com.example.Order> findByCustomer(java.util.UUID customerId)` or `void clear()`.
+pub fn parse_java_method_spec(s: &str) -> Result<JavaMethodSpec, String> {
+  parse_method_spec(s).map_err(|e| format!("'{}' is not a valid method: {}", s.trim(), e))
+}
+
+pub fn validate_java_method_spec(s: &str) -> Result<String, String> {
+  parse_java_method_spec(s).map(|_| s.trim().to_string())
+}
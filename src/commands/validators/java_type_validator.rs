@@ -0,0 +1,148 @@
+use crate::commands::validators::java_identifier_validator::is_java_reserved_word;
+
+const PRIMITIVE_TYPES: [&str; 8] =
+  ["boolean", "byte", "char", "short", "int", "long", "float", "double"];
+
+/// A parsed field type such as `int[]`, `List<Long>` or `Map<String, java.math.BigDecimal>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JavaTypeReference {
+  /// Package written in front of the type, e.g. `java.math` in `java.math.BigDecimal`.
+  pub package_name: Option<String>,
+  /// Simple name, including the outer classes of a nested type, e.g. `Map.Entry`.
+  pub name: String,
+  pub type_arguments: Vec<JavaTypeReference>,
+  pub array_dimensions: usize,
+}
+
+impl JavaTypeReference {
+  pub fn is_primitive(&self) -> bool {
+    PRIMITIVE_TYPES.contains(&self.name.as_str())
+  }
+
+  /// The outermost class of the name, the one an import has to name.
+  pub fn top_level_name(&self) -> &str {
+    self.name.split('.').next().unwrap_or(&self.name)
+  }
+
+  /// Renders the type with simple names, as it is written once its imports are in place.
+  pub fn render(&self) -> String {
+    let mut rendered = self.name.clone();
+    if !self.type_arguments.is_empty() {
+      let arguments =
+        self.type_arguments.iter().map(|argument| argument.render()).collect::<Vec<_>>();
+      rendered.push_str(&format!("<{}>", arguments.join(", ")));
+    }
+    rendered.push_str(&"[]".repeat(self.array_dimensions));
+    rendered
+  }
+}
+
+struct TypeParser<'a> {
+  source: &'a str,
+  position: usize,
+}
+
+impl<'a> TypeParser<'a> {
+  fn skip_whitespace(&mut self) {
+    let rest = &self.source[self.position..];
+    self.position += rest.len() - rest.trim_start().len();
+  }
+
+  fn eat(&mut self, expected: char) -> bool {
+    self.skip_whitespace();
+    if self.source[self.position..].starts_with(expected) {
+      self.position += expected.len_utf8();
+      true
+    } else {
+      false
+    }
+  }
+
+  fn parse_identifier(&mut self) -> Result<&'a str, String> {
+    self.skip_whitespace();
+    let rest = &self.source[self.position..];
+    let length = rest
+      .char_indices()
+      .find(|&(index, c)| {
+        !(c.is_alphabetic() || c == '_' || c == '$' || (index > 0 && c.is_alphanumeric()))
+      })
+      .map(|(index, _)| index)
+      .unwrap_or(rest.len());
+    if length == 0 {
+      return Err(match rest.chars().next() {
+        Some(c) => format!("unexpected '{}'", c),
+        None => "unexpected end of type".to_string(),
+      });
+    }
+    self.position += length;
+    Ok(&rest[..length])
+  }
+
+  fn parse_type(&mut self, is_type_argument: bool) -> Result<JavaTypeReference, String> {
+    let mut segments = vec![self.parse_identifier()?];
+    while self.eat('.') {
+      segments.push(self.parse_identifier()?);
+    }
+    // Lower case segments are the package, the rest is the (possibly nested) class name
+    let package_length = segments
+      .iter()
+      .position(|segment| !segment.starts_with(|c: char| c.is_lowercase()))
+      .unwrap_or(segments.len() - 1);
+    let package_name = (package_length > 0).then(|| segments[..package_length].join("."));
+    let name = segments[package_length..].join(".");
+    let is_primitive = package_name.is_none() && PRIMITIVE_TYPES.contains(&name.as_str());
+    if let Some(segment) =
+      segments.iter().find(|segment| !is_primitive && is_java_reserved_word(segment))
+    {
+      return Err(format!("'{}' conflicts with a Java reserved word", segment));
+    }
+    let mut type_arguments = Vec::new();
+    if self.eat('<') {
+      if is_primitive {
+        return Err(format!("primitive type '{}' cannot have type arguments", name));
+      }
+      loop {
+        type_arguments.push(self.parse_type(true)?);
+        if self.eat('>') {
+          break;
+        }
+        if !self.eat(',') {
+          return Err("expected ',' or '>' in type arguments".to_string());
+        }
+      }
+    }
+    let mut array_dimensions = 0;
+    while self.eat('[') {
+      if !self.eat(']') {
+        return Err("expected ']'".to_string());
+      }
+      array_dimensions += 1;
+    }
+    if is_type_argument && is_primitive && array_dimensions == 0 {
+      return Err(format!("primitive type '{}' cannot be a type argument", name));
+    }
+    Ok(JavaTypeReference { package_name, name, type_arguments, array_dimensions })
+  }
+}
+
+/// Parses a field type: a simple or fully qualified name, optionally followed by type arguments
+/// and array dimensions, e.g. `List<Long>`, `int[]` or `Map<String, com.example.Tag>`.
+pub fn parse_java_type(s: &str) -> Result<JavaTypeReference, String> {
+  let mut parser = TypeParser { source: s, position: 0 };
+  let java_type = parser
+    .parse_type(false)
+    .map_err(|e| format!("'{}' is not a valid field type: {}", s.trim(), e))?;
+  parser.skip_whitespace();
+  if parser.position < s.len() {
+    return Err(format!(
+      "'{}' is not a valid field type: unexpected '{}'",
+      s.trim(),
+      &s[parser.position..]
+    ));
+  }
+  Ok(java_type)
+}
+
+pub fn validate_java_type(s: &str) -> Result<String, String> {
+  parse_java_type(s).map(|_| s.trim().to_string())
+}
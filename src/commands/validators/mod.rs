@@ -1,3 +1,9 @@
+pub mod annotation_attribute_validator;
+pub mod attribute_override_validator;
 pub mod directory_validator;
 pub mod java_class_name_validator;
+pub mod java_fqcn_validator;
+pub mod java_identifier_validator;
+pub mod java_method_spec_validator;
+pub mod java_type_validator;
 pub mod package_name_validator;
@@ -1,3 +1,5 @@
+use crate::commands::validators::java_identifier_validator::is_java_reserved_word;
+
 pub fn validate_package_name(s: &str) -> Result<String, String> {
   if s.trim().is_empty() {
     return Err("Package name cannot be empty".to_string());
@@ -10,5 +12,8 @@ pub fn validate_package_name(s: &str) -> Result<String, String> {
   if s.starts_with('.') || s.ends_with('.') || s.contains("..") {
     return Err("Package name cannot start/end with dots or contain consecutive dots".to_string());
   }
+  if let Some(segment) = s.split('.').find(|segment| is_java_reserved_word(segment)) {
+    return Err(format!("Package segment '{}' conflicts with a Java reserved word", segment));
+  }
   Ok(s.to_string())
 }
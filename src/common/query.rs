@@ -2,7 +2,35 @@
 
 use crate::common::ts_file::TSFile;
 use std::collections::HashMap;
-use tree_sitter::{Node, Query, QueryCursor, StreamingIterator};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tree_sitter::{Language, Node, Query, QueryCursor, StreamingIterator};
+
+/// Compiled queries keyed by their source. Every `TSFile` is parsed with the Java grammar, so the
+/// query string alone identifies a compiled query.
+static QUERY_CACHE: OnceLock<Mutex<HashMap<String, Arc<Query>>>> = OnceLock::new();
+static QUERY_COMPILATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the compiled query for `query_string`, compiling it only on its first use.
+pub fn get_or_compile_query(
+  language: &Language,
+  query_string: &str,
+) -> Result<Arc<Query>, tree_sitter::QueryError> {
+  let cache = QUERY_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+  let mut cache = cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  if let Some(query) = cache.get(query_string) {
+    return Ok(Arc::clone(query));
+  }
+  let query = Arc::new(Query::new(language, query_string)?);
+  QUERY_COMPILATIONS.fetch_add(1, Ordering::Relaxed);
+  cache.insert(query_string.to_string(), Arc::clone(&query));
+  Ok(query)
+}
+
+/// Number of queries compiled by `get_or_compile_query` since the process started.
+pub fn compiled_query_count() -> usize {
+  QUERY_COMPILATIONS.load(Ordering::Relaxed)
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum QueryError {
@@ -185,7 +213,7 @@ impl<'a> TSQueryBuilder<'a> {
 
   pub fn execute(self) -> Result<TSQueryResult<'a>, QueryError> {
     let tree = self.file.tree.as_ref().ok_or(QueryError::NoTree)?;
-    let query = Query::new(&self.file.language, &self.query_string)?;
+    let query = get_or_compile_query(&self.file.language, &self.query_string)?;
     let mut cursor = QueryCursor::new();
     let root_node = self.scope_node.unwrap_or_else(|| tree.root_node());
     let mut query_matches = cursor.matches(&query, root_node, self.file.source_code.as_bytes());
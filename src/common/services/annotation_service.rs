@@ -4,6 +4,8 @@ use crate::common::ts_file::TSFile;
 use crate::common::types::annotation_types::{
   AnnotationInsertionPoint, AnnotationInsertionPosition,
 };
+use crate::common::utils::annotation_value_util::wrap_annotation;
+use crate::common::utils::indent_util::indent_unit;
 use tree_sitter::Node;
 
 impl Default for AnnotationInsertionPoint {
@@ -211,8 +213,8 @@ fn detect_indentation(
   if let Some(line_text) = full_text.get(line_start..decl_start) {
     return line_text.to_string();
   }
-  // Fallback: use 2 spaces (common Java indentation for field annotations)
-  "  ".to_string()
+  // Fallback: one level of the generated code indentation
+  indent_unit().to_string()
 }
 
 pub fn add_annotation<'a>(
@@ -249,13 +251,19 @@ pub fn add_annotation<'a>(
       current_node = parent;
       node_kind = current_node.kind();
     }
-    // If we found an annotation, navigate up to find the containing class_declaration
+    // If we found an annotation, navigate up to find the declaration it belongs to
     if matches!(node_kind, "annotation" | "marker_annotation") {
-      // The annotation is likely a child of modifiers, which is a child of class_declaration
-      // So we need to go up the parent chain to find class_declaration
+      // The annotation is a child of modifiers, which is a child of the annotated declaration
+      // (class, field, method or interface), so stop at the nearest one
       let mut current_ancestor = Some(current_node);
       while let Some(ancestor) = current_ancestor {
-        if ancestor.kind() == "class_declaration" {
+        if matches!(
+          ancestor.kind(),
+          "class_declaration"
+            | "field_declaration"
+            | "method_declaration"
+            | "interface_declaration"
+        ) {
           current_node = ancestor;
           node_kind = current_node.kind();
           break;
@@ -263,6 +271,17 @@ pub fn add_annotation<'a>(
         current_ancestor = ancestor.parent();
       }
     }
+    // Without modifiers the declaration starts with its type, e.g. a package-private field, so
+    // climb from the type node to the declaration starting at the same byte
+    while !matches!(
+      node_kind,
+      "class_declaration" | "field_declaration" | "interface_declaration" | "method_declaration"
+    ) && let Some(parent) = current_node.parent()
+      && parent.start_byte() == current_node.start_byte()
+    {
+      current_node = parent;
+      node_kind = current_node.kind();
+    }
     if !matches!(
       node_kind,
       "class_declaration" | "field_declaration" | "interface_declaration" | "method_declaration"
@@ -271,7 +290,13 @@ pub fn add_annotation<'a>(
     }
     declaration_node = Some(current_node);
     let declaration_node = declaration_node.unwrap();
-    let all_annotations = get_all_annotation_nodes(ts_file, declaration_node);
+    // Only keep the declaration's own annotations, not the ones of nested members
+    let all_annotations: Vec<Node> = get_all_annotation_nodes(ts_file, declaration_node)
+      .into_iter()
+      .filter(|annotation| {
+        annotation.parent().and_then(|modifiers| modifiers.parent()) == Some(declaration_node)
+      })
+      .collect();
     let current_text = ts_file.get_text_from_node(&declaration_node);
     current_text.as_ref()?;
     let current_text = current_text.unwrap().to_string();
@@ -294,28 +319,7 @@ pub fn add_annotation<'a>(
       indentation,
     )
   };
-  let mut annotation_insertion_point = AnnotationInsertionPoint::new();
-  annotation_insertion_point.position = insertion_position.clone();
-  match insertion_position {
-    AnnotationInsertionPosition::BeforeFirstAnnotation => {
-      if !all_annotations.is_empty() {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = all_annotations[0].start_byte();
-      } else {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = declaration_start_byte;
-      }
-    }
-    AnnotationInsertionPosition::AboveScopeDeclaration => {
-      if all_annotations.is_empty() {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = declaration_start_byte;
-      } else {
-        annotation_insertion_point.break_line_before = true;
-        annotation_insertion_point.insert_byte = all_annotations.last()?.end_byte();
-      }
-    }
-  }
+  let annotation_text = wrap_annotation(annotation_text, &indentation);
   let new_content = match insertion_position {
     AnnotationInsertionPosition::BeforeFirstAnnotation => {
       if !all_annotations.is_empty() {
@@ -325,13 +329,10 @@ pub fn add_annotation<'a>(
         let before = &current_text[..relative_pos];
         let after = &current_text[relative_pos..];
 
-        // If 'before' is empty (meaning first annotation has no indentation),
-        // use the detected indentation from field context
-        if before.trim().is_empty() && !indentation.is_empty() {
-          format!("{}{}\n{}", indentation, annotation_text, after)
-        } else if before.is_empty() {
-          // Fallback: use field-level indentation (2 spaces for fields)
-          format!("  {}\n{}", annotation_text, after)
+        // If 'before' is empty the replaced range starts at the first annotation, after its
+        // indentation, so the new annotation takes that place and the old one is re-indented
+        if before.is_empty() {
+          format!("{}\n{}{}", annotation_text, indentation, after)
         } else {
           // Use the existing indentation pattern
           format!("{}{}\n{}", before, annotation_text, after)
@@ -371,7 +372,6 @@ pub fn add_annotation_argument<'a>(
   }
   // Collect all necessary information before any mutable operations
   let (
-    annotation_start_byte,
     annotation_end_byte,
     node_kind,
     current_text,
@@ -405,7 +405,6 @@ pub fn add_annotation_argument<'a>(
       .map(|n| n.end_byte() - annotation_start + name_offset);
     let existing_arguments = get_annotation_argument_pair_nodes(ts_file, annotation_node);
     (
-      annotation_node.start_byte(),
       annotation_node.end_byte(),
       node_kind,
       current_text,
@@ -435,7 +434,8 @@ pub fn add_annotation_argument<'a>(
       // Add argument to existing arguments
       // @Column(name = "test") -> @Column(name = "test", key = value)
       let last_argument = existing_arguments.last()?;
-      let insert_pos = last_argument.end_byte() - annotation_start_byte;
+      // `current_text` starts at `actual_start_byte`, which includes any leading indentation
+      let insert_pos = last_argument.end_byte() - actual_start_byte;
       let before = &current_text[..insert_pos];
       let after = &current_text[insert_pos..];
       format!("{}, {}{}", before, argument_pair, after)
@@ -509,3 +509,61 @@ pub fn add_annotation_single_value<'a>(
   };
   ts_file.replace_text_by_byte_range(annotation_start_byte, annotation_end_byte, &new_content)
 }
+
+/// Removes the annotation starting at `annotation_byte_position`, together with the whitespace
+/// separating it from the next token so the following line keeps its indentation.
+pub fn remove_annotation(ts_file: &mut TSFile, annotation_byte_position: usize) -> Option<()> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte) = {
+    let annotation_node = ts_file.get_named_node_at_byte_position(annotation_byte_position)?;
+    if !matches!(annotation_node.kind(), "annotation" | "marker_annotation") {
+      return None;
+    }
+    let end_byte = annotation_node.end_byte();
+    let trailing_whitespace = ts_file.source_code[end_byte..]
+      .find(|c: char| !c.is_whitespace())
+      .unwrap_or(ts_file.source_code.len() - end_byte);
+    (annotation_node.start_byte(), end_byte + trailing_whitespace)
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(())
+}
+
+/// Removes the `key = value` argument from the annotation starting at
+/// `annotation_byte_position`. When it is the only argument the argument list is dropped,
+/// turning the annotation into a marker annotation.
+pub fn remove_annotation_argument(
+  ts_file: &mut TSFile,
+  annotation_byte_position: usize,
+  key: &str,
+) -> Option<()> {
+  if ts_file.tree.is_none() || key.trim().is_empty() {
+    return None;
+  }
+  let (start_byte, end_byte) = {
+    let annotation_node = ts_file.get_named_node_at_byte_position(annotation_byte_position)?;
+    if annotation_node.kind() != "annotation" {
+      return None;
+    }
+    let pairs = get_annotation_argument_pair_nodes(ts_file, annotation_node);
+    let index = pairs.iter().position(|pair| {
+      pair
+        .child_by_field_name("key")
+        .and_then(|key_node| ts_file.get_text_from_node(&key_node))
+        .is_some_and(|text| text == key)
+    })?;
+    let pair = pairs[index];
+    if pairs.len() == 1 {
+      let argument_list = pair.parent()?;
+      (argument_list.start_byte(), argument_list.end_byte())
+    } else if index + 1 < pairs.len() {
+      // @Column(key = 1, name = "x") -> @Column(name = "x")
+      (pair.start_byte(), pairs[index + 1].start_byte())
+    } else {
+      // @Column(name = "x", key = 1) -> @Column(name = "x")
+      (pairs[index - 1].end_byte(), pair.end_byte())
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(())
+}
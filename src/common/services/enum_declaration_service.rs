@@ -73,3 +73,21 @@ pub fn get_enum_name_node<'a>(
   }
   None
 }
+
+pub fn get_enum_constant_name_nodes<'a>(
+  ts_file: &'a TSFile,
+  enum_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || enum_declaration_node.kind() != "enum_declaration" {
+    return Vec::new();
+  }
+  let Some(enum_body_node) = enum_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = enum_body_node.walk();
+  enum_body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "enum_constant")
+    .filter_map(|constant_node| constant_node.child_by_field_name("name"))
+    .collect()
+}
@@ -1,11 +1,15 @@
 #![allow(dead_code)]
 
 use crate::common::services::annotation_service::add_annotation;
+use crate::common::services::formatting_service::member_separator;
 use crate::common::ts_file::TSFile;
 use crate::common::types::annotation_types::AnnotationInsertionPosition;
+use crate::common::types::generated_style::GeneratedStyle;
 use crate::common::types::java_basic_types::{FieldInsertionPoint, FieldInsertionPosition};
 use crate::common::types::java_field_modifier::JavaFieldModifier;
 use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::utils::annotation_value_util::render_string_value;
+use crate::common::utils::indent_util::indent_unit;
 use tree_sitter::Node;
 
 pub struct AddFieldDeclarationParams<'a> {
@@ -15,6 +19,10 @@ pub struct AddFieldDeclarationParams<'a> {
   pub field_type: &'a str,
   pub field_name: &'a str,
   pub field_initialization: Option<&'a str>,
+  /// Text of a `/** ... */` block written above the field and its annotations.
+  pub javadoc: Option<&'a str>,
+  /// Marks the field as generated, see [`prepare_field_insertion`].
+  pub generated_marker: Option<&'a GeneratedStyle>,
 }
 
 pub struct FieldAnnotationBuilder<'a> {
@@ -75,6 +83,16 @@ impl<'a> FieldAnnotationBuilder<'a> {
     Ok(self)
   }
 
+  /// Adds a string attribute, quoting and escaping `value` as a Java string literal
+  pub fn with_string_argument(
+    &mut self,
+    annotation_text: &str,
+    key: &str,
+    value: &str,
+  ) -> Result<&mut Self, String> {
+    self.with_argument(annotation_text, key, &render_string_value(value)?)
+  }
+
   pub fn with_value(&mut self, annotation_text: &str, value: &str) -> Result<&mut Self, String> {
     // Find the pending annotation and add the single value to it
     let pending_annotation = self
@@ -183,6 +201,149 @@ pub fn find_field_declaration_node_by_name<'a>(
     .first_node()
 }
 
+/// Fails with `cannot edit: source has syntax errors near line L, column C` when the class
+/// declared at `class_declaration_byte_position` contains `ERROR` or `MISSING` nodes, e.g. an
+/// unclosed brace, so a field is never inserted into a body the parser could not make sense of.
+pub fn ensure_class_has_no_syntax_errors(
+  ts_file: &TSFile,
+  class_declaration_byte_position: usize,
+) -> Result<(), String> {
+  if !ts_file.has_errors() {
+    return Ok(());
+  }
+  let class_range =
+    find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)
+      .map(|class_declaration_node| class_declaration_node.byte_range())
+      .unwrap_or(0..ts_file.source_code.len());
+  // A missing closing brace is reported as an empty range right at the end of the class
+  let error_range = ts_file.error_ranges().into_iter().find(|error_range| {
+    error_range.start <= class_range.end && error_range.end >= class_range.start
+  });
+  match error_range {
+    Some(error_range) => {
+      let point = ts_file.byte_position_to_point(error_range.start);
+      Err(format!(
+        "cannot edit: source has syntax errors near line {}, column {}",
+        point.row + 1,
+        point.column + 1
+      ))
+    }
+    None => Ok(()),
+  }
+}
+
+/// Fails with `field '<name>' already exists` when the class declared at
+/// `class_declaration_byte_position` already has a field named `field_name`. Fields of nested
+/// classes are not considered.
+pub fn ensure_field_name_available(
+  ts_file: &TSFile,
+  class_declaration_byte_position: usize,
+  field_name: &str,
+) -> Result<(), String> {
+  let Some(class_declaration_node) =
+    find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)
+  else {
+    return Ok(());
+  };
+  let Some(class_body_node) = class_declaration_node.child_by_field_name("body") else {
+    return Ok(());
+  };
+  let mut cursor = class_body_node.walk();
+  let is_declared = class_body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "field_declaration")
+    .flat_map(|field_node| {
+      let mut field_cursor = field_node.walk();
+      field_node.children_by_field_name("declarator", &mut field_cursor).collect::<Vec<_>>()
+    })
+    .filter_map(|declarator| declarator.child_by_field_name("name"))
+    .any(|name_node| ts_file.get_text_from_node(&name_node) == Some(field_name));
+  if is_declared {
+    return Err(format!("field '{}' already exists", field_name));
+  }
+  Ok(())
+}
+
+/// Fails when `@MapsId` has no identifier to derive from: the class declared at
+/// `class_declaration_byte_position` needs an `@Id` or `@EmbeddedId` field, and an `@EmbeddedId`
+/// one when `maps_id_value` names an attribute of the composite id. Classes extending another
+/// class are accepted since the identifier may be inherited from a `@MappedSuperclass`.
+pub fn ensure_maps_id_supported(
+  ts_file: &TSFile,
+  class_declaration_byte_position: usize,
+  maps_id_value: Option<&str>,
+) -> Result<(), String> {
+  let Some(class_declaration_node) =
+    find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)
+  else {
+    return Ok(());
+  };
+  if class_declaration_node.child_by_field_name("superclass").is_some() {
+    return Ok(());
+  }
+  let class_name = class_declaration_node
+    .child_by_field_name("name")
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or_default();
+  let id_annotations: Vec<&str> = class_declaration_node
+    .child_by_field_name("body")
+    .map(|class_body_node| {
+      let mut cursor = class_body_node.walk();
+      class_body_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "field_declaration")
+        .flat_map(|field_node| {
+          let mut field_cursor = field_node.walk();
+          field_node
+            .named_children(&mut field_cursor)
+            .filter(|child| child.kind() == "modifiers")
+            .flat_map(|modifiers| {
+              let mut modifiers_cursor = modifiers.walk();
+              modifiers.named_children(&mut modifiers_cursor).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+        })
+        .filter_map(|modifier| modifier.child_by_field_name("name"))
+        .filter_map(|name_node| ts_file.get_text_from_node(&name_node))
+        .map(|name| name.rsplit('.').next().unwrap_or(name))
+        .filter(|name| matches!(*name, "Id" | "EmbeddedId"))
+        .collect()
+    })
+    .unwrap_or_default();
+  match maps_id_value {
+    Some(value) if !id_annotations.contains(&"EmbeddedId") => Err(format!(
+      "@MapsId(\"{}\") maps an attribute of an embedded id, but {} has no @EmbeddedId field",
+      value, class_name
+    )),
+    None if id_annotations.is_empty() => {
+      Err(format!("@MapsId requires {} to declare an @Id or @EmbeddedId field", class_name))
+    }
+    _ => Ok(()),
+  }
+}
+
+/// Picks where a new field goes: before the first field with `at_top`, right after
+/// `after_field`, or at the end of the class body otherwise. Fails when `after_field` is not a
+/// field of the class declared at `class_declaration_byte_position`.
+pub fn resolve_field_insertion_position(
+  ts_file: &TSFile,
+  class_declaration_byte_position: usize,
+  after_field: Option<&str>,
+  at_top: bool,
+) -> Result<FieldInsertionPosition, String> {
+  match (after_field, at_top) {
+    (Some(_), true) => Err("after_field and at_top cannot be combined".to_string()),
+    (Some(field_name), false) => {
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)
+        .and_then(|class_node| find_field_declaration_node_by_name(ts_file, field_name, class_node))
+        .ok_or_else(|| format!("Field not found: {}", field_name))?;
+      Ok(FieldInsertionPosition::AfterField(field_name.to_string()))
+    }
+    (None, true) => Ok(FieldInsertionPosition::BeforeFirstField),
+    (None, false) => Ok(FieldInsertionPosition::EndOfClassBody),
+  }
+}
+
 pub fn find_field_declaration_nodes_by_type<'a>(
   ts_file: &'a TSFile,
   field_declarator_type: &str,
@@ -469,6 +630,22 @@ pub fn get_field_insertion_position<'a>(
       field_insertion_point.break_line_before = true;
       field_insertion_point.insert_byte = class_body.end_byte() - 1; // Before closing brace
     }
+    FieldInsertionPosition::BeforeFirstField => {
+      if let Some(first_field) = all_fields.first() {
+        field_insertion_point.break_line_after = true;
+        field_insertion_point.insert_byte = first_field.start_byte();
+      } else {
+        let class_body = get_class_body_node(ts_file, class_declaration_node)?;
+        field_insertion_point.break_line_before = true;
+        field_insertion_point.insert_byte = class_body.end_byte() - 1; // Before closing brace
+      }
+    }
+    FieldInsertionPosition::AfterField(field_name) => {
+      let field_node =
+        find_field_declaration_node_by_name(ts_file, field_name, class_declaration_node)?;
+      field_insertion_point.break_line_before = true;
+      field_insertion_point.insert_byte = field_node.end_byte();
+    }
   }
 
   Some(field_insertion_point)
@@ -510,6 +687,38 @@ fn find_class_declaration_node_from_position<'a>(
   }
 }
 
+/// Renders `text` as an indented Javadoc block, on one line unless the text spans several.
+fn format_javadoc(text: &str, indentation: &str) -> String {
+  let text = text.trim().replace("*/", "*&#47;");
+  if !text.contains('\n') {
+    return format!("{}/** {} */\n", indentation, text);
+  }
+  let mut javadoc = format!("{}/**\n", indentation);
+  for line in text.lines() {
+    let line = line.trim_end();
+    if line.is_empty() {
+      javadoc.push_str(&format!("{} *\n", indentation));
+    } else {
+      javadoc.push_str(&format!("{} * {}\n", indentation, line));
+    }
+  }
+  javadoc.push_str(&format!("{} */\n", indentation));
+  javadoc
+}
+
+/// Start byte of `node` including the comments, such as its Javadoc, directly above it.
+fn leading_comments_start_byte(node: Node) -> usize {
+  let mut start_byte = node.start_byte();
+  let mut previous = node.prev_sibling();
+  while let Some(comment) = previous
+    && matches!(comment.kind(), "block_comment" | "line_comment")
+  {
+    start_byte = comment.start_byte();
+    previous = comment.prev_sibling();
+  }
+  start_byte
+}
+
 pub fn add_field_declaration<'a, F, R>(
   ts_file: &'a mut TSFile,
   class_declaration_byte_position: usize,
@@ -531,16 +740,41 @@ where
   // Get the class body node
   let class_body_node = get_class_body_node(ts_file, class_declaration_node)?;
   // Collect all necessary information before any mutable operations
-  let (class_body_start_byte, class_body_end_byte, current_body_text, all_fields) = {
+  let (class_body_start_byte, class_body_end_byte, current_body_text, all_fields, anchor_field) = {
     let current_body_text = ts_file.get_text_from_node(&class_body_node)?.to_string();
     let all_fields = get_all_field_declaration_nodes(ts_file, class_declaration_node);
-    (class_body_node.start_byte(), class_body_node.end_byte(), current_body_text, all_fields)
+    let anchor_field = match &params.insertion_position {
+      FieldInsertionPosition::AfterField(field_name) => {
+        Some(find_field_declaration_node_by_name(ts_file, field_name, class_declaration_node)?)
+      }
+      _ => None,
+    };
+    (
+      class_body_node.start_byte(),
+      class_body_node.end_byte(),
+      current_body_text,
+      all_fields,
+      anchor_field,
+    )
   };
   // Build the field declaration text
   let modifiers_str =
     params.field_modifiers.iter().map(|m| m.keyword()).collect::<Vec<_>>().join(" ");
+  let indentation = indent_unit();
   let mut field_text = String::new();
-  field_text.push_str("  "); // Indentation
+  // The marker comment goes above the Javadoc, which has to stay next to the declaration
+  if params.generated_marker == Some(&GeneratedStyle::Comment) {
+    field_text.push_str(&format!("{}{}\n", indentation, GeneratedStyle::Comment.marker()));
+  }
+  if let Some(javadoc) = params.javadoc
+    && !javadoc.trim().is_empty()
+  {
+    field_text.push_str(&format_javadoc(javadoc, indentation));
+  }
+  if params.generated_marker == Some(&GeneratedStyle::Annotation) {
+    field_text.push_str(&format!("{}{}\n", indentation, GeneratedStyle::Annotation.marker()));
+  }
+  field_text.push_str(indentation);
   if params.visibility_modifier.has_keyword() {
     field_text.push_str(params.visibility_modifier.keyword());
     field_text.push(' ');
@@ -568,7 +802,7 @@ where
         let relative_pos = last_field.end_byte() - class_body_start_byte;
         let before = &current_body_text[..relative_pos];
         let after = &current_body_text[relative_pos..];
-        format!("{}\n{}{}", before, field_text, after)
+        format!("{}{}{}{}", before, member_separator(), field_text, after)
       } else {
         // No fields exist, insert after opening brace and before any content
         // The class body text includes braces, so we need to find the right position
@@ -587,7 +821,7 @@ where
         let relative_pos = last_field.end_byte() - class_body_start_byte;
         let before = &current_body_text[..relative_pos];
         let after = &current_body_text[relative_pos..];
-        format!("{}\n{}{}", before, field_text, after)
+        format!("{}{}{}{}", before, member_separator(), field_text, after)
       } else if let Some(after_brace) = current_body_text.strip_prefix('{') {
         format!("{{\n{}{}", field_text, after_brace)
       } else {
@@ -595,13 +829,39 @@ where
       }
     }
     FieldInsertionPosition::EndOfClassBody => {
-      // Insert before the closing brace
+      // Insert before the closing brace, after the last member when there is one
       if let Some(before_brace) = current_body_text.strip_suffix('}') {
-        format!("{}\n{}\n}}", before_brace, field_text)
+        let last_member = before_brace.trim_end();
+        if last_member == "{" {
+          format!("{}\n{}\n}}", before_brace, field_text)
+        } else {
+          format!("{}{}{}\n}}", last_member, member_separator(), field_text)
+        }
       } else {
         format!("{}\n{}\n", current_body_text, field_text)
       }
     }
+    FieldInsertionPosition::BeforeFirstField => {
+      if let Some(first_field) = all_fields.first() {
+        // Insert at the start of the first field's line, annotations and Javadoc included
+        let relative_pos = leading_comments_start_byte(*first_field) - class_body_start_byte;
+        let line_start = current_body_text[..relative_pos].rfind('\n').map_or(0, |pos| pos + 1);
+        let before = &current_body_text[..line_start];
+        let after = &current_body_text[line_start..];
+        format!("{}{}{}{}", before, field_text, member_separator(), after)
+      } else if let Some(before_brace) = current_body_text.strip_suffix('}') {
+        format!("{}\n{}\n}}", before_brace, field_text)
+      } else {
+        current_body_text
+      }
+    }
+    FieldInsertionPosition::AfterField(_) => {
+      let anchor_field = anchor_field?;
+      let relative_pos = anchor_field.end_byte() - class_body_start_byte;
+      let before = &current_body_text[..relative_pos];
+      let after = &current_body_text[relative_pos..];
+      format!("{}{}{}{}", before, member_separator(), field_text, after)
+    }
   };
   // Replace the class body with the new content - tree is updated incrementally
   let update_success = ts_file.replace_text_by_byte_range(
@@ -625,3 +885,183 @@ where
     None
   }
 }
+
+/// Removes the field declaration at `field_byte_position`, including its annotations and the
+/// blank line separating it from the surrounding members.
+pub fn remove_field_declaration(ts_file: &mut TSFile, field_byte_position: usize) -> Option<()> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte) = {
+    let field_node =
+      ts_file.get_node_at_byte_position_with_kind(field_byte_position, "field_declaration")?;
+    member_removal_range(&ts_file.source_code, field_node.start_byte(), field_node.end_byte())
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(())
+}
+
+/// Removes the member of `kind`, e.g. `field_declaration` or `method_declaration`, starting at
+/// `member_byte_position` along with the comments directly above it, like
+/// [`remove_field_declaration`] does for fields. Returns the removed lines, indentation included
+/// and without the trailing line break, so the member can be inserted elsewhere.
+pub fn take_member_declaration(
+  ts_file: &mut TSFile,
+  member_byte_position: usize,
+  kind: &str,
+) -> Option<String> {
+  ts_file.tree.as_ref()?;
+  let (member_text, start_byte, end_byte) = {
+    let member_node = ts_file.get_node_at_byte_position_with_kind(member_byte_position, kind)?;
+    let comments_start_byte = leading_comments_start_byte(member_node);
+    let source = &ts_file.source_code;
+    let line_start = source[..comments_start_byte].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+    let text_start = if source[line_start..comments_start_byte].trim().is_empty() {
+      line_start
+    } else {
+      comments_start_byte
+    };
+    let (start_byte, end_byte) =
+      member_removal_range(source, comments_start_byte, member_node.end_byte());
+    (source[text_start..member_node.end_byte()].to_string(), start_byte, end_byte)
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(member_text)
+}
+
+/// Widens `start_byte..end_byte` of a member to its whole lines and one adjacent blank line.
+fn member_removal_range(
+  source: &str,
+  mut start_byte: usize,
+  mut end_byte: usize,
+) -> (usize, usize) {
+  // Take the whole line when the field is alone on it
+  let line_start = source[..start_byte].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+  if source[line_start..start_byte].trim().is_empty() {
+    start_byte = line_start;
+  }
+  if source[end_byte..].starts_with('\n') {
+    end_byte += 1;
+  }
+  // Drop one adjacent blank line, preferring the one above the field
+  let previous_line_start =
+    source[..start_byte.saturating_sub(1)].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+  let next_line_end = source[end_byte..].find('\n').map(|pos| end_byte + pos);
+  if start_byte > 0
+    && start_byte == line_start
+    && source[previous_line_start..start_byte - 1].trim().is_empty()
+  {
+    start_byte = previous_line_start;
+  } else if let Some(next_line_end) = next_line_end
+    && source[end_byte..next_line_end].trim().is_empty()
+  {
+    end_byte = next_line_end + 1;
+  }
+  (start_byte, end_byte)
+}
+
+/// Whether `field_node` carries the marker of an earlier `--mark-generated` run, either
+/// `@Generated("syntaxpresso")` or the marker comment among the comments directly above it.
+pub fn is_generated_field(ts_file: &TSFile, field_node: Node) -> bool {
+  let generator_value = format!("\"{}\"", GeneratedStyle::GENERATOR_NAME);
+  let mut cursor = field_node.walk();
+  let has_annotation = field_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "modifiers")
+    .flat_map(|modifiers| {
+      let mut modifiers_cursor = modifiers.walk();
+      modifiers.named_children(&mut modifiers_cursor).collect::<Vec<_>>()
+    })
+    .filter(|modifier| modifier.kind() == "annotation")
+    .any(|annotation| {
+      let name = annotation
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+        .unwrap_or_default();
+      let arguments = annotation
+        .child_by_field_name("arguments")
+        .and_then(|arguments_node| ts_file.get_text_from_node(&arguments_node))
+        .unwrap_or_default();
+      (name == "Generated" || name.ends_with(".Generated")) && arguments.contains(&generator_value)
+    });
+  if has_annotation {
+    return true;
+  }
+  let mut previous = field_node.prev_sibling();
+  while let Some(comment) = previous
+    && matches!(comment.kind(), "block_comment" | "line_comment")
+  {
+    if ts_file.get_text_from_node(&comment).map(str::trim) == Some(GeneratedStyle::MARKER_COMMENT) {
+      return true;
+    }
+    previous = comment.prev_sibling();
+  }
+  false
+}
+
+/// Removes the field named `field_name`, along with the comments above it, when it carries the
+/// marker of an earlier `--mark-generated` run. Returns the position the field had among the
+/// fields of the class, or `None` when no marked field was removed.
+pub fn remove_generated_field(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_name: &str,
+) -> Option<FieldInsertionPosition> {
+  let (start_byte, end_byte, position) = {
+    let class_declaration_node =
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
+    let field_node =
+      find_field_declaration_node_by_name(ts_file, field_name, class_declaration_node)?;
+    if !is_generated_field(ts_file, field_node) {
+      return None;
+    }
+    let fields = get_all_field_declaration_nodes(ts_file, class_declaration_node)
+      .into_iter()
+      .filter(|field| field.parent() == field_node.parent())
+      .collect::<Vec<_>>();
+    let index = fields.iter().position(|field| *field == field_node)?;
+    let previous_field_name = index
+      .checked_sub(1)
+      .and_then(|previous| fields[previous].child_by_field_name("declarator"))
+      .and_then(|declarator| declarator.child_by_field_name("name"))
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node));
+    let position = match previous_field_name {
+      Some(name) => FieldInsertionPosition::AfterField(name.to_string()),
+      None if fields.len() > 1 => FieldInsertionPosition::BeforeFirstField,
+      None => FieldInsertionPosition::EndOfClassBody,
+    };
+    let (start_byte, end_byte) = member_removal_range(
+      &ts_file.source_code,
+      leading_comments_start_byte(field_node),
+      field_node.end_byte(),
+    );
+    (start_byte, end_byte, position)
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(position)
+}
+
+/// Resolves where a new field goes, like [`resolve_field_insertion_position`]. With a
+/// `generated_marker`, a field of the same name marked by an earlier run is removed first and
+/// its replacement takes its place, unless `after_field` or `at_top` ask for another one.
+pub fn prepare_field_insertion(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_name: &str,
+  after_field: Option<&str>,
+  at_top: bool,
+  generated_marker: Option<&GeneratedStyle>,
+) -> Result<FieldInsertionPosition, String> {
+  ensure_class_has_no_syntax_errors(ts_file, class_declaration_byte_position)?;
+  let insertion_position = resolve_field_insertion_position(
+    ts_file,
+    class_declaration_byte_position,
+    after_field,
+    at_top,
+  )?;
+  if generated_marker.is_none() {
+    return Ok(insertion_position);
+  }
+  match remove_generated_field(ts_file, class_declaration_byte_position, field_name) {
+    Some(previous_position) if after_field.is_none() && !at_top => Ok(previous_position),
+    _ => Ok(insertion_position),
+  }
+}
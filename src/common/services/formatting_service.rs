@@ -0,0 +1,124 @@
+use std::cell::Cell;
+
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+thread_local! {
+  static MEMBER_SPACING: Cell<usize> = const { Cell::new(1) };
+}
+
+/// Sets how many blank lines separate an inserted field or method from its neighbors, as set by
+/// the global `--member-spacing` option. The setting is kept per thread, so it only affects the
+/// command running on the calling thread.
+pub fn set_member_spacing(blank_lines: usize) {
+  MEMBER_SPACING.with(|member_spacing| member_spacing.set(blank_lines));
+}
+
+pub fn get_member_spacing() -> usize {
+  MEMBER_SPACING.with(Cell::get)
+}
+
+/// The whitespace placed between an inserted member and the member next to it: the line break
+/// ending the first one followed by `--member-spacing` blank lines.
+pub fn member_separator() -> String {
+  "\n".repeat(get_member_spacing() + 1)
+}
+
+/// Node kinds whose children are type members separated by blank lines.
+const MEMBER_CONTAINER_KINDS: [&str; 4] =
+  ["class_body", "interface_body", "enum_body_declarations", "annotation_type_body"];
+
+const COMMENT_KINDS: [&str; 2] = ["line_comment", "block_comment"];
+
+/// How many blank lines a whitespace gap between two siblings should contain.
+enum BlankLines {
+  /// Keep the current count, capped at one.
+  AtMostOne,
+  /// Always exactly one.
+  ExactlyOne,
+}
+
+fn is_comment(node: &Node) -> bool {
+  COMMENT_KINDS.contains(&node.kind())
+}
+
+/// Rewrites the whitespace `gap` so it holds the wanted number of blank lines while keeping the
+/// indentation of the following line and the line ending style of the file. Returns `None` when
+/// the gap is already normalized or does not span lines.
+fn normalize_gap(gap: &str, blank_lines: &BlankLines) -> Option<String> {
+  let newline_count = gap.matches('\n').count();
+  if newline_count == 0 {
+    return None;
+  }
+  let line_ending = if gap.contains("\r\n") { "\r\n" } else { "\n" };
+  let indentation = &gap[gap.rfind('\n').map(|pos| pos + 1).unwrap_or(0)..];
+  let wanted_blank_lines = match blank_lines {
+    BlankLines::AtMostOne => (newline_count - 1).min(1),
+    BlankLines::ExactlyOne => 1,
+  };
+  let normalized = format!("{}{}", line_ending.repeat(wanted_blank_lines + 1), indentation);
+  if normalized == gap { None } else { Some(normalized) }
+}
+
+/// Collects the `(start, end, replacement)` edits normalizing the gaps between the children of
+/// `container_node`. The gaps after `{` and before `}` keep at most one blank line, members are
+/// separated by exactly one, and a comment stays attached to the member it documents.
+fn collect_container_edits(
+  ts_file: &TSFile,
+  container_node: Node,
+  edits: &mut Vec<(usize, usize, String)>,
+) {
+  let mut cursor = container_node.walk();
+  let children: Vec<Node> = container_node.children(&mut cursor).collect();
+  for pair in children.windows(2) {
+    let (previous, next) = (pair[0], pair[1]);
+    let Some(gap) = ts_file.get_text_from_range(previous.end_byte(), next.start_byte()) else {
+      continue;
+    };
+    if !gap.trim().is_empty() {
+      continue;
+    }
+    let blank_lines = if !previous.is_named() || !next.is_named() || is_comment(&previous) {
+      BlankLines::AtMostOne
+    } else {
+      BlankLines::ExactlyOne
+    };
+    if let Some(normalized) = normalize_gap(gap, &blank_lines) {
+      edits.push((previous.end_byte(), next.start_byte(), normalized));
+    }
+  }
+}
+
+/// Normalizes blank lines inside every class, interface, enum and annotation body of the file,
+/// nested ones included: no more than one consecutive blank line and exactly one blank line
+/// between members. Returns whether the source was changed.
+pub fn normalize_blank_lines(ts_file: &mut TSFile) -> bool {
+  if ts_file.tree.is_none() {
+    return false;
+  }
+  let mut edits = Vec::new();
+  {
+    let query_string = format!(
+      "[{}] @container",
+      MEMBER_CONTAINER_KINDS.iter().map(|kind| format!("({})", kind)).collect::<Vec<_>>().join(" ")
+    );
+    let container_nodes =
+      match ts_file.query_builder(&query_string).returning("container").execute() {
+        Ok(result) => result.nodes(),
+        Err(_) => Vec::new(),
+      };
+    for container_node in container_nodes {
+      collect_container_edits(ts_file, container_node, &mut edits);
+    }
+  }
+  if edits.is_empty() {
+    return false;
+  }
+  // Apply from the end of the file so earlier byte ranges stay valid
+  edits.sort_by_key(|edit| std::cmp::Reverse(edit.0));
+  edits.dedup_by(|a, b| a.0 == b.0);
+  for (start_byte, end_byte, new_text) in edits {
+    ts_file.replace_text_by_range(start_byte, end_byte, &new_text);
+  }
+  true
+}
@@ -3,8 +3,25 @@
 use crate::common::services::package_declaration_service::get_package_declaration_node;
 use crate::common::ts_file::TSFile;
 use crate::common::types::import_types::{ImportInsertionPoint, ImportInsertionPosition};
+use std::cell::Cell;
+use std::collections::HashSet;
 use tree_sitter::Node;
 
+thread_local! {
+  static ORGANIZE_IMPORTS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enables sorting and de-duplicating the imports of every edited file right before it is saved,
+/// as set by the global `--organize-imports` option. The setting is kept per thread, so it only
+/// affects the command running on the calling thread.
+pub fn set_organize_imports(organize_imports: bool) {
+  ORGANIZE_IMPORTS.with(|cell| cell.set(organize_imports));
+}
+
+pub fn is_organize_imports() -> bool {
+  ORGANIZE_IMPORTS.with(Cell::get)
+}
+
 impl ImportInsertionPoint {
   fn new() -> Self {
     Self {
@@ -175,6 +192,7 @@ pub fn add_import<'a>(
   if find_import_declaration_node(ts_file, import_package_scope, import_class).is_some() {
     return None;
   }
+  tracing::debug!(package = import_package_scope, class = import_class, "adding import");
   // Get the root node to work with the entire file
   let root_node = ts_file.tree.as_ref()?.root_node();
   let file_content = ts_file.get_text_from_node(&root_node)?.to_string();
@@ -229,6 +247,33 @@ pub fn add_import<'a>(
         import_insertion_point.insert_byte = 0;
       }
     }
+    ImportInsertionPosition::Alphabetical => {
+      let new_import = format!("{}.{}", import_package_scope, import_class);
+      let sorted_imports: Vec<(String, Node)> = all_imports
+        .iter()
+        .filter_map(|import_node| {
+          let text = ts_file.get_text_from_node(import_node)?;
+          let name = text.strip_prefix("import")?.trim().trim_end_matches(';').trim();
+          if name.starts_with("static ") { None } else { Some((name.to_string(), *import_node)) }
+        })
+        .collect();
+      if let Some((_, next_import)) = sorted_imports.iter().find(|(name, _)| *name > new_import) {
+        import_insertion_point.break_line_after = true;
+        import_insertion_point.insert_byte = next_import.start_byte();
+      } else if let Some((_, last_import)) = sorted_imports.last() {
+        import_insertion_point.break_line_before = true;
+        import_insertion_point.insert_byte = last_import.end_byte();
+      } else if let Some(package_node) = package_declaration_node {
+        // No non-static imports but package exists, insert after package
+        import_insertion_point.break_line_before = true;
+        import_insertion_point.break_line_after = true;
+        import_insertion_point.insert_byte = package_node.end_byte();
+      } else {
+        // No package and no imports, insert at beginning
+        import_insertion_point.break_line_after = true;
+        import_insertion_point.insert_byte = 0;
+      }
+    }
   }
   // Build the new content string with proper formatting
   let new_content = {
@@ -246,3 +291,163 @@ pub fn add_import<'a>(
   // Replace the entire file content with the new content
   ts_file.replace_text_by_byte_range(0, file_content.len(), &new_content)
 }
+
+/// Whether the import has the `static` keyword or ends in `.*`, going by its nodes rather than
+/// its text so packages such as `com.acme.staticdata` are not mistaken for either.
+pub fn is_static_or_wildcard_import(import_node: Node) -> bool {
+  let mut cursor = import_node.walk();
+  import_node.children(&mut cursor).any(|child| matches!(child.kind(), "static" | "asterisk"))
+}
+
+/// Removes the single-type import `import <package_scope>.<class_name>;`, including its line
+/// break. Wildcard imports are never removed since other classes may rely on them.
+pub fn remove_import(ts_file: &mut TSFile, package_scope: &str, class_name: &str) -> Option<()> {
+  ts_file.tree.as_ref()?;
+  let full_import = format!("{}.{}", package_scope, class_name);
+  let (start_byte, end_byte) = {
+    let import_declaration_node =
+      get_all_import_declaration_nodes(ts_file).into_iter().find(|import_node| {
+        get_import_declaration_full_import_scope_node(ts_file, *import_node)
+          .and_then(|scope_node| ts_file.get_text_from_node(&scope_node))
+          .is_some_and(|text| text == full_import)
+          && !is_static_or_wildcard_import(*import_node)
+      })?;
+    let end_byte = import_declaration_node.end_byte();
+    let line_break = ts_file.source_code[end_byte..].starts_with('\n') as usize;
+    (import_declaration_node.start_byte(), end_byte + line_break)
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(())
+}
+
+/// Checks whether `class_name` is referenced anywhere in the file outside import declarations.
+pub fn is_class_name_referenced(ts_file: &TSFile, class_name: &str) -> bool {
+  if ts_file.tree.is_none() || class_name.trim().is_empty() {
+    return false;
+  }
+  let query_string = format!(
+    r#"
+        (
+          [
+            (identifier) @reference
+            (type_identifier) @reference
+          ]
+          (#eq? @reference "{}")
+        )
+        "#,
+    class_name
+  );
+  let references = match ts_file.query_builder(&query_string).returning("reference").execute() {
+    Ok(result) => result.nodes(),
+    Err(_) => Vec::new(),
+  };
+  references.iter().any(|node| {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+      if parent.kind() == "import_declaration" {
+        return false;
+      }
+      current = parent.parent();
+    }
+    true
+  })
+}
+
+/// Finds the package scope a class is imported from through a single-type import.
+pub fn find_imported_package_scope(ts_file: &TSFile, class_name: &str) -> Option<String> {
+  get_all_import_declaration_nodes(ts_file).into_iter().find_map(|import_node| {
+    let class_name_node = get_import_declaration_class_name_node(ts_file, import_node)?;
+    if ts_file.get_text_from_node(&class_name_node)? != class_name {
+      return None;
+    }
+    let scope_node = get_import_declaration_relative_import_scope_node(ts_file, import_node)?;
+    ts_file.get_text_from_node(&scope_node).map(|s| s.to_string())
+  })
+}
+
+/// Import name as written after `import`, without the trailing `;` and with whitespace removed,
+/// e.g. `static org.junit.Assert.*` or `java.util.List`.
+fn get_normalized_import_name(ts_file: &TSFile, import_node: &Node) -> Option<String> {
+  let text = ts_file.get_text_from_node(import_node)?;
+  let name = text.strip_prefix("import")?.trim().trim_end_matches(';').trim();
+  match name.strip_prefix("static") {
+    Some(rest) if rest.starts_with(char::is_whitespace) => {
+      Some(format!("static {}", rest.split_whitespace().collect::<String>()))
+    }
+    _ => Some(name.split_whitespace().collect()),
+  }
+}
+
+/// Removes every import declaration that repeats an earlier one, including its line break.
+/// Returns the number of removed imports.
+pub fn deduplicate_imports(ts_file: &mut TSFile) -> usize {
+  let duplicate_ranges: Vec<(usize, usize)> = {
+    let mut seen = HashSet::new();
+    get_all_import_declaration_nodes(ts_file)
+      .into_iter()
+      .filter(|import_node| {
+        get_normalized_import_name(ts_file, import_node).is_some_and(|name| !seen.insert(name))
+      })
+      .map(|import_node| {
+        let end_byte = import_node.end_byte();
+        let line_break = ts_file.source_code[end_byte..].starts_with('\n') as usize;
+        (import_node.start_byte(), end_byte + line_break)
+      })
+      .collect()
+  };
+  // Remove from the bottom up so the remaining byte ranges stay valid
+  for (start_byte, end_byte) in duplicate_ranges.iter().rev() {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, "");
+  }
+  duplicate_ranges.len()
+}
+
+/// Byte range of the import block along with its sorted replacement, or `None` when the file
+/// has no imports or they are interleaved with comments or other declarations.
+fn build_organized_import_block(ts_file: &TSFile) -> Option<(usize, usize, String)> {
+  let tree = ts_file.tree.as_ref()?;
+  let import_nodes = get_all_import_declaration_nodes(ts_file);
+  let (first, last) = (import_nodes.first()?, import_nodes.last()?);
+  let (start_byte, end_byte) = (first.start_byte(), last.end_byte());
+  let mut cursor = tree.root_node().walk();
+  let interleaved = tree.root_node().children(&mut cursor).any(|child| {
+    child.start_byte() >= start_byte
+      && child.end_byte() <= end_byte
+      && child.kind() != "import_declaration"
+  });
+  if interleaved {
+    return None;
+  }
+  let mut names: Vec<String> = import_nodes
+    .iter()
+    .map(|import_node| get_normalized_import_name(ts_file, import_node))
+    .collect::<Option<_>>()?;
+  names.sort();
+  names.dedup();
+  let (static_names, names): (Vec<String>, Vec<String>) =
+    names.into_iter().partition(|name| name.starts_with("static "));
+  let render = |names: &[String]| {
+    names.iter().map(|name| format!("import {};", name)).collect::<Vec<_>>().join("\n")
+  };
+  let organized = match (static_names.is_empty(), names.is_empty()) {
+    (false, false) => format!("{}\n\n{}", render(&static_names), render(&names)),
+    (true, _) => render(&names),
+    (false, true) => render(&static_names),
+  };
+  Some((start_byte, end_byte, organized))
+}
+
+/// Rewrites the import block sorted alphabetically, static imports first and separated from
+/// the others by a blank line. Duplicates are dropped. Files whose imports are interleaved with
+/// comments or other declarations are left untouched. Returns whether the file changed.
+pub fn organize_imports(ts_file: &mut TSFile) -> bool {
+  let Some((start_byte, end_byte, organized)) = build_organized_import_block(ts_file) else {
+    return false;
+  };
+  if ts_file.source_code[start_byte..end_byte] == organized {
+    return false;
+  }
+  tracing::debug!("organizing imports");
+  ts_file.replace_text_by_range(start_byte, end_byte, &organized);
+  true
+}
@@ -3,7 +3,9 @@ pub mod annotation_type_declaration_service;
 pub mod class_declaration_service;
 pub mod enum_declaration_service;
 pub mod field_declaration_service;
+pub mod formatting_service;
 pub mod import_declaration_service;
 pub mod interface_declaration_service;
 pub mod package_declaration_service;
 pub mod record_declaration_service;
+pub mod relationship_field_service;
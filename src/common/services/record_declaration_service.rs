@@ -46,6 +46,14 @@ pub fn get_public_record_node<'a>(ts_file: &'a TSFile) -> Option<Node<'a>> {
   }
 }
 
+/// Whether the public type of a file is a top-level record, rather than a record nested in a
+/// class. Records can't be JPA entities, so the entity scanners skip these files.
+pub fn is_record_file(ts_file: &TSFile) -> bool {
+  get_public_record_node(ts_file)
+    .and_then(|node| node.parent())
+    .is_some_and(|parent| parent.kind() == "program")
+}
+
 pub fn get_record_name_node<'a>(
   ts_file: &'a TSFile,
   record_declaration_node: Node<'a>,
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use crate::common::services::class_declaration_service::get_public_class_node;
+use crate::common::services::field_declaration_service::{
+  ensure_class_has_no_syntax_errors, ensure_field_name_available, ensure_maps_id_supported,
+};
+use crate::common::ts_file::TSFile;
+
+/// Fails before any file is written when the owning entity, or the inverse entity of a
+/// bidirectional mapping, has syntax errors or already declares the field to be added, naming
+/// the side and file that conflicts, or when `@MapsId` is requested on an owning entity without
+/// an identifier to derive.
+pub fn ensure_relationship_fields_available(
+  owning_side_entity_file_b64_src: &str,
+  owning_side_entity_file_path: &Path,
+  owning_side_field_name: &str,
+  inverse_side: Option<(&Path, &str)>,
+  maps_id: Option<&Option<String>>,
+) -> Result<(), String> {
+  let mut sides = vec![(
+    TSFile::from_base64_source_code(owning_side_entity_file_b64_src),
+    owning_side_field_name,
+    "owning",
+    owning_side_entity_file_path,
+  )];
+  if let Some((inverse_entity_file_path, inverse_side_field_name)) = inverse_side {
+    sides.push((
+      TSFile::from_file(inverse_entity_file_path)
+        .map_err(|_| "Unable to parse Entity file".to_string())?,
+      inverse_side_field_name,
+      "inverse",
+      inverse_entity_file_path,
+    ));
+  }
+  for (entity_ts_file, field_name, side, entity_file_path) in &sides {
+    if let Some(public_class_node) = get_public_class_node(entity_ts_file) {
+      ensure_class_has_no_syntax_errors(entity_ts_file, public_class_node.start_byte())?;
+      ensure_field_name_available(entity_ts_file, public_class_node.start_byte(), field_name)
+        .map_err(|e| format!("{} on the {} side ({})", e, side, entity_file_path.display()))?;
+    }
+  }
+  let owning_entity_ts_file = &sides[0].0;
+  if let Some(maps_id) = maps_id
+    && let Some(public_class_node) = get_public_class_node(owning_entity_ts_file)
+  {
+    ensure_maps_id_supported(
+      owning_entity_ts_file,
+      public_class_node.start_byte(),
+      maps_id.as_deref(),
+    )?;
+  }
+  Ok(())
+}
@@ -1,27 +1,49 @@
 #![allow(dead_code)]
 
-use crate::common::{query::TSQueryBuilder, utils::path_security_util::PathSecurityValidator};
+use crate::common::{
+  query::{TSQueryBuilder, get_or_compile_query},
+  services::import_declaration_service,
+  utils::{
+    file_util::{self, atomic_write},
+    path_security_util::PathSecurityValidator,
+    timing_util::{TimingPhase, measure},
+  },
+};
+use crate::responses::edit_record_response::EditRecord;
 use base64::Engine;
+use std::cell::Cell;
 use std::fs;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
-use tree_sitter::{
-  InputEdit, Language, Node, Parser, Point, Query, QueryCursor, StreamingIterator, Tree,
-};
+use tree_sitter::{InputEdit, Language, Node, Parser, Point, QueryCursor, StreamingIterator, Tree};
+
+thread_local! {
+  static LOADED_FILES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Number of files read and parsed by [`TSFile::from_file`] on the calling thread.
+pub fn loaded_file_count() -> usize {
+  LOADED_FILES.with(Cell::get)
+}
 
 pub struct TSFile {
   pub language: Language,
   parser: Parser,
   new_path: Option<PathBuf>,
   modified: bool,
+  /// Set by `apply_edit` until `reparse` brings the tree back in sync with the source
+  tree_dirty: bool,
   pub file: Option<PathBuf>,
   pub tree: Option<Tree>,
   pub source_code: String,
+  original_source_code: String,
 }
 
 impl TSFile {
   fn set_data(&mut self, source_code: &str) {
     self.tree = self.parser.parse(source_code, None);
     self.source_code = source_code.to_string();
+    self.tree_dirty = false;
   }
 
   /// Calculate new position after text replacement
@@ -116,15 +138,17 @@ impl TSFile {
         Err(_) => "Invalid source code".to_string(),
       };
     parser.set_language(&language.into()).expect("Error loading Java parser");
-    let tree = parser.parse(&converted_source_code, None);
+    let tree = measure(TimingPhase::Parse, || parser.parse(&converted_source_code, None));
     TSFile {
       language: language.into(),
       parser,
       file: None,
       tree,
+      original_source_code: converted_source_code.clone(),
       source_code: converted_source_code,
       new_path: None,
       modified: false,
+      tree_dirty: false,
     }
   }
 
@@ -132,32 +156,37 @@ impl TSFile {
     let mut parser = Parser::new();
     let language = tree_sitter_java::LANGUAGE;
     parser.set_language(&language.into()).expect("Error loading Java parser");
-    let tree = parser.parse(source_code, None);
+    let tree = measure(TimingPhase::Parse, || parser.parse(source_code, None));
     TSFile {
       language: language.into(),
       parser,
       file: None,
       tree,
       source_code: source_code.to_string(),
+      original_source_code: source_code.to_string(),
       new_path: None,
       modified: false,
+      tree_dirty: false,
     }
   }
 
   pub fn from_file(path: &Path) -> std::io::Result<Self> {
-    let source_code = fs::read_to_string(path)?;
+    let source_code = measure(TimingPhase::Scan, || fs::read_to_string(path))?;
+    LOADED_FILES.with(|cell| cell.set(cell.get() + 1));
     let mut parser = Parser::new();
     let language = tree_sitter_java::LANGUAGE;
     parser.set_language(&language.into()).expect("Error loading Java parser");
-    let tree = parser.parse(&source_code, None);
+    let tree = measure(TimingPhase::Parse, || parser.parse(&source_code, None));
     Ok(TSFile {
       language: language.into(),
       parser,
       file: Some(path.to_path_buf()),
       tree,
+      original_source_code: source_code.clone(),
       source_code,
       new_path: None,
       modified: false,
+      tree_dirty: false,
     })
   }
 
@@ -204,17 +233,29 @@ impl TSFile {
     self.apply_incremental_edit(position, position, text);
   }
 
+  /// Sorts and de-duplicates the imports of an edited file under `--organize-imports`
+  fn organize_imports_before_save(&mut self) {
+    if !import_declaration_service::is_organize_imports()
+      || self.source_code == self.original_source_code
+    {
+      return;
+    }
+    import_declaration_service::deduplicate_imports(self);
+    import_declaration_service::organize_imports(self);
+  }
+
   /// Save to original file path
   pub fn save(&mut self) -> std::io::Result<()> {
     let file = self.file.as_ref().ok_or_else(|| {
       std::io::Error::other("File path is not set. Use save_as(path, base_path) instead.")
     })?;
     if let Some(new_path) = &self.new_path {
-      fs::rename(file, new_path)?;
+      file_util::rename(file, new_path)?;
       self.file = Some(new_path.clone());
       self.new_path = None;
     }
-    fs::write(self.file.as_ref().unwrap(), &self.source_code)?;
+    self.organize_imports_before_save();
+    atomic_write(self.file.as_ref().unwrap(), &self.source_code)?;
     self.modified = false;
     Ok(())
   }
@@ -263,9 +304,10 @@ impl TSFile {
     // Save using the validated path
     // Create parent directories if they don't exist
     if let Some(parent) = validated_path.parent() {
-      fs::create_dir_all(parent)?;
+      file_util::create_dir_all(parent)?;
     }
-    fs::write(&validated_path, &self.source_code)?;
+    self.organize_imports_before_save();
+    atomic_write(&validated_path, &self.source_code)?;
     self.file = Some(validated_path);
     self.modified = false;
     Ok(())
@@ -314,9 +356,10 @@ impl TSFile {
     }
     // Create parent directories if they don't exist
     if let Some(parent) = path.parent() {
-      fs::create_dir_all(parent)?;
+      file_util::create_dir_all(parent)?;
     }
-    fs::write(path, &self.source_code)?;
+    self.organize_imports_before_save();
+    atomic_write(path, &self.source_code)?;
     self.file = Some(path.to_path_buf());
     self.modified = false;
     Ok(())
@@ -366,6 +409,33 @@ impl TSFile {
     self.modified
   }
 
+  /// Describe every change made since the file was loaded as a minimal replacement
+  /// against the original source, so a client can undo it without reparsing.
+  /// Returns an empty list when the source is unchanged.
+  pub fn edit_records(&self) -> Vec<EditRecord> {
+    let old = self.original_source_code.as_str();
+    let new = self.source_code.as_str();
+    if old == new {
+      return Vec::new();
+    }
+    let mut prefix = old.bytes().zip(new.bytes()).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+      prefix -= 1;
+    }
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix =
+      old.bytes().rev().zip(new.bytes().rev()).take(max_suffix).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+      suffix -= 1;
+    }
+    vec![EditRecord {
+      start_byte: prefix,
+      old_end_byte: old.len() - suffix,
+      new_text: new[prefix..new.len() - suffix].to_string(),
+      old_text: old[prefix..old.len() - suffix].to_string(),
+    }]
+  }
+
   /// Get the file path (if set)
   pub fn file_path(&self) -> Option<&PathBuf> {
     self.file.as_ref()
@@ -381,7 +451,7 @@ impl TSFile {
   /// Returns a vector of nodes from all captures in all matches
   pub fn query(&self, query_string: &str) -> Result<Vec<Node<'_>>, Box<dyn std::error::Error>> {
     let tree = self.tree.as_ref().ok_or("No parsed tree available")?;
-    let query = Query::new(&self.language, query_string)?;
+    let query = get_or_compile_query(&self.language, query_string)?;
     let mut cursor = QueryCursor::new();
     let root_node = tree.root_node();
     let mut nodes = Vec::new();
@@ -395,7 +465,7 @@ impl TSFile {
   }
 
   /// Convert byte position to line/column (0-based for tree-sitter)
-  fn byte_position_to_point(&self, byte_position: usize) -> Point {
+  pub fn byte_position_to_point(&self, byte_position: usize) -> Point {
     let mut row = 0;
     let mut col = 0;
     for (i, ch) in self.source_code.char_indices() {
@@ -412,16 +482,15 @@ impl TSFile {
     Point::new(row, col)
   }
 
-  /// Perform incremental update for any text modification
-  /// This is the core method that all text modifications should use for consistency and performance
-  fn apply_incremental_edit(&mut self, start_byte: usize, end_byte: usize, new_text: &str) -> bool {
+  /// Tell the tree about an edit and apply it to the source, without reparsing
+  /// Returns false when there is no tree to edit
+  fn edit_tree_and_source(&mut self, start_byte: usize, end_byte: usize, new_text: &str) -> bool {
     // Calculate positions before borrowing tree mutably
     let start_position = self.byte_position_to_point(start_byte);
     let old_end_position = self.byte_position_to_point(end_byte);
     let new_end_position = self.calculate_new_position(start_byte, new_text);
     let new_end_byte = start_byte + new_text.len();
-
-    if let Some(tree) = &mut self.tree {
+    let has_tree = if let Some(tree) = &mut self.tree {
       // Create edit descriptor for tree-sitter
       let edit = InputEdit {
         start_byte,
@@ -431,24 +500,100 @@ impl TSFile {
         old_end_position,
         new_end_position,
       };
-
       // Tell tree about the edit BEFORE changing source
       tree.edit(&edit);
-
-      // Apply the text change
-      self.source_code.replace_range(start_byte..end_byte, new_text);
-
-      // Incremental re-parse (much faster than full reparse!)
-      self.tree = self.parser.parse(&self.source_code, Some(tree));
-      self.modified = true;
       true
     } else {
-      // Fallback for when there's no tree (shouldn't happen in normal usage)
-      self.source_code.replace_range(start_byte..end_byte, new_text);
-      self.tree = self.parser.parse(&self.source_code, None);
-      self.modified = true;
       false
+    };
+    // Apply the text change
+    self.source_code.replace_range(start_byte..end_byte, new_text);
+    self.modified = true;
+    has_tree
+  }
+
+  /// Perform incremental update for any text modification
+  /// This is the core method that all text modifications should use for consistency and performance
+  fn apply_incremental_edit(&mut self, start_byte: usize, end_byte: usize, new_text: &str) -> bool {
+    let has_tree = self.edit_tree_and_source(start_byte, end_byte, new_text);
+    // Incremental re-parse (much faster than full reparse!), also flushing pending `apply_edit`s.
+    // Without a tree (shouldn't happen in normal usage) this falls back to a full parse.
+    self.tree = self.parser.parse(&self.source_code, self.tree.as_ref());
+    self.tree_dirty = false;
+    has_tree
+  }
+
+  /// Apply an edit without reparsing, for callers batching several edits
+  ///
+  /// Byte offsets refer to the source as left by the previous edits. The tree is adjusted
+  /// but not reparsed, so node kinds and ranges are stale until `reparse` is called.
+  ///
+  /// # Returns
+  /// An error when the range is out of bounds or splits a character
+  pub fn apply_edit(
+    &mut self,
+    start_byte: usize,
+    old_end_byte: usize,
+    new_text: &str,
+  ) -> Result<(), String> {
+    if start_byte > old_end_byte || old_end_byte > self.source_code.len() {
+      return Err(format!(
+        "Invalid edit range {}..{} for a source of {} bytes",
+        start_byte,
+        old_end_byte,
+        self.source_code.len()
+      ));
+    }
+    if !self.source_code.is_char_boundary(start_byte)
+      || !self.source_code.is_char_boundary(old_end_byte)
+    {
+      return Err(format!("Edit range {}..{} splits a character", start_byte, old_end_byte));
+    }
+    self.edit_tree_and_source(start_byte, old_end_byte, new_text);
+    self.tree_dirty = true;
+    Ok(())
+  }
+
+  /// Reparse once after a batch of `apply_edit` calls, reusing the unchanged parts of the tree
+  /// Does nothing when no edit is pending
+  pub fn reparse(&mut self) {
+    if !self.tree_dirty {
+      return;
+    }
+    self.tree = self.parser.parse(&self.source_code, self.tree.as_ref());
+    self.tree_dirty = false;
+  }
+
+  /// Whether edits were applied with `apply_edit` since the last parse
+  pub fn is_tree_dirty(&self) -> bool {
+    self.tree_dirty
+  }
+
+  /// Whether the parsed tree contains `ERROR` or `MISSING` nodes
+  pub fn has_errors(&self) -> bool {
+    self.tree.as_ref().is_some_and(|tree| tree.root_node().has_error())
+  }
+
+  /// Byte ranges of the `ERROR` and `MISSING` nodes in source order. The content of an `ERROR`
+  /// node is not searched further, and a `MISSING` node has an empty range at the point where
+  /// the parser expected it.
+  pub fn error_ranges(&self) -> Vec<Range<usize>> {
+    let Some(tree) = &self.tree else {
+      return Vec::new();
+    };
+    let mut ranges = Vec::new();
+    let mut pending = vec![tree.root_node()];
+    while let Some(node) = pending.pop() {
+      if node.is_error() || node.is_missing() {
+        ranges.push(node.byte_range());
+        continue;
+      }
+      let mut cursor = node.walk();
+      // Pushed in reverse so the children are visited in source order
+      let children: Vec<Node> = node.children(&mut cursor).filter(Node::has_error).collect();
+      pending.extend(children.into_iter().rev());
     }
+    ranges
   }
 
   /// Find a node by byte position
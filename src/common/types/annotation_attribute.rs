@@ -0,0 +1,7 @@
+/// An attribute of an annotation to add, with its value written as a Java expression, e.g.
+/// `name` and `"users"` for `@Table(name = "users")`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotationAttribute {
+  pub name: String,
+  pub value: String,
+}
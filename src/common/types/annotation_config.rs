@@ -9,7 +9,8 @@ use crate::common::types::other_type::OtherType;
 /// to configure the annotations that will be added to entity fields.
 ///
 /// For OneToOne relationships:
-/// - `fetch_type` and `collection_type` should be `None`
+/// - `fetch_type` is only set on the owning side and `None` leaves the provider default
+/// - `collection_type` should be `None`
 ///
 /// For ManyToOne relationships:
 /// - `fetch_type` and `collection_type` are used for the owning and inverse sides respectively
@@ -21,10 +22,19 @@ pub struct AnnotationConfig {
   pub other_options: Vec<OtherType>,
   pub mapped_by_field: Option<String>,
   pub needs_join_column: bool,
+  pub join_column_name: Option<String>,
+  pub referenced_column_name: Option<String>,
+  /// `@MapsId` on the owning side, with the embedded id attribute it maps when given
+  pub maps_id: Option<Option<String>>,
 
   // Optional fields for ManyToOne relationships
   pub fetch_type: Option<FetchType>,
   pub collection_type: Option<CollectionType>,
+  /// Declared type of the collection when it differs from `collection_type`
+  pub declared_collection_type: Option<CollectionType>,
+  pub initialize_collection: bool,
+  pub order_by: Option<String>,
+  pub order_column: Option<String>,
 }
 
 impl AnnotationConfig {
@@ -35,6 +45,7 @@ impl AnnotationConfig {
     other_options: Vec<OtherType>,
     mapped_by_field: Option<String>,
     needs_join_column: bool,
+    fetch_type: Option<FetchType>,
   ) -> Self {
     Self {
       is_owning_side,
@@ -42,8 +53,15 @@ impl AnnotationConfig {
       other_options,
       mapped_by_field,
       needs_join_column,
-      fetch_type: None,
+      join_column_name: None,
+      referenced_column_name: None,
+      maps_id: None,
+      fetch_type,
       collection_type: None,
+      declared_collection_type: None,
+      initialize_collection: false,
+      order_by: None,
+      order_column: None,
     }
   }
 
@@ -63,11 +81,65 @@ impl AnnotationConfig {
       other_options,
       mapped_by_field,
       needs_join_column,
+      join_column_name: None,
+      referenced_column_name: None,
+      maps_id: None,
       fetch_type: Some(fetch_type),
       collection_type: Some(collection_type),
+      declared_collection_type: None,
+      initialize_collection: false,
+      order_by: None,
+      order_column: None,
     }
   }
 
+  /// Overrides the derived `@JoinColumn` name and sets its `referencedColumnName`
+  pub fn with_join_column_names(
+    mut self,
+    join_column_name: Option<String>,
+    referenced_column_name: Option<String>,
+  ) -> Self {
+    self.join_column_name = join_column_name;
+    self.referenced_column_name = referenced_column_name;
+    self
+  }
+
+  /// Derives the owning entity's id from the relationship with `@MapsId`. The shared key column
+  /// makes the derived `@JoinColumn` wrong, so it is only kept when its name was given explicitly.
+  pub fn with_maps_id(mut self, maps_id: Option<Option<String>>) -> Self {
+    if maps_id.is_some() && self.join_column_name.is_none() {
+      self.needs_join_column = false;
+    }
+    self.maps_id = maps_id;
+    self
+  }
+
+  /// Initializes the collection field inline, e.g. `= new HashSet<>()`
+  pub fn with_collection_initializer(mut self, initialize_collection: bool) -> Self {
+    self.initialize_collection = initialize_collection;
+    self
+  }
+
+  /// Declares the collection field as `declared_collection_type` instead of the collection type
+  pub fn with_declared_collection_type(
+    mut self,
+    declared_collection_type: Option<CollectionType>,
+  ) -> Self {
+    self.declared_collection_type = declared_collection_type;
+    self
+  }
+
+  /// Orders the collection with `@OrderBy` or persists its order with `@OrderColumn`
+  pub fn with_collection_ordering(
+    mut self,
+    order_by: Option<String>,
+    order_column: Option<String>,
+  ) -> Self {
+    self.order_by = order_by;
+    self.order_column = order_column;
+    self
+  }
+
   /// Get the fetch type, or None if not applicable
   pub fn get_fetch_type(&self) -> Option<&FetchType> {
     self.fetch_type.as_ref()
@@ -77,4 +149,9 @@ impl AnnotationConfig {
   pub fn get_collection_type(&self) -> Option<&CollectionType> {
     self.collection_type.as_ref()
   }
+
+  /// Get the declared type of the collection field, which defaults to the collection type
+  pub fn get_declared_collection_type(&self) -> Option<&CollectionType> {
+    self.declared_collection_type.as_ref().or(self.collection_type.as_ref())
+  }
 }
@@ -1,10 +1,18 @@
 #![allow(dead_code)]
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::types::fetch_type::FetchType;
+use crate::common::types::field_access::FieldAccess;
+use crate::common::types::generated_style::GeneratedStyle;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 use crate::common::types::{
   java_field_temporal::JavaFieldTemporal, java_field_time_zone_storage::JavaFieldTimeZoneStorage,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct BasicFieldConfig {
   pub field_name: String,
   pub field_type: String,
@@ -14,7 +22,31 @@ pub struct BasicFieldConfig {
   pub field_scale: Option<u16>,
   pub field_temporal: Option<JavaFieldTemporal>,
   pub field_timezone_storage: Option<JavaFieldTimeZoneStorage>,
+  #[serde(default)]
   pub field_unique: bool,
+  #[serde(default)]
   pub field_nullable: bool,
+  #[serde(default)]
   pub field_large_object: bool,
+  pub basic_fetch: Option<FetchType>,
+  #[serde(default)]
+  pub field_natural_id: bool,
+  pub field_converter_class: Option<String>,
+  #[serde(default)]
+  pub column_naming_strategy: ColumnNamingStrategy,
+  #[serde(default)]
+  pub explicit_column_name: bool,
+  #[serde(default)]
+  pub field_access: FieldAccess,
+  pub after_field: Option<String>,
+  #[serde(default)]
+  pub at_top: bool,
+  pub javadoc: Option<String>,
+  pub mark_generated: Option<GeneratedStyle>,
+  pub default_value: Option<String>,
+  pub column_definition: Option<String>,
+  #[serde(default)]
+  pub persistence_namespace: PersistenceNamespace,
+  #[serde(default)]
+  pub format_java: bool,
 }
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildToolType {
+  Maven,
+  Gradle,
+  GradleKotlin,
+  Unknown,
+}
+
+impl BuildToolType {
+  /// Detect the build tool of a project by looking for its build file in `cwd`
+  pub fn detect(cwd: &Path) -> Self {
+    if cwd.join("pom.xml").is_file() {
+      BuildToolType::Maven
+    } else if cwd.join("build.gradle").is_file() {
+      BuildToolType::Gradle
+    } else if cwd.join("build.gradle.kts").is_file() {
+      BuildToolType::GradleKotlin
+    } else {
+      BuildToolType::Unknown
+    }
+  }
+
+  /// Get the build file name for this build tool
+  pub fn get_build_file_name(&self) -> Option<&'static str> {
+    match self {
+      BuildToolType::Maven => Some("pom.xml"),
+      BuildToolType::Gradle => Some("build.gradle"),
+      BuildToolType::GradleKotlin => Some("build.gradle.kts"),
+      BuildToolType::Unknown => None,
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      BuildToolType::Maven => "maven",
+      BuildToolType::Gradle => "gradle",
+      BuildToolType::GradleKotlin => "gradle-kotlin",
+      BuildToolType::Unknown => "unknown",
+    }
+  }
+}
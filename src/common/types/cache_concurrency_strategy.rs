@@ -0,0 +1,35 @@
+use clap::ValueEnum;
+
+/// Hibernate second-level cache concurrency strategies, as used by `@Cache(usage = ...)`
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum CacheConcurrencyStrategy {
+  #[value(name = "read_only")]
+  ReadOnly,
+  #[value(name = "nonstrict_read_write")]
+  NonstrictReadWrite,
+  #[value(name = "read_write")]
+  ReadWrite,
+  #[value(name = "transactional")]
+  Transactional,
+}
+
+impl CacheConcurrencyStrategy {
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "read_only" => Ok(CacheConcurrencyStrategy::ReadOnly),
+      "nonstrict_read_write" => Ok(CacheConcurrencyStrategy::NonstrictReadWrite),
+      "read_write" => Ok(CacheConcurrencyStrategy::ReadWrite),
+      "transactional" => Ok(CacheConcurrencyStrategy::Transactional),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      CacheConcurrencyStrategy::ReadOnly => "READ_ONLY",
+      CacheConcurrencyStrategy::NonstrictReadWrite => "NONSTRICT_READ_WRITE",
+      CacheConcurrencyStrategy::ReadWrite => "READ_WRITE",
+      CacheConcurrencyStrategy::Transactional => "TRANSACTIONAL",
+    }
+  }
+}
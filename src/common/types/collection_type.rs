@@ -28,6 +28,14 @@ impl CollectionType {
     }
   }
 
+  /// The `java.util` implementation a collection field is initialized with.
+  pub fn as_java_implementation(&self) -> &'static str {
+    match self {
+      CollectionType::Set => "HashSet",
+      CollectionType::List | CollectionType::Collection => "ArrayList",
+    }
+  }
+
   pub fn as_java_import(&self) -> &'static str {
     match self {
       CollectionType::Set => "java.util.Set",
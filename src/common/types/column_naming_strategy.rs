@@ -0,0 +1,18 @@
+use clap::ValueEnum;
+
+/// Naming strategy applied to the `name` of generated `@Column` annotations.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum ColumnNamingStrategy {
+  /// Use the field name verbatim.
+  #[value(name = "none")]
+  None,
+
+  /// `firstName` becomes `first_name`.
+  #[default]
+  #[value(name = "snake_case")]
+  SnakeCase,
+
+  /// `firstName` becomes `FIRST_NAME`.
+  #[value(name = "upper_snake_case")]
+  UpperSnakeCase,
+}
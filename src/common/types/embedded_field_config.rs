@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use schemars::JsonSchema;
+
+use crate::common::types::field_access::FieldAccess;
+use crate::common::types::generated_style::GeneratedStyle;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+
+/// Maps an attribute of the embeddable to a column of the embedding entity.
+#[derive(Debug, Clone, JsonSchema)]
+pub struct AttributeOverrideConfig {
+  pub attribute_name: String,
+  pub column_name: String,
+}
+
+#[derive(Debug, Clone, JsonSchema)]
+pub struct EmbeddedFieldConfig {
+  pub field_name: String,
+  pub embeddable_type: String,
+  pub embeddable_package_name: String,
+  pub attribute_overrides: Vec<AttributeOverrideConfig>,
+  pub field_access: FieldAccess,
+  pub after_field: Option<String>,
+  pub at_top: bool,
+  pub javadoc: Option<String>,
+  pub mark_generated: Option<GeneratedStyle>,
+  pub persistence_namespace: PersistenceNamespace,
+  pub format_java: bool,
+}
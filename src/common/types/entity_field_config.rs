@@ -0,0 +1,33 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::common::types::basic_field_config::BasicFieldConfig;
+use crate::common::types::enum_field_config::EnumFieldConfig;
+use crate::common::types::id_field_config::IdFieldConfig;
+
+/// One field of a `create-jpa-entity-fields` batch, tagged by its `kind`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EntityFieldConfig {
+  Basic(BasicFieldConfig),
+  Id(IdFieldConfig),
+  Enum(EnumFieldConfig),
+}
+
+impl EntityFieldConfig {
+  pub fn field_name(&self) -> &str {
+    match self {
+      EntityFieldConfig::Basic(field_config) => &field_config.field_name,
+      EntityFieldConfig::Id(field_config) => &field_config.field_name,
+      EntityFieldConfig::Enum(field_config) => &field_config.field_name,
+    }
+  }
+
+  pub fn kind(&self) -> &'static str {
+    match self {
+      EntityFieldConfig::Basic(_) => "basic",
+      EntityFieldConfig::Id(_) => "id",
+      EntityFieldConfig::Enum(_) => "enum",
+    }
+  }
+}
@@ -1,14 +1,39 @@
 #![allow(dead_code)]
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::types::field_access::FieldAccess;
+use crate::common::types::generated_style::GeneratedStyle;
 use crate::common::types::java_enum_type::JavaEnumType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct EnumFieldConfig {
   pub field_name: String,
   pub enum_type: String,
   pub enum_package_name: String,
   pub enum_type_storage: JavaEnumType,
   pub field_length: Option<u16>,
+  #[serde(default)]
   pub field_nullable: bool,
+  #[serde(default)]
   pub field_unique: bool,
+  #[serde(default)]
+  pub column_naming_strategy: ColumnNamingStrategy,
+  #[serde(default)]
+  pub explicit_column_name: bool,
+  #[serde(default)]
+  pub field_access: FieldAccess,
+  pub after_field: Option<String>,
+  #[serde(default)]
+  pub at_top: bool,
+  pub javadoc: Option<String>,
+  pub mark_generated: Option<GeneratedStyle>,
+  pub default_value: Option<String>,
+  #[serde(default)]
+  pub persistence_namespace: PersistenceNamespace,
+  #[serde(default)]
+  pub format_java: bool,
 }
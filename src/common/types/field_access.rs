@@ -0,0 +1,29 @@
+use clap::ValueEnum;
+
+use crate::common::types::java_visibility_modifier::JavaVisibilityModifier;
+
+/// Access modifier of generated entity fields.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum FieldAccess {
+  #[default]
+  #[value(name = "private")]
+  Private,
+
+  /// For fields declared in a `@MappedSuperclass` and used by subclasses.
+  #[value(name = "protected")]
+  Protected,
+
+  /// Package-private, written without a modifier keyword.
+  #[value(name = "package")]
+  Package,
+}
+
+impl FieldAccess {
+  pub fn visibility_modifier(&self) -> JavaVisibilityModifier {
+    match self {
+      FieldAccess::Private => JavaVisibilityModifier::Private,
+      FieldAccess::Protected => JavaVisibilityModifier::Protected,
+      FieldAccess::Package => JavaVisibilityModifier::PackagePrivate,
+    }
+  }
+}
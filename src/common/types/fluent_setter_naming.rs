@@ -0,0 +1,14 @@
+use clap::ValueEnum;
+
+/// Name of the setters generated with `--fluent-accessors`, which return `this`.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum FluentSetterNaming {
+  /// `name(String name)`, named after the field.
+  #[default]
+  #[value(name = "field")]
+  Field,
+
+  /// `setName(String name)`, keeping the JavaBeans prefix.
+  #[value(name = "set")]
+  Set,
+}
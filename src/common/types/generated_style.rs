@@ -0,0 +1,34 @@
+use clap::ValueEnum;
+
+/// How members created with `--mark-generated` are marked, so a later run can replace them
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+pub enum GeneratedStyle {
+  /// `@Generated("syntaxpresso")` from `javax.annotation.processing`
+  #[value(name = "annotation")]
+  Annotation,
+  /// A `// generated by syntaxpresso` line above the member
+  #[value(name = "comment")]
+  Comment,
+}
+
+impl GeneratedStyle {
+  pub const GENERATOR_NAME: &'static str = "syntaxpresso";
+  pub const ANNOTATION_PACKAGE: &'static str = "javax.annotation.processing";
+  pub const MARKER_COMMENT: &'static str = "// generated by syntaxpresso";
+
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "annotation" => Ok(GeneratedStyle::Annotation),
+      "comment" => Ok(GeneratedStyle::Comment),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  /// The line written above the member, without indentation
+  pub fn marker(&self) -> String {
+    match self {
+      GeneratedStyle::Annotation => format!("@Generated(\"{}\")", Self::GENERATOR_NAME),
+      GeneratedStyle::Comment => Self::MARKER_COMMENT.to_string(),
+    }
+  }
+}
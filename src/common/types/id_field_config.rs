@@ -1,10 +1,17 @@
 #![allow(dead_code)]
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::types::field_access::FieldAccess;
+use crate::common::types::generated_style::GeneratedStyle;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 use crate::common::types::{
   java_id_generation::JavaIdGeneration, java_id_generation_type::JavaIdGenerationType,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct IdFieldConfig {
   pub field_name: String,
   pub field_type: String,
@@ -15,5 +22,19 @@ pub struct IdFieldConfig {
   pub field_sequence_name: Option<String>,
   pub field_initial_value: Option<i64>,
   pub field_allocation_size: Option<i64>,
+  #[serde(default)]
   pub field_nullable: bool,
+  #[serde(default)]
+  pub column_naming_strategy: ColumnNamingStrategy,
+  #[serde(default)]
+  pub field_access: FieldAccess,
+  pub after_field: Option<String>,
+  #[serde(default)]
+  pub at_top: bool,
+  pub javadoc: Option<String>,
+  pub mark_generated: Option<GeneratedStyle>,
+  #[serde(default)]
+  pub persistence_namespace: PersistenceNamespace,
+  #[serde(default)]
+  pub format_java: bool,
 }
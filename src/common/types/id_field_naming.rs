@@ -0,0 +1,28 @@
+use clap::ValueEnum;
+
+use crate::common::utils::naming_util;
+
+/// Default name of the id field of an entity.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum IdFieldNaming {
+  /// `id` for every entity.
+  #[default]
+  #[value(name = "id")]
+  Id,
+
+  /// Named after the entity, e.g. `userId` for `User`.
+  #[value(name = "entity_id")]
+  EntityId,
+}
+
+impl IdFieldNaming {
+  /// The id field name for `entity_type`, falling back to `id` when the entity name is unknown.
+  pub fn field_name(&self, entity_type: &str) -> String {
+    match self {
+      IdFieldNaming::EntityId if !entity_type.is_empty() => {
+        format!("{}Id", naming_util::to_camel_case(entity_type))
+      }
+      _ => "id".to_string(),
+    }
+  }
+}
@@ -13,4 +13,6 @@ pub enum ImportInsertionPosition {
   BeforeFirstImport,
   AfterLastImport,
   AfterPackageDeclaration,
+  /// Keeps the non-static imports sorted by inserting the new import in alphabetical order
+  Alphabetical,
 }
@@ -0,0 +1,51 @@
+use clap::ValueEnum;
+
+/// Indentation unit used for generated code
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum IndentStyle {
+  #[value(name = "2")]
+  TwoSpaces,
+  #[value(name = "4")]
+  FourSpaces,
+  #[value(name = "tab")]
+  Tab,
+}
+
+impl IndentStyle {
+  /// Style of the built-in templates, which every generated member is written in
+  pub const TEMPLATE_UNIT: &'static str = "  ";
+
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "2" => Ok(IndentStyle::TwoSpaces),
+      "4" => Ok(IndentStyle::FourSpaces),
+      "tab" => Ok(IndentStyle::Tab),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  /// The text of one indentation level
+  pub fn unit(&self) -> &'static str {
+    match self {
+      IndentStyle::TwoSpaces => "  ",
+      IndentStyle::FourSpaces => "    ",
+      IndentStyle::Tab => "\t",
+    }
+  }
+
+  /// Rewrites the leading indentation of every line of `text` from [`Self::TEMPLATE_UNIT`]
+  /// levels to this style. Spaces left over after the last full level (e.g. the ` *` of a
+  /// Javadoc) are kept as they are.
+  pub fn reindent(&self, text: &str) -> String {
+    text
+      .split_inclusive('\n')
+      .map(|line| {
+        let content = line.trim_start_matches(' ');
+        let leading_spaces = line.len() - content.len();
+        let levels = leading_spaces / Self::TEMPLATE_UNIT.len();
+        let remainder = leading_spaces % Self::TEMPLATE_UNIT.len();
+        format!("{}{}{}", self.unit().repeat(levels), " ".repeat(remainder), content)
+      })
+      .collect()
+  }
+}
@@ -314,12 +314,20 @@ impl JavaBasicType {
     ]
   }
 
+  /// The legacy `java.util` date types, the only ones `@Temporal` applies to
   pub fn get_types_with_temporal(&self) -> Vec<JavaBasicTypeResponse> {
-    vec![JavaBasicTypeResponse {
-      id: "java.util.Calendar".into(),
-      name: "Calendar".into(),
-      package_path: Some("java.util".into()),
-    }]
+    vec![
+      JavaBasicTypeResponse {
+        id: "java.util.Date".into(),
+        name: "Date".into(),
+        package_path: Some("java.util".into()),
+      },
+      JavaBasicTypeResponse {
+        id: "java.util.Calendar".into(),
+        name: "Calendar".into(),
+        package_path: Some("java.util".into()),
+      },
+    ]
   }
 
   pub fn get_types_with_extra_other(&self) -> Vec<JavaBasicTypeResponse> {
@@ -373,6 +381,9 @@ pub enum FieldInsertionPosition {
   AfterLastField,
   BeforeFirstMethod,
   EndOfClassBody,
+  BeforeFirstField,
+  /// Right after the named field, e.g. to keep related columns together.
+  AfterField(String),
 }
 
 #[derive(Debug, Clone)]
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::common::utils::case_util;
+use crate::common::utils::naming_util;
 use clap::ValueEnum;
 
 #[derive(Debug, Clone, PartialEq, ValueEnum)]
@@ -32,7 +32,7 @@ impl JavaFileType {
   /// Generated Java source code as a String
   pub fn get_source_content(&self, package_name: &str, type_name: &str) -> String {
     // Ensure type name is in PascalCase for Java conventions
-    let normalized_type_name = case_util::to_pascal_case(type_name);
+    let normalized_type_name = naming_util::to_pascal_case(type_name);
     match self {
       JavaFileType::Class => {
         format!("package {};\n\npublic class {} {{}}", package_name, normalized_type_name)
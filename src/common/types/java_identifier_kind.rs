@@ -0,0 +1,18 @@
+use clap::ValueEnum;
+
+/// The kind of Java name being validated, since each follows different naming rules.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum JavaIdentifierKind {
+  #[default]
+  #[value(name = "class")]
+  Class,
+
+  #[value(name = "package")]
+  Package,
+
+  #[value(name = "field")]
+  Field,
+
+  #[value(name = "method")]
+  Method,
+}
@@ -22,6 +22,15 @@ impl JavaSourceDirectoryType {
     }
   }
 
+  /// Get the Maven/Gradle source set name for this source type
+  pub fn get_source_set_name(&self) -> Option<&'static str> {
+    match self {
+      JavaSourceDirectoryType::Main => Some("main"),
+      JavaSourceDirectoryType::Test => Some("test"),
+      JavaSourceDirectoryType::All => None,
+    }
+  }
+
   /// Get the Maven/Gradle standard directory structure path
   pub fn get_full_path(&self, base_path: &Path, package_name: &str) -> PathBuf {
     let package_path = package_name.replace('.', "/");
@@ -1,16 +1,33 @@
+use schemars::JsonSchema;
+
 use crate::common::types::{
   cascade_type::CascadeType, collection_type::CollectionType, fetch_type::FetchType,
-  mapping_type::MappingType, other_type::OtherType,
+  mapping_type::MappingType, other_type::OtherType, persistence_namespace::PersistenceNamespace,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema)]
 pub struct ManyToOneFieldConfig {
   pub inverse_field_type: String,
+  /// Package of the inverse entity, needed when several entities share its class name
+  pub inverse_field_package: Option<String>,
   pub fetch_type: FetchType,
   pub collection_type: CollectionType,
+  /// Declared type of the inverse side collection when it differs from `collection_type`, which
+  /// still picks the initializer, e.g. a `Collection` initialized with an `ArrayList`
+  pub declared_collection_type: Option<CollectionType>,
   pub mapping_type: Option<MappingType>,
   pub owning_side_cascades: Vec<CascadeType>,
   pub inverse_side_cascades: Vec<CascadeType>,
   pub owning_side_other: Vec<OtherType>,
   pub inverse_side_other: Vec<OtherType>,
+  pub join_column_name: Option<String>,
+  pub referenced_column_name: Option<String>,
+  /// Adds `@MapsId` to the owning side, sharing the target's primary key; an inner value names
+  /// the attribute of the owning entity's embedded id it maps
+  pub maps_id: Option<Option<String>>,
+  pub initialize_collection: bool,
+  pub order_by: Option<String>,
+  pub order_column: Option<String>,
+  pub persistence_namespace: PersistenceNamespace,
+  pub format_java: bool,
 }
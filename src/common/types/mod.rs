@@ -1,13 +1,24 @@
+pub mod annotation_attribute;
 pub mod annotation_config;
 pub mod annotation_types;
 pub mod basic_field_config;
+pub mod build_tool_type;
+pub mod cache_concurrency_strategy;
 pub mod cascade_type;
 pub mod collection_type;
+pub mod column_naming_strategy;
+pub mod embedded_field_config;
+pub mod entity_field_config;
 pub mod entity_side;
 pub mod enum_field_config;
 pub mod fetch_type;
+pub mod field_access;
+pub mod fluent_setter_naming;
+pub mod generated_style;
 pub mod id_field_config;
+pub mod id_field_naming;
 pub mod import_types;
+pub mod indent_style;
 pub mod java_basic_types;
 pub mod java_enum_type;
 pub mod java_field_modifier;
@@ -16,10 +27,16 @@ pub mod java_field_time_zone_storage;
 pub mod java_file_type;
 pub mod java_id_generation;
 pub mod java_id_generation_type;
+pub mod java_identifier_kind;
 pub mod java_source_directory_type;
 pub mod java_visibility_modifier;
 pub mod many_to_one_field_config;
 pub mod mapping_type;
+pub mod name_case;
 pub mod one_to_one_field_config;
 pub mod other_type;
+pub mod persistence_namespace;
 pub mod processed_imports;
+pub mod project_module;
+pub mod relationship_kind;
+pub mod text_edit;
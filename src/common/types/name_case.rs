@@ -0,0 +1,26 @@
+use clap::ValueEnum;
+
+/// Target case of the `convert-name` command.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum NameCase {
+  /// `httpServer`, used for fields and methods.
+  #[default]
+  #[value(name = "camel")]
+  Camel,
+
+  /// `HttpServer`, used for types.
+  #[value(name = "pascal")]
+  Pascal,
+
+  /// `http_server`, used for tables and columns.
+  #[value(name = "snake")]
+  Snake,
+
+  /// `HTTP_SERVER`, used for constants and enum constants.
+  #[value(name = "screaming_snake")]
+  ScreamingSnake,
+
+  /// `http-server`.
+  #[value(name = "kebab")]
+  Kebab,
+}
@@ -1,13 +1,26 @@
+use schemars::JsonSchema;
+
 use crate::common::types::{
-  cascade_type::CascadeType, mapping_type::MappingType, other_type::OtherType,
+  cascade_type::CascadeType, fetch_type::FetchType, mapping_type::MappingType,
+  other_type::OtherType, persistence_namespace::PersistenceNamespace,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema)]
 pub struct OneToOneFieldConfig {
   pub inverse_field_type: String,
+  /// Package of the inverse entity, needed when several entities share its class name
+  pub inverse_field_package: Option<String>,
   pub mapping_type: Option<MappingType>,
+  pub fetch_type: Option<FetchType>,
   pub owning_side_cascades: Vec<CascadeType>,
   pub inverse_side_cascades: Vec<CascadeType>,
   pub owning_side_other: Vec<OtherType>,
   pub inverse_side_other: Vec<OtherType>,
+  pub join_column_name: Option<String>,
+  pub referenced_column_name: Option<String>,
+  /// Adds `@MapsId` to the owning side, sharing the target's primary key; an inner value names
+  /// the attribute of the owning entity's embedded id it maps
+  pub maps_id: Option<Option<String>>,
+  pub persistence_namespace: PersistenceNamespace,
+  pub format_java: bool,
 }
@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+
+/// Package the JPA API is imported from, `jakarta` since JPA 3.0 and `javax` before it.
+#[derive(Debug, Clone, Default, PartialEq, ValueEnum)]
+pub enum PersistenceNamespace {
+  #[default]
+  #[value(name = "jakarta")]
+  Jakarta,
+
+  /// For Java EE 8 / Spring Boot 2 projects still on JPA 2.x.
+  #[value(name = "javax")]
+  Javax,
+}
+
+impl PersistenceNamespace {
+  /// The package every generated `@Entity`, `@Column`, etc. import is resolved against.
+  pub fn package(&self) -> &'static str {
+    match self {
+      PersistenceNamespace::Jakarta => "jakarta.persistence",
+      PersistenceNamespace::Javax => "javax.persistence",
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      PersistenceNamespace::Jakarta => "jakarta",
+      PersistenceNamespace::Javax => "javax",
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+/// A buildable module of a Maven/Gradle project.
+///
+/// The root project is represented with `name: None`, sub-modules are named after their path
+/// relative to the project root (e.g. `api` or `services/core`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectModule {
+  pub name: Option<String>,
+  pub path: PathBuf,
+}
@@ -0,0 +1,57 @@
+use super::{
+  cascade_type::CascadeType, collection_type::CollectionType, fetch_type::FetchType,
+  other_type::OtherType,
+};
+
+/// The relationships the core can generate, each with the options its command accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationshipKind {
+  OneToOne,
+  ManyToOne,
+}
+
+impl RelationshipKind {
+  /// Cascade types offered per side; `ALL` is left out as it equals selecting every one of them
+  pub fn cascade_options(&self) -> Vec<CascadeType> {
+    vec![
+      CascadeType::Persist,
+      CascadeType::Merge,
+      CascadeType::Remove,
+      CascadeType::Refresh,
+      CascadeType::Detach,
+    ]
+  }
+
+  /// Fetch types that can be set explicitly, the first one being the default
+  pub fn fetch_type_options(&self) -> Vec<FetchType> {
+    vec![FetchType::Lazy, FetchType::Eager]
+  }
+
+  /// Collection types of the inverse side field, empty when it is not a collection
+  pub fn collection_type_options(&self) -> Vec<CollectionType> {
+    match self {
+      RelationshipKind::OneToOne => vec![],
+      RelationshipKind::ManyToOne => {
+        vec![CollectionType::List, CollectionType::Set, CollectionType::Collection]
+      }
+    }
+  }
+
+  /// Other options of the owning side (the `@ManyToOne` side for many-to-one)
+  pub fn owning_side_other_options(&self) -> Vec<OtherType> {
+    match self {
+      RelationshipKind::OneToOne => {
+        vec![OtherType::Mandatory, OtherType::Unique, OtherType::OrphanRemoval]
+      }
+      RelationshipKind::ManyToOne => vec![OtherType::Mandatory, OtherType::Unique],
+    }
+  }
+
+  /// Other options of the inverse side (the `@OneToMany` side for many-to-one)
+  pub fn inverse_side_other_options(&self) -> Vec<OtherType> {
+    match self {
+      RelationshipKind::OneToOne => vec![OtherType::Mandatory, OtherType::OrphanRemoval],
+      RelationshipKind::ManyToOne => vec![OtherType::OrphanRemoval],
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+/// A replacement of `start_byte..old_end_byte` of a source with `new_text`, as sent by clients
+/// that compute their own positions
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+  pub start_byte: usize,
+  pub old_end_byte: usize,
+  pub new_text: String,
+}
@@ -0,0 +1,104 @@
+use std::cell::Cell;
+
+use crate::common::utils::indent_util::indent_unit;
+
+thread_local! {
+  static ANNOTATION_WRAP: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Sets the width past which generated annotations put each attribute on its own line, as set by
+/// the global `--annotation-wrap` option. The setting is kept per thread, so it only affects the
+/// command running on the calling thread.
+pub fn set_annotation_wrap(columns: Option<usize>) {
+  ANNOTATION_WRAP.with(|annotation_wrap| annotation_wrap.set(columns));
+}
+
+pub fn get_annotation_wrap() -> Option<usize> {
+  ANNOTATION_WRAP.with(Cell::get)
+}
+
+/// Renders `value` as the Java string literal of an annotation attribute such as
+/// `@Column(name = ...)` or `@Table(name = ...)`, escaping backslashes and double quotes.
+///
+/// Control characters are rejected instead of escaped: no table, column or sequence name
+/// legitimately contains one, so they almost always come from a mangled input.
+pub fn render_string_value(value: &str) -> Result<String, String> {
+  if value.chars().any(char::is_control) {
+    return Err(format!(
+      "'{}' cannot be used as an annotation value because it contains control characters",
+      value.escape_debug()
+    ));
+  }
+  let mut literal = String::with_capacity(value.len() + 2);
+  literal.push('"');
+  for c in value.chars() {
+    if c == '\\' || c == '"' {
+      literal.push('\\');
+    }
+    literal.push(c);
+  }
+  literal.push('"');
+  Ok(literal)
+}
+
+/// Splits the argument list of `annotation_text` at its top-level commas, skipping the ones
+/// inside nested annotations, arrays and literals. `None` for marker annotations.
+fn split_arguments(annotation_text: &str) -> Option<(&str, Vec<&str>)> {
+  let open = annotation_text.find('(')?;
+  let arguments_text = annotation_text[open + 1..].strip_suffix(')')?;
+  let mut arguments = Vec::new();
+  let mut depth = 0usize;
+  let mut quote = None;
+  let mut escaped = false;
+  let mut argument_start = 0;
+  for (index, c) in arguments_text.char_indices() {
+    if let Some(quote_char) = quote {
+      if escaped {
+        escaped = false;
+      } else if c == '\\' {
+        escaped = true;
+      } else if c == quote_char {
+        quote = None;
+      }
+      continue;
+    }
+    match c {
+      '"' | '\'' => quote = Some(c),
+      '(' | '{' | '[' => depth += 1,
+      ')' | '}' | ']' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        arguments.push(arguments_text[argument_start..index].trim());
+        argument_start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  arguments.push(arguments_text[argument_start..].trim());
+  Some((&annotation_text[..open], arguments))
+}
+
+/// Breaks an annotation with several attributes onto one line per attribute when, written at
+/// `indentation`, it would exceed the `--annotation-wrap` width. Without the option, or when
+/// it fits, the annotation stays on a single line.
+pub fn wrap_annotation(annotation_text: &str, indentation: &str) -> String {
+  let Some(columns) = get_annotation_wrap() else {
+    return annotation_text.to_string();
+  };
+  let width = indentation.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum::<usize>()
+    + annotation_text.chars().count();
+  if width <= columns || annotation_text.contains('\n') {
+    return annotation_text.to_string();
+  }
+  match split_arguments(annotation_text) {
+    Some((name, arguments)) if arguments.len() > 1 => {
+      let attribute_indentation = format!("{}{}", indentation, indent_unit());
+      let attributes = arguments
+        .iter()
+        .map(|argument| format!("{}{}", attribute_indentation, argument))
+        .collect::<Vec<_>>()
+        .join(",\n");
+      format!("{}(\n{}\n{})", name, attributes, indentation)
+    }
+    _ => annotation_text.to_string(),
+  }
+}
@@ -0,0 +1,12 @@
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::utils::naming_util::{to_screaming_snake_case, to_snake_case};
+
+/// Returns the `@Column(name)` value for `field_name` under `strategy`, keeping acronyms as one
+/// word.
+pub fn to_column_name(field_name: &str, strategy: &ColumnNamingStrategy) -> String {
+  match strategy {
+    ColumnNamingStrategy::None => field_name.to_string(),
+    ColumnNamingStrategy::SnakeCase => to_snake_case(field_name),
+    ColumnNamingStrategy::UpperSnakeCase => to_screaming_snake_case(field_name),
+  }
+}
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::common::utils::timing_util::{TimingPhase, measure};
+
+/// Distinguishes the temporary files of concurrent writes within the same process
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+  static NO_WRITE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Turns every file system change made through this module into a no-op, as set by the global
+/// `--no-write` option. Commands still run their full generation and validation logic. The
+/// setting is kept per thread, so it only affects the command running on the calling thread.
+pub fn set_no_write(no_write: bool) {
+  NO_WRITE.with(|cell| cell.set(no_write));
+}
+
+pub fn is_no_write() -> bool {
+  NO_WRITE.with(Cell::get)
+}
+
+/// [`fs::create_dir_all`] that does nothing under `--no-write`
+pub fn create_dir_all(path: &Path) -> io::Result<()> {
+  if is_no_write() {
+    return Ok(());
+  }
+  measure(TimingPhase::Write, || fs::create_dir_all(path))
+}
+
+/// [`fs::rename`] that does nothing under `--no-write`
+pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+  if is_no_write() {
+    tracing::debug!(from = %from.display(), to = %to.display(), "skipped move (--no-write)");
+    return Ok(());
+  }
+  measure(TimingPhase::Write, || fs::rename(from, to))
+}
+
+/// [`fs::remove_file`] that does nothing under `--no-write`
+pub fn remove_file(path: &Path) -> io::Result<()> {
+  if is_no_write() {
+    tracing::debug!(path = %path.display(), "skipped removal (--no-write)");
+    return Ok(());
+  }
+  measure(TimingPhase::Write, || fs::remove_file(path))
+}
+
+/// Hidden sibling of `path` that no other write, including one from another process, uses.
+fn temp_path_for(path: &Path) -> io::Result<PathBuf> {
+  let file_name = path
+    .file_name()
+    .ok_or_else(|| io::Error::other(format!("Not a file path: '{}'", path.display())))?;
+  let temp_file_name = format!(
+    ".{}.{}.{}.tmp",
+    file_name.to_string_lossy(),
+    std::process::id(),
+    TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+  );
+  Ok(path.with_file_name(temp_file_name))
+}
+
+fn write_temp_file(temp_path: &Path, target_path: &Path, contents: &[u8]) -> io::Result<()> {
+  let mut temp_file = File::create_new(temp_path)?;
+  temp_file.write_all(contents)?;
+  // Keep the permissions of the file being replaced, e.g. an executable bit
+  if let Ok(metadata) = fs::metadata(target_path) {
+    temp_file.set_permissions(metadata.permissions())?;
+  }
+  temp_file.sync_all()
+}
+
+/// Writes `contents` to `path` by writing a temporary file in the same directory and renaming it
+/// over the target. Readers and concurrent writers only ever see the old or the new content, and
+/// a crash mid-write never leaves a truncated file. The temporary file is removed when any step
+/// fails. Nothing is written under `--no-write`.
+pub fn atomic_write(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+  if is_no_write() {
+    tracing::debug!(path = %path.display(), "skipped save (--no-write)");
+    return Ok(());
+  }
+  let temp_path = temp_path_for(path)?;
+  let result = measure(TimingPhase::Write, || {
+    write_temp_file(&temp_path, path, contents.as_ref()).and_then(|_| fs::rename(&temp_path, path))
+  });
+  match &result {
+    Ok(()) => {
+      tracing::debug!(path = %path.display(), bytes = contents.as_ref().len(), "saved file")
+    }
+    Err(error) => {
+      tracing::warn!(path = %path.display(), %error, "failed to save file");
+      let _ = fs::remove_file(&temp_path);
+    }
+  }
+  result
+}
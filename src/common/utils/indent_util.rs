@@ -0,0 +1,31 @@
+use std::cell::Cell;
+
+use crate::common::types::indent_style::IndentStyle;
+
+thread_local! {
+  static INDENT_OVERRIDE: Cell<Option<IndentStyle>> = const { Cell::new(None) };
+}
+
+/// Forces the indentation of generated code, as set by the global `--indent` option. The
+/// setting is kept per thread, so it only affects the command running on the calling thread.
+pub fn set_indent_override(indent_style: Option<IndentStyle>) {
+  INDENT_OVERRIDE.with(|indent_override| indent_override.set(indent_style));
+}
+
+pub fn get_indent_override() -> Option<IndentStyle> {
+  INDENT_OVERRIDE.with(Cell::get)
+}
+
+/// The text of one indentation level for generated members: the override when set, otherwise
+/// the two spaces of the built-in templates.
+pub fn indent_unit() -> &'static str {
+  get_indent_override().map_or(IndentStyle::TEMPLATE_UNIT, |indent_style| indent_style.unit())
+}
+
+/// Re-indents a generated template with the override, leaving it untouched when unset.
+pub fn apply_indent_override(template: String) -> String {
+  match get_indent_override() {
+    Some(indent_style) => indent_style.reindent(&template),
+    None => template,
+  }
+}
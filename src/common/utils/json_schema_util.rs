@@ -0,0 +1,101 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+
+use clap::ValueEnum;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer};
+
+use crate::common::types::{
+  cache_concurrency_strategy::CacheConcurrencyStrategy, cascade_type::CascadeType,
+  collection_type::CollectionType, column_naming_strategy::ColumnNamingStrategy,
+  fetch_type::FetchType, field_access::FieldAccess, fluent_setter_naming::FluentSetterNaming,
+  generated_style::GeneratedStyle, id_field_naming::IdFieldNaming, java_basic_types::JavaBasicType,
+  java_enum_type::JavaEnumType, java_field_temporal::JavaFieldTemporal,
+  java_field_time_zone_storage::JavaFieldTimeZoneStorage, java_file_type::JavaFileType,
+  java_id_generation::JavaIdGeneration, java_id_generation_type::JavaIdGenerationType,
+  java_identifier_kind::JavaIdentifierKind, java_source_directory_type::JavaSourceDirectoryType,
+  mapping_type::MappingType, name_case::NameCase, other_type::OtherType,
+  persistence_namespace::PersistenceNamespace,
+};
+
+/// Builds a string enum schema from the values clap accepts for `T`, so the schema can never
+/// drift from the command line.
+pub fn value_enum_schema<T: ValueEnum>() -> Schema {
+  let values: Vec<String> = T::value_variants()
+    .iter()
+    .filter_map(|variant| variant.to_possible_value())
+    .map(|value| value.get_name().to_string())
+    .collect();
+  json_schema!({ "type": "string", "enum": values })
+}
+
+/// Parses a `ValueEnum` from the same names clap accepts, so JSON inputs (e.g. the field configs
+/// of `create-jpa-entity-fields`) use the values advertised by the schema.
+pub fn deserialize_value_enum<'de, T: ValueEnum, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<T, D::Error> {
+  let value = String::deserialize(deserializer)?;
+  T::from_str(&value, false).map_err(serde::de::Error::custom)
+}
+
+/// Implements `JsonSchema` and `Deserialize` for `ValueEnum` types and registers them with
+/// [`value_enum_subschema_for`].
+macro_rules! value_enum_json_schemas {
+  ($($value_enum:ident),* $(,)?) => {
+    $(
+      impl JsonSchema for $value_enum {
+        fn schema_name() -> Cow<'static, str> {
+          Cow::Borrowed(stringify!($value_enum))
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+          value_enum_schema::<$value_enum>()
+        }
+      }
+
+      impl<'de> Deserialize<'de> for $value_enum {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+          deserialize_value_enum(deserializer)
+        }
+      }
+    )*
+
+    /// Returns a `$ref` schema for the `ValueEnum` type identified by `type_id`, if it is known.
+    pub fn value_enum_subschema_for<I: PartialEq<TypeId>>(
+      type_id: &I,
+      generator: &mut SchemaGenerator,
+    ) -> Option<Schema> {
+      $(
+        if *type_id == TypeId::of::<$value_enum>() {
+          return Some(generator.subschema_for::<$value_enum>());
+        }
+      )*
+      None
+    }
+  };
+}
+
+value_enum_json_schemas!(
+  CacheConcurrencyStrategy,
+  CascadeType,
+  CollectionType,
+  ColumnNamingStrategy,
+  FetchType,
+  FieldAccess,
+  FluentSetterNaming,
+  GeneratedStyle,
+  IdFieldNaming,
+  JavaBasicType,
+  JavaEnumType,
+  JavaFieldTemporal,
+  JavaFieldTimeZoneStorage,
+  JavaFileType,
+  JavaIdentifierKind,
+  JavaIdGeneration,
+  JavaIdGenerationType,
+  JavaSourceDirectoryType,
+  MappingType,
+  NameCase,
+  OtherType,
+  PersistenceNamespace,
+);
@@ -0,0 +1,19 @@
+use tracing::level_filters::LevelFilter;
+
+/// Sends `tracing` logs to stderr, keeping stdout for the JSON response. `--verbose` shows the
+/// debug logs (scanned files, added imports, saved files and timings) and `--quiet` silences
+/// everything, warnings included. Only the first call installs the subscriber.
+pub fn init_logging(verbose: bool, quiet: bool) {
+  let max_level = if quiet {
+    LevelFilter::OFF
+  } else if verbose {
+    LevelFilter::DEBUG
+  } else {
+    LevelFilter::WARN
+  };
+  let _ = tracing_subscriber::fmt()
+    .with_writer(std::io::stderr)
+    .with_max_level(max_level)
+    .with_target(false)
+    .try_init();
+}
@@ -1,3 +1,12 @@
-pub mod case_util;
+pub mod annotation_value_util;
+pub mod column_naming_util;
+pub mod file_util;
+pub mod indent_util;
+pub mod json_schema_util;
+pub mod logging_util;
+pub mod naming_util;
 pub mod path_security_util;
 pub mod path_util;
+pub mod project_layout_util;
+pub mod scan_cache_util;
+pub mod timing_util;
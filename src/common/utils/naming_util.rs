@@ -0,0 +1,109 @@
+/// Splits a Java identifier into words on `_`, `-`, whitespace, lower-to-upper and
+/// digit-to-upper transitions. Acronyms stay together (`HTTPServer` is `HTTP` + `Server`),
+/// including a trailing plural `s` (`userIDs` is `user` + `IDs`).
+pub fn split_words(identifier: &str) -> Vec<String> {
+  let chars: Vec<char> = identifier.chars().collect();
+  let mut words = Vec::new();
+  let mut current = String::new();
+  for (i, &c) in chars.iter().enumerate() {
+    if c == '_' || c == '-' || c.is_whitespace() {
+      if !current.is_empty() {
+        words.push(std::mem::take(&mut current));
+      }
+      continue;
+    }
+    if !current.is_empty() && c.is_uppercase() {
+      let prev = chars[i - 1];
+      let next = chars.get(i + 1).copied();
+      let is_plural_acronym =
+        next == Some('s') && chars.get(i + 2).is_none_or(|after| !after.is_lowercase());
+      let starts_new_word = prev.is_lowercase()
+        || prev.is_ascii_digit()
+        || (prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase()) && !is_plural_acronym);
+      if starts_new_word {
+        words.push(std::mem::take(&mut current));
+      }
+    }
+    current.push(c);
+  }
+  if !current.is_empty() {
+    words.push(current);
+  }
+  words
+}
+
+/// Upper-cases the first character of a word and lower-cases the rest, so acronyms read as one
+/// word (`HTTP` becomes `Http`).
+fn capitalize(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+    None => String::new(),
+  }
+}
+
+/// `HTTPServer` becomes `http_server`.
+pub fn to_snake_case(name: &str) -> String {
+  split_words(name).iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("_")
+}
+
+/// `HTTPServer` becomes `HTTP_SERVER`.
+pub fn to_screaming_snake_case(name: &str) -> String {
+  to_snake_case(name).to_uppercase()
+}
+
+/// `HTTPServer` becomes `http-server`.
+pub fn to_kebab_case(name: &str) -> String {
+  split_words(name).iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("-")
+}
+
+/// `http_server` becomes `HttpServer`.
+pub fn to_pascal_case(name: &str) -> String {
+  split_words(name).iter().map(|word| capitalize(word)).collect()
+}
+
+/// `HTTPServer` becomes `httpServer`.
+pub fn to_camel_case(name: &str) -> String {
+  split_words(name)
+    .iter()
+    .enumerate()
+    .map(|(i, word)| if i == 0 { word.to_lowercase() } else { capitalize(word) })
+    .collect()
+}
+
+fn has_separators(name: &str) -> bool {
+  name.chars().any(|c| c == '_' || c == '-' || c.is_whitespace())
+}
+
+/// Like [`to_camel_case`], but keeps a name that already is camelCase as typed, acronyms
+/// included (`userID` stays `userID`).
+pub fn ensure_camel_case(name: &str) -> String {
+  let is_camel_case = !has_separators(name) && name.chars().next().is_some_and(char::is_lowercase);
+  if is_camel_case { name.to_string() } else { to_camel_case(name) }
+}
+
+/// Like [`to_pascal_case`], but keeps a name that already is PascalCase as typed, acronyms
+/// included (`HTTPServer` stays `HTTPServer`).
+pub fn ensure_pascal_case(name: &str) -> String {
+  let is_pascal_case = !has_separators(name)
+    && name.chars().next().is_some_and(char::is_uppercase)
+    && name.chars().any(char::is_lowercase);
+  if is_pascal_case { name.to_string() } else { to_pascal_case(name) }
+}
+
+/// The field name suggested for a field of type `type_name`: `UserProfile` becomes
+/// `userProfile`.
+pub fn field_name_for_type(type_name: &str) -> String {
+  to_camel_case(type_name)
+}
+
+/// The field name suggested for a collection of `type_name`, e.g. `orderList`.
+pub fn collection_field_name_for_type(type_name: &str, collection_suffix: &str) -> String {
+  let base_name = field_name_for_type(type_name);
+  if base_name.is_empty() { base_name } else { format!("{}{}", base_name, collection_suffix) }
+}
+
+/// The table name derived from an entity name: `OrderItem` becomes `order_item`.
+pub fn table_name_for_entity(entity_name: &str) -> String {
+  to_snake_case(entity_name)
+}
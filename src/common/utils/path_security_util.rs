@@ -52,6 +52,45 @@ fn log_security_event(event: &SecurityEvent) {
   }
 }
 
+/// Normalizes a Windows path for containment checks: drops the `\\?\` verbatim prefix added by
+/// canonicalization (turning `\\?\UNC\server\share` back into `\\server\share`), uses `\` as
+/// the only separator and upper-cases the drive letter, so `c:/Work` and `\\?\C:\Work` compare
+/// equal. Any other text is left as is.
+///
+/// # Examples
+/// ```
+/// use syntaxpresso_core::common::utils::path_security_util::normalize_windows_path;
+///
+/// assert_eq!(normalize_windows_path(r"\\?\c:\Work/project"), r"C:\Work\project");
+/// ```
+pub fn normalize_windows_path(path: &str) -> String {
+  let path = if let Some(rest) = path.strip_prefix(r"\\?\UNC\") {
+    format!(r"\\{}", rest)
+  } else {
+    path.strip_prefix(r"\\?\").unwrap_or(path).to_string()
+  };
+  let mut normalized = path.replace('/', r"\");
+  if let [drive, b':', ..] = normalized.as_bytes()
+    && drive.is_ascii_alphabetic()
+  {
+    normalized[..1].make_ascii_uppercase();
+  }
+  normalized
+}
+
+/// Applies [`normalize_windows_path`] on Windows, where canonicalized paths carry the verbatim
+/// prefix. Elsewhere `\` is a valid file name character and paths are returned unchanged.
+fn normalize_path(path: PathBuf) -> PathBuf {
+  #[cfg(windows)]
+  {
+    PathBuf::from(normalize_windows_path(&path.to_string_lossy()))
+  }
+  #[cfg(not(windows))]
+  {
+    path
+  }
+}
+
 impl PathSecurityValidator {
   /// Creates a new path security validator with the given base path.
   /// The base path is canonicalized to resolve any symbolic links or relative components.
@@ -79,7 +118,7 @@ impl PathSecurityValidator {
     if !canonical_base.is_dir() {
       return Err(format!("Base path '{}' is not a directory", canonical_base.display()));
     }
-    Ok(Self { base_path: canonical_base })
+    Ok(Self { base_path: normalize_path(canonical_base) })
   }
 
   /// Validates that a target path is contained within the base path.
@@ -113,6 +152,7 @@ impl PathSecurityValidator {
   pub fn validate_path_containment(&self, target_path: &Path) -> Result<PathBuf, String> {
     // Handle absolute paths - convert to relative if they're within base
     let working_path = if target_path.is_absolute() {
+      let target_path = normalize_path(target_path.to_path_buf());
       if target_path.starts_with(&self.base_path) {
         target_path
           .strip_prefix(&self.base_path)
@@ -131,7 +171,7 @@ impl PathSecurityValidator {
     // Build the full path relative to base
     let full_path = self.base_path.join(&working_path);
     // Canonicalize the target path
-    let canonical_target = if full_path.exists() {
+    let canonical_target = normalize_path(if full_path.exists() {
       // Path exists - canonicalize directly
       fs::canonicalize(&full_path).map_err(|e| {
         format!("Cannot canonicalize existing path '{}': {}", full_path.display(), e)
@@ -139,7 +179,7 @@ impl PathSecurityValidator {
     } else {
       // Path doesn't exist yet - canonicalize parent and append filename
       canonicalize_non_existent_path(&full_path)?
-    };
+    });
     // Verify containment after canonicalization
     if !canonical_target.starts_with(&self.base_path) {
       let event = SecurityEvent::PathTraversalAttempt {
@@ -1,13 +1,17 @@
 #![allow(dead_code)]
 
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use walkdir::WalkDir;
 
 use crate::common::{
-  ts_file::TSFile, types::java_source_directory_type::JavaSourceDirectoryType,
-  utils::path_security_util::PathSecurityValidator,
+  ts_file::TSFile,
+  types::java_source_directory_type::JavaSourceDirectoryType,
+  utils::{
+    file_util,
+    path_security_util::PathSecurityValidator,
+    timing_util::{TimingPhase, measure},
+  },
 };
 
 /// Recursively searches for a directory with the given name within the root directory.
@@ -29,22 +33,58 @@ fn find_directory_recursively(root_dir: &Path, target_dir: &str) -> Option<PathB
 }
 
 pub fn parse_all_files(cwd: &Path, source_directory_type: &JavaSourceDirectoryType) -> Vec<TSFile> {
-  let extension = "java";
-  let mut files = Vec::new();
+  iter_all_files(cwd, source_directory_type).collect()
+}
+
+/// Lazily parses every Java file of the given source directory type, one file per iteration.
+///
+/// # Arguments
+/// * `cwd` - The project root directory
+/// * `source_directory_type` - Whether to walk the main or the test sources
+///
+/// # Returns
+/// An iterator yielding a `TSFile` for each successfully read Java file
+pub fn iter_all_files(
+  cwd: &Path,
+  source_directory_type: &JavaSourceDirectoryType,
+) -> impl Iterator<Item = TSFile> + use<> {
   let src_dir_path = source_directory_type.get_directory_path();
-  let target_dir = cwd.join(src_dir_path);
-  if target_dir.exists() {
-    for entry in WalkDir::new(&target_dir).into_iter().flatten() {
-      let path = entry.path();
-      if let Some(ext) = path.extension()
-        && ext.to_string_lossy().eq_ignore_ascii_case(extension)
-        && let Ok(ts_file) = TSFile::from_file(path)
-      {
-        files.push(ts_file);
+  iter_all_files_in_directory(&cwd.join(src_dir_path))
+}
+
+/// Recursively parses every Java file found under the given directory.
+///
+/// # Arguments
+/// * `target_dir` - The directory to search in
+///
+/// # Returns
+/// A `Vec<TSFile>` with one entry per successfully read Java file
+pub fn parse_all_files_in_directory(target_dir: &Path) -> Vec<TSFile> {
+  iter_all_files_in_directory(target_dir).collect()
+}
+
+/// Lazy counterpart of [`parse_all_files_in_directory`]; files are read as the iterator advances.
+///
+/// # Arguments
+/// * `target_dir` - The directory to search in
+///
+/// # Returns
+/// An iterator yielding a `TSFile` for each successfully read Java file
+pub fn iter_all_files_in_directory(target_dir: &Path) -> impl Iterator<Item = TSFile> + use<> {
+  let extension = "java";
+  let mut entries = WalkDir::new(target_dir).into_iter();
+  // Only the walk is timed here; reading and parsing each file are timed by `TSFile::from_file`
+  let walk = std::iter::from_fn(move || measure(TimingPhase::Scan, || entries.next()));
+  walk.flatten().filter_map(move |entry| {
+    let path = entry.path();
+    match path.extension() {
+      Some(ext) if ext.to_string_lossy().eq_ignore_ascii_case(extension) => {
+        tracing::debug!(path = %path.display(), "scanning file");
+        TSFile::from_file(path).ok()
       }
+      _ => None,
     }
-  }
-  files
+  })
 }
 
 /// Resolves the file system path for a given package scope within the specified source directory type.
@@ -114,7 +154,7 @@ pub fn get_file_path_from_package_scope(
   // Validate the full package directory path for security
   let validated_package_dir = validator.validate_directory_creation(&full_package_dir)?;
   // Create the directory structure
-  match fs::create_dir_all(&validated_package_dir) {
+  match file_util::create_dir_all(&validated_package_dir) {
     Ok(_) => Ok(validated_package_dir),
     Err(e) => Err(format!(
       "Failed to create package directory '{}': {}",
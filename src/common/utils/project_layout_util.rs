@@ -0,0 +1,356 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::common::types::{
+  build_tool_type::BuildToolType, java_source_directory_type::JavaSourceDirectoryType,
+  persistence_namespace::PersistenceNamespace, project_module::ProjectModule,
+};
+use crate::common::utils::timing_util::{TimingPhase, measure};
+
+/// Directories that never contain sub-modules and are skipped while walking the project.
+const IGNORED_MODULE_DIRECTORIES: [&str; 6] =
+  ["src", "target", "build", "out", "bin", "node_modules"];
+
+/// Returns the content of the block starting at the first `{` of `text`, without the braces.
+fn extract_braced_block(text: &str) -> Option<&str> {
+  let open = text.find('{')?;
+  let mut depth = 0usize;
+  for (i, ch) in text[open..].char_indices() {
+    match ch {
+      '{' => depth += 1,
+      '}' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(&text[open + 1..open + i]);
+        }
+      }
+      _ => {}
+    }
+  }
+  None
+}
+
+/// Returns the first single or double quoted string found in `text`.
+fn extract_first_quoted(text: &str) -> Option<&str> {
+  let start = text.find(['\'', '"'])?;
+  let quote = text[start..].chars().next()?;
+  let rest = &text[start + 1..];
+  let end = rest.find(quote)?;
+  Some(&rest[..end])
+}
+
+/// Finds the first directory passed to `srcDir`, `srcDirs` or `setSrcDirs` in `text`.
+fn extract_src_dir(text: &str) -> Option<String> {
+  // ASCII-only comparison keeps `index` a valid byte offset into `text`, which lowercasing the
+  // whole text would not for some non-ASCII characters
+  let index = text
+    .as_bytes()
+    .windows("srcdir".len())
+    .position(|window| window.eq_ignore_ascii_case(b"srcdir"))?;
+  let statement = text[index..].lines().next()?;
+  extract_first_quoted(statement).map(|s| s.to_string())
+}
+
+fn is_identifier_char(ch: char) -> bool {
+  ch.is_alphanumeric() || ch == '_'
+}
+
+/// Finds every standalone occurrence of `word` in `text`, returning the text that follows it.
+fn find_word_occurrences<'a>(text: &'a str, word: &str) -> Vec<&'a str> {
+  let mut occurrences = Vec::new();
+  for (index, _) in text.match_indices(word) {
+    let before = text[..index].chars().next_back();
+    let after = text[index + word.len()..].chars().next();
+    if before.is_some_and(is_identifier_char) || after.is_some_and(is_identifier_char) {
+      continue;
+    }
+    occurrences.push(&text[index + word.len()..]);
+  }
+  occurrences
+}
+
+/// Finds the `java` source directory declared inside a source set definition.
+fn extract_java_src_dir(source_set_text: &str) -> Option<String> {
+  for rest in find_word_occurrences(source_set_text, "java") {
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with('{') {
+      if let Some(dir) = extract_braced_block(trimmed).and_then(extract_src_dir) {
+        return Some(dir);
+      }
+    } else if trimmed.starts_with('.')
+      && let Some(dir) = extract_src_dir(trimmed.lines().next().unwrap_or_default())
+    {
+      return Some(dir);
+    }
+  }
+  None
+}
+
+/// Parses a Gradle build script (Groovy or Kotlin DSL) looking for a custom `java` source
+/// directory declared for the given source set.
+///
+/// Supported forms:
+/// - `sourceSets { main { java { srcDirs = ['src'] } } }`
+/// - `sourceSets { main { java.srcDir 'src' } }`
+/// - `sourceSets { named("main") { java.setSrcDirs(listOf("src")) } }`
+/// - `sourceSets.main.java.srcDirs = ['src']`
+/// - `sourceSets["main"].java.srcDir("src")`
+pub fn find_gradle_source_dir(build_file_content: &str, source_set_name: &str) -> Option<String> {
+  for rest in find_word_occurrences(build_file_content, "sourceSets") {
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with('{') {
+      let Some(block) = extract_braced_block(trimmed) else {
+        continue;
+      };
+      for source_set_rest in find_word_occurrences(block, source_set_name) {
+        // Skip closing quote/parenthesis of `named("main")` or `getByName("main")`
+        let header = source_set_rest.trim_start_matches(['"', '\'', ')', ' ']);
+        if header.starts_with('{')
+          && let Some(dir) = extract_braced_block(header).and_then(extract_java_src_dir)
+        {
+          return Some(dir);
+        }
+      }
+    } else if trimmed.starts_with('.') || trimmed.starts_with('[') {
+      let line = trimmed.lines().next().unwrap_or_default();
+      if find_word_occurrences(line, source_set_name).is_empty() {
+        continue;
+      }
+      if let Some(dir) = extract_java_src_dir(line) {
+        return Some(dir);
+      }
+    }
+  }
+  None
+}
+
+/// Returns the trimmed text of the first `<tag>...</tag>` element found in `xml`.
+fn find_xml_tag_value<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+  let open_tag = format!("<{}>", tag);
+  let close_tag = format!("</{}>", tag);
+  let start = xml.find(&open_tag)? + open_tag.len();
+  let end = xml[start..].find(&close_tag)? + start;
+  Some(xml[start..end].trim())
+}
+
+/// Parses a Maven `pom.xml` looking for a custom `<sourceDirectory>` or
+/// `<testSourceDirectory>` for the given source set.
+pub fn find_maven_source_dir(pom_content: &str, source_set_name: &str) -> Option<String> {
+  let tag = match source_set_name {
+    "main" => "sourceDirectory",
+    "test" => "testSourceDirectory",
+    _ => return None,
+  };
+  let value = find_xml_tag_value(pom_content, tag)?;
+  let value = ["${project.basedir}/", "${basedir}/"]
+    .iter()
+    .find_map(|prefix| value.strip_prefix(prefix))
+    .unwrap_or(value);
+  if value.is_empty() { None } else { Some(value.to_string()) }
+}
+
+/// Parses a Maven `pom.xml` looking for the Java version the project compiles for.
+///
+/// Checked in order: `<maven.compiler.release>`, `<maven.compiler.source>`, `<java.version>`
+/// (Spring Boot) and the compiler plugin `<release>`. A `${property}` value is resolved
+/// against the properties of the same file.
+pub fn find_maven_java_version(pom_content: &str) -> Option<String> {
+  ["maven.compiler.release", "maven.compiler.source", "java.version", "release"]
+    .iter()
+    .filter_map(|tag| find_xml_tag_value(pom_content, tag))
+    .find_map(|value| match value.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+      Some(property) => find_xml_tag_value(pom_content, property),
+      None => Some(value),
+    })
+    .filter(|value| !value.is_empty())
+    .map(|value| value.to_string())
+}
+
+/// Normalizes a Gradle Java version literal, e.g. `'17'`, `JavaVersion.VERSION_17` or
+/// `JavaVersion.VERSION_1_8`.
+fn parse_gradle_java_version(value: &str) -> Option<String> {
+  let value = value.trim().trim_matches(['"', '\'', ')']).trim();
+  let value = match value.find("VERSION_") {
+    Some(index) => value[index + "VERSION_".len()..].replace('_', "."),
+    None => value.to_string(),
+  };
+  let version: String = value.chars().take_while(|ch| ch.is_ascii_digit() || *ch == '.').collect();
+  if version.is_empty() { None } else { Some(version) }
+}
+
+/// Parses a Gradle build script (Groovy or Kotlin DSL) looking for the Java version the project
+/// compiles for, declared through a toolchain (`JavaLanguageVersion.of(17)`) or
+/// `sourceCompatibility`.
+pub fn find_gradle_java_version(build_file_content: &str) -> Option<String> {
+  if let Some(rest) = find_word_occurrences(build_file_content, "JavaLanguageVersion").first()
+    && let Some(arguments) = rest.strip_prefix(".of(")
+  {
+    return parse_gradle_java_version(arguments.split(')').next().unwrap_or_default());
+  }
+  find_word_occurrences(build_file_content, "sourceCompatibility").into_iter().find_map(|rest| {
+    let statement = rest.lines().next().unwrap_or_default();
+    parse_gradle_java_version(
+      statement.trim_start().trim_start_matches('=').trim_start_matches('('),
+    )
+  })
+}
+
+/// Returns the major version of the Spring Boot parent (Maven) or plugin (Gradle) declared in a
+/// build file.
+fn find_spring_boot_major_version(build_file_content: &str) -> Option<u32> {
+  let version = if let Some(index) = build_file_content.find("spring-boot-starter-parent") {
+    find_xml_tag_value(&build_file_content[index..], "version")?
+  } else {
+    let index = build_file_content.find("org.springframework.boot")?;
+    let line = build_file_content[index..].lines().next()?;
+    let version_index = line.find("version")?;
+    extract_first_quoted(&line[version_index..])?
+  };
+  version.split('.').next()?.trim().parse().ok()
+}
+
+/// Infers the JPA namespace a build file compiles against.
+///
+/// An explicit `jakarta.persistence` or `javax.persistence` dependency wins. Otherwise the
+/// Spring Boot version decides, as Spring Boot 3 moved to Jakarta Persistence. Returns `None`
+/// when neither is declared.
+pub fn find_persistence_namespace(build_file_content: &str) -> Option<PersistenceNamespace> {
+  if build_file_content.contains("jakarta.persistence") {
+    return Some(PersistenceNamespace::Jakarta);
+  }
+  if build_file_content.contains("javax.persistence") {
+    return Some(PersistenceNamespace::Javax);
+  }
+  find_spring_boot_major_version(build_file_content).map(|major| {
+    if major >= 3 { PersistenceNamespace::Jakarta } else { PersistenceNamespace::Javax }
+  })
+}
+
+/// Resolves the Java source root of a project for the given source directory type.
+///
+/// The project layout is detected by inspecting `cwd` for a `pom.xml`, `build.gradle` or
+/// `build.gradle.kts`. When the build file declares a custom source directory for the
+/// source set (Maven's `<sourceDirectory>` or Gradle's `sourceSets`), that directory is
+/// returned. Otherwise the standard Maven/Gradle layout (`src/main/java`, `src/test/java`)
+/// is used, which is also the fallback when no build file is found.
+///
+/// # Arguments
+/// * `cwd` - The root directory of the project
+/// * `source_directory_type` - The type of source directory (main, test or all)
+///
+/// # Returns
+/// The absolute source root path (it may not exist yet)
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+/// use syntaxpresso_core::common::utils::project_layout_util::resolve_source_root;
+///
+/// let source_root = resolve_source_root(Path::new("/tmp/project"), &JavaSourceDirectoryType::Main);
+/// assert_eq!(source_root, Path::new("/tmp/project/src/main/java"));
+/// ```
+pub fn resolve_source_root(cwd: &Path, source_directory_type: &JavaSourceDirectoryType) -> PathBuf {
+  let default_root = cwd.join(source_directory_type.get_directory_path());
+  let Some(source_set_name) = source_directory_type.get_source_set_name() else {
+    return default_root;
+  };
+  let build_tool = BuildToolType::detect(cwd);
+  let Some(build_file_name) = build_tool.get_build_file_name() else {
+    return default_root;
+  };
+  let Ok(build_file_content) = fs::read_to_string(cwd.join(build_file_name)) else {
+    return default_root;
+  };
+  let custom_dir = match build_tool {
+    BuildToolType::Maven => find_maven_source_dir(&build_file_content, source_set_name),
+    BuildToolType::Gradle | BuildToolType::GradleKotlin => {
+      find_gradle_source_dir(&build_file_content, source_set_name)
+    }
+    BuildToolType::Unknown => None,
+  };
+  match custom_dir {
+    Some(dir) => cwd.join(dir),
+    None => default_root,
+  }
+}
+
+fn is_ignored_module_directory(entry: &walkdir::DirEntry) -> bool {
+  let name = entry.file_name().to_string_lossy();
+  entry.depth() > 0
+    && (name.starts_with('.') || IGNORED_MODULE_DIRECTORIES.contains(&name.as_ref()))
+}
+
+/// Finds every sub-module of a multi-module Maven/Gradle project.
+///
+/// A sub-module is any directory below `cwd` containing its own `pom.xml`, `build.gradle` or
+/// `build.gradle.kts`. Build output and hidden directories are skipped. Modules are returned
+/// sorted by name, which is the module path relative to `cwd` using `/` as separator.
+///
+/// # Arguments
+/// * `cwd` - The root directory of the project
+///
+/// # Returns
+/// A `Vec<ProjectModule>` with the discovered sub-modules, empty for single-module projects
+pub fn find_project_modules(cwd: &Path) -> Vec<ProjectModule> {
+  measure(TimingPhase::Scan, || scan_project_modules(cwd))
+}
+
+fn scan_project_modules(cwd: &Path) -> Vec<ProjectModule> {
+  let mut modules: Vec<ProjectModule> = WalkDir::new(cwd)
+    .min_depth(1)
+    .into_iter()
+    .filter_entry(|e| e.file_type().is_dir() && !is_ignored_module_directory(e))
+    .flatten()
+    .filter(|e| BuildToolType::detect(e.path()) != BuildToolType::Unknown)
+    .filter_map(|e| {
+      let relative_path = e.path().strip_prefix(cwd).ok()?;
+      let name = relative_path.to_string_lossy().replace('\\', "/");
+      Some(ProjectModule { name: Some(name), path: e.path().to_path_buf() })
+    })
+    .collect();
+  modules.sort_by(|a, b| a.name.cmp(&b.name));
+  modules
+}
+
+/// Selects the project modules an operation should be scoped to.
+///
+/// Without a filter, single-module projects yield the root project only, while multi-module
+/// projects yield every sub-module plus the root project when it has its own `src` directory.
+/// With a filter, only the sub-module whose name matches is returned.
+///
+/// # Arguments
+/// * `cwd` - The root directory of the project
+/// * `module_filter` - Optional name of the single module to scope the operation to
+///
+/// # Returns
+/// * `Ok(Vec<ProjectModule>)` - The modules to operate on
+/// * `Err(String)` - If the requested module does not exist
+pub fn select_project_modules(
+  cwd: &Path,
+  module_filter: Option<&str>,
+) -> Result<Vec<ProjectModule>, String> {
+  let modules = find_project_modules(cwd);
+  let root_module = ProjectModule { name: None, path: cwd.to_path_buf() };
+  match module_filter {
+    Some(module_name) => {
+      let module_name = module_name.trim_matches(['/', ':']).replace(':', "/");
+      modules
+        .into_iter()
+        .find(|m| m.name.as_deref() == Some(module_name.as_str()))
+        .map(|m| vec![m])
+        .ok_or_else(|| format!("Module not found: {}", module_name))
+    }
+    None if modules.is_empty() => Ok(vec![root_module]),
+    None => {
+      let mut selected = Vec::new();
+      if cwd.join("src").is_dir() {
+        selected.push(root_module);
+      }
+      selected.extend(modules);
+      Ok(selected)
+    }
+  }
+}
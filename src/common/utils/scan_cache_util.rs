@@ -0,0 +1,210 @@
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use walkdir::WalkDir;
+
+use crate::common::utils::{
+  file_util::{self, atomic_write},
+  timing_util::{TimingPhase, measure},
+};
+
+/// Directories that hold build output or tooling state rather than sources.
+const IGNORED_DIRECTORIES: [&str; 4] = ["target", "build", "out", "node_modules"];
+
+/// Build files whose changes can add or remove modules.
+const BUILD_FILES: [&str; 5] =
+  ["pom.xml", "build.gradle", "build.gradle.kts", "settings.gradle", "settings.gradle.kts"];
+
+thread_local! {
+  static SCAN_CACHE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Lets project scans reuse the result of a previous run while no source changed, as set by the
+/// global `--cache` option. The setting is kept per thread, so it only affects the command
+/// running on the calling thread.
+pub fn set_scan_cache(enabled: bool) {
+  SCAN_CACHE.with(|cell| cell.set(enabled));
+}
+
+pub fn is_scan_cache_enabled() -> bool {
+  SCAN_CACHE.with(Cell::get)
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+  project: PathBuf,
+  scan_key: String,
+  fingerprint: String,
+  data: T,
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher` its output does not change between Rust releases, so it
+/// can name and validate files persisted across runs.
+struct StableHasher(u64);
+
+impl StableHasher {
+  fn new() -> Self {
+    Self(0xcbf2_9ce4_8422_2325)
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 ^= u64::from(*byte);
+      self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+    }
+  }
+
+  /// Writes a length-prefixed chunk, so consecutive chunks cannot be confused with one another.
+  fn write_chunk(&mut self, bytes: &[u8]) {
+    self.write(&(bytes.len() as u64).to_le_bytes());
+    self.write(bytes);
+  }
+
+  fn finish(&self) -> String {
+    format!("{:016x}", self.0)
+  }
+}
+
+/// Whether `dir` is the root of a Maven or Gradle module.
+fn is_module_root(dir: &Path) -> bool {
+  BUILD_FILES.iter().any(|build_file| dir.join(build_file).is_file())
+}
+
+/// Build output and tooling directories are only skipped right under the project or a module
+/// root. Deeper down they may be packages such as `com.acme.build`, which the scans do visit.
+fn is_ignored_directory(entry: &walkdir::DirEntry) -> bool {
+  let is_ignored_name = entry
+    .file_name()
+    .to_str()
+    .is_some_and(|name| name.starts_with('.') || IGNORED_DIRECTORIES.contains(&name));
+  is_ignored_name
+    && entry.depth() > 0
+    && entry.file_type().is_dir()
+    && (entry.depth() == 1 || entry.path().parent().is_some_and(is_module_root))
+}
+
+/// Hashes the path of every directory and the path, size and modification time of every Java
+/// and build file under `cwd`. Stat calls only, so it is much cheaper than parsing the sources.
+fn compute_fingerprint(cwd: &Path) -> String {
+  let mut entries: Vec<(PathBuf, u64, u128)> = WalkDir::new(cwd)
+    .into_iter()
+    .filter_entry(|entry| !is_ignored_directory(entry))
+    .flatten()
+    .filter_map(|entry| {
+      let path = entry.path();
+      let relative_path = path.strip_prefix(cwd).ok()?.to_path_buf();
+      if entry.file_type().is_dir() {
+        return Some((relative_path, 0, 0));
+      }
+      let is_java_file = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("java"));
+      let is_build_file =
+        entry.file_name().to_str().is_some_and(|name| BUILD_FILES.contains(&name));
+      if !is_java_file && !is_build_file {
+        return None;
+      }
+      let metadata = entry.metadata().ok()?;
+      let modified_nanos = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_nanos());
+      Some((relative_path, metadata.len(), modified_nanos))
+    })
+    .collect();
+  entries.sort();
+  let mut hasher = StableHasher::new();
+  for (relative_path, size, modified_nanos) in entries {
+    hasher.write_chunk(relative_path.as_os_str().as_encoded_bytes());
+    hasher.write(&size.to_le_bytes());
+    hasher.write(&modified_nanos.to_le_bytes());
+  }
+  hasher.finish()
+}
+
+/// Directory holding the scan caches of the current user: `%LOCALAPPDATA%` on Windows,
+/// `~/Library/Caches` on macOS and `$XDG_CACHE_HOME` or `~/.cache` elsewhere. `None` when the
+/// environment names none of them.
+fn user_cache_directory() -> Option<PathBuf> {
+  let absolute_var =
+    |name: &str| std::env::var_os(name).map(PathBuf::from).filter(|path| path.is_absolute());
+  let base_directory = if cfg!(windows) {
+    absolute_var("LOCALAPPDATA")
+  } else if cfg!(target_os = "macos") {
+    absolute_var("HOME").map(|home| home.join("Library/Caches"))
+  } else {
+    absolute_var("XDG_CACHE_HOME").or_else(|| absolute_var("HOME").map(|home| home.join(".cache")))
+  };
+  base_directory.map(|directory| directory.join("syntaxpresso-core"))
+}
+
+/// Cache file of a scan, named after the project and the scan so each gets its own entry.
+fn cache_file_path(cwd: &Path, scan_key: &str) -> Option<PathBuf> {
+  let mut hasher = StableHasher::new();
+  hasher.write_chunk(cwd.as_os_str().as_encoded_bytes());
+  hasher.write_chunk(scan_key.as_bytes());
+  Some(user_cache_directory()?.join(format!("{}.json", hasher.finish())))
+}
+
+fn read_cache_entry<T: DeserializeOwned>(
+  cache_path: &Path,
+  cwd: &Path,
+  scan_key: &str,
+  fingerprint: &str,
+) -> Option<T> {
+  let contents = fs::read_to_string(cache_path).ok()?;
+  let entry: CacheEntry<T> = serde_json::from_str(&contents).ok()?;
+  let is_same_scan =
+    entry.project == cwd && entry.scan_key == scan_key && entry.fingerprint == fingerprint;
+  is_same_scan.then_some(entry.data)
+}
+
+fn write_cache_entry<T: Serialize>(
+  cache_path: &Path,
+  cwd: &Path,
+  scan_key: &str,
+  fingerprint: &str,
+  data: &T,
+) {
+  let entry = CacheEntry {
+    project: cwd.to_path_buf(),
+    scan_key: scan_key.to_string(),
+    fingerprint: fingerprint.to_string(),
+    data,
+  };
+  let result = serde_json::to_vec(&entry).map_err(std::io::Error::other).and_then(|contents| {
+    file_util::create_dir_all(cache_path.parent().unwrap_or(cache_path))?;
+    atomic_write(cache_path, contents)
+  });
+  if let Err(error) = result {
+    tracing::debug!(path = %cache_path.display(), %error, "failed to write scan cache");
+  }
+}
+
+/// Runs `scan` over the project at `cwd`, or, under `--cache`, returns the result stored by the
+/// last run of the same `scan_key` when no Java file, directory or build file changed since.
+/// The cache lives in the per-user cache directory and a failure to read or write it only costs
+/// a rescan.
+pub fn cached_scan<T, F>(cwd: &Path, scan_key: &str, scan: F) -> Result<T, String>
+where
+  T: Serialize + DeserializeOwned,
+  F: FnOnce() -> Result<T, String>,
+{
+  if !is_scan_cache_enabled() {
+    return scan();
+  }
+  let Some(cache_path) = cache_file_path(cwd, scan_key) else {
+    tracing::debug!(scan = scan_key, "no user cache directory, scanning without cache");
+    return scan();
+  };
+  let fingerprint = measure(TimingPhase::Scan, || compute_fingerprint(cwd));
+  if let Some(data) = read_cache_entry(&cache_path, cwd, scan_key, &fingerprint) {
+    tracing::debug!(scan = scan_key, "reused cached scan");
+    return Ok(data);
+  }
+  let data = scan()?;
+  write_cache_entry(&cache_path, cwd, scan_key, &fingerprint, &data);
+  Ok(data)
+}
@@ -0,0 +1,63 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::responses::timing_response::TimingResponse;
+
+/// Phase of a command that `--with-timing` reports separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingPhase {
+  /// Building Tree-sitter trees of loaded sources
+  Parse,
+  /// Walking project directories and reading source files
+  Scan,
+  /// Creating, saving, moving and deleting files
+  Write,
+}
+
+thread_local! {
+  static WITH_TIMING: Cell<bool> = const { Cell::new(false) };
+  static ELAPSED: Cell<[Duration; 3]> = const { Cell::new([Duration::ZERO; 3]) };
+}
+
+/// Enables the per phase timings reported in the response, as set by the global `--with-timing`
+/// option, and clears the timings collected so far. The setting is kept per thread, so it only
+/// affects the command running on the calling thread.
+pub fn set_with_timing(with_timing: bool) {
+  WITH_TIMING.with(|cell| cell.set(with_timing));
+  ELAPSED.with(|cell| cell.set([Duration::ZERO; 3]));
+}
+
+pub fn is_with_timing() -> bool {
+  WITH_TIMING.with(Cell::get)
+}
+
+/// Runs `operation`, adding the time it took to `phase` under `--with-timing`. Phases must not be
+/// nested, otherwise the inner time is counted twice.
+pub fn measure<R>(phase: TimingPhase, operation: impl FnOnce() -> R) -> R {
+  if !is_with_timing() {
+    return operation();
+  }
+  let started_at = Instant::now();
+  let result = operation();
+  let elapsed = started_at.elapsed();
+  ELAPSED.with(|cell| {
+    let mut totals = cell.get();
+    totals[phase as usize] += elapsed;
+    cell.set(totals);
+  });
+  result
+}
+
+/// Milliseconds spent in every phase so far, or `None` without `--with-timing`.
+pub fn get_timings() -> Option<TimingResponse> {
+  if !is_with_timing() {
+    return None;
+  }
+  let totals = ELAPSED.with(Cell::get);
+  let to_ms = |phase: TimingPhase| totals[phase as usize].as_secs_f64() * 1000.0;
+  Some(TimingResponse {
+    parse: to_ms(TimingPhase::Parse),
+    scan: to_ms(TimingPhase::Scan),
+    write: to_ms(TimingPhase::Write),
+  })
+}
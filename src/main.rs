@@ -1,5 +1,16 @@
+use std::time::Instant;
+
 use clap::Parser;
 use syntaxpresso_core::commands::Commands;
+use syntaxpresso_core::common::services::formatting_service::set_member_spacing;
+use syntaxpresso_core::common::services::import_declaration_service::set_organize_imports;
+use syntaxpresso_core::common::types::indent_style::IndentStyle;
+use syntaxpresso_core::common::utils::annotation_value_util::set_annotation_wrap;
+use syntaxpresso_core::common::utils::file_util::set_no_write;
+use syntaxpresso_core::common::utils::indent_util::set_indent_override;
+use syntaxpresso_core::common::utils::logging_util::init_logging;
+use syntaxpresso_core::common::utils::scan_cache_util::set_scan_cache;
+use syntaxpresso_core::common::utils::timing_util::set_with_timing;
 use syntaxpresso_core::responses::error_response::ErrorResponse;
 
 #[derive(Parser)]
@@ -10,12 +21,59 @@ use syntaxpresso_core::responses::error_response::ErrorResponse;
 struct Cli {
   #[command(subcommand)]
   command: Commands,
+
+  /// Forces the indentation of generated code instead of the default two spaces
+  #[arg(long, global = true)]
+  indent: Option<IndentStyle>,
+
+  /// Puts each attribute of generated annotations longer than this many columns on its own line
+  #[arg(long, global = true, value_name = "COLS")]
+  annotation_wrap: Option<usize>,
+
+  /// Blank lines between an inserted field or method and the members next to it
+  #[arg(long, global = true, value_name = "N", default_value_t = 1)]
+  member_spacing: usize,
+
+  /// Logs scanned files, added imports and timings to stderr
+  #[arg(long, global = true, conflicts_with = "quiet")]
+  verbose: bool,
+
+  /// Silences every log line, warnings included
+  #[arg(long, global = true)]
+  quiet: bool,
+
+  /// Runs all generation and validation without touching any file or returning generated content
+  #[arg(long, global = true)]
+  no_write: bool,
+
+  /// Sorts and de-duplicates the imports of every file a command edits before saving it
+  #[arg(long, global = true)]
+  organize_imports: bool,
+
+  /// Adds the milliseconds spent parsing, scanning and writing files to the response
+  #[arg(long, global = true)]
+  with_timing: bool,
+
+  /// Reuses the result of entity and package scans while no Java file of the project changed
+  #[arg(long, global = true)]
+  cache: bool,
 }
 
 fn main() {
   let cli = Cli::parse();
+  init_logging(cli.verbose, cli.quiet);
+  set_indent_override(cli.indent);
+  set_annotation_wrap(cli.annotation_wrap);
+  set_member_spacing(cli.member_spacing);
+  set_no_write(cli.no_write);
+  set_organize_imports(cli.organize_imports);
+  set_with_timing(cli.with_timing);
+  set_scan_cache(cli.cache);
 
-  match cli.command.execute() {
+  let started_at = Instant::now();
+  let result = cli.command.execute();
+  tracing::debug!(elapsed_ms = started_at.elapsed().as_millis() as u64, "command finished");
+  match result {
     Ok(json) => println!("{}", json),
     Err(e) => {
       let error_response =
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::responses::edit_record_response::EditRecord;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyEditsResponse {
+  pub source_code: String,
+  /// One edit per applied edit, in ascending order and relative to `source_code`, restoring the
+  /// original source when applied together
+  pub reverse_edits: Vec<EditRecord>,
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeJPAEnumStorageResponse {
+  pub entity: FileResponse,
+  pub old_storage: String,
+  pub new_storage: String,
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeJPAFieldTypeResponse {
+  pub entity: FileResponse,
+  pub old_field_type: String,
+  pub new_field_type: String,
+  pub warnings: Vec<String>,
+}
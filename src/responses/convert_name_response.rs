@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertNameResponse {
+  pub converted_name: String,
+  /// The words the name was split into, acronyms kept together
+  pub words: Vec<String>,
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+/// Outcome of one field of the batch, in request order
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldCreationStatusResponse {
+  pub field_name: String,
+  pub kind: String,
+  pub succeed: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJpaEntityFieldsResponse {
+  pub file: FileResponse,
+  pub fields: Vec<FieldCreationStatusResponse>,
+  pub created_count: usize,
+}
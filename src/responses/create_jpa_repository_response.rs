@@ -10,4 +10,5 @@ pub struct CreateJPARepositoryResponse {
   pub id_field_found: bool,
   pub superclass_type: Option<String>,
   pub repository: Option<FileResponse>,
+  pub warnings: Vec<String>,
 }
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteJavaFileResponse {
+  pub file_path: String,
+  pub deleted: bool,
+  /// Files still referencing the type, which kept `--check-usages` from deleting it.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub referencing_files: Vec<String>,
+}
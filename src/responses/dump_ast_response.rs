@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AstNodeResponse {
+  pub kind: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub field_name: Option<String>,
+  pub start_byte: usize,
+  pub end_byte: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text: Option<String>,
+  pub children: Vec<AstNodeResponse>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpAstResponse {
+  pub s_expression: String,
+  pub has_errors: bool,
+  pub root: AstNodeResponse,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// A single text replacement applied to a file, carrying enough data to reverse it.
+///
+/// `start_byte..old_end_byte` is the replaced range in the original source and `new_text`
+/// took its place, so the change is undone by replacing
+/// `start_byte..start_byte + new_text.len()` in the saved source with `old_text`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditRecord {
+  pub start_byte: usize,
+  pub old_end_byte: usize,
+  pub new_text: String,
+  pub old_text: String,
+}
@@ -1,9 +1,15 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+use crate::responses::edit_record_response::EditRecord;
+
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileResponse {
   pub file_type: String,
   pub file_package_name: String,
   pub file_path: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub edits: Vec<EditRecord>,
 }
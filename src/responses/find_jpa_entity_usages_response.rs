@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityUsageResponse {
+  pub file_path: String,
+  pub kind: String,
+  pub start_byte: usize,
+  pub end_byte: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindJpaEntityUsagesResponse {
+  pub entity_type: String,
+  pub usages: Vec<EntityUsageResponse>,
+}
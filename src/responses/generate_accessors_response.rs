@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateAccessorsResponse {
+  pub entity: FileResponse,
+  pub generated_method_names: Vec<String>,
+}
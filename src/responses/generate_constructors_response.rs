@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateConstructorsResponse {
+  pub entity: FileResponse,
+  pub generated_constructors: Vec<String>,
+}
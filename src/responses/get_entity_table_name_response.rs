@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// Where the effective table name of an entity comes from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableNameSource {
+  /// `@Table(name = ...)` on the entity itself.
+  Explicit,
+  /// `@Table(name = ...)` on the root of a single table hierarchy.
+  Inherited,
+  /// No `@Table` name; derived from the entity name through the naming strategy.
+  Derived,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityTableNameResponse {
+  pub entity_type: String,
+  pub table_name: String,
+  pub source: TableNameSource,
+  /// The entity whose declaration determines the table name.
+  pub declaring_type: String,
+}
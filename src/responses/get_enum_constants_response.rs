@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEnumConstantsResponse {
+  pub enum_type: String,
+  pub enum_package_name: String,
+  pub constants: Vec<String>,
+}
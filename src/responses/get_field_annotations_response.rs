@@ -0,0 +1,22 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// An annotation placed on a field. The single value of e.g. `@Temporal(TemporalType.DATE)` is
+/// reported under the `value` key, as Java names it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldAnnotationResponse {
+  /// Name as written in the source, e.g. `Column` or `jakarta.persistence.Column`
+  pub name: String,
+  /// Numeric, boolean and string literals become JSON values and `{...}` become arrays; any other
+  /// expression, e.g. `FetchType.LAZY`, keeps its source text
+  pub attributes: Map<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFieldAnnotationsResponse {
+  pub field_name: String,
+  pub field_type: String,
+  pub annotations: Vec<FieldAnnotationResponse>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFqcnResponse {
+  /// Fully-qualified name, the bare type name for files in the default package
+  pub fqcn: String,
+  /// Empty for files in the default package
+  pub package_name: String,
+  pub type_name: String,
+}
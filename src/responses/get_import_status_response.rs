@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+/// How a type is made visible to a file, checked in this order
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportStatus {
+  /// Imported by a single-type import
+  Imported,
+  /// Covered by an on-demand import of its package
+  Wildcard,
+  /// Declared in the package of the file
+  SamePackage,
+  /// Declared in `java.lang`, which is imported implicitly
+  JavaLang,
+  /// Not visible, an import is needed
+  NotImported,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetImportStatusResponse {
+  pub type_fqcn: String,
+  pub status: ImportStatus,
+  pub import_needed: bool,
+}
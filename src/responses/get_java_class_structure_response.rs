@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaFieldStructureResponse {
+  pub name: String,
+  pub field_type: String,
+  pub modifiers: Vec<String>,
+  pub start_byte: usize,
+  pub end_byte: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaParameterStructureResponse {
+  pub name: String,
+  pub parameter_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaMethodStructureResponse {
+  pub name: String,
+  pub kind: String,
+  pub return_type: Option<String>,
+  pub parameters: Vec<JavaParameterStructureResponse>,
+  pub modifiers: Vec<String>,
+  pub start_byte: usize,
+  pub end_byte: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaTypeStructureResponse {
+  pub name: String,
+  pub kind: String,
+  pub modifiers: Vec<String>,
+  pub start_byte: usize,
+  pub end_byte: usize,
+  pub fields: Vec<JavaFieldStructureResponse>,
+  pub methods: Vec<JavaMethodStructureResponse>,
+  pub nested_types: Vec<JavaTypeStructureResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJavaClassStructureResponse {
+  pub package_name: String,
+  pub types: Vec<JavaTypeStructureResponse>,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+/// Kind of the top-level type declared in a Java file. Classes annotated with a JPA type
+/// annotation report that annotation instead of `class`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JavaFileKind {
+  Class,
+  Interface,
+  Enum,
+  Record,
+  Annotation,
+  /// Class annotated with `@Entity`
+  Entity,
+  /// Class annotated with `@MappedSuperclass`
+  MappedSuperclass,
+  /// Class annotated with `@Embeddable`
+  Embeddable,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJavaFileTypeResponse {
+  pub type_name: String,
+  pub package_name: String,
+  pub kind: JavaFileKind,
+}
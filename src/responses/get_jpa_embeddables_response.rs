@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddableFieldResponse {
+  pub name: String,
+  pub field_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JpaEmbeddableResponse {
+  pub embeddable_type: String,
+  pub embeddable_package_name: String,
+  pub file_path: String,
+  /// Persistent fields, i.e. without the `static` and `transient` ones
+  pub fields: Vec<EmbeddableFieldResponse>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJpaEmbeddablesResponse {
+  pub embeddables: Vec<JpaEmbeddableResponse>,
+  pub embeddables_count: usize,
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JpaEnumResponse {
+  pub enum_type: String,
+  pub enum_package_name: String,
+  pub file_path: String,
+  pub constants: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJpaEnumsResponse {
+  pub enums: Vec<JpaEnumResponse>,
+  pub enums_count: usize,
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JpaRelationshipResponse {
+  pub field_name: String,
+  pub kind: String,
+  pub target_type: String,
+  pub mapped_by: Option<String>,
+  pub fetch_type: String,
+  pub cascades: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJpaRelationshipsResponse {
+  pub entity_type: String,
+  pub entity_package_name: String,
+  pub relationships: Vec<JpaRelationshipResponse>,
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageTreeNodeResponse {
+  /// Last segment of the package name, e.g. `service`
+  pub name: String,
+  pub package_name: String,
+  /// Number of Java files directly in the package, not counting sub-packages
+  pub file_count: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+  pub children: Vec<PackageTreeNodeResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPackagesTreeResponse {
+  pub packages: Vec<PackageTreeNodeResponse>,
+  pub packages_count: usize,
+}
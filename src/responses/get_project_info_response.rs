@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceRootResponse {
+  pub path: String,
+  pub source_set: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetProjectInfoResponse {
+  pub build_tool: String,
+  pub java_version: Option<String>,
+  pub persistence_namespace: Option<String>,
+  pub source_roots: Vec<SourceRootResponse>,
+}
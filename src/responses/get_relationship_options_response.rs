@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+/// Option values of one relationship kind, as accepted by its create command
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipOptionsResponse {
+  pub cascades: Vec<String>,
+  pub fetch_types: Vec<String>,
+  pub collection_types: Vec<String>,
+  pub owning_side_other: Vec<String>,
+  pub inverse_side_other: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRelationshipOptionsResponse {
+  pub one_to_one: RelationshipOptionsResponse,
+  pub many_to_one: RelationshipOptionsResponse,
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImplementInterfaceResponse {
+  pub class: FileResponse,
+  pub interface_name: String,
+  pub stubbed_method_names: Vec<String>,
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// A Java basic type with the `@Column`, `@Temporal`, `@TimeZoneStorage` and `@Lob` options the
+/// basic field command accepts for it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaTypeMetadataResponse {
+  pub id: String,
+  pub name: String,
+  pub package_path: Option<String>,
+  pub supports_length: bool,
+  pub supports_precision_scale: bool,
+  pub supports_temporal: bool,
+  pub supports_time_zone_storage: bool,
+  pub supports_lob: bool,
+}
@@ -1,12 +1,45 @@
+pub mod apply_edits_response;
 pub mod basic_java_type_response;
+pub mod change_jpa_enum_storage_response;
+pub mod change_jpa_field_type_response;
+pub mod convert_name_response;
 pub mod create_entity_field_response;
+pub mod create_jpa_entity_fields_response;
 pub mod create_jpa_one_to_one_relationship_response;
 pub mod create_jpa_repository_response;
 pub mod create_many_to_one_relationship_response;
+pub mod delete_java_file_response;
+pub mod dump_ast_response;
+pub mod edit_record_response;
 pub mod error_response;
 pub mod file_response;
+pub mod find_jpa_entity_usages_response;
+pub mod generate_accessors_response;
+pub mod generate_constructors_response;
+pub mod get_entity_table_name_response;
+pub mod get_enum_constants_response;
+pub mod get_field_annotations_response;
 pub mod get_files_response;
+pub mod get_fqcn_response;
+pub mod get_import_status_response;
+pub mod get_java_class_structure_response;
+pub mod get_java_file_type_response;
+pub mod get_jpa_embeddables_response;
 pub mod get_jpa_entity_info_response;
+pub mod get_jpa_enums_response;
+pub mod get_jpa_relationships_response;
 pub mod get_packages_response;
+pub mod get_packages_tree_response;
+pub mod get_project_info_response;
+pub mod get_relationship_options_response;
+pub mod implement_interface_response;
+pub mod java_type_metadata_response;
+pub mod move_jpa_field_response;
 pub mod package_response;
+pub mod remove_jpa_field_response;
+pub mod remove_unused_imports_response;
+pub mod rename_jpa_entity_response;
 pub mod response;
+pub mod timing_response;
+pub mod validate_identifier_response;
+pub mod validate_jpa_entity_response;
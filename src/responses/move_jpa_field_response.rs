@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveJPAFieldResponse {
+  pub entity: FileResponse,
+  pub superclass: FileResponse,
+  pub moved_field_name: String,
+  pub moved_method_names: Vec<String>,
+}
@@ -1,7 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Hash, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Hash, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PackageResponse {
   pub package_name: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
 }
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveJPAFieldResponse {
+  pub entity: FileResponse,
+  pub removed_field_name: String,
+  pub inverse_entity: Option<FileResponse>,
+  pub inverse_field_name: Option<String>,
+}
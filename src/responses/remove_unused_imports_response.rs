@@ -0,0 +1,10 @@
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveUnusedImportsResponse {
+  pub file: FileResponse,
+  pub removed_imports: Vec<String>,
+}
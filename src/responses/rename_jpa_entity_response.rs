@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+use crate::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameJPAEntityResponse {
+  pub old_entity_name: String,
+  pub new_entity_name: String,
+  pub entity: FileResponse,
+  pub repository: Option<FileResponse>,
+  pub touched_files: Vec<String>,
+}
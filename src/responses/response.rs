@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::common::utils::file_util::is_no_write;
+use crate::common::utils::timing_util::get_timings;
+use crate::responses::timing_response::TimingResponse;
+
 /// Universal response wrapper for all API operations.
 ///
 /// This struct provides a consistent format for all responses, whether successful or failed.
@@ -52,6 +56,20 @@ pub struct Response<T> {
   /// Error description, present only on failed operations
   #[serde(skip_serializing_if = "Option::is_none")]
   pub error_reason: Option<String>,
+
+  /// Decisions the user should know about, e.g. a dropped annotation; empty for clean operations
+  #[serde(default)]
+  pub warnings: Vec<String>,
+}
+
+/// Response as printed, with the phase timings appended under `--with-timing`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PrintedResponse<'a, T> {
+  #[serde(flatten)]
+  response: &'a Response<T>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  timing_ms: Option<TimingResponse>,
 }
 
 impl<T> Response<T>
@@ -70,7 +88,7 @@ where
   /// # Returns
   /// A new Response representing a successful operation
   pub fn success(command: String, cwd: String, data: T) -> Self {
-    Self { command, cwd, succeed: true, data: Some(data), error_reason: None }
+    Self { command, cwd, succeed: true, data: Some(data), error_reason: None, warnings: Vec::new() }
   }
 
   /// Creates a successful response without any data payload.
@@ -85,7 +103,7 @@ where
   /// # Returns
   /// A new Response representing a successful operation without data
   pub fn success_empty(command: String, cwd: String) -> Self {
-    Self { command, cwd, succeed: true, data: None, error_reason: None }
+    Self { command, cwd, succeed: true, data: None, error_reason: None, warnings: Vec::new() }
   }
 
   /// Creates a failure response with the provided error message.
@@ -107,7 +125,44 @@ where
     if reason.trim().is_empty() {
       panic!("Error reason cannot be empty");
     }
-    Self { command, cwd, succeed: false, data: None, error_reason: Some(reason) }
+    Self {
+      command,
+      cwd,
+      succeed: false,
+      data: None,
+      error_reason: Some(reason),
+      warnings: Vec::new(),
+    }
+  }
+
+  /// Attaches warnings about lossy decisions taken by the operation.
+  ///
+  /// # Arguments
+  /// * `warnings` - One human readable message per decision
+  ///
+  /// # Returns
+  /// This Response carrying the warnings
+  pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+    self.warnings = warnings;
+    self
+  }
+
+  /// Copy of this Response without its data payload, printed under `--no-write` so that a
+  /// validation run reports success or failure and warnings but no generated content.
+  fn without_data(&self) -> Response<()> {
+    Response {
+      command: self.command.clone(),
+      cwd: self.cwd.clone(),
+      succeed: self.succeed,
+      data: None,
+      error_reason: self.error_reason.clone(),
+      warnings: self.warnings.clone(),
+    }
+  }
+
+  /// Wraps this Response for printing, attaching the timings collected under `--with-timing`.
+  fn printed(&self) -> PrintedResponse<'_, T> {
+    PrintedResponse { response: self, timing_ms: get_timings() }
   }
 
   /// Serializes this Response to a compact JSON string.
@@ -118,14 +173,17 @@ where
   ///
   /// # Examples
   /// ```json
-  /// {"succeed":true,"data":{"field":"value"}}
-  /// {"succeed":false,"errorReason":"Something went wrong"}
+  /// {"succeed":true,"data":{"field":"value"},"warnings":[]}
+  /// {"succeed":false,"errorReason":"Something went wrong","warnings":[]}
   /// ```
   ///
   /// # Returns
   /// Result containing the JSON string or serialization error
   pub fn to_json(&self) -> Result<String, serde_json::Error> {
-    serde_json::to_string(self)
+    if is_no_write() {
+      return serde_json::to_string(&self.without_data().printed());
+    }
+    serde_json::to_string(&self.printed())
   }
 
   /// Serializes this Response to a pretty-formatted JSON string.
@@ -135,7 +193,10 @@ where
   /// # Returns
   /// Result containing the pretty JSON string or serialization error
   pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
-    serde_json::to_string_pretty(self)
+    if is_no_write() {
+      return serde_json::to_string_pretty(&self.without_data().printed());
+    }
+    serde_json::to_string_pretty(&self.printed())
   }
 
   /// Checks if this response represents a successful operation.
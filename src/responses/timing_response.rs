@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+/// Milliseconds a command spent in each phase, reported under `--with-timing`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimingResponse {
+  pub parse: f64,
+  pub scan: f64,
+  pub write: f64,
+}
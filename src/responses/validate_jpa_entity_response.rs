@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+  Error,
+  Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JpaEntityDiagnostic {
+  pub severity: DiagnosticSeverity,
+  pub code: String,
+  pub message: String,
+  pub start_byte: usize,
+  pub end_byte: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateJpaEntityResponse {
+  pub entity_type: String,
+  pub is_valid: bool,
+  pub diagnostics: Vec<JpaEntityDiagnostic>,
+}
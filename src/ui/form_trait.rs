@@ -246,6 +246,8 @@ pub mod helpers {
   use crossterm::event::KeyCode;
   use ratatui::widgets::ListState;
   use serde::Serialize;
+  use unicode_segmentation::UnicodeSegmentation;
+  use unicode_width::UnicodeWidthStr;
 
   use super::{FormState, InputMode};
   use crate::responses::response::Response;
@@ -302,28 +304,58 @@ pub mod helpers {
     state.select(Some(i));
   }
 
-  /// Handle text input (any characters allowed)
-  pub fn handle_text_input(
+  /// Number of user-perceived characters (grapheme clusters) in `text`. Text input cursors
+  /// count graphemes, so this is the cursor position at the end of the text.
+  pub fn text_len(text: &str) -> usize {
+    text.graphemes(true).count()
+  }
+
+  /// Byte offset of the grapheme at `cursor`, or the text length past the last one.
+  pub fn byte_index(text: &str, cursor: usize) -> usize {
+    text.grapheme_indices(true).nth(cursor).map_or(text.len(), |(index, _)| index)
+  }
+
+  /// Terminal column of `cursor`, accounting for wide characters.
+  pub fn cursor_column(text: &str, cursor: usize) -> u16 {
+    text[..byte_index(text, cursor)].width() as u16
+  }
+
+  /// Inserts `c` at `cursor` and moves the cursor past it. A combining mark joins the
+  /// preceding grapheme instead of starting a new one, so the cursor stays where it is.
+  pub fn insert_char(text: &mut String, cursor: &mut usize, c: char) {
+    let previous_len = text_len(text);
+    text.insert(byte_index(text, *cursor), c);
+    if text_len(text) > previous_len {
+      *cursor += 1;
+    }
+  }
+
+  /// Removes the grapheme at `cursor`, if any.
+  pub fn remove_grapheme(text: &mut String, cursor: usize) {
+    let start = byte_index(text, cursor);
+    let end = byte_index(text, cursor + 1);
+    text.replace_range(start..end, "");
+  }
+
+  fn edit_text(
     key: KeyCode,
     text: &mut String,
     cursor: &mut usize,
     mode: &mut InputMode,
+    accepts: impl Fn(char) -> bool,
   ) {
     match key {
-      KeyCode::Char(c) => {
-        text.insert(*cursor, c);
-        *cursor += 1;
+      KeyCode::Char(c) if accepts(c) => {
+        insert_char(text, cursor, c);
       }
       KeyCode::Backspace => {
         if *cursor > 0 {
-          text.remove(*cursor - 1);
           *cursor -= 1;
+          remove_grapheme(text, *cursor);
         }
       }
       KeyCode::Delete => {
-        if *cursor < text.len() {
-          text.remove(*cursor);
-        }
+        remove_grapheme(text, *cursor);
       }
       KeyCode::Left => {
         if *cursor > 0 {
@@ -331,7 +363,7 @@ pub mod helpers {
         }
       }
       KeyCode::Right => {
-        if *cursor < text.len() {
+        if *cursor < text_len(text) {
           *cursor += 1;
         }
       }
@@ -339,7 +371,7 @@ pub mod helpers {
         *cursor = 0;
       }
       KeyCode::End => {
-        *cursor = text.len();
+        *cursor = text_len(text);
       }
       KeyCode::Enter => {
         *mode = InputMode::Normal;
@@ -348,6 +380,26 @@ pub mod helpers {
     }
   }
 
+  /// Handle text input (any characters allowed)
+  pub fn handle_text_input(
+    key: KeyCode,
+    text: &mut String,
+    cursor: &mut usize,
+    mode: &mut InputMode,
+  ) {
+    edit_text(key, text, cursor, mode, |_| true);
+  }
+
+  /// Handle qualified Java name input (letters, digits, `.`, `_` and `$` allowed)
+  pub fn handle_qualified_name_input(
+    key: KeyCode,
+    text: &mut String,
+    cursor: &mut usize,
+    mode: &mut InputMode,
+  ) {
+    edit_text(key, text, cursor, mode, |c| c.is_alphanumeric() || matches!(c, '.' | '_' | '$'));
+  }
+
   /// Handle numeric input (only digits allowed)
   pub fn handle_numeric_input(
     key: KeyCode,
@@ -355,43 +407,7 @@ pub mod helpers {
     cursor: &mut usize,
     mode: &mut InputMode,
   ) {
-    match key {
-      KeyCode::Char(c) if c.is_ascii_digit() => {
-        text.insert(*cursor, c);
-        *cursor += 1;
-      }
-      KeyCode::Backspace => {
-        if *cursor > 0 {
-          text.remove(*cursor - 1);
-          *cursor -= 1;
-        }
-      }
-      KeyCode::Delete => {
-        if *cursor < text.len() {
-          text.remove(*cursor);
-        }
-      }
-      KeyCode::Left => {
-        if *cursor > 0 {
-          *cursor -= 1;
-        }
-      }
-      KeyCode::Right => {
-        if *cursor < text.len() {
-          *cursor += 1;
-        }
-      }
-      KeyCode::Home => {
-        *cursor = 0;
-      }
-      KeyCode::End => {
-        *cursor = text.len();
-      }
-      KeyCode::Enter => {
-        *mode = InputMode::Normal;
-      }
-      _ => {}
-    }
+    edit_text(key, text, cursor, mode, |c| c.is_ascii_digit());
   }
 }
 
@@ -10,11 +10,13 @@ use ratatui::{
 };
 use std::path::PathBuf;
 
-use crate::commands::{create_jpa_entity_basic_field_command, get_java_basic_types_command};
+use crate::commands::{create_jpa_entity_basic_field_command, get_java_type_metadata_command};
 use crate::common::types::basic_field_config::BasicFieldConfig;
-use crate::common::types::java_basic_types::JavaBasicType;
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::types::field_access::FieldAccess;
 use crate::common::types::java_field_temporal::JavaFieldTemporal;
 use crate::common::types::java_field_time_zone_storage::JavaFieldTimeZoneStorage;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 use crate::responses::basic_java_type_response::JavaBasicTypeResponse;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, helpers};
 
@@ -28,6 +30,7 @@ enum FocusedField {
   Temporal,
   PrecisionAndScale,
   OtherOptions,
+  ConverterClass,
   BackButton,
   ConfirmButton,
 }
@@ -52,6 +55,10 @@ pub struct CreateBasicFieldForm {
   mandatory: bool,
   unique: bool,
   large_object: bool,
+  natural_id: bool,
+
+  // Advanced options
+  converter_class: String,
 
   // Type lists and metadata
   all_types: Vec<JavaBasicTypeResponse>,
@@ -72,6 +79,7 @@ pub struct CreateBasicFieldForm {
   field_length_cursor: usize,
   field_precision_cursor: usize,
   field_scale_cursor: usize,
+  converter_class_cursor: usize,
 
   // Visibility flags
   field_length_hidden: bool,
@@ -144,6 +152,8 @@ impl CreateBasicFieldForm {
       mandatory: false,
       unique: false,
       large_object: false,
+      natural_id: false,
+      converter_class: String::new(),
       all_types: type_data.all_types,
       types_with_length: type_data.types_with_length,
       types_with_time_zone_storage: type_data.types_with_time_zone_storage,
@@ -158,6 +168,7 @@ impl CreateBasicFieldForm {
       field_length_cursor: 3,
       field_precision_cursor: 2,
       field_scale_cursor: 1,
+      converter_class_cursor: 0,
       field_length_hidden: false,
       field_temporal_hidden: true,
       field_time_zone_storage_hidden: true,
@@ -238,51 +249,30 @@ impl CreateBasicFieldForm {
       types_with_precision_and_scale: vec![],
     };
 
-    // Fetch all types
-    let response = get_java_basic_types_command::execute(&JavaBasicType::AllTypes);
-    if let Some(types) = response.data {
-      type_data.all_types = types;
-    }
-
-    // Fetch types with length
-    let response = get_java_basic_types_command::execute(&JavaBasicType::TypesWithLength);
-    if let Some(types) = response.data {
-      for type_obj in types {
-        type_data.types_with_length.push(type_obj.id);
+    // Fetch every type with its capability flags in one call
+    let response = get_java_type_metadata_command::execute();
+    for type_metadata in response.data.unwrap_or_default() {
+      let type_id = type_metadata.id.clone();
+      if type_metadata.supports_length {
+        type_data.types_with_length.push(type_id.clone());
       }
-    }
-
-    // Fetch types with time zone storage
-    let response = get_java_basic_types_command::execute(&JavaBasicType::TypesWithTimeZoneStorage);
-    if let Some(types) = response.data {
-      for type_obj in types {
-        type_data.types_with_time_zone_storage.push(type_obj.id);
+      if type_metadata.supports_time_zone_storage {
+        type_data.types_with_time_zone_storage.push(type_id.clone());
       }
-    }
-
-    // Fetch types with temporal
-    let response = get_java_basic_types_command::execute(&JavaBasicType::TypesWithTemporal);
-    if let Some(types) = response.data {
-      for type_obj in types {
-        type_data.types_with_temporal.push(type_obj.id);
+      if type_metadata.supports_temporal {
+        type_data.types_with_temporal.push(type_id.clone());
       }
-    }
-
-    // Fetch types with extra other
-    let response = get_java_basic_types_command::execute(&JavaBasicType::TypesWithExtraOther);
-    if let Some(types) = response.data {
-      for type_obj in types {
-        type_data.types_with_extra_other.push(type_obj.id);
+      if type_metadata.supports_lob {
+        type_data.types_with_extra_other.push(type_id.clone());
       }
-    }
-
-    // Fetch types with precision and scale
-    let response =
-      get_java_basic_types_command::execute(&JavaBasicType::TypesWithPrecisionAndScale);
-    if let Some(types) = response.data {
-      for type_obj in types {
-        type_data.types_with_precision_and_scale.push(type_obj.id);
+      if type_metadata.supports_precision_scale {
+        type_data.types_with_precision_and_scale.push(type_id);
       }
+      type_data.all_types.push(JavaBasicTypeResponse {
+        id: type_metadata.id,
+        name: type_metadata.name,
+        package_path: type_metadata.package_path,
+      });
     }
 
     Ok(type_data)
@@ -330,7 +320,8 @@ impl CreateBasicFieldForm {
         FocusedField::TimeZoneStorage => FocusedField::Temporal,
         FocusedField::Temporal => FocusedField::PrecisionAndScale,
         FocusedField::PrecisionAndScale => FocusedField::OtherOptions,
-        FocusedField::OtherOptions => FocusedField::BackButton,
+        FocusedField::OtherOptions => FocusedField::ConverterClass,
+        FocusedField::ConverterClass => FocusedField::BackButton,
         FocusedField::BackButton => FocusedField::ConfirmButton,
         FocusedField::ConfirmButton => FocusedField::FieldType,
       };
@@ -356,7 +347,8 @@ impl CreateBasicFieldForm {
         FocusedField::Temporal => FocusedField::TimeZoneStorage,
         FocusedField::PrecisionAndScale => FocusedField::Temporal,
         FocusedField::OtherOptions => FocusedField::PrecisionAndScale,
-        FocusedField::BackButton => FocusedField::OtherOptions,
+        FocusedField::ConverterClass => FocusedField::OtherOptions,
+        FocusedField::BackButton => FocusedField::ConverterClass,
         FocusedField::ConfirmButton => FocusedField::BackButton,
       };
 
@@ -384,10 +376,13 @@ impl CreateBasicFieldForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::FieldName => {
-          self.field_name_cursor = self.field_name.len();
+          self.field_name_cursor = helpers::text_len(&self.field_name);
         }
         FocusedField::FieldLength => {
-          self.field_length_cursor = self.field_length.len();
+          self.field_length_cursor = helpers::text_len(&self.field_length);
+        }
+        FocusedField::ConverterClass => {
+          self.converter_class_cursor = helpers::text_len(&self.converter_class);
         }
         _ => {}
       }
@@ -422,6 +417,7 @@ impl CreateBasicFieldForm {
       FocusedField::Temporal => self.handle_temporal_insert(key),
       FocusedField::PrecisionAndScale => self.handle_precision_scale_input(key),
       FocusedField::OtherOptions => self.handle_other_options_insert(key),
+      FocusedField::ConverterClass => self.handle_converter_class_input(key),
       FocusedField::BackButton => {
         // Back button requires double press for confirmation
         if key == KeyCode::Enter {
@@ -461,60 +457,28 @@ impl CreateBasicFieldForm {
   }
 
   fn handle_field_name_input(&mut self, key: KeyCode) {
-    match key {
-      KeyCode::Char(c) => {
-        self.field_name.insert(self.field_name_cursor, c);
-        self.field_name_cursor += 1;
-      }
-      KeyCode::Backspace => {
-        if self.field_name_cursor > 0 {
-          self.field_name.remove(self.field_name_cursor - 1);
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Delete => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name.remove(self.field_name_cursor);
-        }
-      }
-      KeyCode::Left => {
-        if self.field_name_cursor > 0 {
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Right => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name_cursor += 1;
-        }
-      }
-      KeyCode::Home => {
-        self.field_name_cursor = 0;
-      }
-      KeyCode::End => {
-        self.field_name_cursor = self.field_name.len();
-      }
-      KeyCode::Enter => {
-        self.state.input_mode = InputMode::Normal;
-      }
-      _ => {}
-    }
+    helpers::handle_text_input(
+      key,
+      &mut self.field_name,
+      &mut self.field_name_cursor,
+      &mut self.state.input_mode,
+    );
   }
 
   fn handle_field_length_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) if c.is_ascii_digit() => {
-        self.field_length.insert(self.field_length_cursor, c);
-        self.field_length_cursor += 1;
+        helpers::insert_char(&mut self.field_length, &mut self.field_length_cursor, c);
       }
       KeyCode::Backspace => {
         if self.field_length_cursor > 0 {
-          self.field_length.remove(self.field_length_cursor - 1);
           self.field_length_cursor -= 1;
+          helpers::remove_grapheme(&mut self.field_length, self.field_length_cursor);
         }
       }
       KeyCode::Delete => {
-        if self.field_length_cursor < self.field_length.len() {
-          self.field_length.remove(self.field_length_cursor);
+        if self.field_length_cursor < helpers::text_len(&self.field_length) {
+          helpers::remove_grapheme(&mut self.field_length, self.field_length_cursor);
         }
       }
       KeyCode::Left => {
@@ -523,7 +487,7 @@ impl CreateBasicFieldForm {
         }
       }
       KeyCode::Right => {
-        if self.field_length_cursor < self.field_length.len() {
+        if self.field_length_cursor < helpers::text_len(&self.field_length) {
           self.field_length_cursor += 1;
         }
       }
@@ -531,7 +495,7 @@ impl CreateBasicFieldForm {
         self.field_length_cursor = 0;
       }
       KeyCode::End => {
-        self.field_length_cursor = self.field_length.len();
+        self.field_length_cursor = helpers::text_len(&self.field_length);
       }
       KeyCode::Enter => {
         self.state.input_mode = InputMode::Normal;
@@ -587,13 +551,12 @@ impl CreateBasicFieldForm {
     // In a more sophisticated implementation, you could track which one is focused
     match key {
       KeyCode::Char(c) if c.is_ascii_digit() => {
-        self.field_precision.insert(self.field_precision_cursor, c);
-        self.field_precision_cursor += 1;
+        helpers::insert_char(&mut self.field_precision, &mut self.field_precision_cursor, c);
       }
       KeyCode::Backspace => {
         if self.field_precision_cursor > 0 {
-          self.field_precision.remove(self.field_precision_cursor - 1);
           self.field_precision_cursor -= 1;
+          helpers::remove_grapheme(&mut self.field_precision, self.field_precision_cursor);
         }
       }
       KeyCode::Tab => {
@@ -610,11 +573,11 @@ impl CreateBasicFieldForm {
   fn handle_other_options_insert(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char('j') | KeyCode::Down => {
-        let len = if !self.other_extra_hidden { 3 } else { 2 };
+        let len = if !self.other_extra_hidden { 4 } else { 3 };
         helpers::navigate_list_static(&KeyCode::Down, &mut self.other_options_state, len);
       }
       KeyCode::Char('k') | KeyCode::Up => {
-        let len = if !self.other_extra_hidden { 3 } else { 2 };
+        let len = if !self.other_extra_hidden { 4 } else { 3 };
         helpers::navigate_list_static(&KeyCode::Up, &mut self.other_options_state, len);
       }
       KeyCode::Char(' ') | KeyCode::Enter => {
@@ -625,12 +588,14 @@ impl CreateBasicFieldForm {
               0 => self.large_object = !self.large_object,
               1 => self.mandatory = !self.mandatory,
               2 => self.unique = !self.unique,
+              3 => self.natural_id = !self.natural_id,
               _ => {}
             }
           } else {
             match idx {
               0 => self.mandatory = !self.mandatory,
               1 => self.unique = !self.unique,
+              2 => self.natural_id = !self.natural_id,
               _ => {}
             }
           }
@@ -643,11 +608,21 @@ impl CreateBasicFieldForm {
     }
   }
 
+  fn handle_converter_class_input(&mut self, key: KeyCode) {
+    helpers::handle_qualified_name_input(
+      key,
+      &mut self.converter_class,
+      &mut self.converter_class_cursor,
+      &mut self.state.input_mode,
+    );
+  }
+
   fn execute_create_basic_field(&mut self) {
-    // Parse numeric fields
-    let field_length = self.field_length.parse::<u16>().ok();
-    let field_precision = self.field_precision.parse::<u16>().ok();
-    let field_scale = self.field_scale.parse::<u16>().ok();
+    // Parse numeric fields, skipping the ones hidden for the selected type
+    let field_length = self.field_length.parse::<u16>().ok().filter(|_| !self.field_length_hidden);
+    let field_precision =
+      self.field_precision.parse::<u16>().ok().filter(|_| !self.field_precision_hidden);
+    let field_scale = self.field_scale.parse::<u16>().ok().filter(|_| !self.field_scale_hidden);
 
     // Get time zone storage
     let field_timezone_storage = if !self.field_time_zone_storage_hidden {
@@ -682,6 +657,21 @@ impl CreateBasicFieldForm {
       field_unique: self.unique,
       field_nullable: !self.mandatory,
       field_large_object: self.large_object,
+      basic_fetch: None,
+      field_natural_id: self.natural_id,
+      field_converter_class: Some(self.converter_class.trim().to_string())
+        .filter(|converter| !converter.is_empty()),
+      column_naming_strategy: ColumnNamingStrategy::default(),
+      explicit_column_name: false,
+      field_access: FieldAccess::default(),
+      after_field: None,
+      at_top: false,
+      javadoc: None,
+      mark_generated: None,
+      default_value: None,
+      column_definition: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
     };
 
     // Call command layer instead of service directly
@@ -738,7 +728,10 @@ impl CreateBasicFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.field_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.field_name, self.field_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -755,7 +748,10 @@ impl CreateBasicFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.field_length_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.field_length, self.field_length_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -867,9 +863,13 @@ impl CreateBasicFieldForm {
       )));
       items.push(ListItem::new(format!(" [{}] Mandatory", if self.mandatory { "x" } else { " " })));
       items.push(ListItem::new(format!(" [{}] Unique", if self.unique { "x" } else { " " })));
+      items
+        .push(ListItem::new(format!(" [{}] Natural ID", if self.natural_id { "x" } else { " " })));
     } else {
       items.push(ListItem::new(format!(" [{}] Mandatory", if self.mandatory { "x" } else { " " })));
       items.push(ListItem::new(format!(" [{}] Unique", if self.unique { "x" } else { " " })));
+      items
+        .push(ListItem::new(format!(" [{}] Natural ID", if self.natural_id { "x" } else { " " })));
     }
 
     let border_style =
@@ -884,6 +884,22 @@ impl CreateBasicFieldForm {
     frame.render_stateful_widget(list, area, &mut self.other_options_state);
   }
 
+  fn render_converter_class_input(&mut self, frame: &mut Frame, area: Rect) {
+    let is_focused = self.focused_field == FocusedField::ConverterClass;
+    let border_style =
+      if is_focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+    let title = self.generate_title("Converter class (optional, fully qualified)", is_focused);
+    let input = Paragraph::new(self.converter_class.as_str())
+      .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
+    frame.render_widget(input, area);
+    if is_focused && self.state.input_mode == InputMode::Insert {
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.converter_class, self.converter_class_cursor) + 1,
+        area.y + 1,
+      ));
+    }
+  }
+
   fn render_buttons(&self, frame: &mut Frame, area: Rect) {
     use crate::ui::form_trait::button_helpers::{ButtonType, render_two_button_layout};
 
@@ -921,9 +937,9 @@ impl CreateBasicFieldForm {
     let other_height = if self.other_hidden && self.other_extra_hidden {
       0
     } else if !self.other_extra_hidden {
-      5
+      6
     } else {
-      4
+      5
     };
 
     let mut constraints = vec![
@@ -947,6 +963,7 @@ impl CreateBasicFieldForm {
     if other_height > 0 {
       constraints.push(Constraint::Length(other_height));
     }
+    constraints.push(Constraint::Length(3)); // Converter class input
 
     constraints.push(Constraint::Min(0)); // Flexible space for errors
     constraints.push(Constraint::Length(1)); // Confirm button
@@ -990,6 +1007,9 @@ impl CreateBasicFieldForm {
       chunk_idx += 1;
     }
 
+    self.render_converter_class_input(frame, chunks[chunk_idx]);
+    chunk_idx += 1;
+
     // Render error message if present
     if let Some(ref error_msg) = self.state.error_message {
       let error_paragraph =
@@ -7,6 +7,7 @@ use ratatui::{
 };
 use std::path::PathBuf;
 
+use crate::common::types::id_field_naming::IdFieldNaming;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 use crate::ui::forms::create_basic_field::CreateBasicFieldForm;
 use crate::ui::forms::create_enum_field::CreateEnumFieldForm;
@@ -72,13 +73,19 @@ pub struct CreateEntityFieldForm {
   cwd: PathBuf,
   entity_file_b64_src: String,
   entity_file_path: PathBuf,
+  id_field_naming: IdFieldNaming,
 
   // Child form (if navigated to)
   child_form: Option<ChildFormType>,
 }
 
 impl CreateEntityFieldForm {
-  pub fn new(cwd: PathBuf, entity_file_b64_src: String, entity_file_path: PathBuf) -> Self {
+  pub fn new(
+    cwd: PathBuf,
+    entity_file_b64_src: String,
+    entity_file_path: PathBuf,
+    id_field_naming: IdFieldNaming,
+  ) -> Self {
     let mut category_state = ListState::default();
     category_state.select(Some(0));
 
@@ -91,6 +98,7 @@ impl CreateEntityFieldForm {
       cwd,
       entity_file_b64_src,
       entity_file_path,
+      id_field_naming,
       child_form: None,
     }
   }
@@ -134,6 +142,7 @@ impl CreateEntityFieldForm {
           self.cwd.clone(),
           self.entity_file_b64_src.clone(),
           self.entity_file_path.clone(),
+          &self.id_field_naming,
         );
         self.child_form = Some(ChildFormType::Id(Box::new(id_form)));
         self.phase = FormPhase::ChildForm;
@@ -115,7 +115,7 @@ impl CreateEntityRelationshipForm {
   }
 
   fn fetch_entity_files(cwd: &Path) -> Result<String, Box<dyn std::error::Error>> {
-    let response = get_all_jpa_entities_command::execute(cwd);
+    let response = get_all_jpa_entities_command::execute(cwd, None);
     let json = response.to_json_pretty()?;
     Ok(json)
   }
@@ -10,9 +10,13 @@ use ratatui::{
 use std::path::{Path, PathBuf};
 
 use crate::commands::{create_jpa_entity_enum_field_command, get_java_files_command};
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
 use crate::common::types::enum_field_config::EnumFieldConfig;
+use crate::common::types::field_access::FieldAccess;
 use crate::common::types::java_enum_type::JavaEnumType;
 use crate::common::types::java_file_type::JavaFileType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::utils::naming_util::field_name_for_type;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, helpers};
 
 /// Represents which field is currently focused
@@ -110,7 +114,7 @@ impl CreateEnumFieldForm {
       (String::new(), String::new(), String::new())
     };
 
-    let default_field_name = Self::auto_field_name(&default_enum_type);
+    let default_field_name = field_name_for_type(&default_enum_type);
 
     Self {
       state: FormState::new(),
@@ -141,7 +145,7 @@ impl CreateEnumFieldForm {
 
   /// Fetch enum types from syntaxpresso-core
   fn fetch_enum_types(cwd: &Path) -> Result<Vec<EnumFileResponse>, Box<dyn std::error::Error>> {
-    let response = get_java_files_command::execute(cwd, &JavaFileType::Enum);
+    let response = get_java_files_command::execute(cwd, &JavaFileType::Enum, None);
 
     let mut enum_types = Vec::new();
     if let Some(data) = response.data {
@@ -157,15 +161,6 @@ impl CreateEnumFieldForm {
     Ok(enum_types)
   }
 
-  /// Auto-generate field name from enum type (CamelCase -> camelCase)
-  fn auto_field_name(type_name: &str) -> String {
-    if type_name.is_empty() {
-      return String::new();
-    }
-    let first_char = type_name.chars().next().unwrap();
-    format!("{}{}", first_char.to_lowercase(), &type_name[1..])
-  }
-
   /// Update enum type and related values
   fn update_enum_type(&mut self) {
     if let Some(idx) = self.enum_type_state.selected()
@@ -175,8 +170,8 @@ impl CreateEnumFieldForm {
       self.enum_type = enum_info.file_type.clone();
       self.enum_package_name = enum_info.file_package_name.clone();
       self.enum_type_path = enum_info.file_path.clone();
-      self.field_name = Self::auto_field_name(&self.enum_type);
-      self.field_name_cursor = self.field_name.len();
+      self.field_name = field_name_for_type(&self.enum_type);
+      self.field_name_cursor = helpers::text_len(&self.field_name);
     }
   }
 
@@ -248,10 +243,10 @@ impl CreateEnumFieldForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::FieldName => {
-          self.field_name_cursor = self.field_name.len();
+          self.field_name_cursor = helpers::text_len(&self.field_name);
         }
         FocusedField::FieldLength => {
-          self.field_length_cursor = self.field_length.len();
+          self.field_length_cursor = helpers::text_len(&self.field_length);
         }
         _ => {}
       }
@@ -342,60 +337,28 @@ impl CreateEnumFieldForm {
   }
 
   fn handle_field_name_input(&mut self, key: KeyCode) {
-    match key {
-      KeyCode::Char(c) => {
-        self.field_name.insert(self.field_name_cursor, c);
-        self.field_name_cursor += 1;
-      }
-      KeyCode::Backspace => {
-        if self.field_name_cursor > 0 {
-          self.field_name.remove(self.field_name_cursor - 1);
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Delete => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name.remove(self.field_name_cursor);
-        }
-      }
-      KeyCode::Left => {
-        if self.field_name_cursor > 0 {
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Right => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name_cursor += 1;
-        }
-      }
-      KeyCode::Home => {
-        self.field_name_cursor = 0;
-      }
-      KeyCode::End => {
-        self.field_name_cursor = self.field_name.len();
-      }
-      KeyCode::Enter => {
-        self.state.input_mode = InputMode::Normal;
-      }
-      _ => {}
-    }
+    helpers::handle_text_input(
+      key,
+      &mut self.field_name,
+      &mut self.field_name_cursor,
+      &mut self.state.input_mode,
+    );
   }
 
   fn handle_field_length_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) if c.is_ascii_digit() => {
-        self.field_length.insert(self.field_length_cursor, c);
-        self.field_length_cursor += 1;
+        helpers::insert_char(&mut self.field_length, &mut self.field_length_cursor, c);
       }
       KeyCode::Backspace => {
         if self.field_length_cursor > 0 {
-          self.field_length.remove(self.field_length_cursor - 1);
           self.field_length_cursor -= 1;
+          helpers::remove_grapheme(&mut self.field_length, self.field_length_cursor);
         }
       }
       KeyCode::Delete => {
-        if self.field_length_cursor < self.field_length.len() {
-          self.field_length.remove(self.field_length_cursor);
+        if self.field_length_cursor < helpers::text_len(&self.field_length) {
+          helpers::remove_grapheme(&mut self.field_length, self.field_length_cursor);
         }
       }
       KeyCode::Left => {
@@ -404,7 +367,7 @@ impl CreateEnumFieldForm {
         }
       }
       KeyCode::Right => {
-        if self.field_length_cursor < self.field_length.len() {
+        if self.field_length_cursor < helpers::text_len(&self.field_length) {
           self.field_length_cursor += 1;
         }
       }
@@ -412,7 +375,7 @@ impl CreateEnumFieldForm {
         self.field_length_cursor = 0;
       }
       KeyCode::End => {
-        self.field_length_cursor = self.field_length.len();
+        self.field_length_cursor = helpers::text_len(&self.field_length);
       }
       KeyCode::Enter => {
         self.state.input_mode = InputMode::Normal;
@@ -475,6 +438,16 @@ impl CreateEnumFieldForm {
       field_length,
       field_nullable: !self.mandatory,
       field_unique: self.unique,
+      column_naming_strategy: ColumnNamingStrategy::default(),
+      explicit_column_name: false,
+      field_access: FieldAccess::default(),
+      after_field: None,
+      at_top: false,
+      javadoc: None,
+      mark_generated: None,
+      default_value: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
     };
 
     // Call command layer instead of service directly
@@ -558,7 +531,10 @@ impl CreateEnumFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.field_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.field_name, self.field_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -575,7 +551,10 @@ impl CreateEnumFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.field_length_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.field_length, self.field_length_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
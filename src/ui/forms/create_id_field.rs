@@ -13,10 +13,14 @@ use std::path::{Path, PathBuf};
 use crate::commands::{
   create_jpa_entity_id_field_command, get_java_basic_types_command, get_jpa_entity_info_command,
 };
+use crate::common::types::column_naming_strategy::ColumnNamingStrategy;
+use crate::common::types::field_access::FieldAccess;
 use crate::common::types::id_field_config::IdFieldConfig;
+use crate::common::types::id_field_naming::IdFieldNaming;
 use crate::common::types::java_basic_types::JavaBasicType;
 use crate::common::types::java_id_generation::JavaIdGeneration;
 use crate::common::types::java_id_generation_type::JavaIdGenerationType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 use crate::responses::basic_java_type_response::JavaBasicTypeResponse;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 
@@ -149,7 +153,14 @@ pub struct CreateIdFieldForm {
 }
 
 impl CreateIdFieldForm {
-  pub fn new(cwd: PathBuf, entity_file_b64_src: String, entity_file_path: PathBuf) -> Self {
+  /// Builds the form with the field name `id_field_naming` gives for the entity, or `id` when
+  /// the entity cannot be read.
+  pub fn new(
+    cwd: PathBuf,
+    entity_file_b64_src: String,
+    entity_file_path: PathBuf,
+    id_field_naming: &IdFieldNaming,
+  ) -> Self {
     // Fetch ID types from syntaxpresso-core
     let all_id_types = match Self::fetch_id_types() {
       Ok(types) => types,
@@ -161,8 +172,12 @@ impl CreateIdFieldForm {
 
     assert!(!all_id_types.is_empty(), "all_id_types must not be empty!");
 
-    // Fetch entity info to generate generator and sequence names
-    let (generator_name, sequence_name) = match Self::fetch_entity_info(&cwd, &entity_file_path) {
+    // Fetch entity info to generate the field, generator and sequence names
+    let entity_info = Self::fetch_entity_info(&cwd, &entity_file_path);
+    let entity_type = entity_info.as_ref().map_or("", |(_, entity_type)| entity_type.as_str());
+    let field_name = id_field_naming.field_name(entity_type);
+    let field_name_cursor = helpers::text_len(&field_name);
+    let (generator_name, sequence_name) = match entity_info {
       Ok((entity_table_name, entity_type)) => {
         // Use table name if available, otherwise derive from entity type
         let base_name = if let Some(table_name) = entity_table_name {
@@ -181,8 +196,8 @@ impl CreateIdFieldForm {
       Err(_) => (String::new(), String::new()),
     };
 
-    let generator_name_cursor = generator_name.len();
-    let sequence_name_cursor = sequence_name.len();
+    let generator_name_cursor = helpers::text_len(&generator_name);
+    let sequence_name_cursor = helpers::text_len(&sequence_name);
 
     let mut field_type_state = ListState::default();
     field_type_state.select(Some(0));
@@ -208,7 +223,7 @@ impl CreateIdFieldForm {
       field_type_index: 0,
       field_package_path: default_package,
       field_type: default_type,
-      field_name: "id".to_string(),
+      field_name,
       id_generation_index: 1, // Auto
       id_generation: IdGeneration::Auto,
       generation_type_index: 0, // OrmProvided
@@ -224,7 +239,7 @@ impl CreateIdFieldForm {
       id_generation_state,
       generation_type_state,
       other_options_state,
-      field_name_cursor: 2, // "id".len()
+      field_name_cursor,
       generator_name_cursor,
       sequence_name_cursor,
       initial_value_cursor: 1,
@@ -449,19 +464,19 @@ impl CreateIdFieldForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::FieldName => {
-          self.field_name_cursor = self.field_name.len();
+          self.field_name_cursor = helpers::text_len(&self.field_name);
         }
         FocusedField::GeneratorName => {
-          self.generator_name_cursor = self.generator_name.len();
+          self.generator_name_cursor = helpers::text_len(&self.generator_name);
         }
         FocusedField::SequenceName => {
-          self.sequence_name_cursor = self.sequence_name.len();
+          self.sequence_name_cursor = helpers::text_len(&self.sequence_name);
         }
         FocusedField::InitialValue => {
-          self.initial_value_cursor = self.initial_value.len();
+          self.initial_value_cursor = helpers::text_len(&self.initial_value);
         }
         FocusedField::AllocationSize => {
-          self.allocation_size_cursor = self.allocation_size.len();
+          self.allocation_size_cursor = helpers::text_len(&self.allocation_size);
         }
         _ => {}
       }
@@ -534,43 +549,12 @@ impl CreateIdFieldForm {
   }
 
   fn handle_field_name_input(&mut self, key: KeyCode) {
-    match key {
-      KeyCode::Char(c) => {
-        self.field_name.insert(self.field_name_cursor, c);
-        self.field_name_cursor += 1;
-      }
-      KeyCode::Backspace => {
-        if self.field_name_cursor > 0 {
-          self.field_name.remove(self.field_name_cursor - 1);
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Delete => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name.remove(self.field_name_cursor);
-        }
-      }
-      KeyCode::Left => {
-        if self.field_name_cursor > 0 {
-          self.field_name_cursor -= 1;
-        }
-      }
-      KeyCode::Right => {
-        if self.field_name_cursor < self.field_name.len() {
-          self.field_name_cursor += 1;
-        }
-      }
-      KeyCode::Home => {
-        self.field_name_cursor = 0;
-      }
-      KeyCode::End => {
-        self.field_name_cursor = self.field_name.len();
-      }
-      KeyCode::Enter => {
-        self.state.input_mode = InputMode::Normal;
-      }
-      _ => {}
-    }
+    helpers::handle_text_input(
+      key,
+      &mut self.field_name,
+      &mut self.field_name_cursor,
+      &mut self.state.input_mode,
+    );
   }
 
   fn handle_id_generation_insert(&mut self, key: KeyCode) {
@@ -727,6 +711,14 @@ impl CreateIdFieldForm {
       field_initial_value: initial_value,
       field_allocation_size: allocation_size,
       field_nullable: !self.mandatory,
+      column_naming_strategy: ColumnNamingStrategy::default(),
+      field_access: FieldAccess::default(),
+      after_field: None,
+      at_top: false,
+      javadoc: None,
+      mark_generated: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
     };
 
     // Call command layer instead of service directly
@@ -787,7 +779,10 @@ impl CreateIdFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.field_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.field_name, self.field_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -867,7 +862,7 @@ impl CreateIdFieldForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((area.x + helpers::cursor_column(value, cursor) + 1, area.y + 1));
     }
   }
 
@@ -1034,6 +1029,15 @@ impl CreateIdFieldForm {
     self.render_buttons(frame, chunks[chunk_idx]);
   }
 
+  /// Current field name input and its cursor position
+  pub fn field_name(&self) -> &str {
+    &self.field_name
+  }
+
+  pub fn field_name_cursor(&self) -> usize {
+    self.field_name_cursor
+  }
+
   /// Check if user wants to go back to category selection
   pub fn should_go_back(&self) -> bool {
     self.should_go_back
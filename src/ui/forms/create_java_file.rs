@@ -89,7 +89,7 @@ impl CreateJavaFileForm {
       autocomplete_scroll_offset: 0,
       show_autocomplete: false,
       file_name_cursor: 7, // Position at end of "NewFile"
-      package_name_cursor: default_package.len(),
+      package_name_cursor: helpers::text_len(&default_package),
       focused_field: FocusedField::FileType,
       cwd,
     }
@@ -97,7 +97,7 @@ impl CreateJavaFileForm {
 
   /// Fetch packages directly from service
   fn fetch_packages(cwd: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main);
+    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main, None);
 
     if let Some(data) = response.data {
       Ok(data.packages.iter().map(|p| p.package_name.clone()).collect())
@@ -173,10 +173,10 @@ impl CreateJavaFileForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::FileName => {
-          self.file_name_cursor = self.file_name.len();
+          self.file_name_cursor = helpers::text_len(&self.file_name);
         }
         FocusedField::PackageName => {
-          self.package_name_cursor = self.package_name.len();
+          self.package_name_cursor = helpers::text_len(&self.package_name);
         }
         _ => {}
       }
@@ -227,18 +227,17 @@ impl CreateJavaFileForm {
   fn handle_file_name_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.file_name.insert(self.file_name_cursor, c);
-        self.file_name_cursor += 1;
+        helpers::insert_char(&mut self.file_name, &mut self.file_name_cursor, c);
       }
       KeyCode::Backspace => {
         if self.file_name_cursor > 0 {
-          self.file_name.remove(self.file_name_cursor - 1);
           self.file_name_cursor -= 1;
+          helpers::remove_grapheme(&mut self.file_name, self.file_name_cursor);
         }
       }
       KeyCode::Delete => {
-        if self.file_name_cursor < self.file_name.len() {
-          self.file_name.remove(self.file_name_cursor);
+        if self.file_name_cursor < helpers::text_len(&self.file_name) {
+          helpers::remove_grapheme(&mut self.file_name, self.file_name_cursor);
         }
       }
       KeyCode::Left => {
@@ -247,7 +246,7 @@ impl CreateJavaFileForm {
         }
       }
       KeyCode::Right => {
-        if self.file_name_cursor < self.file_name.len() {
+        if self.file_name_cursor < helpers::text_len(&self.file_name) {
           self.file_name_cursor += 1;
         }
       }
@@ -255,7 +254,7 @@ impl CreateJavaFileForm {
         self.file_name_cursor = 0;
       }
       KeyCode::End => {
-        self.file_name_cursor = self.file_name.len();
+        self.file_name_cursor = helpers::text_len(&self.file_name);
       }
       _ => {}
     }
@@ -264,8 +263,7 @@ impl CreateJavaFileForm {
   fn handle_package_name_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.package_name.insert(self.package_name_cursor, c);
-        self.package_name_cursor += 1;
+        helpers::insert_char(&mut self.package_name, &mut self.package_name_cursor, c);
         // Update autocomplete suggestions
         self.filter_packages();
         self.autocomplete_selected_index = None;
@@ -273,8 +271,8 @@ impl CreateJavaFileForm {
       }
       KeyCode::Backspace => {
         if self.package_name_cursor > 0 {
-          self.package_name.remove(self.package_name_cursor - 1);
           self.package_name_cursor -= 1;
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           // Update autocomplete suggestions
           self.filter_packages();
           self.autocomplete_selected_index = None;
@@ -282,8 +280,8 @@ impl CreateJavaFileForm {
         }
       }
       KeyCode::Delete => {
-        if self.package_name_cursor < self.package_name.len() {
-          self.package_name.remove(self.package_name_cursor);
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           // Update autocomplete suggestions
           self.filter_packages();
           self.autocomplete_selected_index = None;
@@ -296,7 +294,7 @@ impl CreateJavaFileForm {
         }
       }
       KeyCode::Right => {
-        if self.package_name_cursor < self.package_name.len() {
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
           self.package_name_cursor += 1;
         }
       }
@@ -304,7 +302,7 @@ impl CreateJavaFileForm {
         self.package_name_cursor = 0;
       }
       KeyCode::End => {
-        self.package_name_cursor = self.package_name.len();
+        self.package_name_cursor = helpers::text_len(&self.package_name);
       }
       KeyCode::Down => {
         // Navigate down in autocomplete suggestions
@@ -362,7 +360,7 @@ impl CreateJavaFileForm {
           if let Some(idx) = self.autocomplete_selected_index {
             if let Some(selected) = self.filtered_packages.get(idx) {
               self.package_name = selected.clone();
-              self.package_name_cursor = self.package_name.len();
+              self.package_name_cursor = helpers::text_len(&self.package_name);
               self.show_autocomplete = false;
               self.autocomplete_selected_index = None;
               self.autocomplete_scroll_offset = 0;
@@ -371,7 +369,7 @@ impl CreateJavaFileForm {
           } else if !self.filtered_packages.is_empty() {
             // If nothing selected, select first suggestion
             self.package_name = self.filtered_packages[0].clone();
-            self.package_name_cursor = self.package_name.len();
+            self.package_name_cursor = helpers::text_len(&self.package_name);
             self.show_autocomplete = false;
             self.autocomplete_selected_index = None;
             self.autocomplete_scroll_offset = 0;
@@ -409,6 +407,7 @@ impl CreateJavaFileForm {
       &self.file_name,
       &self.file_type,
       &JavaSourceDirectoryType::Main,
+      false,
     );
 
     // Use helper function to output response and exit
@@ -463,7 +462,10 @@ impl CreateJavaFileForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.file_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.file_name, self.file_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -487,7 +489,10 @@ impl CreateJavaFileForm {
     frame.render_widget(input, area);
 
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.package_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.package_name, self.package_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
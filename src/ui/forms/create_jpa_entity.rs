@@ -11,6 +11,7 @@ use crate::commands::{
   create_jpa_entity_command, get_all_jpa_mapped_superclasses, get_all_packages_command,
 };
 use crate::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 
 /// Represents which field is currently focused
@@ -94,7 +95,7 @@ impl CreateJpaEntityForm {
       superclass_list,
       superclass_state,
       entity_name_cursor: 9, // Position at end of "NewEntity"
-      package_name_cursor: default_package.len(),
+      package_name_cursor: helpers::text_len(&default_package),
       focused_field: FocusedField::EntityName,
       cwd,
     }
@@ -102,7 +103,7 @@ impl CreateJpaEntityForm {
 
   /// Fetch packages from syntaxpresso-core using the get-all-packages command
   fn fetch_packages(cwd: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main);
+    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main, None);
 
     let mut packages = Vec::new();
     if let Some(data) = response.data {
@@ -116,7 +117,7 @@ impl CreateJpaEntityForm {
 
   /// Fetch mapped superclasses from syntaxpresso-core
   fn fetch_superclasses(cwd: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let response = get_all_jpa_mapped_superclasses::execute(cwd);
+    let response = get_all_jpa_mapped_superclasses::execute(cwd, None);
 
     let mut superclasses = Vec::new();
     if let Some(data) = response.data {
@@ -196,10 +197,10 @@ impl CreateJpaEntityForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::EntityName => {
-          self.entity_name_cursor = self.entity_name.len();
+          self.entity_name_cursor = helpers::text_len(&self.entity_name);
         }
         FocusedField::PackageName => {
-          self.package_name_cursor = self.package_name.len();
+          self.package_name_cursor = helpers::text_len(&self.package_name);
         }
         _ => {}
       }
@@ -247,18 +248,17 @@ impl CreateJpaEntityForm {
   fn handle_entity_name_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.entity_name.insert(self.entity_name_cursor, c);
-        self.entity_name_cursor += 1;
+        helpers::insert_char(&mut self.entity_name, &mut self.entity_name_cursor, c);
       }
       KeyCode::Backspace => {
         if self.entity_name_cursor > 0 {
-          self.entity_name.remove(self.entity_name_cursor - 1);
           self.entity_name_cursor -= 1;
+          helpers::remove_grapheme(&mut self.entity_name, self.entity_name_cursor);
         }
       }
       KeyCode::Delete => {
-        if self.entity_name_cursor < self.entity_name.len() {
-          self.entity_name.remove(self.entity_name_cursor);
+        if self.entity_name_cursor < helpers::text_len(&self.entity_name) {
+          helpers::remove_grapheme(&mut self.entity_name, self.entity_name_cursor);
         }
       }
       KeyCode::Left => {
@@ -267,7 +267,7 @@ impl CreateJpaEntityForm {
         }
       }
       KeyCode::Right => {
-        if self.entity_name_cursor < self.entity_name.len() {
+        if self.entity_name_cursor < helpers::text_len(&self.entity_name) {
           self.entity_name_cursor += 1;
         }
       }
@@ -275,7 +275,7 @@ impl CreateJpaEntityForm {
         self.entity_name_cursor = 0;
       }
       KeyCode::End => {
-        self.entity_name_cursor = self.entity_name.len();
+        self.entity_name_cursor = helpers::text_len(&self.entity_name);
       }
       _ => {}
     }
@@ -284,24 +284,23 @@ impl CreateJpaEntityForm {
   fn handle_package_name_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.package_name.insert(self.package_name_cursor, c);
-        self.package_name_cursor += 1;
+        helpers::insert_char(&mut self.package_name, &mut self.package_name_cursor, c);
         self.filter_packages();
         self.package_autocomplete_selected_index = None;
         self.package_autocomplete_scroll_offset = 0;
       }
       KeyCode::Backspace => {
         if self.package_name_cursor > 0 {
-          self.package_name.remove(self.package_name_cursor - 1);
           self.package_name_cursor -= 1;
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           self.filter_packages();
           self.package_autocomplete_selected_index = None;
           self.package_autocomplete_scroll_offset = 0;
         }
       }
       KeyCode::Delete => {
-        if self.package_name_cursor < self.package_name.len() {
-          self.package_name.remove(self.package_name_cursor);
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           self.filter_packages();
           self.package_autocomplete_selected_index = None;
           self.package_autocomplete_scroll_offset = 0;
@@ -313,7 +312,7 @@ impl CreateJpaEntityForm {
         }
       }
       KeyCode::Right => {
-        if self.package_name_cursor < self.package_name.len() {
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
           self.package_name_cursor += 1;
         }
       }
@@ -321,7 +320,7 @@ impl CreateJpaEntityForm {
         self.package_name_cursor = 0;
       }
       KeyCode::End => {
-        self.package_name_cursor = self.package_name.len();
+        self.package_name_cursor = helpers::text_len(&self.package_name);
       }
       KeyCode::Down => {
         if self.show_package_autocomplete && !self.filtered_packages.is_empty() {
@@ -374,7 +373,7 @@ impl CreateJpaEntityForm {
           if let Some(idx) = self.package_autocomplete_selected_index {
             if let Some(selected) = self.filtered_packages.get(idx) {
               self.package_name = selected.clone();
-              self.package_name_cursor = self.package_name.len();
+              self.package_name_cursor = helpers::text_len(&self.package_name);
               self.show_package_autocomplete = false;
               self.package_autocomplete_selected_index = None;
               self.package_autocomplete_scroll_offset = 0;
@@ -382,7 +381,7 @@ impl CreateJpaEntityForm {
             }
           } else if !self.filtered_packages.is_empty() {
             self.package_name = self.filtered_packages[0].clone();
-            self.package_name_cursor = self.package_name.len();
+            self.package_name_cursor = helpers::text_len(&self.package_name);
             self.show_package_autocomplete = false;
             self.package_autocomplete_selected_index = None;
             self.package_autocomplete_scroll_offset = 0;
@@ -426,6 +425,12 @@ impl CreateJpaEntityForm {
       &self.entity_name,
       superclass_type,
       superclass_package_name,
+      None,
+      None,
+      false,
+      false,
+      false,
+      &PersistenceNamespace::default(),
     );
 
     // Use helper function to output response and exit
@@ -441,7 +446,10 @@ impl CreateJpaEntityForm {
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
     frame.render_widget(input, area);
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.entity_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.entity_name, self.entity_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
@@ -465,7 +473,10 @@ impl CreateJpaEntityForm {
     frame.render_widget(input, area);
 
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.package_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.package_name, self.package_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
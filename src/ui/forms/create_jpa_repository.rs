@@ -129,7 +129,7 @@ impl CreateJpaRepositoryForm {
       id_field_type,
       id_field_package,
       package_name: default_repo_package.clone(),
-      package_name_cursor: default_repo_package.len(),
+      package_name_cursor: helpers::text_len(&default_repo_package),
       focused_field: FocusedField::IdFieldType,
       auto_detected_id: auto_detected,
       entity_name,
@@ -155,7 +155,7 @@ impl CreateJpaRepositoryForm {
 
   /// Fetch packages for autocomplete from get-all-packages command
   fn fetch_packages(cwd: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main);
+    let response = get_all_packages_command::execute(cwd, &JavaSourceDirectoryType::Main, None);
 
     if let Some(data) = response.data {
       Ok(data.packages.iter().map(|p| p.package_name.clone()).collect())
@@ -278,7 +278,7 @@ impl CreateJpaRepositoryForm {
   /// Called when entering insert mode - 'a' moves cursor to end for text inputs
   fn on_enter_insert_mode(&mut self, key: KeyCode) {
     if key == KeyCode::Char('a') && self.focused_field == FocusedField::PackageName {
-      self.package_name_cursor = self.package_name.len();
+      self.package_name_cursor = helpers::text_len(&self.package_name);
     }
   }
 
@@ -322,24 +322,23 @@ impl CreateJpaRepositoryForm {
   fn handle_package_name_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.package_name.insert(self.package_name_cursor, c);
-        self.package_name_cursor += 1;
+        helpers::insert_char(&mut self.package_name, &mut self.package_name_cursor, c);
         self.filter_packages();
         self.package_autocomplete_selected_index = None;
         self.package_autocomplete_scroll_offset = 0;
       }
       KeyCode::Backspace => {
         if self.package_name_cursor > 0 {
-          self.package_name.remove(self.package_name_cursor - 1);
           self.package_name_cursor -= 1;
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           self.filter_packages();
           self.package_autocomplete_selected_index = None;
           self.package_autocomplete_scroll_offset = 0;
         }
       }
       KeyCode::Delete => {
-        if self.package_name_cursor < self.package_name.len() {
-          self.package_name.remove(self.package_name_cursor);
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
+          helpers::remove_grapheme(&mut self.package_name, self.package_name_cursor);
           self.filter_packages();
           self.package_autocomplete_selected_index = None;
           self.package_autocomplete_scroll_offset = 0;
@@ -351,7 +350,7 @@ impl CreateJpaRepositoryForm {
         }
       }
       KeyCode::Right => {
-        if self.package_name_cursor < self.package_name.len() {
+        if self.package_name_cursor < helpers::text_len(&self.package_name) {
           self.package_name_cursor += 1;
         }
       }
@@ -359,7 +358,7 @@ impl CreateJpaRepositoryForm {
         self.package_name_cursor = 0;
       }
       KeyCode::End => {
-        self.package_name_cursor = self.package_name.len();
+        self.package_name_cursor = helpers::text_len(&self.package_name);
       }
       KeyCode::Down => {
         if self.show_package_autocomplete && !self.filtered_packages.is_empty() {
@@ -412,7 +411,7 @@ impl CreateJpaRepositoryForm {
           if let Some(idx) = self.package_autocomplete_selected_index {
             if let Some(selected) = self.filtered_packages.get(idx) {
               self.package_name = selected.clone();
-              self.package_name_cursor = self.package_name.len();
+              self.package_name_cursor = helpers::text_len(&self.package_name);
               self.show_package_autocomplete = false;
               self.package_autocomplete_selected_index = None;
               self.package_autocomplete_scroll_offset = 0;
@@ -420,7 +419,7 @@ impl CreateJpaRepositoryForm {
             }
           } else if !self.filtered_packages.is_empty() {
             self.package_name = self.filtered_packages[0].clone();
-            self.package_name_cursor = self.package_name.len();
+            self.package_name_cursor = helpers::text_len(&self.package_name);
             self.show_package_autocomplete = false;
             self.package_autocomplete_selected_index = None;
             self.package_autocomplete_scroll_offset = 0;
@@ -524,7 +523,10 @@ impl CreateJpaRepositoryForm {
     frame.render_widget(input, area);
 
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.package_name_cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.package_name, self.package_name_cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 
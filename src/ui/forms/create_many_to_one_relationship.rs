@@ -17,6 +17,9 @@ use crate::common::types::fetch_type::FetchType;
 use crate::common::types::many_to_one_field_config::ManyToOneFieldConfig;
 use crate::common::types::mapping_type::MappingType;
 use crate::common::types::other_type::OtherType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::types::relationship_kind::RelationshipKind;
+use crate::common::utils::naming_util::{collection_field_name_for_type, field_name_for_type};
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 
 /// Entity type information
@@ -226,7 +229,7 @@ impl CreateManyToOneRelationshipForm {
     current_entity_name: &str,
     current_entity_package: &str,
   ) -> Vec<EntityTypeInfo> {
-    match get_all_jpa_entities_service::run(cwd) {
+    match get_all_jpa_entities_service::run(cwd, None) {
       Ok(entities) => entities
         .into_iter()
         .filter(|entity| {
@@ -246,32 +249,14 @@ impl CreateManyToOneRelationshipForm {
     }
   }
 
-  /// Auto-generate field name from entity type
-  fn auto_field_name(type_name: &str) -> String {
-    if type_name.is_empty() {
-      return String::new();
-    }
-    let mut chars = type_name.chars();
-    if let Some(first) = chars.next() {
-      format!("{}{}", first.to_lowercase(), chars.as_str())
-    } else {
-      String::new()
-    }
-  }
-
   /// Auto-generate inverse field name from entity type and collection type
   fn auto_inverse_field_name(entity_name: &str, collection_type_index: usize) -> String {
-    if entity_name.is_empty() {
-      return String::new();
-    }
     let collection_suffix = match collection_type_index {
       0 => "List",       // List
       1 => "Set",        // Set
       _ => "Collection", // Collection
     };
-    // Convert to camelCase: entityNameList, entityNameSet, entityNameCollection
-    let base_name = Self::auto_field_name(entity_name);
-    format!("{}{}", base_name, collection_suffix)
+    collection_field_name_for_type(entity_name, collection_suffix)
   }
 
   /// Update target entity and auto-fill owning field name
@@ -280,8 +265,8 @@ impl CreateManyToOneRelationshipForm {
       self.target_entity_index = Some(idx);
       if let Some(entity) = self.entity_types.get(idx) {
         // Owning side (Many side): single reference to target entity
-        self.owning_field_name = Self::auto_field_name(&entity.name);
-        self.owning_field_name_cursor = self.owning_field_name.len();
+        self.owning_field_name = field_name_for_type(&entity.name);
+        self.owning_field_name_cursor = helpers::text_len(&self.owning_field_name);
       }
     }
   }
@@ -290,7 +275,7 @@ impl CreateManyToOneRelationshipForm {
   fn update_inverse_field_name(&mut self) {
     self.inverse_field_name =
       Self::auto_inverse_field_name(&self.current_entity_name, self.collection_type_index);
-    self.inverse_field_name_cursor = self.inverse_field_name.len();
+    self.inverse_field_name_cursor = helpers::text_len(&self.inverse_field_name);
   }
 
   /// Update mapping type
@@ -331,19 +316,20 @@ impl CreateManyToOneRelationshipForm {
 
   /// Get fetch type from index
   fn get_fetch_type(&self) -> FetchType {
-    match self.fetch_type_index {
-      0 => FetchType::Lazy,
-      _ => FetchType::Eager,
-    }
+    RelationshipKind::ManyToOne
+      .fetch_type_options()
+      .get(self.fetch_type_index)
+      .cloned()
+      .unwrap_or(FetchType::Lazy)
   }
 
   /// Get collection type from index
   fn get_collection_type(&self) -> CollectionType {
-    match self.collection_type_index {
-      0 => CollectionType::List,
-      1 => CollectionType::Set,
-      _ => CollectionType::Collection,
-    }
+    RelationshipKind::ManyToOne
+      .collection_type_options()
+      .get(self.collection_type_index)
+      .cloned()
+      .unwrap_or(CollectionType::List)
   }
 
   /// Get cascade types from indices
@@ -361,23 +347,17 @@ impl CreateManyToOneRelationshipForm {
 
   /// Get all cascade types
   fn get_all_cascades() -> Vec<CascadeType> {
-    vec![
-      CascadeType::Persist,
-      CascadeType::Merge,
-      CascadeType::Remove,
-      CascadeType::Refresh,
-      CascadeType::Detach,
-    ]
+    RelationshipKind::ManyToOne.cascade_options()
   }
 
   /// Get owning side other options (Many side)
   fn get_owning_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::Unique]
+    RelationshipKind::ManyToOne.owning_side_other_options()
   }
 
   /// Get inverse side other options (One side)
   fn get_inverse_other_options() -> Vec<OtherType> {
-    vec![OtherType::OrphanRemoval]
+    RelationshipKind::ManyToOne.inverse_side_other_options()
   }
 
   /// Toggle item in a list
@@ -478,10 +458,10 @@ impl CreateManyToOneRelationshipForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::OwningFieldName => {
-          self.owning_field_name_cursor = self.owning_field_name.len();
+          self.owning_field_name_cursor = helpers::text_len(&self.owning_field_name);
         }
         FocusedField::InverseFieldName => {
-          self.inverse_field_name_cursor = self.inverse_field_name.len();
+          self.inverse_field_name_cursor = helpers::text_len(&self.inverse_field_name);
         }
         _ => {}
       }
@@ -551,13 +531,23 @@ impl CreateManyToOneRelationshipForm {
     // Build field config
     let field_config = ManyToOneFieldConfig {
       inverse_field_type: target_entity_name,
+      inverse_field_package: None,
       fetch_type: self.get_fetch_type(),
       collection_type: self.get_collection_type(),
+      declared_collection_type: None,
       mapping_type: Some(self.get_mapping_type()),
       owning_side_cascades: Self::get_cascade_types(&self.owning_cascades),
       inverse_side_cascades: Self::get_cascade_types(&self.inverse_cascades),
       owning_side_other: Self::get_other_types(&self.owning_other, true),
       inverse_side_other: Self::get_other_types(&self.inverse_other, false),
+      join_column_name: None,
+      referenced_column_name: None,
+      maps_id: None,
+      initialize_collection: true,
+      order_by: None,
+      order_column: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
     };
 
     // Call command layer instead of service directly
@@ -1072,7 +1062,7 @@ impl CreateManyToOneRelationshipForm {
     frame.render_widget(input, area);
 
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((area.x + helpers::cursor_column(text, cursor) + 1, area.y + 1));
     }
   }
 
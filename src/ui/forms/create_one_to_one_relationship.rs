@@ -12,9 +12,13 @@ use std::path::{Path, PathBuf};
 use crate::commands::create_jpa_one_to_one_relationship_command;
 use crate::commands::services::{get_all_jpa_entities_service, get_jpa_entity_info_service};
 use crate::common::types::cascade_type::CascadeType;
+use crate::common::types::fetch_type::FetchType;
 use crate::common::types::mapping_type::MappingType;
 use crate::common::types::one_to_one_field_config::OneToOneFieldConfig;
 use crate::common::types::other_type::OtherType;
+use crate::common::types::persistence_namespace::PersistenceNamespace;
+use crate::common::types::relationship_kind::RelationshipKind;
+use crate::common::utils::naming_util::field_name_for_type;
 use crate::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 
 /// Entity type information
@@ -38,6 +42,7 @@ enum FocusedField {
   MappingType,
   TargetEntityType,
   OwningFieldName,
+  FetchType,
   OwningCascades,
   OwningOther,
 
@@ -75,6 +80,7 @@ pub struct CreateOneToOneRelationshipForm {
 
   // Field values
   mapping_type_index: usize,
+  fetch_type_index: usize,
   target_entity_index: Option<usize>,
   owning_field_name: String,
   inverse_field_name: String,
@@ -96,6 +102,7 @@ pub struct CreateOneToOneRelationshipForm {
 
   // List states
   mapping_type_state: ListState,
+  fetch_type_state: ListState,
   entity_type_state: ListState,
   owning_cascades_state: ListState,
   inverse_cascades_state: ListState,
@@ -137,6 +144,9 @@ impl CreateOneToOneRelationshipForm {
     let mut mapping_type_state = ListState::default();
     mapping_type_state.select(Some(0));
 
+    let mut fetch_type_state = ListState::default();
+    fetch_type_state.select(Some(0));
+
     let mut entity_type_state = ListState::default();
     entity_type_state.select(Some(0));
 
@@ -156,6 +166,7 @@ impl CreateOneToOneRelationshipForm {
       state: FormState::new(),
       phase: FormPhase::OwningConfiguration,
       mapping_type_index: 0,
+      fetch_type_index: 0,
       target_entity_index: None,
       owning_field_name: String::new(),
       inverse_field_name: String::new(),
@@ -167,6 +178,7 @@ impl CreateOneToOneRelationshipForm {
       owning_other: Vec::new(),
       inverse_other: Vec::new(),
       mapping_type_state,
+      fetch_type_state,
       entity_type_state,
       owning_cascades_state,
       inverse_cascades_state,
@@ -208,7 +220,7 @@ impl CreateOneToOneRelationshipForm {
     current_entity_name: &str,
     current_entity_package: &str,
   ) -> Vec<EntityTypeInfo> {
-    match get_all_jpa_entities_service::run(cwd) {
+    match get_all_jpa_entities_service::run(cwd, None) {
       Ok(entities) => {
         entities
           .into_iter()
@@ -255,26 +267,13 @@ impl CreateOneToOneRelationshipForm {
     entity_types
   }
 
-  /// Auto-generate field name from entity type
-  fn auto_field_name(type_name: &str) -> String {
-    if type_name.is_empty() {
-      return String::new();
-    }
-    let mut chars = type_name.chars();
-    if let Some(first) = chars.next() {
-      format!("{}{}", first.to_lowercase(), chars.as_str())
-    } else {
-      String::new()
-    }
-  }
-
   /// Update target entity and auto-fill field name
   fn update_target_entity(&mut self) {
     if let Some(idx) = self.entity_type_state.selected() {
       self.target_entity_index = Some(idx);
       if let Some(entity) = self.entity_types.get(idx) {
-        self.owning_field_name = Self::auto_field_name(&entity.name);
-        self.owning_field_name_cursor = self.owning_field_name.len();
+        self.owning_field_name = field_name_for_type(&entity.name);
+        self.owning_field_name_cursor = helpers::text_len(&self.owning_field_name);
       }
     }
   }
@@ -286,6 +285,21 @@ impl CreateOneToOneRelationshipForm {
     }
   }
 
+  /// Update fetch type
+  fn update_fetch_type(&mut self) {
+    if let Some(idx) = self.fetch_type_state.selected() {
+      self.fetch_type_index = idx;
+    }
+  }
+
+  /// Get fetch type from index, `None` leaving the provider default
+  fn get_fetch_type(&self) -> Option<FetchType> {
+    match self.fetch_type_index {
+      0 => None,
+      index => RelationshipKind::OneToOne.fetch_type_options().get(index - 1).cloned(),
+    }
+  }
+
   /// Check if bidirectional mapping is selected
   fn is_bidirectional(&self) -> bool {
     self.mapping_type_index == 0
@@ -314,23 +328,17 @@ impl CreateOneToOneRelationshipForm {
 
   /// Get all cascade types
   fn get_all_cascades() -> Vec<CascadeType> {
-    vec![
-      CascadeType::Persist,
-      CascadeType::Merge,
-      CascadeType::Remove,
-      CascadeType::Refresh,
-      CascadeType::Detach,
-    ]
+    RelationshipKind::OneToOne.cascade_options()
   }
 
   /// Get owning side other options
   fn get_owning_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::Unique, OtherType::OrphanRemoval]
+    RelationshipKind::OneToOne.owning_side_other_options()
   }
 
   /// Get inverse side other options
   fn get_inverse_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::OrphanRemoval]
+    RelationshipKind::OneToOne.inverse_side_other_options()
   }
 
   /// Toggle item in a list
@@ -350,7 +358,8 @@ impl CreateOneToOneRelationshipForm {
       FormPhase::OwningConfiguration => match self.focused_field {
         FocusedField::MappingType => FocusedField::TargetEntityType,
         FocusedField::TargetEntityType => FocusedField::OwningFieldName,
-        FocusedField::OwningFieldName => FocusedField::OwningCascades,
+        FocusedField::OwningFieldName => FocusedField::FetchType,
+        FocusedField::FetchType => FocusedField::OwningCascades,
         FocusedField::OwningCascades => FocusedField::OwningOther,
         FocusedField::OwningOther => FocusedField::BackButton,
         FocusedField::BackButton => {
@@ -390,7 +399,8 @@ impl CreateOneToOneRelationshipForm {
         }
         FocusedField::TargetEntityType => FocusedField::MappingType,
         FocusedField::OwningFieldName => FocusedField::TargetEntityType,
-        FocusedField::OwningCascades => FocusedField::OwningFieldName,
+        FocusedField::FetchType => FocusedField::OwningFieldName,
+        FocusedField::OwningCascades => FocusedField::FetchType,
         FocusedField::OwningOther => FocusedField::OwningCascades,
         FocusedField::BackButton => FocusedField::OwningOther,
         FocusedField::NextButton => FocusedField::BackButton,
@@ -418,10 +428,10 @@ impl CreateOneToOneRelationshipForm {
     if key == KeyCode::Char('a') {
       match self.focused_field {
         FocusedField::OwningFieldName => {
-          self.owning_field_name_cursor = self.owning_field_name.len();
+          self.owning_field_name_cursor = helpers::text_len(&self.owning_field_name);
         }
         FocusedField::InverseFieldName => {
-          self.inverse_field_name_cursor = self.inverse_field_name.len();
+          self.inverse_field_name_cursor = helpers::text_len(&self.inverse_field_name);
         }
         _ => {}
       }
@@ -433,8 +443,8 @@ impl CreateOneToOneRelationshipForm {
     match self.focused_field {
       FocusedField::NextButton => {
         // Auto-generate inverse field name from current entity name
-        self.inverse_field_name = Self::auto_field_name(&self.current_entity_name);
-        self.inverse_field_name_cursor = self.inverse_field_name.len();
+        self.inverse_field_name = field_name_for_type(&self.current_entity_name);
+        self.inverse_field_name_cursor = helpers::text_len(&self.inverse_field_name);
 
         // Move to inverse configuration phase
         self.phase = FormPhase::InverseConfiguration;
@@ -484,6 +494,7 @@ impl CreateOneToOneRelationshipForm {
           &mut self.state.input_mode,
         );
       }
+      FocusedField::FetchType => self.handle_fetch_type_insert(key),
       FocusedField::OwningCascades => self.handle_cascades_insert(key, true),
       FocusedField::InverseCascades => self.handle_cascades_insert(key, false),
       FocusedField::OwningOther => self.handle_other_insert(key, true),
@@ -491,8 +502,8 @@ impl CreateOneToOneRelationshipForm {
       FocusedField::NextButton => {
         if key == KeyCode::Enter {
           // Auto-generate inverse field name from current entity name
-          self.inverse_field_name = Self::auto_field_name(&self.current_entity_name);
-          self.inverse_field_name_cursor = self.inverse_field_name.len();
+          self.inverse_field_name = field_name_for_type(&self.current_entity_name);
+          self.inverse_field_name_cursor = helpers::text_len(&self.inverse_field_name);
 
           self.phase = FormPhase::InverseConfiguration;
           self.focused_field = FocusedField::InverseFieldName;
@@ -541,6 +552,23 @@ impl CreateOneToOneRelationshipForm {
     }
   }
 
+  fn handle_fetch_type_insert(&mut self, key: KeyCode) {
+    match key {
+      KeyCode::Char('j') | KeyCode::Down => {
+        helpers::navigate_list_static(&KeyCode::Down, &mut self.fetch_type_state, 3);
+        self.update_fetch_type();
+      }
+      KeyCode::Char('k') | KeyCode::Up => {
+        helpers::navigate_list_static(&KeyCode::Up, &mut self.fetch_type_state, 3);
+        self.update_fetch_type();
+      }
+      KeyCode::Enter => {
+        self.state.input_mode = InputMode::Normal;
+      }
+      _ => {}
+    }
+  }
+
   fn handle_entity_type_insert(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char('j') | KeyCode::Down => {
@@ -645,11 +673,18 @@ impl CreateOneToOneRelationshipForm {
     // Build field config
     let field_config = OneToOneFieldConfig {
       inverse_field_type: target_entity_name,
+      inverse_field_package: None,
       mapping_type: Some(self.get_mapping_type()),
+      fetch_type: self.get_fetch_type(),
       owning_side_cascades: Self::get_cascade_types(&self.owning_cascades),
       inverse_side_cascades: Self::get_cascade_types(&self.inverse_cascades),
       owning_side_other: Self::get_other_types(&self.owning_other, true),
       inverse_side_other: Self::get_other_types(&self.inverse_other, false),
+      join_column_name: None,
+      referenced_column_name: None,
+      maps_id: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
     };
 
     // Call command layer instead of service directly
@@ -707,6 +742,32 @@ impl CreateOneToOneRelationshipForm {
     frame.render_stateful_widget(list, area, &mut self.mapping_type_state);
   }
 
+  fn render_fetch_type_selector(&mut self, frame: &mut Frame, area: Rect) {
+    let is_focused = self.focused_field == FocusedField::FetchType;
+
+    let fetch_types = ["Provider Default", "Lazy", "Eager"];
+    let items: Vec<ListItem> = fetch_types
+      .iter()
+      .enumerate()
+      .map(|(i, name)| {
+        let is_selected = self.fetch_type_state.selected() == Some(i);
+        let prefix = if is_selected { "●" } else { "○" };
+        ListItem::new(format!(" {} {}", prefix, name))
+      })
+      .collect();
+
+    let border_style =
+      if is_focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+
+    let title = self.generate_title("Fetch Type", is_focused);
+    let list = List::new(items)
+      .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style))
+      .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+      .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut self.fetch_type_state);
+  }
+
   fn render_entity_type_selector(&mut self, frame: &mut Frame, area: Rect) {
     let is_focused = self.focused_field == FocusedField::TargetEntityType;
 
@@ -756,7 +817,7 @@ impl CreateOneToOneRelationshipForm {
     frame.render_widget(input, area);
 
     if is_focused && self.state.input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((area.x + helpers::cursor_column(text, cursor) + 1, area.y + 1));
     }
   }
 
@@ -938,6 +999,7 @@ impl CreateOneToOneRelationshipForm {
       Constraint::Length(4), // Mapping type
       Constraint::Length(9), // Entity type
       Constraint::Length(3), // Owning field name
+      Constraint::Length(5), // Fetch type
       Constraint::Length(7), // Owning cascades
       Constraint::Length(5), // Owning other
       Constraint::Min(0),    // Errors
@@ -974,6 +1036,9 @@ impl CreateOneToOneRelationshipForm {
     );
     idx += 1;
 
+    self.render_fetch_type_selector(frame, chunks[idx]);
+    idx += 1;
+
     // Render cascade selectors
     {
       let owning_cascades = &self.owning_cascades;
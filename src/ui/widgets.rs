@@ -8,7 +8,7 @@ use ratatui::{
   widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
-use crate::ui::form_trait::InputMode;
+use crate::ui::form_trait::{InputMode, helpers};
 
 /// Generic text input widget with cursor
 pub struct TextInput {
@@ -20,26 +20,23 @@ pub struct TextInput {
 impl TextInput {
   pub fn new(label: impl Into<String>, default_value: impl Into<String>) -> Self {
     let value = default_value.into();
-    let cursor = value.len();
+    let cursor = helpers::text_len(&value);
     Self { value, cursor, label: label.into() }
   }
 
   pub fn handle_input(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char(c) => {
-        self.value.insert(self.cursor, c);
-        self.cursor += 1;
+        helpers::insert_char(&mut self.value, &mut self.cursor, c);
       }
       KeyCode::Backspace => {
         if self.cursor > 0 {
-          self.value.remove(self.cursor - 1);
           self.cursor -= 1;
+          helpers::remove_grapheme(&mut self.value, self.cursor);
         }
       }
       KeyCode::Delete => {
-        if self.cursor < self.value.len() {
-          self.value.remove(self.cursor);
-        }
+        helpers::remove_grapheme(&mut self.value, self.cursor);
       }
       KeyCode::Left => {
         if self.cursor > 0 {
@@ -47,7 +44,7 @@ impl TextInput {
         }
       }
       KeyCode::Right => {
-        if self.cursor < self.value.len() {
+        if self.cursor < helpers::text_len(&self.value) {
           self.cursor += 1;
         }
       }
@@ -55,7 +52,7 @@ impl TextInput {
         self.cursor = 0;
       }
       KeyCode::End => {
-        self.cursor = self.value.len();
+        self.cursor = helpers::text_len(&self.value);
       }
       _ => {}
     }
@@ -80,7 +77,10 @@ impl TextInput {
 
     // Show cursor if focused and in insert mode
     if is_focused && input_mode == InputMode::Insert {
-      frame.set_cursor_position((area.x + self.cursor as u16 + 1, area.y + 1));
+      frame.set_cursor_position((
+        area.x + helpers::cursor_column(&self.value, self.cursor) + 1,
+        area.y + 1,
+      ));
     }
   }
 }
@@ -0,0 +1,69 @@
+// Add Class Annotation Service Integration Tests
+// This module contains tests for adding an annotation with attributes to a class
+
+mod common;
+
+use common::{encode, setup_domain_file};
+use std::fs;
+use syntaxpresso_core::commands::services::add_class_annotation_service::run;
+use syntaxpresso_core::common::types::annotation_attribute::AnnotationAttribute;
+
+const PLAIN_SOURCE: &str = r#"package com.example.domain;
+
+import java.util.UUID;
+
+public class User {
+
+  private UUID id;
+}
+"#;
+
+fn attribute(name: &str, value: &str) -> AnnotationAttribute {
+  AnnotationAttribute { name: name.to_string(), value: value.to_string() }
+}
+
+#[test]
+fn test_adds_entity_to_a_plain_class() {
+  let (_project, user_path) = setup_domain_file("User.java", PLAIN_SOURCE);
+  run(&encode(PLAIN_SOURCE), &user_path, "jakarta.persistence.Entity", &[]).unwrap();
+  let content = fs::read_to_string(&user_path).unwrap();
+  assert!(content.contains("import java.util.UUID;\nimport jakarta.persistence.Entity;\n"));
+  assert!(content.contains("@Entity\npublic class User {"));
+}
+
+#[test]
+fn test_adds_attributes_in_the_given_order() {
+  let (_project, user_path) = setup_domain_file("User.java", PLAIN_SOURCE);
+  let attributes = [attribute("name", "\"users\""), attribute("schema", "\"auth\"")];
+  run(&encode(PLAIN_SOURCE), &user_path, "jakarta.persistence.Table", &attributes).unwrap();
+  let content = fs::read_to_string(&user_path).unwrap();
+  assert!(content.contains("@Table(name = \"users\", schema = \"auth\")\npublic class User {"));
+}
+
+#[test]
+fn test_rejects_a_duplicate_annotation() {
+  let source = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+}
+"#;
+  let (_project, user_path) = setup_domain_file("User.java", source);
+  let error = run(&encode(source), &user_path, "jakarta.persistence.Entity", &[])
+    .err()
+    .expect("Expected error");
+  assert_eq!(error, "User is already annotated with @Entity");
+  assert_eq!(fs::read_to_string(&user_path).unwrap(), source);
+}
+
+#[test]
+fn test_rejects_an_attribute_given_twice() {
+  let (_project, user_path) = setup_domain_file("User.java", PLAIN_SOURCE);
+  let attributes = [attribute("name", "\"a\""), attribute("name", "\"b\"")];
+  let error = run(&encode(PLAIN_SOURCE), &user_path, "jakarta.persistence.Table", &attributes)
+    .err()
+    .expect("Expected error");
+  assert_eq!(error, "Attribute 'name' is given twice");
+}
@@ -394,4 +394,43 @@ public class User {
       "Should preserve existing argument"
     );
   }
+
+  #[test]
+  fn test_remove_annotation_keeps_next_line_indentation() {
+    let mut ts_file =
+      create_ts_file("class A {\n  @Lob\n  @Column(name = \"bio\")\n  private String bio;\n}");
+    let annotation_pos = ts_file.source_code.find("@Lob").unwrap_or(0);
+
+    let result = remove_annotation(&mut ts_file, annotation_pos);
+
+    assert!(result.is_some(), "Should successfully remove annotation");
+    assert_eq!(
+      ts_file.source_code,
+      "class A {\n  @Column(name = \"bio\")\n  private String bio;\n}"
+    );
+  }
+
+  #[test]
+  fn test_remove_annotation_argument() {
+    let mut ts_file = create_ts_file(
+      "@Column(name = \"code\", length = 10, nullable = false)\nprivate String code;",
+    );
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap_or(0);
+
+    let middle = remove_annotation_argument(&mut ts_file, annotation_pos, "length");
+    let last = remove_annotation_argument(&mut ts_file, annotation_pos, "nullable");
+
+    assert!(middle.is_some() && last.is_some(), "Should remove both arguments");
+    assert!(ts_file.source_code.starts_with("@Column(name = \"code\")\n"));
+  }
+
+  #[test]
+  fn test_remove_only_annotation_argument_turns_into_marker() {
+    let mut ts_file = create_ts_file("@Column(length = 10)\nprivate String code;");
+
+    let result = remove_annotation_argument(&mut ts_file, 0, "length");
+
+    assert!(result.is_some(), "Should remove the argument list");
+    assert!(ts_file.source_code.starts_with("@Column\n"));
+  }
 }
@@ -0,0 +1,86 @@
+// Annotation Value Util Integration Tests
+// This module contains tests for rendering user supplied names as annotation string attributes
+
+use syntaxpresso_core::common::utils::annotation_value_util::render_string_value;
+
+#[test]
+fn test_plain_value_is_quoted() {
+  assert_eq!(render_string_value("user_account").unwrap(), "\"user_account\"");
+  assert_eq!(render_string_value("").unwrap(), "\"\"");
+}
+
+#[test]
+fn test_double_quote_is_escaped() {
+  assert_eq!(render_string_value("user\"s").unwrap(), r#""user\"s""#);
+}
+
+#[test]
+fn test_backslash_is_escaped() {
+  assert_eq!(render_string_value(r"dbo\users").unwrap(), r#""dbo\\users""#);
+  assert_eq!(render_string_value(r#"a\"b"#).unwrap(), r#""a\\\"b""#);
+}
+
+#[test]
+fn test_non_ascii_value_is_kept() {
+  assert_eq!(render_string_value("usuário").unwrap(), "\"usuário\"");
+}
+
+#[test]
+fn test_control_characters_are_rejected() {
+  for value in ["user\nname", "user\tname", "user\u{0}name"] {
+    let error = render_string_value(value).unwrap_err();
+    assert!(error.contains("control characters"), "error: {}", error);
+  }
+  assert!(render_string_value("user\nname").unwrap_err().contains(r"'user\nname'"));
+}
+
+mod wrap_annotation_tests {
+  use syntaxpresso_core::common::utils::annotation_value_util::{
+    set_annotation_wrap, wrap_annotation,
+  };
+
+  const ANNOTATION: &str =
+    r#"@Column(name = "a, b", columnDefinition = "varchar(10)", length = 10)"#;
+
+  /// Wraps with `--annotation-wrap` set; the setting is per thread, so it can't leak into other
+  /// tests
+  fn wrap(columns: Option<usize>, annotation_text: &str) -> String {
+    set_annotation_wrap(columns);
+    let wrapped = wrap_annotation(annotation_text, "  ");
+    set_annotation_wrap(None);
+    wrapped
+  }
+
+  #[test]
+  fn test_single_line_without_width() {
+    assert_eq!(wrap(None, ANNOTATION), ANNOTATION);
+  }
+
+  #[test]
+  fn test_wraps_at_top_level_commas_only() {
+    assert_eq!(
+      wrap(Some(40), ANNOTATION),
+      "@Column(\n    name = \"a, b\",\n    columnDefinition = \"varchar(10)\",\n    length = 10\n  )"
+    );
+  }
+
+  #[test]
+  fn test_width_includes_indentation() {
+    let width = 2 + ANNOTATION.len();
+    assert_eq!(wrap(Some(width), ANNOTATION), ANNOTATION);
+    assert_ne!(wrap(Some(width - 1), ANNOTATION), ANNOTATION);
+  }
+
+  #[test]
+  fn test_single_attribute_and_nested_arrays_stay_together() {
+    let single = r#"@OrderBy("last_name ASC, first_name ASC")"#;
+    assert_eq!(wrap(Some(10), single), single);
+    assert_eq!(
+      wrap(
+        Some(10),
+        "@OneToMany(cascade = {CascadeType.PERSIST, CascadeType.MERGE}, orphanRemoval = true)"
+      ),
+      "@OneToMany(\n    cascade = {CascadeType.PERSIST, CascadeType.MERGE},\n    orphanRemoval = true\n  )"
+    );
+  }
+}
@@ -0,0 +1,82 @@
+// Apply Edits Service Integration Tests
+// This module contains tests for applying client computed edits and reversing them
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::apply_edits_service::run;
+
+const SOURCE: &str = "package com.example;\n\npublic class Customer {\n  private Long id;\n}\n";
+
+fn apply(source: &str, edits_json: &str) -> Result<(String, String), String> {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  let response = run(&b64_source, edits_json)?;
+  let reverse_json = serde_json::to_string(&response.reverse_edits).unwrap();
+  Ok((response.source_code, reverse_json))
+}
+
+#[test]
+fn test_applies_non_overlapping_edits_against_original_positions() {
+  let class_name = SOURCE.find("Customer").unwrap();
+  let field_type = SOURCE.find("Long").unwrap();
+  let edits = format!(
+    r#"[
+      {{"startByte": {}, "oldEndByte": {}, "newText": "Integer"}},
+      {{"startByte": {}, "oldEndByte": {}, "newText": "Client"}},
+      {{"startByte": 21, "oldEndByte": 21, "newText": "\nimport java.util.List;\n"}}
+    ]"#,
+    field_type,
+    field_type + 4,
+    class_name,
+    class_name + 8
+  );
+  let (source, _) = apply(SOURCE, &edits).expect("Edits should apply");
+  assert_eq!(
+    source,
+    "package com.example;\n\nimport java.util.List;\n\npublic class Client {\n  private Integer id;\n}\n"
+  );
+}
+
+#[test]
+fn test_reverse_edits_restore_the_original_source() {
+  let field_end = SOURCE.find("id;\n").unwrap() + 4;
+  let edits = format!(
+    r#"[
+      {{"startByte": 0, "oldEndByte": 20, "newText": "package com.example.domain;"}},
+      {{"startByte": {}, "oldEndByte": {}, "newText": "  private String name;\n"}}
+    ]"#,
+    field_end, field_end
+  );
+  let (source, reverse_edits) = apply(SOURCE, &edits).expect("Edits should apply");
+  assert!(source.contains("package com.example.domain;\n"));
+  assert!(source.contains("  private Long id;\n  private String name;\n}"));
+  let (restored, _) = apply(&source, &reverse_edits).expect("Reverse edits should apply");
+  assert_eq!(restored, SOURCE);
+}
+
+#[test]
+fn test_rejects_overlapping_edits() {
+  let edits = r#"[
+    {"startByte": 22, "oldEndByte": 35, "newText": "class"},
+    {"startByte": 30, "oldEndByte": 44, "newText": "record"}
+  ]"#;
+  let error = apply(SOURCE, edits).expect_err("Overlapping edits should be rejected");
+  assert_eq!(error, "Edits 22..35 and 30..44 overlap");
+}
+
+#[test]
+fn test_rejects_insertions_at_the_same_position() {
+  let edits = r#"[
+    {"startByte": 21, "oldEndByte": 21, "newText": "import a.B;\n"},
+    {"startByte": 21, "oldEndByte": 21, "newText": "import c.D;\n"}
+  ]"#;
+  let error = apply(SOURCE, edits).expect_err("Ambiguous insertions should be rejected");
+  assert_eq!(error, "Edits 21..21 and 21..21 overlap");
+}
+
+#[test]
+fn test_rejects_out_of_bounds_and_malformed_edits() {
+  let error = apply(SOURCE, r#"[{"startByte": 10, "oldEndByte": 500, "newText": ""}]"#)
+    .expect_err("Out of bounds edits should be rejected");
+  assert_eq!(error, format!("Invalid edit range 10..500 for a source of {} bytes", SOURCE.len()));
+  let error = apply(SOURCE, r#"[{"start": 1}]"#).expect_err("Malformed edits should be rejected");
+  assert!(error.starts_with("Invalid edits JSON: "));
+}
@@ -0,0 +1,172 @@
+// Change JPA Enum Storage Service Integration Tests
+// This module contains tests for switching enum fields between STRING and ORDINAL storage
+
+mod common;
+
+use common::{domain_path, encode, setup_domain_project};
+use std::fs;
+use syntaxpresso_core::commands::services::change_jpa_enum_storage_service::run;
+use syntaxpresso_core::common::types::java_enum_type::JavaEnumType;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::responses::change_jpa_enum_storage_response::ChangeJPAEnumStorageResponse;
+
+const ENUM_SOURCE: &str = r#"package com.example.domain;
+
+public enum OrderStatus {
+  PENDING,
+  SHIPPED
+}
+"#;
+
+fn change_storage(
+  entity_source: &str,
+  field_name: &str,
+  target_storage: JavaEnumType,
+  field_length: Option<u16>,
+) -> Result<(ChangeJPAEnumStorageResponse, String), String> {
+  let project =
+    setup_domain_project(&[("OrderStatus.java", ENUM_SOURCE), ("Order.java", entity_source)]);
+  let entity_path = domain_path(&project, "Order.java");
+  let response = run(
+    project.path(),
+    &encode(entity_source),
+    &entity_path,
+    field_name,
+    &target_storage,
+    field_length,
+    &PersistenceNamespace::default(),
+  )?;
+  Ok((response, fs::read_to_string(&entity_path).unwrap()))
+}
+
+#[test]
+fn test_string_to_ordinal_removes_length() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+@Entity
+public class Order {
+
+  @Enumerated(value = EnumType.STRING)
+  @Column(length = 20)
+  private OrderStatus status;
+}
+"#;
+  let (response, source) = change_storage(entity, "status", JavaEnumType::Ordinal, None).unwrap();
+  assert_eq!(response.old_storage, "STRING");
+  assert_eq!(response.new_storage, "ORDINAL");
+  assert_eq!(response.warnings.len(), 1);
+  assert!(
+    source.contains("  @Enumerated(value = EnumType.ORDINAL)\n  private OrderStatus status;")
+  );
+  assert!(!source.contains("@Column"));
+  assert!(!source.contains("import jakarta.persistence.Column;"));
+}
+
+#[test]
+fn test_string_to_ordinal_keeps_other_column_arguments() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+@Entity
+public class Order {
+
+  @Enumerated(EnumType.STRING)
+  @Column(name = "status", length = 20)
+  private OrderStatus status;
+}
+"#;
+  let (_, source) = change_storage(entity, "status", JavaEnumType::Ordinal, None).unwrap();
+  assert!(source.contains("@Enumerated(EnumType.ORDINAL)"));
+  assert!(source.contains("@Column(name = \"status\")"));
+  assert!(source.contains("import jakarta.persistence.Column;"));
+}
+
+#[test]
+fn test_ordinal_to_string_adds_length_and_annotation() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+
+  private OrderStatus status;
+}
+"#;
+  let (response, source) =
+    change_storage(entity, "status", JavaEnumType::String, Some(32)).unwrap();
+  assert_eq!(response.old_storage, "ORDINAL");
+  assert_eq!(response.new_storage, "STRING");
+  assert!(source.contains("@Enumerated(value = EnumType.STRING)"));
+  assert!(source.contains("@Column(length = 32)"));
+  assert!(source.contains("import jakarta.persistence.Enumerated;"));
+  assert!(source.contains("import jakarta.persistence.EnumType;"));
+  assert!(source.contains("import jakarta.persistence.Column;"));
+}
+
+#[test]
+fn test_same_storage_replaces_length_without_warning() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+@Entity
+public class Order {
+
+  @Enumerated(value = EnumType.STRING)
+  @Column(name = "status", length = 20)
+  private OrderStatus status;
+}
+"#;
+  let (response, source) =
+    change_storage(entity, "status", JavaEnumType::String, Some(40)).unwrap();
+  assert!(response.warnings.is_empty());
+  assert!(source.contains("@Column(name = \"status\", length = 40)"));
+}
+
+#[test]
+fn test_rejects_non_enum_field() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+
+  private String code;
+}
+"#;
+  let error =
+    change_storage(entity, "code", JavaEnumType::Ordinal, None).err().expect("Change should fail");
+  assert_eq!(error, "Field 'code' is not an enum field");
+}
+
+#[test]
+fn test_rejects_length_for_ordinal_storage() {
+  let entity = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+
+  private OrderStatus status;
+}
+"#;
+  let error = change_storage(entity, "status", JavaEnumType::Ordinal, Some(20))
+    .err()
+    .expect("Change should fail");
+  assert_eq!(error, "Length only applies to STRING storage");
+}
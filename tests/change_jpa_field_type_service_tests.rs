@@ -0,0 +1,171 @@
+// Change JPA Field Type Service Integration Tests
+// This module contains tests for changing the type of an existing JPA entity field
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::change_jpa_field_type_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::responses::change_jpa_field_type_response::ChangeJPAFieldTypeResponse;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.catalog;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.Temporal;
+import jakarta.persistence.TemporalType;
+import java.math.BigDecimal;
+import java.util.Date;
+
+@Entity
+public class Product {
+
+  @Column(name = "code", length = 20, nullable = false)
+  private String code;
+
+  @Column(name = "price", precision = 10, scale = 4)
+  private BigDecimal price;
+
+  @Temporal(TemporalType.DATE)
+  @Column(name = "released_on")
+  private Date releasedOn;
+}
+"#;
+
+/// Changes the type of `field_name` in the test entity, returning the response and new source
+fn change_type(
+  field_name: &str,
+  field_type: &str,
+  package_name: Option<&str>,
+) -> (ChangeJPAFieldTypeResponse, String) {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Product.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  let response = run(
+    &b64_source,
+    &entity_path,
+    field_name,
+    field_type,
+    package_name,
+    &PersistenceNamespace::default(),
+  )
+  .expect("Type change should succeed");
+  (response, fs::read_to_string(&entity_path).unwrap())
+}
+
+mod precision_and_scale_tests {
+  use super::*;
+
+  #[test]
+  fn test_string_to_big_decimal_drops_length_and_offers_precision() {
+    let (response, source) = change_type("code", "BigDecimal", Some("java.math"));
+    assert_eq!(response.old_field_type, "String");
+    assert_eq!(response.new_field_type, "BigDecimal");
+    assert_eq!(response.entity.file_package_name, "com.example.catalog");
+    assert!(
+      source.contains("@Column(name = \"code\", nullable = false)\n  private BigDecimal code;")
+    );
+    assert_eq!(source.matches("import java.math.BigDecimal;").count(), 1);
+    assert_eq!(response.warnings.len(), 2);
+    assert!(response.warnings[0].contains("@Column(length)"));
+    assert!(response.warnings[1].contains("precision = 19 and scale = 2"));
+  }
+
+  #[test]
+  fn test_big_decimal_to_string_drops_precision_and_scale() {
+    let (response, source) = change_type("price", "String", None);
+    assert!(source.contains("@Column(name = \"price\")\n  private String price;"));
+    assert!(!source.contains("import java.math.BigDecimal;"));
+    assert_eq!(response.warnings.len(), 2);
+    assert!(response.warnings[0].contains("@Column(precision)"));
+    assert!(response.warnings[1].contains("@Column(scale)"));
+  }
+}
+
+mod temporal_tests {
+  use super::*;
+
+  #[test]
+  fn test_date_to_local_date_drops_temporal_and_unused_imports() {
+    let (response, source) = change_type("releasedOn", "LocalDate", Some("java.time"));
+    assert!(source.contains("  @Column(name = \"released_on\")\n  private LocalDate releasedOn;"));
+    assert!(!source.contains("@Temporal"));
+    assert!(!source.contains("import jakarta.persistence.Temporal;"));
+    assert!(!source.contains("import jakarta.persistence.TemporalType;"));
+    assert!(!source.contains("import java.util.Date;"));
+    assert!(source.contains("import java.time.LocalDate;"));
+    assert_eq!(
+      response.warnings,
+      vec!["Removed @Temporal because LocalDate is not a legacy date type"]
+    );
+  }
+
+  #[test]
+  fn test_string_to_calendar_suggests_temporal() {
+    let (response, _) = change_type("code", "Calendar", Some("java.util"));
+    assert!(response.warnings.iter().any(|w| w.contains("may need @Temporal")));
+  }
+}
+
+#[test]
+fn test_unknown_field_is_rejected() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Product.java");
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  let result =
+    run(&b64_source, &entity_path, "missing", "String", None, &PersistenceNamespace::default());
+  assert_eq!(result.err().unwrap(), "Field not found: missing");
+}
+
+mod undo_metadata_tests {
+  use super::*;
+
+  #[test]
+  fn test_edit_records_round_trip_to_original_source() {
+    let (response, source) = change_type("releasedOn", "LocalDate", Some("java.time"));
+    assert!(!response.entity.edits.is_empty());
+    let mut restored = source;
+    for edit in response.entity.edits.iter().rev() {
+      restored
+        .replace_range(edit.start_byte..edit.start_byte + edit.new_text.len(), &edit.old_text);
+    }
+    assert_eq!(restored, ENTITY_SOURCE);
+  }
+}
+
+mod response_warnings_tests {
+  use super::*;
+  use syntaxpresso_core::commands::change_jpa_field_type_command::execute;
+  use syntaxpresso_core::responses::response::Response;
+
+  #[test]
+  fn test_string_to_integer_reports_dropped_length_at_top_level() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("Product.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let response = execute(
+      temp_dir.path(),
+      &b64_source,
+      &entity_path,
+      "code",
+      "Integer",
+      None,
+      &PersistenceNamespace::default(),
+    );
+    assert!(response.is_success());
+    assert_eq!(response.warnings, vec!["Removed @Column(length) because Integer has no length"]);
+    let json = response.to_json().unwrap();
+    assert!(
+      json.contains(r#""warnings":["Removed @Column(length) because Integer has no length"]"#)
+    );
+  }
+
+  #[test]
+  fn test_clean_response_has_empty_warnings() {
+    let response = Response::success("cmd".to_string(), "/tmp".to_string(), "data");
+    assert!(response.warnings.is_empty());
+    assert!(response.to_json().unwrap().contains(r#""warnings":[]"#));
+  }
+}
@@ -0,0 +1,38 @@
+// Column Naming Util Integration Tests
+// This module contains tests for deriving @Column names from Java field names
+
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::common::utils::column_naming_util::*;
+
+#[test]
+fn test_camel_case_to_snake_case() {
+  assert_eq!(to_column_name("firstName", &ColumnNamingStrategy::SnakeCase), "first_name");
+  assert_eq!(to_column_name("createdAtUtc", &ColumnNamingStrategy::SnakeCase), "created_at_utc");
+  assert_eq!(to_column_name("name", &ColumnNamingStrategy::SnakeCase), "name");
+  assert_eq!(to_column_name("already_snake", &ColumnNamingStrategy::SnakeCase), "already_snake");
+}
+
+#[test]
+fn test_acronyms_stay_together() {
+  assert_eq!(to_column_name("userID", &ColumnNamingStrategy::SnakeCase), "user_id");
+  assert_eq!(to_column_name("userIDs", &ColumnNamingStrategy::SnakeCase), "user_ids");
+  assert_eq!(to_column_name("HTTPStatus", &ColumnNamingStrategy::SnakeCase), "http_status");
+  assert_eq!(
+    to_column_name("parsedURLValue", &ColumnNamingStrategy::SnakeCase),
+    "parsed_url_value"
+  );
+  assert_eq!(to_column_name("URL", &ColumnNamingStrategy::SnakeCase), "url");
+}
+
+#[test]
+fn test_digits_stay_with_preceding_word() {
+  assert_eq!(to_column_name("address2Line", &ColumnNamingStrategy::SnakeCase), "address2_line");
+  assert_eq!(to_column_name("ipV4Address", &ColumnNamingStrategy::SnakeCase), "ip_v4_address");
+}
+
+#[test]
+fn test_strategies() {
+  assert_eq!(to_column_name("userID", &ColumnNamingStrategy::None), "userID");
+  assert_eq!(to_column_name("userID", &ColumnNamingStrategy::SnakeCase), "user_id");
+  assert_eq!(to_column_name("userID", &ColumnNamingStrategy::UpperSnakeCase), "USER_ID");
+}
@@ -0,0 +1,67 @@
+// Shared Integration Test Fixtures
+// This module contains the temporary projects and helpers reused by the integration tests
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use base64::Engine;
+use tempfile::TempDir;
+
+/// Directory of the `com.example.domain` package under `src/main/java`
+const DOMAIN_PACKAGE_DIR: &str = "src/main/java/com/example/domain";
+
+/// Encodes a Java source as the base64 argument taken by the commands
+pub fn encode(source: &str) -> String {
+  base64::engine::general_purpose::STANDARD.encode(source)
+}
+
+/// Creates a temporary project with each `(path, source)` pair written under `src/main/java`
+pub fn setup_project(files: &[(&str, &str)]) -> TempDir {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  for (relative_path, source) in files {
+    let path = source_path(&temp_dir, relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, source).unwrap();
+  }
+  temp_dir
+}
+
+/// Creates a temporary project with each `(file name, source)` pair written into the
+/// `com.example.domain` package, which exists even when no file is given
+pub fn setup_domain_project(files: &[(&str, &str)]) -> TempDir {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  fs::create_dir_all(temp_dir.path().join(DOMAIN_PACKAGE_DIR)).unwrap();
+  for (file_name, source) in files {
+    fs::write(domain_path(&temp_dir, file_name), source).unwrap();
+  }
+  temp_dir
+}
+
+/// Creates a project holding a single `com.example.domain` file and returns it with the file path
+pub fn setup_domain_file(file_name: &str, source: &str) -> (TempDir, PathBuf) {
+  let temp_dir = setup_domain_project(&[(file_name, source)]);
+  let path = domain_path(&temp_dir, file_name);
+  (temp_dir, path)
+}
+
+/// Creates a project with empty main and test source directories and an optional root build file
+pub fn setup_build_project(build_file: Option<(&str, &str)>) -> TempDir {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  fs::create_dir_all(temp_dir.path().join("src/main/java")).unwrap();
+  fs::create_dir_all(temp_dir.path().join("src/test/java")).unwrap();
+  if let Some((file_name, content)) = build_file {
+    fs::write(temp_dir.path().join(file_name), content).unwrap();
+  }
+  temp_dir
+}
+
+/// Path of a file under the `src/main/java` directory of the project
+pub fn source_path(project: &TempDir, relative_path: &str) -> PathBuf {
+  project.path().join("src/main/java").join(relative_path)
+}
+
+/// Path of a file in the `com.example.domain` package of the project
+pub fn domain_path(project: &TempDir, file_name: &str) -> PathBuf {
+  project.path().join(DOMAIN_PACKAGE_DIR).join(file_name)
+}
@@ -0,0 +1,75 @@
+// Create Java File Service Integration Tests
+// This module contains tests for creating Java files from templates, including the handling of
+// files that already exist with and without `force`
+
+mod common;
+
+use common::{setup_project, source_path};
+use std::fs;
+use syntaxpresso_core::commands::services::create_java_file_service::run;
+use syntaxpresso_core::common::types::java_file_type::JavaFileType;
+use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use tempfile::TempDir;
+
+const EXISTING_SOURCE: &str =
+  "package com.example;\n\npublic class Customer {\n  private Long id;\n}\n";
+
+fn create_customer(project: &TempDir, force: bool) -> Result<String, String> {
+  run(
+    project.path(),
+    "com.example",
+    "Customer",
+    &JavaFileType::Interface,
+    &JavaSourceDirectoryType::Main,
+    force,
+  )
+  .map(|response| response.file_path)
+}
+
+#[test]
+fn test_creates_new_file() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let file_path = create_customer(&project, false).expect("File creation should succeed");
+  assert!(file_path.ends_with("src/main/java/com/example/Customer.java"));
+  assert_eq!(
+    fs::read_to_string(&file_path).unwrap(),
+    "package com.example;\n\npublic interface Customer {}"
+  );
+}
+
+#[test]
+fn test_existing_file_is_rejected_without_force() {
+  let project = setup_project(&[("com/example/Customer.java", EXISTING_SOURCE)]);
+  let file_path = source_path(&project, "com/example/Customer.java");
+  let error = create_customer(&project, false).expect_err("Existing file should be rejected");
+  assert!(error.starts_with("File already exists: "));
+  assert!(error.contains("com/example/Customer.java"));
+  assert_eq!(fs::read_to_string(&file_path).unwrap(), EXISTING_SOURCE);
+}
+
+#[test]
+fn test_existing_file_is_overwritten_with_force() {
+  let project = setup_project(&[("com/example/Customer.java", EXISTING_SOURCE)]);
+  let file_path = source_path(&project, "com/example/Customer.java");
+  create_customer(&project, true).expect("Forced creation should succeed");
+  assert_eq!(
+    fs::read_to_string(&file_path).unwrap(),
+    "package com.example;\n\npublic interface Customer {}"
+  );
+}
+
+#[test]
+fn test_force_does_not_bypass_path_security() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let error = run(
+    project.path(),
+    "..",
+    "Customer",
+    &JavaFileType::Class,
+    &JavaSourceDirectoryType::Main,
+    true,
+  )
+  .err()
+  .expect("Paths outside the project should be rejected");
+  assert!(error.contains("security validation failed"), "{}", error);
+}
@@ -0,0 +1,110 @@
+// Create Java Interface Service Integration Tests
+// This module contains tests for scaffolding interfaces from method signatures
+
+use std::fs;
+use syntaxpresso_core::commands::services::create_java_interface_service::run;
+use tempfile::TempDir;
+
+fn methods(specs: &[&str]) -> Vec<String> {
+  specs.iter().map(|spec| spec.to_string()).collect()
+}
+
+#[test]
+fn test_generates_interface_with_methods_and_imports() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let response = run(
+    project.path(),
+    "com.example.service",
+    "OrderService",
+    &methods(&[
+      "List<com.example.domain.Order> findByCustomer(UUID customerId)",
+      "void cancel(com.example.domain.Order order, String reason)",
+    ]),
+  )
+  .unwrap();
+  assert_eq!(response.file_type, "OrderService");
+  assert_eq!(response.file_package_name, "com.example.service");
+  assert!(response.file_path.ends_with("src/main/java/com/example/service/OrderService.java"));
+  let source = fs::read_to_string(&response.file_path).unwrap();
+  assert_eq!(
+    source,
+    r#"package com.example.service;
+
+import com.example.domain.Order;
+import java.util.List;
+import java.util.UUID;
+
+public interface OrderService {
+
+  List<Order> findByCustomer(UUID customerId);
+
+  void cancel(Order order, String reason);
+}
+"#
+  );
+}
+
+#[test]
+fn test_same_package_types_need_no_import() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let response =
+    run(project.path(), "com.example.service", "Pricing", &methods(&["Quote quote(int[] items)"]))
+      .unwrap();
+  let source = fs::read_to_string(&response.file_path).unwrap();
+  assert!(!source.contains("import"));
+  assert!(source.contains("  Quote quote(int[] items);\n"));
+}
+
+mod invalid_method_tests {
+  use super::*;
+
+  fn run_with(spec: &str) -> String {
+    let project = TempDir::new().expect("Failed to create temp directory");
+    run(project.path(), "com.example.service", "OrderService", &methods(&[spec]))
+      .err()
+      .expect("Expected error")
+  }
+
+  #[test]
+  fn test_rejects_missing_parentheses() {
+    assert_eq!(
+      run_with("Order find"),
+      "'Order find' is not a valid method: expected '(' after the method name"
+    );
+  }
+
+  #[test]
+  fn test_rejects_missing_return_type() {
+    assert_eq!(
+      run_with("find(Long id)"),
+      "'find(Long id)' is not a valid method: expected a return type before the method name"
+    );
+  }
+
+  #[test]
+  fn test_rejects_unnamed_parameter() {
+    assert_eq!(
+      run_with("Order find(Long)"),
+      "'Order find(Long)' is not a valid method: parameter 'Long' must be in the form Type name"
+    );
+  }
+
+  #[test]
+  fn test_rejects_reserved_method_name() {
+    assert!(run_with("void class()").contains("'class' conflicts with a Java reserved word"));
+  }
+
+  #[test]
+  fn test_rejects_duplicate_methods() {
+    let project = TempDir::new().expect("Failed to create temp directory");
+    let error = run(
+      project.path(),
+      "com.example.service",
+      "OrderService",
+      &methods(&["Order find(Long id)", "Order find(Long orderId)"]),
+    )
+    .err()
+    .expect("Expected error");
+    assert_eq!(error, "Method 'Order find(Long orderId)' is declared twice");
+  }
+}
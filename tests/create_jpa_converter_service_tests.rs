@@ -0,0 +1,79 @@
+// Create JPA Converter Service Integration Tests
+// This module contains tests for scaffolding JPA AttributeConverter classes
+
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_converter_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+/// Runs the service for a `Money` to `String` converter and returns the generated source
+fn create_money_converter(
+  project: &TempDir,
+  auto_apply: bool,
+  persistence_namespace: PersistenceNamespace,
+) -> String {
+  let response = run(
+    project.path(),
+    "com.example.converter",
+    "MoneyConverter",
+    "com.example.domain.Money",
+    "java.lang.String",
+    auto_apply,
+    &persistence_namespace,
+  )
+  .unwrap();
+  assert_eq!(response.file_type, "MoneyConverter");
+  assert_eq!(response.file_package_name, "com.example.converter");
+  assert!(response.file_path.ends_with("src/main/java/com/example/converter/MoneyConverter.java"));
+  fs::read_to_string(&response.file_path).unwrap()
+}
+
+#[test]
+fn test_generates_converter_with_stubbed_methods() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let source = create_money_converter(&project, false, PersistenceNamespace::default());
+  assert!(source.starts_with("package com.example.converter;\n\n"));
+  assert!(source.contains(
+    "import com.example.domain.Money;\nimport jakarta.persistence.AttributeConverter;\nimport jakarta.persistence.Converter;\n"
+  ));
+  assert!(!source.contains("import java.lang.String;"));
+  assert!(source.contains(
+    "@Converter\npublic class MoneyConverter implements AttributeConverter<Money, String> {"
+  ));
+  assert!(source.contains("public String convertToDatabaseColumn(Money attribute) {"));
+  assert!(source.contains("public Money convertToEntityAttribute(String dbData) {"));
+  assert_eq!(source.matches("@Override").count(), 2);
+}
+
+#[test]
+fn test_auto_apply_is_written_when_enabled() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let source = create_money_converter(&project, true, PersistenceNamespace::default());
+  assert!(source.contains("@Converter(autoApply = true)\npublic class MoneyConverter"));
+}
+
+#[test]
+fn test_javax_namespace_imports_from_javax() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let source = create_money_converter(&project, false, PersistenceNamespace::Javax);
+  assert!(source.contains("import javax.persistence.AttributeConverter;"));
+  assert!(source.contains("import javax.persistence.Converter;"));
+  assert!(!source.contains("jakarta"));
+}
+
+#[test]
+fn test_existing_converter_is_not_overwritten() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  create_money_converter(&project, false, PersistenceNamespace::default());
+  let result = run(
+    project.path(),
+    "com.example.converter",
+    "MoneyConverter",
+    "com.example.domain.Money",
+    "java.lang.String",
+    true,
+    &PersistenceNamespace::default(),
+  );
+  let error = result.err().expect("Existing converter should not be overwritten");
+  assert!(error.starts_with("File already exists:"));
+}
@@ -0,0 +1,1135 @@
+// Create JPA Entity Basic Field Service Integration Tests
+// This module contains tests for the basic field generation of JPA entities
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_basic_field_service::run;
+use syntaxpresso_core::common::types::basic_field_config::BasicFieldConfig;
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::common::types::field_access::FieldAccess;
+use syntaxpresso_core::common::types::generated_style::GeneratedStyle;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  private Long id;
+}
+"#;
+
+/// Builds a basic field config with explicit column names and every other optional setting
+/// turned off
+fn field_config(
+  field_name: &str,
+  field_type: &str,
+  package_name: Option<&str>,
+) -> BasicFieldConfig {
+  BasicFieldConfig {
+    field_name: field_name.to_string(),
+    field_type: field_type.to_string(),
+    field_type_package_name: package_name.map(|p| p.to_string()),
+    field_length: None,
+    field_precision: None,
+    field_scale: None,
+    field_temporal: None,
+    field_timezone_storage: None,
+    field_unique: false,
+    field_nullable: true,
+    field_large_object: false,
+    basic_fetch: None,
+    field_natural_id: false,
+    field_converter_class: None,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    explicit_column_name: true,
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    default_value: None,
+    column_definition: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+/// Runs the service against a temporary copy of the entity and returns the generated source
+fn create_field(field_config: &BasicFieldConfig) -> String {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("User.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(&b64_source, &entity_path, field_config).expect("Field creation should succeed");
+  fs::read_to_string(&entity_path).unwrap()
+}
+
+/// Runs the service and returns its error, leaving the entity untouched on disk
+fn create_field_error(field_config: &BasicFieldConfig) -> String {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("User.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  let error =
+    run(&b64_source, &entity_path, field_config).err().expect("Field creation should be rejected");
+  assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+  error
+}
+
+mod large_object_tests {
+  use super::*;
+
+  #[test]
+  fn test_string_large_object_omits_length() {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.field_large_object = true;
+    config.field_length = Some(4000);
+    let source = create_field(&config);
+
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+    assert!(source.contains(
+      "  @Column(name = \"biography\", unique = false, nullable = true)\n  @Lob\n  private String biography;"
+    ));
+    assert!(!source.contains("length"));
+    assert!(!source.contains("java.sql"));
+  }
+
+  #[test]
+  fn test_byte_array_large_object() {
+    let mut config = field_config("avatar", "byte[]", None);
+    config.field_large_object = true;
+    config.field_length = Some(1024);
+    let source = create_field(&config);
+
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+    assert!(source.contains("  @Lob\n  private byte[] avatar;"));
+    assert!(!source.contains("length"));
+    assert!(!source.contains("import byte[]"));
+  }
+
+  #[test]
+  fn test_blob_large_object_imports_java_sql() {
+    let mut config = field_config("document", "Blob", None);
+    config.field_large_object = true;
+    config.field_length = Some(1024);
+    let source = create_field(&config);
+
+    assert!(source.contains("import java.sql.Blob;"));
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+    assert!(source.contains("  @Lob\n  private Blob document;"));
+    assert!(!source.contains("length"));
+  }
+
+  #[test]
+  fn test_clob_large_object_imports_java_sql() {
+    let mut config = field_config("contract", "Clob", Some("java.sql"));
+    config.field_large_object = true;
+    let source = create_field(&config);
+
+    assert!(source.contains("import java.sql.Clob;"));
+    assert!(source.contains("  @Lob\n  private Clob contract;"));
+  }
+
+  #[test]
+  fn test_string_without_large_object_keeps_length() {
+    let mut config = field_config("email", "String", Some("java.lang"));
+    config.field_length = Some(320);
+    let source = create_field(&config);
+
+    assert!(
+      source.contains("@Column(name = \"email\", length = 320, unique = false, nullable = true)")
+    );
+    assert!(!source.contains("@Lob"));
+  }
+
+  #[test]
+  fn test_default_length_is_not_emitted() {
+    let mut config = field_config("email", "String", Some("java.lang"));
+    config.field_length = Some(255);
+    let source = create_field(&config);
+
+    assert!(!source.contains("length"));
+  }
+}
+
+mod basic_fetch_tests {
+  use super::*;
+  use syntaxpresso_core::common::types::fetch_type::FetchType;
+
+  #[test]
+  fn test_lazy_basic_fetch_alongside_lob() {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.field_large_object = true;
+    config.basic_fetch = Some(FetchType::Lazy);
+    let source = create_field(&config);
+
+    assert!(source.contains("import jakarta.persistence.Basic;"));
+    assert!(source.contains("import jakarta.persistence.FetchType;"));
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+    assert!(source.contains(
+      "  @Basic(fetch = FetchType.LAZY)\n  @Column(name = \"biography\", unique = false, nullable = true)\n  @Lob\n  private String biography;"
+    ));
+  }
+
+  #[test]
+  fn test_mandatory_lazy_field_is_not_optional() {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.field_nullable = false;
+    config.basic_fetch = Some(FetchType::Lazy);
+    let source = create_field(&config);
+
+    assert!(source.contains("  @Basic(fetch = FetchType.LAZY, optional = false)\n"));
+  }
+
+  #[test]
+  fn test_eager_basic_fetch_omits_default_annotation() {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.basic_fetch = Some(FetchType::Eager);
+    let source = create_field(&config);
+
+    assert!(!source.contains("@Basic"));
+    assert!(!source.contains("import jakarta.persistence.Basic;"));
+    assert!(!source.contains("FetchType"));
+  }
+
+  #[test]
+  fn test_without_basic_fetch_omits_annotation() {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.field_nullable = false;
+    let source = create_field(&config);
+
+    assert!(!source.contains("@Basic"));
+  }
+}
+
+mod column_size_validation_tests {
+  use super::*;
+
+  #[test]
+  fn test_length_on_integer_is_rejected() {
+    let mut config = field_config("age", "Integer", Some("java.lang"));
+    config.field_length = Some(3);
+    assert_eq!(
+      create_field_error(&config),
+      "Length is not supported for field type java.lang.Integer"
+    );
+  }
+
+  #[test]
+  fn test_length_on_string_is_accepted() {
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.field_length = Some(40);
+    let source = create_field(&config);
+    assert!(source.contains("length = 40"));
+  }
+
+  #[test]
+  fn test_precision_on_string_is_rejected() {
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.field_precision = Some(10);
+    assert_eq!(
+      create_field_error(&config),
+      "Precision and scale are not supported for field type java.lang.String"
+    );
+  }
+
+  #[test]
+  fn test_precision_and_scale_on_big_decimal_are_accepted() {
+    let mut config = field_config("price", "BigDecimal", Some("java.math"));
+    config.field_precision = Some(10);
+    config.field_scale = Some(4);
+    let source = create_field(&config);
+    assert!(source.contains("precision = 10, scale = 4"));
+  }
+}
+
+mod precision_scale_tests {
+  use super::*;
+  use syntaxpresso_core::commands::services::create_jpa_entity_basic_field_service::build_precision_scale_warnings;
+
+  fn price_config(precision: Option<u16>, scale: Option<u16>) -> BasicFieldConfig {
+    let mut config = field_config("price", "BigDecimal", Some("java.math"));
+    config.field_precision = precision;
+    config.field_scale = scale;
+    config
+  }
+
+  #[test]
+  fn test_precision_only_defaults_scale_to_zero() {
+    let config = price_config(Some(12), None);
+    assert!(create_field(&config).contains("precision = 12, scale = 0"));
+    assert_eq!(
+      build_precision_scale_warnings(&config),
+      vec!["@Column(scale) defaults to 0 as only precision 12 was given"]
+    );
+  }
+
+  #[test]
+  fn test_scale_only_is_written_with_default_precision() {
+    let config = price_config(None, Some(4));
+    assert!(create_field(&config).contains("precision = 19, scale = 4"));
+    assert_eq!(
+      build_precision_scale_warnings(&config),
+      vec!["@Column(precision) defaults to 19 as only scale 4 was given"]
+    );
+  }
+
+  #[test]
+  fn test_both_are_written_together() {
+    let config = price_config(Some(19), Some(6));
+    assert!(create_field(&config).contains("precision = 19, scale = 6"));
+    assert!(build_precision_scale_warnings(&config).is_empty());
+  }
+
+  #[test]
+  fn test_provider_defaults_are_left_out() {
+    let source = create_field(&price_config(Some(19), Some(2)));
+    assert!(!source.contains("precision"));
+    assert!(!source.contains("scale"));
+  }
+
+  #[test]
+  fn test_scale_larger_than_precision_is_rejected() {
+    assert_eq!(
+      create_field_error(&price_config(Some(4), Some(6))),
+      "Scale 6 cannot exceed precision 4"
+    );
+  }
+}
+
+mod temporal_tests {
+  use super::*;
+  use syntaxpresso_core::commands::services::create_jpa_entity_basic_field_service::build_temporal_warnings;
+  use syntaxpresso_core::common::types::java_field_temporal::JavaFieldTemporal;
+
+  #[test]
+  fn test_date_with_date_temporal_renders_the_annotation() {
+    let mut config = field_config("birthDate", "Date", Some("java.util"));
+    config.field_temporal = Some(JavaFieldTemporal::Date);
+    let source = create_field(&config);
+    assert!(source.contains("import jakarta.persistence.Temporal;"));
+    assert!(source.contains("import jakarta.persistence.TemporalType;"));
+    assert!(source.contains("  @Temporal(value = TemporalType.DATE)\n  private Date birthDate;"));
+    assert!(build_temporal_warnings(&config).is_empty());
+  }
+
+  #[test]
+  fn test_local_date_with_temporal_warns_and_omits_the_annotation() {
+    let mut config = field_config("birthDate", "LocalDate", Some("java.time"));
+    config.field_temporal = Some(JavaFieldTemporal::Date);
+    let source = create_field(&config);
+    assert!(!source.contains("Temporal"));
+    assert_eq!(
+      build_temporal_warnings(&config),
+      vec![
+        "@Temporal only applies to java.util.Date and java.util.Calendar, so it was left out for java.time.LocalDate"
+      ]
+    );
+  }
+}
+
+mod converter_tests {
+  use super::*;
+
+  #[test]
+  fn test_converter_emits_convert_and_suppresses_temporal() {
+    let mut config = field_config("createdAt", "Calendar", Some("java.util"));
+    config.field_temporal =
+      Some(syntaxpresso_core::common::types::java_field_temporal::JavaFieldTemporal::Date);
+    config.field_converter_class = Some("com.example.convert.CalendarConverter".to_string());
+    let source = create_field(&config);
+
+    assert!(source.contains("import jakarta.persistence.Convert;"));
+    assert!(source.contains("import com.example.convert.CalendarConverter;"));
+    assert!(
+      source
+        .contains("  @Convert(converter = CalendarConverter.class)\n  private Calendar createdAt;")
+    );
+    assert!(!source.contains("@Temporal"));
+  }
+
+  #[test]
+  fn test_malformed_converter_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let entity_path = temp_dir.path().join("User.java");
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let mut config = field_config("status", "String", Some("java.lang"));
+    config.field_converter_class = Some("com.example..Bad Converter".to_string());
+    assert!(run(&b64_source, &entity_path, &config).is_err());
+  }
+}
+
+mod natural_id_tests {
+  use super::*;
+
+  #[test]
+  fn test_natural_id_with_unique_keeps_both() {
+    let mut config = field_config("isbn", "String", Some("java.lang"));
+    config.field_natural_id = true;
+    config.field_unique = true;
+    let source = create_field(&config);
+
+    assert_eq!(source.matches("import org.hibernate.annotations.NaturalId;").count(), 1);
+    assert_eq!(source.matches("@NaturalId").count(), 1);
+    assert!(source.contains(
+      "  @NaturalId\n  @Column(name = \"isbn\", unique = true, nullable = true)\n  private String isbn;"
+    ));
+  }
+
+  #[test]
+  fn test_natural_id_is_not_emitted_by_default() {
+    let source = create_field(&field_config("isbn", "String", Some("java.lang")));
+
+    assert!(!source.contains("NaturalId"));
+  }
+}
+
+mod column_naming_strategy_tests {
+  use super::*;
+
+  #[test]
+  fn test_default_strategy_uses_snake_case() {
+    let source = create_field(&field_config("firstName", "String", Some("java.lang")));
+    assert!(source.contains("@Column(name = \"first_name\""));
+  }
+
+  #[test]
+  fn test_none_strategy_uses_field_name_verbatim() {
+    let mut config = field_config("firstName", "String", Some("java.lang"));
+    config.column_naming_strategy = ColumnNamingStrategy::None;
+    let source = create_field(&config);
+    assert!(source.contains("@Column(name = \"firstName\""));
+  }
+
+  #[test]
+  fn test_upper_snake_case_strategy() {
+    let mut config = field_config("userID", "String", Some("java.lang"));
+    config.column_naming_strategy = ColumnNamingStrategy::UpperSnakeCase;
+    let source = create_field(&config);
+    assert!(source.contains("@Column(name = \"USER_ID\""));
+  }
+}
+
+mod implicit_column_name_tests {
+  use super::*;
+
+  #[test]
+  fn test_no_attributes_omits_column_annotation() {
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.explicit_column_name = false;
+    let source = create_field(&config);
+    assert!(source.contains("  private String nickname;"));
+    assert!(!source.contains("@Column"));
+    assert!(!source.contains("import jakarta.persistence.Column;"));
+  }
+
+  #[test]
+  fn test_length_only_omits_name() {
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.explicit_column_name = false;
+    config.field_length = Some(80);
+    let source = create_field(&config);
+    assert!(source.contains("  @Column(length = 80)\n  private String nickname;"));
+    assert!(source.contains("import jakarta.persistence.Column;"));
+  }
+
+  #[test]
+  fn test_non_default_flags_are_kept() {
+    let mut config = field_config("email", "String", Some("java.lang"));
+    config.explicit_column_name = false;
+    config.field_unique = true;
+    config.field_nullable = false;
+    let source = create_field(&config);
+    assert!(source.contains("  @Column(unique = true, nullable = false)\n  private String email;"));
+  }
+
+  #[test]
+  fn test_explicit_name_on() {
+    let source = create_field(&field_config("nickname", "String", Some("java.lang")));
+    assert!(source.contains(
+      "  @Column(name = \"nickname\", unique = false, nullable = true)\n  private String nickname;"
+    ));
+  }
+}
+
+mod field_access_tests {
+  use super::*;
+
+  const MAPPED_SUPERCLASS_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.MappedSuperclass;
+
+@MappedSuperclass
+public abstract class AuditedEntity {
+}
+"#;
+
+  fn create_field_in_mapped_superclass(field_config: &BasicFieldConfig) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("AuditedEntity.java");
+    fs::write(&entity_path, MAPPED_SUPERCLASS_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(MAPPED_SUPERCLASS_SOURCE);
+    run(&b64_source, &entity_path, field_config).expect("Field creation should succeed");
+    fs::read_to_string(&entity_path).unwrap()
+  }
+
+  #[test]
+  fn test_private_is_the_default() {
+    let source = create_field(&field_config("nickname", "String", Some("java.lang")));
+    assert!(source.contains("  private String nickname;"));
+  }
+
+  #[test]
+  fn test_protected_field_in_mapped_superclass() {
+    let mut config = field_config("createdBy", "String", Some("java.lang"));
+    config.field_access = FieldAccess::Protected;
+    let source = create_field_in_mapped_superclass(&config);
+    assert!(source.contains(
+      "@MappedSuperclass\npublic abstract class AuditedEntity {\n\n  @Column(name = \"created_by\", unique = false, nullable = true)\n  protected String createdBy;\n}"
+    ));
+  }
+
+  #[test]
+  fn test_package_field_has_no_modifier() {
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.field_access = FieldAccess::Package;
+    let source = create_field(&config);
+    assert!(source.contains("\n  String nickname;"));
+    assert!(!source.contains("private String nickname;"));
+  }
+}
+
+mod persistence_namespace_tests {
+  use super::*;
+
+  fn create_large_object_field(persistence_namespace: PersistenceNamespace) -> String {
+    let mut config = field_config("biography", "String", Some("java.lang"));
+    config.field_large_object = true;
+    config.persistence_namespace = persistence_namespace;
+    create_field(&config)
+  }
+
+  #[test]
+  fn test_jakarta_is_the_default() {
+    let source = create_large_object_field(PersistenceNamespace::default());
+    assert!(source.contains("import jakarta.persistence.Column;"));
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+    assert!(!source.contains("import javax."));
+  }
+
+  #[test]
+  fn test_javax_namespace_imports_from_javax() {
+    let jakarta_source = create_large_object_field(PersistenceNamespace::Jakarta);
+    let javax_source = create_large_object_field(PersistenceNamespace::Javax);
+    assert!(javax_source.contains("import javax.persistence.Column;"));
+    assert!(javax_source.contains("import javax.persistence.Lob;"));
+    assert!(!javax_source.contains("import jakarta.persistence.Column;"));
+    assert!(!javax_source.contains("import jakarta.persistence.Lob;"));
+    let field = "  @Column(name = \"biography\", unique = false, nullable = true)\n  @Lob\n  private String biography;";
+    assert!(jakarta_source.contains(field));
+    assert!(javax_source.contains(field));
+  }
+}
+
+mod generic_and_array_type_tests {
+  use super::*;
+
+  #[test]
+  fn test_list_imports_raw_type_and_type_argument() {
+    let mut config = field_config("tagIds", "List<Long>", Some("java.util"));
+    config.explicit_column_name = false;
+    let source = create_field(&config);
+    assert!(source.contains("import java.util.List;"));
+    assert!(source.contains("import java.lang.Long;"));
+    assert!(source.contains("  private List<Long> tagIds;"));
+  }
+
+  #[test]
+  fn test_primitive_array_adds_no_import() {
+    let mut config = field_config("checksum", "int[]", None);
+    config.explicit_column_name = false;
+    let source = create_field(&config);
+    assert_eq!(source.matches("import ").count(), 1);
+    assert!(source.contains("  private int[] checksum;"));
+  }
+
+  #[test]
+  fn test_map_imports_every_type_argument() {
+    let mut config = field_config("stockByWarehouse", "Map<String,Integer>", Some("java.util"));
+    config.explicit_column_name = false;
+    let source = create_field(&config);
+    assert!(source.contains("import java.util.Map;"));
+    assert!(source.contains("import java.lang.String;"));
+    assert!(source.contains("import java.lang.Integer;"));
+    assert!(source.contains("  private Map<String, Integer> stockByWarehouse;"));
+  }
+
+  #[test]
+  fn test_qualified_type_argument_is_imported_by_simple_name() {
+    let mut config = field_config("tags", "Set<com.example.tags.Tag>", Some("java.util"));
+    config.explicit_column_name = false;
+    let source = create_field(&config);
+    assert!(source.contains("import java.util.Set;"));
+    assert!(source.contains("import com.example.tags.Tag;"));
+    assert!(source.contains("  private Set<Tag> tags;"));
+  }
+
+  #[test]
+  fn test_invalid_types_are_rejected() {
+    for field_type in
+      ["List<Long", "List<int>", "int<Long>", "String[", "List<>", "Map<String,,Long>"]
+    {
+      let error = create_field_error(&field_config("value", field_type, Some("java.util")));
+      assert!(
+        error.starts_with(&format!("'{}' is not a valid field type", field_type)),
+        "Unexpected error for {}: {}",
+        field_type,
+        error
+      );
+    }
+  }
+}
+
+mod field_position_tests {
+  use super::*;
+
+  const ENTITY_WITH_FIELDS_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  @Column(name = "first_name")
+  private String firstName;
+
+  private String lastName;
+
+  public String getFirstName() {
+    return firstName;
+  }
+}
+"#;
+
+  /// Runs the service against an entity that already has annotated fields and a method
+  fn create_positioned_field(field_config: &BasicFieldConfig) -> Result<String, String> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_WITH_FIELDS_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_WITH_FIELDS_SOURCE);
+    run(&b64_source, &entity_path, field_config)?;
+    Ok(fs::read_to_string(&entity_path).unwrap())
+  }
+
+  fn email_config() -> BasicFieldConfig {
+    let mut config = field_config("email", "String", None);
+    config.explicit_column_name = false;
+    config
+  }
+
+  #[test]
+  fn test_field_is_appended_by_default() {
+    let source = create_positioned_field(&email_config()).unwrap();
+    assert!(source.contains("  }\n\n  private String email;\n}"));
+  }
+
+  #[test]
+  fn test_at_top_inserts_before_first_field_and_its_annotations() {
+    let mut config = email_config();
+    config.at_top = true;
+    let source = create_positioned_field(&config).unwrap();
+    assert!(source.contains(
+      "public class User {\n  private String email;\n\n  @Column(name = \"first_name\")\n  private String firstName;"
+    ));
+  }
+
+  #[test]
+  fn test_after_field_inserts_after_named_field() {
+    let mut config = email_config();
+    config.after_field = Some("firstName".to_string());
+    let source = create_positioned_field(&config).unwrap();
+    assert!(source.contains(
+      "  private String firstName;\n\n  private String email;\n\n  private String lastName;"
+    ));
+  }
+
+  #[test]
+  fn test_after_field_keeps_annotations_on_new_field() {
+    let mut config = field_config("email", "String", None);
+    config.after_field = Some("firstName".to_string());
+    let source = create_positioned_field(&config).unwrap();
+    assert!(source.contains(
+      "  private String firstName;\n\n  @Column(name = \"email\", unique = false, nullable = true)\n  private String email;\n\n  private String lastName;"
+    ));
+  }
+
+  #[test]
+  fn test_unknown_after_field_is_rejected() {
+    let mut config = email_config();
+    config.after_field = Some("middleName".to_string());
+    let error = create_positioned_field(&config).expect_err("Unknown field should be rejected");
+    assert_eq!(error, "Field not found: middleName");
+  }
+
+  #[test]
+  fn test_after_field_and_at_top_cannot_be_combined() {
+    let mut config = email_config();
+    config.after_field = Some("firstName".to_string());
+    config.at_top = true;
+    let error = create_positioned_field(&config).expect_err("Conflicting options should fail");
+    assert_eq!(error, "after_field and at_top cannot be combined");
+  }
+}
+
+mod javadoc_tests {
+  use super::*;
+
+  const DOCUMENTED_ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  /** Primary key. */
+  private Long id;
+}
+"#;
+
+  #[test]
+  fn test_javadoc_precedes_annotations_and_field() {
+    let mut config = field_config("email", "String", None);
+    config.javadoc = Some("Contact address of the user.".to_string());
+    let source = create_field(&config);
+    assert!(source.contains(
+      "  private Long id;\n\n  /** Contact address of the user. */\n  @Column(name = \"email\", unique = false, nullable = true)\n  private String email;"
+    ));
+  }
+
+  #[test]
+  fn test_multi_line_javadoc() {
+    let mut config = field_config("email", "String", None);
+    config.explicit_column_name = false;
+    config.javadoc = Some("Contact address.\n\nMust be unique.".to_string());
+    let source = create_field(&config);
+    assert!(source.contains(
+      "  /**\n   * Contact address.\n   *\n   * Must be unique.\n   */\n  private String email;"
+    ));
+  }
+
+  #[test]
+  fn test_javadoc_cannot_close_the_comment_early() {
+    let mut config = field_config("email", "String", None);
+    config.explicit_column_name = false;
+    config.javadoc = Some("Ends with */ here".to_string());
+    let source = create_field(&config);
+    assert!(source.contains("  /** Ends with *&#47; here */\n  private String email;"));
+  }
+
+  #[test]
+  fn test_at_top_keeps_existing_javadoc_on_its_field() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, DOCUMENTED_ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(DOCUMENTED_ENTITY_SOURCE);
+    let mut config = field_config("email", "String", None);
+    config.explicit_column_name = false;
+    config.at_top = true;
+    config.javadoc = Some("Contact address.".to_string());
+    run(&b64_source, &entity_path, &config).expect("Field creation should succeed");
+    let source = fs::read_to_string(&entity_path).unwrap();
+    assert!(source.contains(
+      "public class User {\n  /** Contact address. */\n  private String email;\n\n  /** Primary key. */\n  private Long id;"
+    ));
+  }
+}
+
+mod default_value_tests {
+  use super::*;
+
+  fn config_with_default(
+    field_name: &str,
+    field_type: &str,
+    default_value: &str,
+  ) -> BasicFieldConfig {
+    let mut config = field_config(field_name, field_type, None);
+    config.explicit_column_name = false;
+    config.default_value = Some(default_value.to_string());
+    config
+  }
+
+  #[test]
+  fn test_string_default_is_quoted() {
+    let source = create_field(&config_with_default("nickname", "String", "say \"hi\""));
+    assert!(source.contains("  private String nickname = \"say \\\"hi\\\"\";"));
+  }
+
+  #[test]
+  fn test_boolean_default() {
+    let source = create_field(&config_with_default("active", "boolean", "true"));
+    assert!(source.contains("  private boolean active = true;"));
+    let error = create_field_error(&config_with_default("active", "Boolean", "yes"));
+    assert_eq!(error, "'yes' is not a valid default value for field type Boolean");
+  }
+
+  #[test]
+  fn test_numeric_defaults_get_literal_suffixes() {
+    let source = create_field(&config_with_default("visits", "Long", "0"));
+    assert!(source.contains("  private Long visits = 0L;"));
+    let source = create_field(&config_with_default("ratio", "Double", "1"));
+    assert!(source.contains("  private Double ratio = 1.0;"));
+    let source = create_field(&config_with_default("weight", "float", "2.5"));
+    assert!(source.contains("  private float weight = 2.5f;"));
+  }
+
+  #[test]
+  fn test_invalid_numeric_default_is_rejected() {
+    let error = create_field_error(&config_with_default("age", "int", "ten"));
+    assert_eq!(error, "'ten' is not a valid default value for field type int");
+    let error = create_field_error(&config_with_default("level", "byte", "300"));
+    assert_eq!(error, "'300' is not a valid default value for field type byte");
+  }
+
+  #[test]
+  fn test_big_decimal_default() {
+    let mut config = config_with_default("price", "BigDecimal", "9.99");
+    config.field_type_package_name = Some("java.math".to_string());
+    let source = create_field(&config);
+    assert!(source.contains("  private BigDecimal price = new BigDecimal(\"9.99\");"));
+  }
+
+  #[test]
+  fn test_other_types_take_expression_verbatim() {
+    let mut config = config_with_default("createdOn", "LocalDate", "LocalDate.now()");
+    config.field_type_package_name = Some("java.time".to_string());
+    let source = create_field(&config);
+    assert!(source.contains("  private LocalDate createdOn = LocalDate.now();"));
+  }
+}
+
+mod syntax_error_tests {
+  use super::*;
+
+  #[test]
+  fn test_field_is_not_added_to_a_class_with_an_unclosed_brace() {
+    let source =
+      "package com.example.domain;\n\n@Entity\npublic class User {\n  private Long id;\n";
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, source).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+    let config = field_config("nickname", "String", Some("java.lang"));
+    let error =
+      run(&b64_source, &entity_path, &config).err().expect("Field creation should be rejected");
+    assert_eq!(error, "cannot edit: source has syntax errors near line 5, column 19");
+    assert_eq!(fs::read_to_string(&entity_path).unwrap(), source);
+  }
+}
+
+mod column_definition_tests {
+  use super::*;
+  use syntaxpresso_core::commands::create_jpa_entity_basic_field_command::execute;
+
+  #[test]
+  fn test_column_definition_is_rendered_with_the_other_flags() {
+    let mut config = field_config("bio", "String", Some("java.lang"));
+    config.column_definition = Some("TEXT".to_string());
+    config.field_unique = true;
+    let source = create_field(&config);
+    assert!(source.contains(
+      "  @Column(name = \"bio\", unique = true, nullable = true, columnDefinition = \"TEXT\")\n  private String bio;"
+    ));
+  }
+
+  #[test]
+  fn test_column_definition_is_escaped() {
+    let mut config = field_config("status", "String", Some("java.lang"));
+    config.column_definition = Some("ENUM(\"A\", \"B\")".to_string());
+    let source = create_field(&config);
+    assert!(source.contains("columnDefinition = \"ENUM(\\\"A\\\", \\\"B\\\")\""), "{}", source);
+  }
+
+  #[test]
+  fn test_column_definition_warns_about_length() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let mut config = field_config("bio", "String", Some("java.lang"));
+    config.column_definition = Some("VARCHAR(40)".to_string());
+    config.field_length = Some(40);
+    let response = execute(temp_dir.path(), &b64_source, &entity_path, &config);
+    assert!(response.succeed);
+    assert_eq!(
+      response.warnings,
+      vec!["@Column(length) may be ignored by the provider when columnDefinition is set"]
+    );
+    let source = fs::read_to_string(&entity_path).unwrap();
+    assert!(source.contains("length = 40"));
+    assert!(source.contains("columnDefinition = \"VARCHAR(40)\""));
+  }
+
+  #[test]
+  fn test_length_without_column_definition_has_no_warning() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let mut config = field_config("bio", "String", Some("java.lang"));
+    config.field_length = Some(40);
+    let response = execute(temp_dir.path(), &b64_source, &entity_path, &config);
+    assert!(response.succeed);
+    assert!(response.warnings.is_empty());
+  }
+}
+
+mod generated_marker_tests {
+  use super::*;
+
+  /// Runs the service once per config on the same entity, as regenerations would, and returns
+  /// the source
+  fn create_fields(configs: &[&BasicFieldConfig]) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    for config in configs {
+      let source = fs::read_to_string(&entity_path).unwrap();
+      let b64_source = base64::engine::general_purpose::STANDARD.encode(&source);
+      run(&b64_source, &entity_path, config).expect("Field creation should succeed");
+    }
+    fs::read_to_string(&entity_path).unwrap()
+  }
+
+  fn marked_config(field_type: &str, style: GeneratedStyle) -> BasicFieldConfig {
+    let mut config = field_config("email", field_type, None);
+    config.explicit_column_name = false;
+    config.mark_generated = Some(style);
+    config
+  }
+
+  #[test]
+  fn test_annotation_marker_and_import() {
+    let source = create_field(&marked_config("String", GeneratedStyle::Annotation));
+    assert!(source.contains("import javax.annotation.processing.Generated;"));
+    assert!(source.contains("  @Generated(\"syntaxpresso\")\n  private String email;"));
+  }
+
+  #[test]
+  fn test_comment_marker_precedes_javadoc() {
+    let mut config = marked_config("String", GeneratedStyle::Comment);
+    config.javadoc = Some("Contact address.".to_string());
+    let source = create_field(&config);
+    assert!(!source.contains("Generated;"));
+    assert!(source.contains(
+      "  // generated by syntaxpresso\n  /** Contact address. */\n  private String email;"
+    ));
+  }
+
+  #[test]
+  fn test_rerun_replaces_marked_field() {
+    let source = create_fields(&[
+      &marked_config("String", GeneratedStyle::Annotation),
+      &marked_config("Integer", GeneratedStyle::Annotation),
+    ]);
+    assert_eq!(source.matches("email;").count(), 1);
+    assert!(source.contains("  @Generated(\"syntaxpresso\")\n  private Integer email;"));
+    assert!(!source.contains("String email"));
+  }
+
+  #[test]
+  fn test_rerun_keeps_the_position_of_the_marked_field() {
+    let mut last = field_config("name", "String", None);
+    last.explicit_column_name = false;
+    let source = create_fields(&[
+      &marked_config("String", GeneratedStyle::Comment),
+      &last,
+      &marked_config("Integer", GeneratedStyle::Comment),
+    ]);
+    assert!(source.contains(
+      "  private Long id;\n\n  // generated by syntaxpresso\n  private Integer email;\n\n  private String name;\n}"
+    ));
+    assert_eq!(source.matches("// generated by syntaxpresso").count(), 1);
+  }
+
+  #[test]
+  fn test_unmarked_field_is_not_replaced() {
+    let mut unmarked = field_config("email", "String", None);
+    unmarked.explicit_column_name = false;
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    run(&b64_source, &entity_path, &unmarked).expect("Field creation should succeed");
+    let source = fs::read_to_string(&entity_path).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(&source);
+    let error =
+      run(&b64_source, &entity_path, &marked_config("Integer", GeneratedStyle::Annotation))
+        .err()
+        .expect("Unmarked fields should not be replaced");
+    assert_eq!(error, "field 'email' already exists");
+  }
+}
+
+mod indent_override_tests {
+  use super::*;
+  use syntaxpresso_core::common::types::indent_style::IndentStyle;
+  use syntaxpresso_core::common::utils::indent_util::set_indent_override;
+
+  /// Creates the field with `--indent` set; the override is per thread, so it can't leak into
+  /// other tests
+  fn create_field_with_indent(field_config: &BasicFieldConfig, indent: IndentStyle) -> String {
+    set_indent_override(Some(indent));
+    let source = create_field(field_config);
+    set_indent_override(None);
+    source
+  }
+
+  #[test]
+  fn test_forced_four_space_indent_ignores_existing_style() {
+    let mut config = field_config("email", "String", Some("java.lang"));
+    config.javadoc = Some("Login email".to_string());
+    let source = create_field_with_indent(&config, IndentStyle::FourSpaces);
+    assert!(source.contains("  private Long id;\n"), "Existing field left untouched:\n{}", source);
+    assert!(source.contains("\n    /** Login email */\n"), "Javadoc indent:\n{}", source);
+    assert!(
+      source.contains("\n    @Column(name = \"email\", unique = false, nullable = true)\n"),
+      "Annotation indent:\n{}",
+      source
+    );
+    assert!(source.contains("\n    private String email;\n"), "Field indent:\n{}", source);
+  }
+
+  #[test]
+  fn test_forced_tab_indent() {
+    let source = create_field_with_indent(&field_config("email", "String", None), IndentStyle::Tab);
+    assert!(source.contains("\n\tprivate String email;\n"), "Field indent:\n{}", source);
+  }
+
+  #[test]
+  fn test_default_indent_without_override() {
+    let source = create_field(&field_config("email", "String", None));
+    assert!(source.contains("\n  private String email;\n"), "Field indent:\n{}", source);
+  }
+
+  #[test]
+  fn test_reindent_keeps_partial_levels() {
+    let template = "class A {\n  /**\n   * Doc\n   */\n  void a() {\n    b();\n  }\n}\n";
+    assert_eq!(
+      IndentStyle::FourSpaces.reindent(template),
+      "class A {\n    /**\n     * Doc\n     */\n    void a() {\n        b();\n    }\n}\n"
+    );
+  }
+}
+
+mod organize_imports_tests {
+  use super::*;
+  use syntaxpresso_core::common::services::import_declaration_service::set_organize_imports;
+
+  const UNSORTED_ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import java.util.List;
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  private Long id;
+}
+"#;
+
+  /// Creates a `BigDecimal` field on an entity with unsorted imports; `--organize-imports` is
+  /// per thread, so it can't leak into other tests
+  fn create_field_with_organize_imports(organize_imports: bool) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, UNSORTED_ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(UNSORTED_ENTITY_SOURCE);
+    set_organize_imports(organize_imports);
+    let result =
+      run(&b64_source, &entity_path, &field_config("amount", "BigDecimal", Some("java.math")));
+    set_organize_imports(false);
+    result.expect("Field creation should succeed");
+    fs::read_to_string(&entity_path).unwrap()
+  }
+
+  #[test]
+  fn test_imports_are_sorted_with_the_flag() {
+    let source = create_field_with_organize_imports(true);
+    assert!(
+      source.contains(
+        "import jakarta.persistence.Column;\nimport jakarta.persistence.Entity;\nimport java.math.BigDecimal;\nimport java.util.List;\n\n@Entity"
+      ),
+      "Organized imports:\n{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_imports_keep_their_order_without_the_flag() {
+    let source = create_field_with_organize_imports(false);
+    assert!(
+      source.contains("import java.util.List;\nimport jakarta.persistence.Entity;\n"),
+      "Untouched imports:\n{}",
+      source
+    );
+    assert!(source.contains("import java.math.BigDecimal;"));
+  }
+}
+
+mod member_spacing_tests {
+  use super::*;
+  use syntaxpresso_core::common::services::formatting_service::set_member_spacing;
+
+  /// Creates `nickname` on `ENTITY_SOURCE` with the given `--member-spacing`; the setting is per
+  /// thread and restored to its default of one blank line afterwards
+  fn create_field_with_spacing(blank_lines: usize, at_top: bool) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let mut config = field_config("nickname", "String", Some("java.lang"));
+    config.at_top = at_top;
+    set_member_spacing(blank_lines);
+    let result = run(&b64_source, &entity_path, &config);
+    set_member_spacing(1);
+    result.expect("Field creation should succeed");
+    fs::read_to_string(&entity_path).unwrap()
+  }
+
+  #[test]
+  fn test_default_spacing_adds_one_blank_line() {
+    let source = create_field_with_spacing(1, false);
+    assert!(
+      source.contains("  private Long id;\n\n  @Column(name = \"nickname\""),
+      "Spaced field:\n{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_zero_spacing_keeps_fields_adjacent() {
+    let source = create_field_with_spacing(0, false);
+    assert!(
+      source.contains("  private Long id;\n  @Column(name = \"nickname\""),
+      "Adjacent field:\n{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_zero_spacing_before_first_field() {
+    let source = create_field_with_spacing(0, true);
+    assert!(
+      source.contains("  private String nickname;\n  private Long id;\n}"),
+      "Adjacent field:\n{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_two_blank_lines() {
+    let source = create_field_with_spacing(2, false);
+    assert!(source.contains("  private Long id;\n\n\n  @Column(name = \"nickname\""));
+  }
+}
@@ -0,0 +1,76 @@
+// Create JPA Entity Embedded Field Service Integration Tests
+// This module contains tests for the @Embedded field generation of JPA entities
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_embedded_field_service::run;
+use syntaxpresso_core::common::types::embedded_field_config::{
+  AttributeOverrideConfig, EmbeddedFieldConfig,
+};
+use syntaxpresso_core::common::types::field_access::FieldAccess;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Customer {
+}
+"#;
+
+/// Builds an `Address homeAddress` field config with the given `(attribute, column)` overrides
+fn field_config(attribute_overrides: &[(&str, &str)]) -> EmbeddedFieldConfig {
+  EmbeddedFieldConfig {
+    field_name: "homeAddress".to_string(),
+    embeddable_type: "Address".to_string(),
+    embeddable_package_name: "com.example.shared".to_string(),
+    attribute_overrides: attribute_overrides
+      .iter()
+      .map(|(attribute_name, column_name)| AttributeOverrideConfig {
+        attribute_name: attribute_name.to_string(),
+        column_name: column_name.to_string(),
+      })
+      .collect(),
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+/// Runs the service against a temporary copy of the entity and returns the generated source
+fn create_field(field_config: EmbeddedFieldConfig) -> String {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Customer.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(temp_dir.path(), &b64_source, &entity_path, field_config)
+    .expect("Field creation should succeed");
+  fs::read_to_string(&entity_path).unwrap()
+}
+
+#[test]
+fn test_embedded_field_without_overrides() {
+  let source = create_field(field_config(&[]));
+  assert!(source.contains("  @Embedded\n  private Address homeAddress;"));
+  assert!(source.contains("import jakarta.persistence.Embedded;"));
+  assert!(source.contains("import com.example.shared.Address;"));
+  assert!(!source.contains("AttributeOverride"));
+  assert!(!source.contains("import jakarta.persistence.Column;"));
+}
+
+#[test]
+fn test_embedded_field_with_overrides() {
+  let source = create_field(field_config(&[("street", "home_street"), ("city", "home_city")]));
+  assert!(source.contains(
+    "  @Embedded\n  @AttributeOverrides({@AttributeOverride(name = \"street\", column = @Column(name = \"home_street\")), @AttributeOverride(name = \"city\", column = @Column(name = \"home_city\"))})\n  private Address homeAddress;"
+  ));
+  assert!(source.contains("import jakarta.persistence.AttributeOverride;"));
+  assert!(source.contains("import jakarta.persistence.AttributeOverrides;"));
+  assert!(source.contains("import jakarta.persistence.Column;"));
+}
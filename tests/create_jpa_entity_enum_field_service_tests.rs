@@ -0,0 +1,140 @@
+// Create JPA Entity Enum Field Service Integration Tests
+// This module contains tests for the enum field generation of JPA entities
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_enum_field_service::run;
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::common::types::enum_field_config::EnumFieldConfig;
+use syntaxpresso_core::common::types::field_access::FieldAccess;
+use syntaxpresso_core::common::types::java_enum_type::JavaEnumType;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+}
+"#;
+
+/// Builds a nullable `OrderStatus status` field config stored as a string
+fn field_config(explicit_column_name: bool) -> EnumFieldConfig {
+  EnumFieldConfig {
+    field_name: "status".to_string(),
+    enum_type: "OrderStatus".to_string(),
+    enum_package_name: "com.example.domain".to_string(),
+    enum_type_storage: JavaEnumType::String,
+    field_length: None,
+    field_nullable: true,
+    field_unique: false,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    explicit_column_name,
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    default_value: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+/// Runs the service against a temporary copy of the entity and returns the generated source
+fn create_field(field_config: EnumFieldConfig) -> String {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Order.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(temp_dir.path(), &b64_source, &entity_path, field_config)
+    .expect("Field creation should succeed");
+  fs::read_to_string(&entity_path).unwrap()
+}
+
+#[test]
+fn test_no_attributes_omits_column_annotation() {
+  let source = create_field(field_config(false));
+  assert!(source.contains("  @Enumerated(value = EnumType.STRING)\n  private OrderStatus status;"));
+  assert!(!source.contains("@Column"));
+}
+
+#[test]
+fn test_length_only_omits_name() {
+  let mut config = field_config(false);
+  config.field_length = Some(32);
+  let source = create_field(config);
+  assert!(source.contains("  @Column(length = 32)\n  private OrderStatus status;"));
+}
+
+#[test]
+fn test_explicit_name_on() {
+  let source = create_field(field_config(true));
+  assert!(
+    source.contains("  @Column(name = \"status\", nullable = true)\n  private OrderStatus status;")
+  );
+}
+
+mod default_value_tests {
+  use super::*;
+
+  const ORDER_STATUS_SOURCE: &str = "package com.example.domain;\n\npublic enum OrderStatus {\n  PENDING,\n  SHIPPED,\n  DELIVERED\n}\n";
+
+  /// Runs the service in a project declaring `OrderStatus` and returns the entity source or the
+  /// error
+  fn create_field_in_project(field_config: EnumFieldConfig) -> Result<String, String> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example/domain");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join("OrderStatus.java"), ORDER_STATUS_SOURCE).unwrap();
+    let entity_path = package_dir.join("Order.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    run(temp_dir.path(), &b64_source, &entity_path, field_config)?;
+    Ok(fs::read_to_string(&entity_path).unwrap())
+  }
+
+  #[test]
+  fn test_default_value_initializes_field() {
+    let mut config = field_config(false);
+    config.default_value = Some("PENDING".to_string());
+    let source = create_field_in_project(config).unwrap();
+    assert!(source.contains("  private OrderStatus status = OrderStatus.PENDING;"));
+  }
+
+  #[test]
+  fn test_qualified_default_value() {
+    let mut config = field_config(false);
+    config.default_value = Some("OrderStatus.SHIPPED".to_string());
+    let source = create_field_in_project(config).unwrap();
+    assert!(source.contains("  private OrderStatus status = OrderStatus.SHIPPED;"));
+  }
+
+  #[test]
+  fn test_unknown_constant_is_rejected() {
+    let mut config = field_config(false);
+    config.default_value = Some("CANCELLED".to_string());
+    let error = create_field_in_project(config).expect_err("Unknown constants should be rejected");
+    assert_eq!(
+      error,
+      "'CANCELLED' is not a constant of enum OrderStatus (expected one of: PENDING, SHIPPED, DELIVERED)"
+    );
+  }
+
+  #[test]
+  fn test_enum_outside_project_is_rejected() {
+    let mut config = field_config(false);
+    config.default_value = Some("PENDING".to_string());
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("Order.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let error = run(temp_dir.path(), &b64_source, &entity_path, config)
+      .err()
+      .expect("Missing enums should be rejected");
+    assert!(error.starts_with("Unable to find enum com.example.domain.OrderStatus"));
+    assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+  }
+}
@@ -0,0 +1,152 @@
+// Create JPA Entity Fields Service Integration Tests
+// This module contains tests for adding several fields to an entity in a single invocation
+
+mod common;
+
+use base64::Engine;
+use common::setup_domain_file;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_fields_service::run;
+use syntaxpresso_core::responses::create_jpa_entity_fields_response::CreateJpaEntityFieldsResponse;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+}
+"#;
+
+fn create_fields(
+  temp_dir: &TempDir,
+  entity_path: &std::path::Path,
+  fields_json: &str,
+) -> Result<CreateJpaEntityFieldsResponse, String> {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(temp_dir.path(), &b64_source, entity_path, fields_json)
+}
+
+const ID_AND_BASIC_FIELDS: &str = r#"[
+  {
+    "kind": "id",
+    "field_name": "id",
+    "field_type": "Long",
+    "field_type_package_name": "java.lang",
+    "field_id_generation": "identity",
+    "field_id_generation_type": "none"
+  },
+  { "kind": "basic", "field_name": "email", "field_type": "String", "field_unique": true },
+  {
+    "kind": "basic",
+    "field_name": "birthDate",
+    "field_type": "LocalDate",
+    "field_type_package_name": "java.time",
+    "field_nullable": true
+  },
+  {
+    "kind": "basic",
+    "field_name": "balance",
+    "field_type": "BigDecimal",
+    "field_type_package_name": "java.math",
+    "field_nullable": true,
+    "field_access": "protected"
+  }
+]"#;
+
+#[test]
+fn test_creates_id_and_three_basic_fields_in_one_call() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let response = create_fields(&temp_dir, &entity_path, ID_AND_BASIC_FIELDS)
+    .unwrap_or_else(|e| panic!("Batch should succeed: {}", e));
+
+  assert_eq!(response.created_count, 4);
+  let statuses: Vec<(&str, &str, bool)> = response
+    .fields
+    .iter()
+    .map(|field| (field.field_name.as_str(), field.kind.as_str(), field.succeed))
+    .collect();
+  assert_eq!(
+    statuses,
+    vec![
+      ("id", "id", true),
+      ("email", "basic", true),
+      ("birthDate", "basic", true),
+      ("balance", "basic", true),
+    ]
+  );
+  assert_eq!(response.file.file_type, "User");
+  assert_eq!(response.file.file_package_name, "com.example.domain");
+  assert!(!response.file.edits.is_empty());
+
+  let source = fs::read_to_string(&entity_path).unwrap();
+  let id = source.find("private Long id;").expect("id field");
+  let email = source.find("private String email;").expect("email field");
+  let birth_date = source.find("private LocalDate birthDate;").expect("birthDate field");
+  let balance = source.find("protected BigDecimal balance;").expect("balance field");
+  assert!(id < email && email < birth_date && birth_date < balance, "{}", source);
+  assert!(source.contains("@GeneratedValue(strategy = GenerationType.IDENTITY)"), "{}", source);
+  assert!(source.contains("@Column(unique = true, nullable = false)\n  private String email;"));
+  for import in [
+    "jakarta.persistence.Id",
+    "jakarta.persistence.Column",
+    "java.time.LocalDate",
+    "java.math.BigDecimal",
+  ] {
+    assert_eq!(source.matches(&format!("import {};", import)).count(), 1, "{}", source);
+  }
+}
+
+#[test]
+fn test_failed_field_is_rolled_back_and_others_are_kept() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let fields_json = r#"[
+    { "kind": "basic", "field_name": "email", "field_type": "String" },
+    { "kind": "basic", "field_name": "email", "field_type": "String" },
+    { "kind": "basic", "field_name": "age", "field_type": "Integer" }
+  ]"#;
+  let response = create_fields(&temp_dir, &entity_path, fields_json).unwrap();
+
+  assert_eq!(response.created_count, 2);
+  assert!(response.fields[0].succeed);
+  assert!(!response.fields[1].succeed);
+  assert!(response.fields[1].error.is_some());
+  assert!(response.fields[2].succeed);
+  let source = fs::read_to_string(&entity_path).unwrap();
+  assert_eq!(source.matches("private String email;").count(), 1, "{}", source);
+  assert!(source.contains("private Integer age;"), "{}", source);
+}
+
+#[test]
+fn test_entity_untouched_when_every_field_fails() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let fields_json = r#"[{ "kind": "basic", "field_name": "email", "field_type": "List<" }]"#;
+  let response = create_fields(&temp_dir, &entity_path, fields_json).unwrap();
+
+  assert_eq!(response.created_count, 0);
+  assert!(!response.fields[0].succeed);
+  assert_eq!(response.file.file_type, "User");
+  assert_eq!(response.file.file_package_name, "com.example.domain");
+  assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+}
+
+#[test]
+fn test_rejects_invalid_fields_json() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  for (fields_json, expected_error) in [
+    ("[]", "At least one field is required"),
+    (r#"[{ "kind": "relationship", "field_name": "x" }]"#, "Invalid fields JSON"),
+    (r#"[{ "kind": "basic", "field_name": "x" }]"#, "Invalid fields JSON"),
+    (
+      r#"[{ "kind": "basic", "field_name": "x", "field_type": "String", "field_access": "open" }]"#,
+      "Invalid fields JSON",
+    ),
+  ] {
+    let error = create_fields(&temp_dir, &entity_path, fields_json)
+      .err()
+      .unwrap_or_else(|| panic!("{} should be rejected", fields_json));
+    assert!(error.starts_with(expected_error), "{}: {}", fields_json, error);
+  }
+  assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+}
@@ -0,0 +1,164 @@
+// Create JPA Entity Id Field Service Integration Tests
+// This module contains tests for the id field generation of JPA entities
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_id_field_service::run;
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::common::types::field_access::FieldAccess;
+use syntaxpresso_core::common::types::id_field_config::IdFieldConfig;
+use syntaxpresso_core::common::types::java_id_generation::JavaIdGeneration;
+use syntaxpresso_core::common::types::java_id_generation_type::JavaIdGenerationType;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Invoice {
+}
+"#;
+
+/// Builds an entity exclusive sequence id config with the given generator name
+fn sequence_config(generator_name: Option<&str>) -> IdFieldConfig {
+  IdFieldConfig {
+    field_name: "id".to_string(),
+    field_type: "Long".to_string(),
+    field_type_package_name: Some("java.lang".to_string()),
+    field_id_generation: JavaIdGeneration::Sequence,
+    field_id_generation_type: JavaIdGenerationType::EntityExclusiveGeneration,
+    field_generator_name: generator_name.map(|s| s.to_string()),
+    field_sequence_name: None,
+    field_initial_value: None,
+    field_allocation_size: None,
+    field_nullable: false,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+/// Runs the service against the test entity, returning the saved source on success
+fn create_id_field(field_config: IdFieldConfig) -> Result<String, String> {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Invoice.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(temp_dir.path(), &b64_source, &entity_path, field_config)?;
+  Ok(fs::read_to_string(&entity_path).unwrap())
+}
+
+#[test]
+fn test_entity_exclusive_sequence_emits_generator_with_defaults() {
+  let source = create_id_field(sequence_config(Some("invoice_gen"))).unwrap();
+  assert!(
+    source
+      .contains("@GeneratedValue(strategy = GenerationType.SEQUENCE, generator = \"invoice_gen\")")
+  );
+  assert!(source.contains(
+    "@SequenceGenerator(name = \"invoice_gen\", sequenceName = \"invoice_gen\", initialValue = 1, allocationSize = 50)"
+  ));
+  assert!(source.contains("import jakarta.persistence.SequenceGenerator;"));
+}
+
+#[test]
+fn test_entity_exclusive_sequence_uses_configured_values() {
+  let mut field_config = sequence_config(Some("invoice_gen"));
+  field_config.field_sequence_name = Some("invoice_seq".to_string());
+  field_config.field_initial_value = Some(1000);
+  field_config.field_allocation_size = Some(1);
+  let source = create_id_field(field_config).unwrap();
+  assert!(source.contains(
+    "@SequenceGenerator(name = \"invoice_gen\", sequenceName = \"invoice_seq\", initialValue = 1000, allocationSize = 1)"
+  ));
+}
+
+#[test]
+fn test_entity_exclusive_sequence_requires_generator_name() {
+  let error = create_id_field(sequence_config(Some("  "))).unwrap_err();
+  assert_eq!(error, "Generator name is required for entity exclusive generation");
+  assert!(create_id_field(sequence_config(None)).is_err());
+}
+
+#[test]
+fn test_entity_exclusive_sequence_rejects_non_positive_allocation_size() {
+  let mut field_config = sequence_config(Some("invoice_gen"));
+  field_config.field_allocation_size = Some(0);
+  assert!(create_id_field(field_config).is_err());
+}
+
+#[test]
+fn test_orm_provided_sequence_has_no_generator() {
+  let mut field_config = sequence_config(None);
+  field_config.field_id_generation_type = JavaIdGenerationType::OrmProvided;
+  let source = create_id_field(field_config).unwrap();
+  assert!(source.contains("@GeneratedValue(strategy = GenerationType.SEQUENCE)"));
+  assert!(!source.contains("@SequenceGenerator"));
+}
+
+#[test]
+fn test_at_top_keeps_id_before_existing_fields() {
+  let entity_source = ENTITY_SOURCE
+    .replace("public class Invoice {\n", "public class Invoice {\n  private String number;\n");
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Invoice.java");
+  fs::write(&entity_path, &entity_source).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(&entity_source);
+  let mut field_config = sequence_config(Some("invoice_gen"));
+  field_config.at_top = true;
+  run(temp_dir.path(), &b64_source, &entity_path, field_config).unwrap();
+  let source = fs::read_to_string(&entity_path).unwrap();
+  let id_position = source.find("  @Id\n").expect("@Id should be added");
+  let number_position = source.find("private String number;").unwrap();
+  assert!(id_position < number_position);
+  assert!(source.contains("public class Invoice {\n  @Id\n"));
+  assert!(source.contains("private Long id;\n\n  private String number;"));
+}
+
+mod annotation_wrap_tests {
+  use super::*;
+  use syntaxpresso_core::common::utils::annotation_value_util::set_annotation_wrap;
+
+  /// Creates the id field with `--annotation-wrap` set; the setting is per thread, so it can't
+  /// leak into other tests
+  fn create_id_field_with_wrap(columns: usize) -> String {
+    set_annotation_wrap(Some(columns));
+    let source = create_id_field(sequence_config(Some("invoice_gen")));
+    set_annotation_wrap(None);
+    source.unwrap()
+  }
+
+  #[test]
+  fn test_annotations_past_the_width_are_wrapped() {
+    let source = create_id_field_with_wrap(100);
+    assert!(
+      source.contains(
+        "  @SequenceGenerator(\n    name = \"invoice_gen\",\n    sequenceName = \"invoice_gen\",\n    initialValue = 1,\n    allocationSize = 50\n  )\n  @Column"
+      ),
+      "Wrapped generator:\n{}",
+      source
+    );
+    assert!(
+      source.contains(
+        "  @GeneratedValue(strategy = GenerationType.SEQUENCE, generator = \"invoice_gen\")\n"
+      ),
+      "Generated value fits in 100 columns:\n{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_annotations_within_the_width_stay_on_one_line() {
+    let source = create_id_field_with_wrap(120);
+    assert!(source.contains(
+      "  @SequenceGenerator(name = \"invoice_gen\", sequenceName = \"invoice_gen\", initialValue = 1, allocationSize = 50)\n"
+    ));
+  }
+}
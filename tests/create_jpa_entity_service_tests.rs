@@ -0,0 +1,107 @@
+// Create JPA Entity Service Integration Tests
+// This module contains tests for scaffolding entities, including the second-level cache
+// annotations added for cacheable entities and Hibernate's dynamic SQL and @Immutable annotations
+
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_service::run;
+use syntaxpresso_core::common::types::cache_concurrency_strategy::CacheConcurrencyStrategy;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+/// Creates `com.example.Order` and returns its source
+fn create_order(
+  cache_concurrency: Option<&CacheConcurrencyStrategy>,
+  cache_region: Option<&str>,
+  dynamic_insert: bool,
+  dynamic_update: bool,
+  immutable: bool,
+  persistence_namespace: &PersistenceNamespace,
+) -> String {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let response = run(
+    temp_dir.path(),
+    "com.example",
+    "Order",
+    None,
+    None,
+    cache_concurrency,
+    cache_region,
+    dynamic_insert,
+    dynamic_update,
+    immutable,
+    persistence_namespace,
+  )
+  .unwrap_or_else(|error| panic!("Entity creation should succeed: {}", error));
+  fs::read_to_string(response.file_path).unwrap()
+}
+
+#[test]
+fn test_entity_without_cache() {
+  let source = create_order(None, None, false, false, false, &PersistenceNamespace::Jakarta);
+  assert!(source.contains("@Entity\n@Table(name = \"order\")\npublic class Order {}"));
+  assert!(!source.contains("Cache"));
+  assert!(!source.contains("Dynamic"));
+  assert!(!source.contains("Immutable"));
+}
+
+#[test]
+fn test_cacheable_entity_with_read_write_strategy() {
+  let source = create_order(
+    Some(&CacheConcurrencyStrategy::ReadWrite),
+    None,
+    false,
+    false,
+    false,
+    &PersistenceNamespace::Jakarta,
+  );
+  assert!(source.contains("import jakarta.persistence.Cacheable;\n"));
+  assert!(source.contains("import org.hibernate.annotations.Cache;\n"));
+  assert!(source.contains("import org.hibernate.annotations.CacheConcurrencyStrategy;\n"));
+  assert!(source.contains(
+    "@Table(name = \"order\")\n@Cacheable\n@Cache(usage = CacheConcurrencyStrategy.READ_WRITE, region = \"com.example.Order\")\npublic class Order {}"
+  ));
+}
+
+#[test]
+fn test_cacheable_entity_with_custom_region() {
+  let source = create_order(
+    Some(&CacheConcurrencyStrategy::ReadOnly),
+    Some("reference-data"),
+    false,
+    false,
+    false,
+    &PersistenceNamespace::Javax,
+  );
+  assert!(source.contains("import javax.persistence.Cacheable;\n"));
+  assert!(
+    source
+      .contains("@Cache(usage = CacheConcurrencyStrategy.READ_ONLY, region = \"reference-data\")")
+  );
+}
+
+#[test]
+fn test_entity_with_dynamic_insert_and_update() {
+  let source = create_order(None, None, true, true, false, &PersistenceNamespace::Jakarta);
+  assert!(source.contains("import org.hibernate.annotations.DynamicInsert;\n"));
+  assert!(source.contains("import org.hibernate.annotations.DynamicUpdate;\n"));
+  assert!(
+    source
+      .contains("@Table(name = \"order\")\n@DynamicInsert\n@DynamicUpdate\npublic class Order {}")
+  );
+}
+
+#[test]
+fn test_entity_with_dynamic_update_only() {
+  let source = create_order(None, None, false, true, false, &PersistenceNamespace::Jakarta);
+  assert!(source.contains("@Table(name = \"order\")\n@DynamicUpdate\npublic class Order {}"));
+  assert!(!source.contains("DynamicInsert"));
+}
+
+#[test]
+fn test_immutable_entity() {
+  let source = create_order(None, None, false, false, true, &PersistenceNamespace::Jakarta);
+  assert!(source.contains("import org.hibernate.annotations.Immutable;\n"));
+  assert!(source.contains("@Table(name = \"order\")\n@Immutable\npublic class Order {}"));
+  // Read-only entities never get setters
+  assert!(!source.contains(" set"));
+}
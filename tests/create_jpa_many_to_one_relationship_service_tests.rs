@@ -0,0 +1,446 @@
+// Create JPA Many To One Relationship Service Integration Tests
+// This module contains tests for writing both sides of many-to-one relationships
+
+mod common;
+
+use base64::Engine;
+use common::{setup_project, source_path};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::create_jpa_many_to_one_relationship_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::common::types::{
+  cascade_type::CascadeType, collection_type::CollectionType, fetch_type::FetchType,
+  many_to_one_field_config::ManyToOneFieldConfig, mapping_type::MappingType, other_type::OtherType,
+};
+use tempfile::TempDir;
+
+const ORDER_SOURCE: &str = r#"package com.example.orders;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+}
+"#;
+
+const CUSTOMER_SOURCE: &str = r#"package com.example.customers;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Customer {
+}
+"#;
+
+/// Creates a project with `Order` and `Customer` entities living in different packages
+fn setup_order_and_customer() -> (TempDir, PathBuf, PathBuf) {
+  let project = setup_project(&[
+    ("com/example/orders/Order.java", ORDER_SOURCE),
+    ("com/example/customers/Customer.java", CUSTOMER_SOURCE),
+  ]);
+  let order_path = source_path(&project, "com/example/orders/Order.java");
+  let customer_path = source_path(&project, "com/example/customers/Customer.java");
+  (project, order_path, customer_path)
+}
+
+fn field_config(mapping_type: Option<MappingType>) -> ManyToOneFieldConfig {
+  ManyToOneFieldConfig {
+    inverse_field_type: "Customer".to_string(),
+    inverse_field_package: None,
+    fetch_type: FetchType::Lazy,
+    collection_type: CollectionType::List,
+    declared_collection_type: None,
+    mapping_type,
+    owning_side_cascades: vec![],
+    inverse_side_cascades: vec![],
+    owning_side_other: vec![],
+    inverse_side_other: vec![],
+    join_column_name: None,
+    referenced_column_name: None,
+    maps_id: None,
+    initialize_collection: true,
+    order_by: None,
+    order_column: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+#[test]
+fn test_bidirectional_mapping_writes_both_entities() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let responses =
+    run(project.path(), &b64_source, &order_path, "customer", "orders", &field_config(None))
+      .expect("Relationship creation should succeed");
+
+  assert_eq!(responses.len(), 2);
+  assert_eq!(responses[0].file_type, "Order");
+  assert_eq!(responses[1].file_type, "Customer");
+  assert!(!responses[1].edits.is_empty());
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains("import com.example.customers.Customer;"));
+  assert!(order_source.contains("@ManyToOne(fetch = FetchType.LAZY"));
+  assert!(order_source.contains("private Customer customer;"));
+  let customer_source = fs::read_to_string(&customer_path).unwrap();
+  assert!(customer_source.contains("import com.example.orders.Order;"));
+  assert!(customer_source.contains("import jakarta.persistence.OneToMany;"));
+  assert!(customer_source.contains("import java.util.List;"));
+  assert!(customer_source.contains("@OneToMany(mappedBy = \"customer\")"));
+  assert!(customer_source.contains("import java.util.ArrayList;"));
+  assert!(customer_source.contains("private List<Order> orders = new ArrayList<>();"));
+}
+
+#[test]
+fn test_unidirectional_mapping_leaves_inverse_entity_untouched() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let responses = run(
+    project.path(),
+    &b64_source,
+    &order_path,
+    "customer",
+    "orders",
+    &field_config(Some(MappingType::UnidirectionalJoinColumn)),
+  )
+  .expect("Relationship creation should succeed");
+
+  assert_eq!(responses.len(), 1);
+  assert!(fs::read_to_string(&order_path).unwrap().contains("private Customer customer;"));
+  assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+}
+
+#[test]
+fn test_unidirectional_mapping_emits_only_owning_annotations() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let mut config = field_config(Some(MappingType::UnidirectionalJoinColumn));
+  // Inverse side options have no collection to apply to and must be ignored
+  config.inverse_side_cascades = vec![CascadeType::All];
+  config.inverse_side_other = vec![OtherType::OrphanRemoval];
+  let responses = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .expect("Relationship creation should succeed");
+
+  assert_eq!(responses.len(), 1);
+  assert_eq!(responses[0].file_type, "Order");
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains(
+    "  @ManyToOne(fetch = FetchType.LAZY, optional = true)\n  @JoinColumn(name = \"customer_id\", nullable = true)\n  private Customer customer;"
+  ), "Owning side:\n{}", order_source);
+  assert!(!order_source.contains("mappedBy"));
+  assert!(!order_source.contains("OneToMany"));
+  assert!(!order_source.contains("java.util"));
+  assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+}
+
+#[test]
+fn test_unidirectional_mapping_ignores_inverse_field_name_clash() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let customer_source = CUSTOMER_SOURCE.replace("{\n}", "{\n  private String orders;\n}");
+  fs::write(&customer_path, &customer_source).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  run(
+    project.path(),
+    &b64_source,
+    &order_path,
+    "customer",
+    "orders",
+    &field_config(Some(MappingType::UnidirectionalJoinColumn)),
+  )
+  .expect("The inverse entity is not edited, so its fields cannot clash");
+
+  assert_eq!(fs::read_to_string(&customer_path).unwrap(), customer_source);
+}
+
+#[test]
+fn test_mandatory_sets_optional_and_nullable_false() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let mut config = field_config(None);
+  config.owning_side_other = vec![OtherType::Mandatory];
+  run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .expect("Relationship creation should succeed");
+
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains("@ManyToOne(fetch = FetchType.LAZY, optional = false)"));
+  assert!(order_source.contains("@JoinColumn(name = \"customer_id\", nullable = false)"));
+}
+
+#[test]
+fn test_optional_by_default() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  run(project.path(), &b64_source, &order_path, "customer", "orders", &field_config(None))
+    .expect("Relationship creation should succeed");
+
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains("@ManyToOne(fetch = FetchType.LAZY, optional = true)"));
+  assert!(order_source.contains("@JoinColumn(name = \"customer_id\", nullable = true)"));
+}
+
+#[test]
+fn test_join_column_names_are_written_when_provided() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let mut config = field_config(None);
+  config.join_column_name = Some("cust_no".to_string());
+  config.referenced_column_name = Some("customer_number".to_string());
+  run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .expect("Relationship creation should succeed");
+
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains(
+    "@JoinColumn(name = \"cust_no\", referencedColumnName = \"customer_number\", nullable = true)"
+  ));
+}
+
+#[test]
+fn test_join_column_names_with_quote_and_backslash_are_escaped() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let mut config = field_config(None);
+  config.join_column_name = Some("cust\"no".to_string());
+  config.referenced_column_name = Some(r"crm\customer_number".to_string());
+  run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .expect("Relationship creation should succeed");
+
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains(
+    r#"@JoinColumn(name = "cust\"no", referencedColumnName = "crm\\customer_number", nullable = true)"#
+  ));
+}
+
+#[test]
+fn test_join_column_name_with_control_character_is_rejected() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+  let mut config = field_config(None);
+  config.join_column_name = Some("cust\nno".to_string());
+  let error = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .err()
+    .expect("Control characters should be rejected");
+
+  assert!(error.contains("control characters"), "error: {}", error);
+  assert_eq!(fs::read_to_string(&order_path).unwrap(), ORDER_SOURCE);
+  assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+}
+
+#[test]
+fn test_maps_id_replaces_derived_join_column() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let order_source = ORDER_SOURCE
+    .replace("public class Order {\n", "public class Order {\n  @Id\n  private Long id;\n");
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(&order_source);
+  let mut config = field_config(Some(MappingType::UnidirectionalJoinColumn));
+  config.maps_id = Some(None);
+  run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+    .expect("Relationship creation should succeed");
+
+  let order_source = fs::read_to_string(&order_path).unwrap();
+  assert!(order_source.contains("import jakarta.persistence.MapsId;"));
+  assert!(order_source.contains(
+    "  @ManyToOne(fetch = FetchType.LAZY, optional = true)\n  @MapsId\n  private Customer customer;"
+  ));
+  assert!(!order_source.contains("@JoinColumn"));
+}
+
+mod collection_initializer_tests {
+  use super::*;
+
+  /// Creates a bidirectional relationship with a `Set` collection and returns the inverse source
+  fn create_set_relationship(initialize_collection: bool) -> String {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.collection_type = CollectionType::Set;
+    config.initialize_collection = initialize_collection;
+    run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .expect("Relationship creation should succeed");
+    fs::read_to_string(&customer_path).unwrap()
+  }
+
+  #[test]
+  fn test_set_collection_is_initialized_with_hash_set() {
+    let customer_source = create_set_relationship(true);
+    assert!(customer_source.contains("import java.util.Set;"));
+    assert!(customer_source.contains("import java.util.HashSet;"));
+    assert!(customer_source.contains("private Set<Order> orders = new HashSet<>();"));
+  }
+
+  #[test]
+  fn test_initializer_is_omitted_when_disabled() {
+    let customer_source = create_set_relationship(false);
+    assert!(customer_source.contains("import java.util.Set;"));
+    assert!(!customer_source.contains("HashSet"));
+    assert!(customer_source.contains("private Set<Order> orders;"));
+  }
+}
+
+mod declared_collection_type_tests {
+  use super::*;
+
+  #[test]
+  fn test_collection_is_declared_independently_from_initializer() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.declared_collection_type = Some(CollectionType::Collection);
+    run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .expect("Relationship creation should succeed");
+
+    let customer_source = fs::read_to_string(&customer_path).unwrap();
+    assert!(customer_source.contains("import java.util.Collection;"));
+    assert!(customer_source.contains("import java.util.ArrayList;"));
+    assert!(!customer_source.contains("import java.util.List;"));
+    assert!(customer_source.contains("private Collection<Order> orders = new ArrayList<>();"));
+  }
+
+  #[test]
+  fn test_declared_type_must_hold_the_collection_type() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.declared_collection_type = Some(CollectionType::Set);
+    let result = run(project.path(), &b64_source, &order_path, "customer", "orders", &config);
+    assert_eq!(
+      result.err().expect("Expected error"),
+      "Declared collection type Set cannot hold a List collection"
+    );
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+}
+
+mod collection_ordering_tests {
+  use super::*;
+
+  #[test]
+  fn test_order_by_is_written_on_inverse_collection() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.order_by = Some("createdAt DESC".to_string());
+    run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .expect("Relationship creation should succeed");
+
+    let customer_source = fs::read_to_string(&customer_path).unwrap();
+    assert!(customer_source.contains("import jakarta.persistence.OrderBy;"));
+    assert!(customer_source.contains(
+      "  @OneToMany(mappedBy = \"customer\")\n  @OrderBy(\"createdAt DESC\")\n  private List<Order> orders"
+    ));
+    assert!(!fs::read_to_string(&order_path).unwrap().contains("OrderBy"));
+  }
+
+  #[test]
+  fn test_order_column_is_written_on_inverse_list() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.order_column = Some("position".to_string());
+    run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .expect("Relationship creation should succeed");
+
+    let customer_source = fs::read_to_string(&customer_path).unwrap();
+    assert!(customer_source.contains("import jakarta.persistence.OrderColumn;"));
+    assert!(customer_source.contains(
+      "  @OneToMany(mappedBy = \"customer\")\n  @OrderColumn(name = \"position\")\n  private List<Order> orders"
+    ));
+  }
+
+  #[test]
+  fn test_order_column_requires_list() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.collection_type = CollectionType::Set;
+    config.order_column = Some("position".to_string());
+    let error = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .err()
+      .expect("@OrderColumn on a Set should be rejected");
+
+    assert_eq!(error, "@OrderColumn requires a List collection type, not Set");
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), ORDER_SOURCE);
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_order_by_and_order_column_are_exclusive() {
+    let (project, order_path, _) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.order_by = Some("createdAt".to_string());
+    config.order_column = Some("position".to_string());
+    let error = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .err()
+      .expect("Combined ordering should be rejected");
+
+    assert_eq!(error, "@OrderBy and @OrderColumn cannot be combined");
+  }
+}
+
+mod inverse_field_package_tests {
+  use super::*;
+
+  const LEGACY_CUSTOMER_SOURCE: &str = r#"package com.example.legacy;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Customer {
+}
+"#;
+
+  /// Adds a second `Customer` entity in `com.example.legacy`
+  fn setup_project_with_duplicate_customer() -> (TempDir, PathBuf, PathBuf, PathBuf) {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let legacy_dir = project.path().join("src/main/java/com/example/legacy");
+    fs::create_dir_all(&legacy_dir).unwrap();
+    let legacy_path = legacy_dir.join("Customer.java");
+    fs::write(&legacy_path, LEGACY_CUSTOMER_SOURCE).unwrap();
+    (project, order_path, customer_path, legacy_path)
+  }
+
+  #[test]
+  fn test_same_named_entities_are_ambiguous_without_package() {
+    let (project, order_path, _, _) = setup_project_with_duplicate_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let error =
+      run(project.path(), &b64_source, &order_path, "customer", "orders", &field_config(None))
+        .err()
+        .expect("Ambiguous entity name should be rejected");
+    assert_eq!(
+      error,
+      "Entity class 'Customer' is ambiguous, found in packages: com.example.customers, com.example.legacy; use --inverse-field-package to pick one"
+    );
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), ORDER_SOURCE);
+  }
+
+  #[test]
+  fn test_inverse_field_package_disambiguates_same_named_entities() {
+    let (project, order_path, customer_path, legacy_path) = setup_project_with_duplicate_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.inverse_field_package = Some("com.example.legacy".to_string());
+    let responses = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .expect("Relationship creation should succeed");
+
+    assert_eq!(responses[1].file_package_name, "com.example.legacy");
+    let order_source = fs::read_to_string(&order_path).unwrap();
+    assert!(order_source.contains("import com.example.legacy.Customer;"));
+    assert!(!order_source.contains("import com.example.customers.Customer;"));
+    assert!(fs::read_to_string(&legacy_path).unwrap().contains("private List<Order> orders"));
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_unknown_inverse_field_package_is_rejected() {
+    let (project, order_path, _) = setup_order_and_customer();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ORDER_SOURCE);
+    let mut config = field_config(None);
+    config.inverse_field_package = Some("com.example.legacy".to_string());
+    let error = run(project.path(), &b64_source, &order_path, "customer", "orders", &config)
+      .err()
+      .expect("Missing entity should be rejected");
+    assert_eq!(error, "Entity class 'com.example.legacy.Customer' not found in project");
+  }
+}
@@ -0,0 +1,68 @@
+// Create JPA Mapped Superclass Service Integration Tests
+// This module contains tests for scaffolding @MappedSuperclass classes
+
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_mapped_superclass_service::run;
+use syntaxpresso_core::commands::services::get_all_jpa_mapped_superclasses;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+/// Runs the service in a fresh project and returns the project with the saved source
+fn create_superclass(generic_id: bool, with_auditing: bool) -> (TempDir, String) {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let response = run(
+    temp_dir.path(),
+    "com.example.domain",
+    "base-entity",
+    generic_id,
+    with_auditing,
+    &PersistenceNamespace::default(),
+  )
+  .expect("Mapped superclass creation should succeed");
+  assert_eq!(response.file_type, "BaseEntity");
+  assert_eq!(response.file_package_name, "com.example.domain");
+  let source = fs::read_to_string(&response.file_path).unwrap();
+  (temp_dir, source)
+}
+
+#[test]
+fn test_plain_mapped_superclass() {
+  let (_project, source) = create_superclass(false, false);
+  assert_eq!(
+    source,
+    "package com.example.domain;\n\nimport jakarta.persistence.MappedSuperclass;\n\n@MappedSuperclass\npublic abstract class BaseEntity {\n}\n"
+  );
+  assert!(!source.contains("@Entity"));
+}
+
+#[test]
+fn test_generic_id_and_auditing_fields() {
+  let (_project, source) = create_superclass(true, true);
+  assert!(source.contains("import java.io.Serializable;"));
+  assert!(source.contains("import org.hibernate.annotations.CreationTimestamp;"));
+  assert!(source.contains("public abstract class BaseEntity<ID extends Serializable> {"));
+  assert!(source.contains("  @Id\n  protected ID id;\n"));
+  assert!(source.contains(
+    "  @CreationTimestamp\n  @Column(name = \"created_at\", nullable = false, updatable = false)\n  protected Instant createdAt;\n"
+  ));
+  assert!(source.contains(
+    "  @UpdateTimestamp\n  @Column(name = \"updated_at\", nullable = false)\n  protected Instant updatedAt;\n"
+  ));
+}
+
+#[test]
+fn test_created_superclass_is_listed_and_not_overwritten() {
+  let (project, _) = create_superclass(false, true);
+  let superclasses = get_all_jpa_mapped_superclasses::run(project.path(), None).unwrap();
+  assert_eq!(superclasses.len(), 1);
+  assert_eq!(superclasses[0].file_type, "BaseEntity");
+  let result = run(
+    project.path(),
+    "com.example.domain",
+    "BaseEntity",
+    false,
+    false,
+    &PersistenceNamespace::default(),
+  );
+  assert!(result.err().unwrap().starts_with("File already exists"));
+}
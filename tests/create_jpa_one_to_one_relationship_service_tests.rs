@@ -0,0 +1,274 @@
+// Create JPA One To One Relationship Service Integration Tests
+// This module contains tests for writing both sides of one-to-one relationships
+
+mod common;
+
+use base64::Engine;
+use common::{setup_project, source_path};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::create_jpa_one_to_one_relationship_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::common::types::{
+  fetch_type::FetchType, mapping_type::MappingType, one_to_one_field_config::OneToOneFieldConfig,
+  other_type::OtherType,
+};
+use tempfile::TempDir;
+
+const USER_SOURCE: &str = r#"package com.example.users;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+}
+"#;
+
+const PROFILE_SOURCE: &str = r#"package com.example.users;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Profile {
+}
+"#;
+
+/// Creates a project with `User` and `Profile` entities
+fn setup_user_and_profile() -> (TempDir, PathBuf, PathBuf) {
+  let project = setup_project(&[
+    ("com/example/users/User.java", USER_SOURCE),
+    ("com/example/users/Profile.java", PROFILE_SOURCE),
+  ]);
+  let user_path = source_path(&project, "com/example/users/User.java");
+  let profile_path = source_path(&project, "com/example/users/Profile.java");
+  (project, user_path, profile_path)
+}
+
+fn field_config(fetch_type: Option<FetchType>) -> OneToOneFieldConfig {
+  OneToOneFieldConfig {
+    inverse_field_type: "Profile".to_string(),
+    inverse_field_package: None,
+    mapping_type: Some(MappingType::BidirectionalJoinColumn),
+    fetch_type,
+    owning_side_cascades: vec![],
+    inverse_side_cascades: vec![],
+    owning_side_other: vec![],
+    inverse_side_other: vec![],
+    join_column_name: None,
+    referenced_column_name: None,
+    maps_id: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+/// Creates the `User.profile` relationship and returns the `User` and `Profile` sources
+fn create_relationship(fetch_type: Option<FetchType>) -> (String, String) {
+  let (project, user_path, profile_path) = setup_user_and_profile();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(USER_SOURCE);
+  run(project.path(), &b64_source, &user_path, "profile", "user", &field_config(fetch_type))
+    .expect("Relationship creation should succeed");
+  (fs::read_to_string(&user_path).unwrap(), fs::read_to_string(&profile_path).unwrap())
+}
+
+#[test]
+fn test_lazy_fetch_type_is_written_on_owning_side() {
+  let (user_source, profile_source) = create_relationship(Some(FetchType::Lazy));
+  assert!(user_source.contains("@OneToOne(fetch = FetchType.LAZY, optional = true)"));
+  assert!(user_source.contains("import jakarta.persistence.FetchType;"));
+  assert!(profile_source.contains("@OneToOne(optional = true, mappedBy = \"profile\")"));
+  assert!(!profile_source.contains("FetchType"));
+}
+
+#[test]
+fn test_eager_fetch_type_is_written_on_owning_side() {
+  let (user_source, _) = create_relationship(Some(FetchType::Eager));
+  assert!(user_source.contains("@OneToOne(fetch = FetchType.EAGER, optional = true)"));
+}
+
+#[test]
+fn test_no_fetch_type_keeps_provider_default() {
+  let (user_source, _) = create_relationship(None);
+  assert!(user_source.contains("@OneToOne(optional = true)"));
+  assert!(!user_source.contains("FetchType"));
+}
+
+#[test]
+fn test_mandatory_sets_optional_and_nullable_false() {
+  let (project, user_path, _) = setup_user_and_profile();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(USER_SOURCE);
+  let mut config = field_config(None);
+  config.owning_side_other = vec![OtherType::Mandatory];
+  run(project.path(), &b64_source, &user_path, "profile", "user", &config)
+    .expect("Relationship creation should succeed");
+
+  let user_source = fs::read_to_string(&user_path).unwrap();
+  assert!(user_source.contains("@OneToOne(optional = false)"));
+  assert!(user_source.contains("@JoinColumn(name = \"profile_id\", nullable = false)"));
+}
+
+#[test]
+fn test_join_column_names_are_written_when_provided() {
+  let (project, user_path, _) = setup_user_and_profile();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(USER_SOURCE);
+  let mut config = field_config(None);
+  config.join_column_name = Some("profile_ref".to_string());
+  config.referenced_column_name = Some("profile_code".to_string());
+  run(project.path(), &b64_source, &user_path, "profile", "user", &config)
+    .expect("Relationship creation should succeed");
+
+  let user_source = fs::read_to_string(&user_path).unwrap();
+  assert!(user_source.contains(
+    "@JoinColumn(name = \"profile_ref\", referencedColumnName = \"profile_code\", nullable = true)"
+  ));
+}
+
+mod maps_id_tests {
+  use super::*;
+
+  const USER_DETAILS_SOURCE: &str = r#"package com.example.users;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+
+@Entity
+public class UserDetails {
+  @Id
+  private Long id;
+}
+"#;
+
+  const USER_SETTINGS_SOURCE: &str = r#"package com.example.users;
+
+import jakarta.persistence.EmbeddedId;
+import jakarta.persistence.Entity;
+
+@Entity
+public class UserSettings {
+  @EmbeddedId
+  private UserSettingsId id;
+}
+"#;
+
+  /// Adds `owning_source` as a new owning entity and maps it to `User` with `maps_id`
+  fn create_shared_key_relationship(
+    owning_source: &str,
+    owning_file_name: &str,
+    maps_id: Option<Option<String>>,
+    join_column_name: Option<&str>,
+  ) -> (Result<(), String>, String) {
+    let (project, user_path, _) = setup_user_and_profile();
+    let owning_path = user_path.with_file_name(owning_file_name);
+    fs::write(&owning_path, owning_source).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(owning_source);
+    let mut config = field_config(None);
+    config.inverse_field_type = "User".to_string();
+    config.mapping_type = Some(MappingType::UnidirectionalJoinColumn);
+    config.join_column_name = join_column_name.map(String::from);
+    config.maps_id = maps_id;
+    let result =
+      run(project.path(), &b64_source, &owning_path, "user", "details", &config).map(|_| ());
+    let owning_output = fs::read_to_string(&owning_path).unwrap();
+    (result, owning_output)
+  }
+
+  #[test]
+  fn test_maps_id_renders_on_one_to_one_sharing_the_primary_key() {
+    let (result, source) =
+      create_shared_key_relationship(USER_DETAILS_SOURCE, "UserDetails.java", Some(None), None);
+
+    result.expect("Relationship creation should succeed");
+    assert!(source.contains("import jakarta.persistence.MapsId;"));
+    assert!(source.contains("  @OneToOne(optional = true)\n  @MapsId\n  private User user;"));
+    assert!(!source.contains("@JoinColumn"));
+    assert!(!source.contains("import jakarta.persistence.JoinColumn;"));
+  }
+
+  #[test]
+  fn test_explicit_join_column_name_is_kept_with_maps_id() {
+    let (result, source) = create_shared_key_relationship(
+      USER_DETAILS_SOURCE,
+      "UserDetails.java",
+      Some(None),
+      Some("user_id"),
+    );
+
+    result.expect("Relationship creation should succeed");
+    assert!(source.contains(
+      "  @MapsId\n  @JoinColumn(name = \"user_id\", nullable = true)\n  private User user;"
+    ));
+  }
+
+  #[test]
+  fn test_maps_id_value_maps_embedded_id_attribute() {
+    let (result, source) = create_shared_key_relationship(
+      USER_SETTINGS_SOURCE,
+      "UserSettings.java",
+      Some(Some("userId".to_string())),
+      None,
+    );
+
+    result.expect("Relationship creation should succeed");
+    assert!(source.contains("  @MapsId(\"userId\")\n  private User user;"));
+  }
+
+  #[test]
+  fn test_maps_id_value_requires_embedded_id() {
+    let (result, source) = create_shared_key_relationship(
+      USER_DETAILS_SOURCE,
+      "UserDetails.java",
+      Some(Some("userId".to_string())),
+      None,
+    );
+
+    let error = result.expect_err("A simple @Id has no attribute to map");
+    assert_eq!(
+      error,
+      "@MapsId(\"userId\") maps an attribute of an embedded id, but UserDetails has no @EmbeddedId field"
+    );
+    assert_eq!(source, USER_DETAILS_SOURCE);
+  }
+
+  #[test]
+  fn test_maps_id_requires_an_id_field() {
+    let (result, source) =
+      create_shared_key_relationship(PROFILE_SOURCE, "Profile.java", Some(None), None);
+
+    let error = result.expect_err("Entities without an id cannot derive one");
+    assert_eq!(error, "@MapsId requires Profile to declare an @Id or @EmbeddedId field");
+    assert_eq!(source, PROFILE_SOURCE);
+  }
+}
+
+mod inverse_field_package_tests {
+  use super::*;
+
+  #[test]
+  fn test_inverse_field_package_disambiguates_same_named_entities() {
+    let (project, user_path, profile_path) = setup_user_and_profile();
+    let archive_dir = project.path().join("src/main/java/com/example/archive");
+    fs::create_dir_all(&archive_dir).unwrap();
+    let archived_profile_path = archive_dir.join("Profile.java");
+    fs::write(
+      &archived_profile_path,
+      PROFILE_SOURCE.replace("com.example.users", "com.example.archive"),
+    )
+    .unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(USER_SOURCE);
+
+    let error =
+      run(project.path(), &b64_source, &user_path, "profile", "user", &field_config(None))
+        .err()
+        .expect("Ambiguous entity name should be rejected");
+    assert!(error.contains("found in packages: com.example.archive, com.example.users"));
+
+    let mut config = field_config(None);
+    config.inverse_field_package = Some("com.example.archive".to_string());
+    run(project.path(), &b64_source, &user_path, "profile", "user", &config)
+      .expect("Relationship creation should succeed");
+    let user_source = fs::read_to_string(&user_path).unwrap();
+    assert!(user_source.contains("import com.example.archive.Profile;"));
+    assert!(fs::read_to_string(&archived_profile_path).unwrap().contains("mappedBy = \"profile\""));
+    assert_eq!(fs::read_to_string(&profile_path).unwrap(), PROFILE_SOURCE);
+  }
+}
@@ -0,0 +1,190 @@
+// Create JPA Repository Service Integration Tests
+// This module contains tests for generating Spring Data repositories typed by the entity's id
+
+mod common;
+
+use common::{encode, setup_domain_file};
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_repository_service::run;
+
+fn read_repository(entity_path: &std::path::Path, entity_name: &str) -> String {
+  fs::read_to_string(entity_path.with_file_name(format!("{}Repository.java", entity_name))).unwrap()
+}
+
+#[test]
+fn test_uses_long_id_type() {
+  let source = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+
+@Entity
+public class Order {
+  @Id
+  private Long id;
+}
+"#;
+  let (project, entity_path) = setup_domain_file("Order.java", source);
+  let response = run(project.path(), &encode(source), &entity_path, None).unwrap();
+  assert!(response.id_field_found);
+  assert!(response.warnings.is_empty());
+  let repository = read_repository(&entity_path, "Order");
+  assert!(repository.contains("extends JpaRepository<Order, Long>"));
+  assert!(repository.contains("import org.springframework.data.jpa.repository.JpaRepository;"));
+}
+
+#[test]
+fn test_uses_uuid_id_type_with_import() {
+  let source = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import java.util.UUID;
+
+@Entity
+public class Customer {
+  @Id
+  private UUID id;
+}
+"#;
+  let (project, entity_path) = setup_domain_file("Customer.java", source);
+  let response = run(project.path(), &encode(source), &entity_path, None).unwrap();
+  assert!(response.id_field_found);
+  let repository = read_repository(&entity_path, "Customer");
+  assert!(repository.contains("extends JpaRepository<Customer, UUID>"));
+  assert!(repository.contains("import java.util.UUID;"));
+}
+
+#[test]
+fn test_uses_embeddable_of_embedded_id() {
+  let source = r#"package com.example.domain;
+
+import com.example.domain.key.OrderLineId;
+import jakarta.persistence.EmbeddedId;
+import jakarta.persistence.Entity;
+
+@Entity
+public class OrderLine {
+  @EmbeddedId
+  private OrderLineId id;
+}
+"#;
+  let (project, entity_path) = setup_domain_file("OrderLine.java", source);
+  let response = run(project.path(), &encode(source), &entity_path, None).unwrap();
+  assert!(response.id_field_found);
+  let repository = read_repository(&entity_path, "OrderLine");
+  assert!(repository.contains("extends JpaRepository<OrderLine, OrderLineId>"));
+  assert!(repository.contains("import com.example.domain.key.OrderLineId;"));
+}
+
+#[test]
+fn test_same_package_embedded_id_needs_no_import() {
+  let source = r#"package com.example.domain;
+
+import jakarta.persistence.EmbeddedId;
+import jakarta.persistence.Entity;
+
+@Entity
+public class OrderLine {
+  @EmbeddedId
+  private OrderLineId id;
+}
+"#;
+  let (project, entity_path) = setup_domain_file("OrderLine.java", source);
+  run(project.path(), &encode(source), &entity_path, None).unwrap();
+  let repository = read_repository(&entity_path, "OrderLine");
+  assert!(repository.contains("extends JpaRepository<OrderLine, OrderLineId>"));
+  assert!(!repository.contains("import com.example.domain.OrderLineId;"));
+}
+
+#[test]
+fn test_falls_back_to_long_with_warning() {
+  let source = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Draft {
+  private String title;
+}
+"#;
+  let (project, entity_path) = setup_domain_file("Draft.java", source);
+  let response = run(project.path(), &encode(source), &entity_path, None).unwrap();
+  assert!(response.id_field_found);
+  assert_eq!(
+    response.warnings,
+    vec!["No @Id or @EmbeddedId field found for Draft; using Long as the repository id type"]
+  );
+  let repository = read_repository(&entity_path, "Draft");
+  assert!(repository.contains("extends JpaRepository<Draft, Long>"));
+}
+
+mod superclass_id_tests {
+  use super::*;
+
+  const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import com.example.common.BaseEntity;
+import jakarta.persistence.Entity;
+import java.util.UUID;
+
+@Entity
+public class Invoice extends BaseEntity<UUID> {
+  private String number;
+}
+"#;
+
+  #[test]
+  fn test_asks_for_superclass_source_when_not_given() {
+    let (project, entity_path) = setup_domain_file("Invoice.java", ENTITY_SOURCE);
+    let response = run(project.path(), &encode(ENTITY_SOURCE), &entity_path, None).unwrap();
+    assert!(!response.id_field_found);
+    assert_eq!(response.superclass_type.as_deref(), Some("BaseEntity"));
+    assert!(response.repository.is_none());
+  }
+
+  #[test]
+  fn test_uses_id_inherited_from_mapped_superclass() {
+    let superclass_source = r#"package com.example.common;
+
+import jakarta.persistence.Id;
+import jakarta.persistence.MappedSuperclass;
+
+@MappedSuperclass
+public abstract class BaseEntity {
+  @Id
+  private Long id;
+}
+"#;
+    let (project, entity_path) = setup_domain_file("Invoice.java", ENTITY_SOURCE);
+    let response =
+      run(project.path(), &encode(ENTITY_SOURCE), &entity_path, Some(&encode(superclass_source)))
+        .unwrap();
+    assert!(response.id_field_found);
+    let repository = read_repository(&entity_path, "Invoice");
+    assert!(repository.contains("extends JpaRepository<Invoice, Long>"));
+  }
+
+  #[test]
+  fn test_resolves_generic_id_from_type_argument() {
+    let superclass_source = r#"package com.example.common;
+
+import jakarta.persistence.Id;
+import jakarta.persistence.MappedSuperclass;
+
+@MappedSuperclass
+public abstract class BaseEntity<ID> {
+  @Id
+  private ID id;
+}
+"#;
+    let (project, entity_path) = setup_domain_file("Invoice.java", ENTITY_SOURCE);
+    let response =
+      run(project.path(), &encode(ENTITY_SOURCE), &entity_path, Some(&encode(superclass_source)))
+        .unwrap();
+    assert!(response.id_field_found);
+    let repository = read_repository(&entity_path, "Invoice");
+    assert!(repository.contains("extends JpaRepository<Invoice, UUID>"));
+    assert!(repository.contains("import java.util.UUID;"));
+  }
+}
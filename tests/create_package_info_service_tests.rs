@@ -0,0 +1,95 @@
+// Create Package Info Service Integration Tests
+// This module contains tests for creating package-info.java files with Javadoc and package-level
+// annotations
+
+mod common;
+
+use common::{setup_project, source_path};
+use std::fs;
+use syntaxpresso_core::commands::services::create_package_info_service::run;
+use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use tempfile::TempDir;
+
+const EXISTING_SOURCE: &str = "/** Existing docs. */\npackage com.example;\n";
+
+fn create_package_info(
+  project: &TempDir,
+  javadoc: Option<&str>,
+  annotations: &[&str],
+  force: bool,
+) -> Result<String, String> {
+  let annotations: Vec<String> = annotations.iter().map(|a| a.to_string()).collect();
+  run(project.path(), "com.example", javadoc, &annotations, &JavaSourceDirectoryType::Main, force)
+    .map(|response| response.file_path)
+}
+
+#[test]
+fn test_creates_package_info_with_annotations() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let file_path = create_package_info(
+    &project,
+    None,
+    &["org.springframework.lang.NonNullApi", "@Deprecated"],
+    false,
+  )
+  .expect("Package info creation should succeed");
+  assert!(file_path.ends_with("src/main/java/com/example/package-info.java"));
+  assert_eq!(
+    fs::read_to_string(&file_path).unwrap(),
+    "@NonNullApi\n@Deprecated\npackage com.example;\n\nimport org.springframework.lang.NonNullApi;\n"
+  );
+}
+
+#[test]
+fn test_existing_file_is_rejected_without_force() {
+  let project = setup_project(&[("com/example/package-info.java", EXISTING_SOURCE)]);
+  let file_path = source_path(&project, "com/example/package-info.java");
+  let error = create_package_info(&project, Some("New docs."), &[], false)
+    .expect_err("Existing file should be rejected");
+  assert!(error.starts_with("File already exists: "));
+  assert_eq!(fs::read_to_string(&file_path).unwrap(), EXISTING_SOURCE);
+}
+
+#[test]
+fn test_existing_file_is_overwritten_with_force() {
+  let project = setup_project(&[("com/example/package-info.java", EXISTING_SOURCE)]);
+  let file_path = source_path(&project, "com/example/package-info.java");
+  create_package_info(&project, Some("New docs."), &[], true)
+    .expect("Forced creation should succeed");
+  assert_eq!(
+    fs::read_to_string(&file_path).unwrap(),
+    "/**\n * New docs.\n */\npackage com.example;\n"
+  );
+}
+
+#[test]
+fn test_javadoc_lines_are_written_as_a_comment_block() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let file_path =
+    create_package_info(&project, Some("Domain model.\n\nSee the wiki."), &[], false).unwrap();
+  assert_eq!(
+    fs::read_to_string(&file_path).unwrap(),
+    "/**\n * Domain model.\n *\n * See the wiki.\n */\npackage com.example;\n"
+  );
+}
+
+#[test]
+fn test_javadoc_comment_terminator_is_escaped() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let file_path =
+    create_package_info(&project, Some("Matches a/*/b paths. */ class Oops {"), &[], false)
+      .unwrap();
+  let source = fs::read_to_string(&file_path).unwrap();
+  assert_eq!(
+    source,
+    "/**\n * Matches a/*&#47;b paths. *&#47; class Oops {\n */\npackage com.example;\n"
+  );
+  assert_eq!(source.matches("*/").count(), 1);
+}
+
+#[test]
+fn test_rejects_invalid_annotation() {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  let error = create_package_info(&project, None, &["org.not-valid.Api"], false).unwrap_err();
+  assert_eq!(error, "Invalid package annotation: @org.not-valid.Api");
+}
@@ -0,0 +1,107 @@
+// Create Spring Rest Controller Service Integration Tests
+// This module contains tests for generating Spring Web REST controllers for JPA entities
+
+mod common;
+
+use common::setup_domain_project;
+use std::fs;
+use syntaxpresso_core::commands::services::create_spring_rest_controller_service::run;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import java.util.UUID;
+
+@Entity
+public class Customer {
+  @Id
+  private UUID id;
+}
+"#;
+
+#[test]
+fn test_generates_controller_with_entity_id_type() {
+  let project = setup_domain_project(&[("Customer.java", ENTITY_SOURCE)]);
+  let response = run(
+    project.path(),
+    "com.example.web",
+    "CustomerController",
+    "api/customers/",
+    "com.example.domain.Customer",
+    "com.example.service.CustomerService",
+    None,
+  )
+  .unwrap();
+  assert_eq!(response.file_type, "CustomerController");
+  assert_eq!(response.file_package_name, "com.example.web");
+  let source = fs::read_to_string(&response.file_path).unwrap();
+  assert!(source.contains("@RestController\n@RequestMapping(\"/api/customers\")"));
+  assert!(source.contains("import com.example.domain.Customer;"));
+  assert!(source.contains("import com.example.service.CustomerService;"));
+  assert!(source.contains("import java.util.UUID;"));
+  assert!(source.contains("import org.springframework.http.ResponseEntity;"));
+  assert!(source.contains("public CustomerController(CustomerService customerService) {"));
+  assert!(source.contains("public ResponseEntity<List<Customer>> findAll() {"));
+  assert!(source.contains(
+    "@GetMapping(\"/{id}\")\n  public ResponseEntity<Customer> findById(@PathVariable UUID id) {"
+  ));
+  assert!(
+    source.contains("public ResponseEntity<Customer> create(@RequestBody Customer customer) {")
+  );
+  assert!(source.contains("public ResponseEntity<Void> delete(@PathVariable UUID id) {"));
+}
+
+#[test]
+fn test_explicit_id_type_skips_java_lang_import() {
+  let project = TempDir::new().unwrap();
+  let response = run(
+    project.path(),
+    "com.example.web",
+    "OrderController",
+    "/orders",
+    "com.example.web.Order",
+    "com.example.web.OrderService",
+    Some("java.lang.Long"),
+  )
+  .unwrap();
+  let source = fs::read_to_string(&response.file_path).unwrap();
+  assert!(source.contains("findById(@PathVariable Long id)"));
+  assert!(!source.contains("import java.lang.Long;"));
+  assert!(!source.contains("import com.example.web.Order;"));
+}
+
+#[test]
+fn test_unknown_entity_without_id_type_is_an_error() {
+  let project = TempDir::new().unwrap();
+  let result = run(
+    project.path(),
+    "com.example.web",
+    "OrderController",
+    "/orders",
+    "com.example.domain.Order",
+    "com.example.service.OrderService",
+    None,
+  );
+  assert!(result.is_err());
+}
+
+#[test]
+fn test_refuses_to_overwrite_existing_controller() {
+  let project = setup_domain_project(&[("Customer.java", ENTITY_SOURCE)]);
+  let create = || {
+    run(
+      project.path(),
+      "com.example.web",
+      "CustomerController",
+      "/customers",
+      "com.example.domain.Customer",
+      "com.example.service.CustomerService",
+      None,
+    )
+  };
+  assert!(create().is_ok());
+  let error = create().err().expect("Second run should fail");
+  assert!(error.starts_with("File already exists"));
+}
@@ -0,0 +1,98 @@
+// Delete Java File Service Integration Tests
+// This module contains tests for deleting Java files of a project, with the path checks and the
+// optional usage check refusing to delete referenced types
+
+mod common;
+
+use common::{domain_path, setup_domain_project};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::delete_java_file_service::run;
+use tempfile::TempDir;
+
+const CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Customer {
+}
+"#;
+
+const ORDER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+  private Customer customer;
+}
+"#;
+
+/// Creates a project where `Order` references `Customer`, returning the project and the path of
+/// `Customer.java`
+fn setup_customer_and_order() -> (TempDir, PathBuf) {
+  let project =
+    setup_domain_project(&[("Customer.java", CUSTOMER_SOURCE), ("Order.java", ORDER_SOURCE)]);
+  let customer_path = domain_path(&project, "Customer.java");
+  (project, customer_path)
+}
+
+#[test]
+fn test_deletes_java_file() {
+  let (project, customer_path) = setup_customer_and_order();
+  let response = run(project.path(), &customer_path, false).unwrap();
+  assert!(response.deleted);
+  assert!(response.file_path.ends_with("com/example/domain/Customer.java"));
+  assert!(response.referencing_files.is_empty());
+  assert!(!customer_path.exists());
+}
+
+#[test]
+fn test_check_usages_refuses_to_delete_referenced_type() {
+  let (project, customer_path) = setup_customer_and_order();
+  let response = run(project.path(), &customer_path, true).unwrap();
+  assert!(!response.deleted);
+  assert_eq!(response.referencing_files.len(), 1);
+  assert!(response.referencing_files[0].ends_with("com/example/domain/Order.java"));
+  assert!(customer_path.exists());
+}
+
+#[test]
+fn test_check_usages_deletes_unreferenced_type() {
+  let (project, customer_path) = setup_customer_and_order();
+  let order_path = customer_path.with_file_name("Order.java");
+  let response = run(project.path(), &order_path, true).unwrap();
+  assert!(response.deleted);
+  assert!(!order_path.exists());
+  assert!(customer_path.exists());
+}
+
+#[test]
+fn test_rejects_non_java_file() {
+  let (project, _) = setup_customer_and_order();
+  let readme_path = project.path().join("README.md");
+  fs::write(&readme_path, "# Shop").unwrap();
+  let error = run(project.path(), &readme_path, false).err().expect("Should be rejected");
+  assert!(error.starts_with("Not a Java file:"));
+  assert!(readme_path.exists());
+}
+
+#[test]
+fn test_rejects_file_outside_project() {
+  let (project, _) = setup_customer_and_order();
+  let outside_dir = TempDir::new().expect("Failed to create temp directory");
+  let outside_path = outside_dir.path().join("Outside.java");
+  fs::write(&outside_path, CUSTOMER_SOURCE).unwrap();
+  let error = run(project.path(), &outside_path, false).err().expect("Should be rejected");
+  assert!(error.contains("outside allowed directory"));
+  assert!(outside_path.exists());
+}
+
+#[test]
+fn test_rejects_missing_file() {
+  let (project, customer_path) = setup_customer_and_order();
+  let missing_path = customer_path.with_file_name("Invoice.java");
+  let error = run(project.path(), &missing_path, false).err().expect("Should be rejected");
+  assert!(error.starts_with("File not found:"));
+}
@@ -0,0 +1,55 @@
+// Dump AST Service Integration Tests
+// This module contains tests for dumping the Tree-sitter tree of a source for debugging
+
+mod common;
+
+use common::encode;
+use syntaxpresso_core::commands::services::dump_ast_service::run;
+
+const SOURCE: &str = "package com.example;\n\npublic class User {\n  private Long id;\n}\n";
+
+#[test]
+fn test_dump_contains_class_declaration() {
+  let response = run(&encode(SOURCE), false).unwrap();
+  assert!(response.s_expression.contains("class_declaration"));
+  assert!(!response.has_errors);
+  assert_eq!(response.root.kind, "program");
+  assert_eq!((response.root.start_byte, response.root.end_byte), (0, SOURCE.len()));
+  let class_node =
+    response.root.children.iter().find(|child| child.kind == "class_declaration").unwrap();
+  let name_node =
+    class_node.children.iter().find(|child| child.field_name.as_deref() == Some("name")).unwrap();
+  assert_eq!(name_node.kind, "identifier");
+  assert_eq!(&SOURCE[name_node.start_byte..name_node.end_byte], "User");
+  assert!(name_node.text.is_none());
+}
+
+#[test]
+fn test_include_text_adds_node_text() {
+  let response = run(&encode(SOURCE), true).unwrap();
+  assert_eq!(response.root.text.as_deref(), Some(SOURCE));
+  let package_node = &response.root.children[0];
+  assert_eq!(package_node.kind, "package_declaration");
+  assert_eq!(package_node.text.as_deref(), Some("package com.example;"));
+}
+
+#[test]
+fn test_json_omits_text_and_field_name_when_absent() {
+  let response = run(&encode(SOURCE), false).unwrap();
+  let json = serde_json::to_value(&response).unwrap();
+  assert!(json["root"].get("text").is_none());
+  assert!(json["root"].get("fieldName").is_none());
+  assert!(json["sExpression"].as_str().unwrap().starts_with("(program"));
+}
+
+#[test]
+fn test_syntax_errors_are_reported() {
+  let response = run(&encode("public class User {\n  private Long id\n}\n"), false).unwrap();
+  assert!(response.has_errors);
+  assert!(response.s_expression.contains("MISSING"));
+}
+
+#[test]
+fn test_invalid_base64_is_rejected() {
+  assert!(run("not base64!", false).is_err());
+}
@@ -0,0 +1,379 @@
+// Duplicate Field Integration Tests
+// This module contains tests for rejecting fields whose name is already declared in the entity
+
+mod common;
+
+use base64::Engine;
+use common::{domain_path, setup_domain_project};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syntaxpresso_core::commands::services::{
+  create_jpa_entity_basic_field_service, create_jpa_entity_enum_field_service,
+  create_jpa_entity_id_field_service, create_jpa_many_to_one_relationship_service,
+  create_jpa_one_to_one_relationship_service,
+};
+use syntaxpresso_core::common::services::class_declaration_service::get_public_class_node;
+use syntaxpresso_core::common::services::field_declaration_service::ensure_field_name_available;
+use syntaxpresso_core::common::ts_file::TSFile;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::common::types::{
+  basic_field_config::BasicFieldConfig, collection_type::CollectionType,
+  column_naming_strategy::ColumnNamingStrategy, enum_field_config::EnumFieldConfig,
+  fetch_type::FetchType, field_access::FieldAccess, id_field_config::IdFieldConfig,
+  java_enum_type::JavaEnumType, java_id_generation::JavaIdGeneration,
+  java_id_generation_type::JavaIdGenerationType, many_to_one_field_config::ManyToOneFieldConfig,
+  mapping_type::MappingType, one_to_one_field_config::OneToOneFieldConfig,
+};
+use tempfile::TempDir;
+
+const ORDER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+}
+"#;
+
+const CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import java.util.List;
+
+@Entity
+public class Customer {
+  private List<Order> orders;
+}
+"#;
+
+/// Creates a project with the `Order` and `Customer` entities
+fn setup_order_and_customer() -> (TempDir, PathBuf, PathBuf) {
+  let project =
+    setup_domain_project(&[("Order.java", ORDER_SOURCE), ("Customer.java", CUSTOMER_SOURCE)]);
+  let order_path = domain_path(&project, "Order.java");
+  let customer_path = domain_path(&project, "Customer.java");
+  (project, order_path, customer_path)
+}
+
+fn read_encoded(path: &Path) -> String {
+  base64::engine::general_purpose::STANDARD.encode(fs::read_to_string(path).unwrap())
+}
+
+fn basic_field_config() -> BasicFieldConfig {
+  BasicFieldConfig {
+    field_name: "code".to_string(),
+    field_type: "String".to_string(),
+    field_type_package_name: Some("java.lang".to_string()),
+    field_length: None,
+    field_precision: None,
+    field_scale: None,
+    field_temporal: None,
+    field_timezone_storage: None,
+    field_unique: false,
+    field_nullable: true,
+    field_large_object: false,
+    basic_fetch: None,
+    field_natural_id: false,
+    field_converter_class: None,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    explicit_column_name: false,
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    default_value: None,
+    column_definition: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+fn enum_field_config() -> EnumFieldConfig {
+  EnumFieldConfig {
+    field_name: "status".to_string(),
+    enum_type: "OrderStatus".to_string(),
+    enum_package_name: "com.example.domain".to_string(),
+    enum_type_storage: JavaEnumType::String,
+    field_length: None,
+    field_nullable: true,
+    field_unique: false,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    explicit_column_name: false,
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    default_value: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+fn id_field_config() -> IdFieldConfig {
+  IdFieldConfig {
+    field_name: "id".to_string(),
+    field_type: "Long".to_string(),
+    field_type_package_name: Some("java.lang".to_string()),
+    field_id_generation: JavaIdGeneration::Identity,
+    field_id_generation_type: JavaIdGenerationType::None,
+    field_generator_name: None,
+    field_sequence_name: None,
+    field_initial_value: None,
+    field_allocation_size: None,
+    field_nullable: false,
+    column_naming_strategy: ColumnNamingStrategy::default(),
+    field_access: FieldAccess::default(),
+    after_field: None,
+    at_top: false,
+    javadoc: None,
+    mark_generated: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+fn many_to_one_config() -> ManyToOneFieldConfig {
+  ManyToOneFieldConfig {
+    inverse_field_type: "Customer".to_string(),
+    inverse_field_package: None,
+    fetch_type: FetchType::Lazy,
+    collection_type: CollectionType::List,
+    declared_collection_type: None,
+    mapping_type: None,
+    owning_side_cascades: vec![],
+    inverse_side_cascades: vec![],
+    owning_side_other: vec![],
+    inverse_side_other: vec![],
+    join_column_name: None,
+    referenced_column_name: None,
+    maps_id: None,
+    initialize_collection: true,
+    order_by: None,
+    order_column: None,
+    persistence_namespace: PersistenceNamespace::default(),
+    format_java: false,
+  }
+}
+
+mod field_generator_tests {
+  use super::*;
+
+  #[test]
+  fn test_basic_field_added_twice_errors_without_writing() {
+    let (_project, order_path, _) = setup_order_and_customer();
+    let config = basic_field_config();
+    create_jpa_entity_basic_field_service::run(&read_encoded(&order_path), &order_path, &config)
+      .expect("First field creation should succeed");
+    let after_first = fs::read_to_string(&order_path).unwrap();
+    let result =
+      create_jpa_entity_basic_field_service::run(&read_encoded(&order_path), &order_path, &config);
+    assert_eq!(result.err(), Some("field 'code' already exists".to_string()));
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), after_first);
+  }
+
+  #[test]
+  fn test_enum_field_added_twice_errors_without_writing() {
+    let (project, order_path, _) = setup_order_and_customer();
+    create_jpa_entity_enum_field_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      enum_field_config(),
+    )
+    .expect("First field creation should succeed");
+    let after_first = fs::read_to_string(&order_path).unwrap();
+    let result = create_jpa_entity_enum_field_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      enum_field_config(),
+    );
+    assert_eq!(result.err(), Some("field 'status' already exists".to_string()));
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), after_first);
+  }
+
+  #[test]
+  fn test_id_field_added_twice_errors_without_writing() {
+    let (project, order_path, _) = setup_order_and_customer();
+    create_jpa_entity_id_field_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      id_field_config(),
+    )
+    .expect("First field creation should succeed");
+    let after_first = fs::read_to_string(&order_path).unwrap();
+    let result = create_jpa_entity_id_field_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      id_field_config(),
+    );
+    assert_eq!(result.err(), Some("field 'id' already exists".to_string()));
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), after_first);
+  }
+
+  #[test]
+  fn test_relationship_with_existing_inverse_field_writes_neither_side() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let config = many_to_one_config();
+    let result = create_jpa_many_to_one_relationship_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      "customer",
+      "orders",
+      &config,
+    );
+    assert_eq!(
+      result.err(),
+      Some(format!(
+        "field 'orders' already exists on the inverse side ({})",
+        customer_path.display()
+      ))
+    );
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), ORDER_SOURCE);
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+}
+
+mod relationship_field_name_tests {
+  use super::*;
+
+  const ORDER_WITH_CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+  private String customer;
+}
+"#;
+
+  fn one_to_one_config() -> OneToOneFieldConfig {
+    OneToOneFieldConfig {
+      inverse_field_type: "Customer".to_string(),
+      inverse_field_package: None,
+      mapping_type: None,
+      fetch_type: None,
+      owning_side_cascades: vec![],
+      inverse_side_cascades: vec![],
+      owning_side_other: vec![],
+      inverse_side_other: vec![],
+      join_column_name: None,
+      referenced_column_name: None,
+      maps_id: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java: false,
+    }
+  }
+
+  #[test]
+  fn test_many_to_one_owning_side_collision_names_the_owning_file() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    fs::write(&order_path, ORDER_WITH_CUSTOMER_SOURCE).unwrap();
+    let result = create_jpa_many_to_one_relationship_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      "customer",
+      "purchases",
+      &many_to_one_config(),
+    );
+    assert_eq!(
+      result.err(),
+      Some(format!(
+        "field 'customer' already exists on the owning side ({})",
+        order_path.display()
+      ))
+    );
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_one_to_one_owning_side_collision_names_the_owning_file() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    fs::write(&order_path, ORDER_WITH_CUSTOMER_SOURCE).unwrap();
+    let result = create_jpa_one_to_one_relationship_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      "customer",
+      "order",
+      &one_to_one_config(),
+    );
+    assert_eq!(
+      result.err(),
+      Some(format!(
+        "field 'customer' already exists on the owning side ({})",
+        order_path.display()
+      ))
+    );
+    assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_one_to_one_inverse_side_collision_names_the_inverse_file() {
+    let (project, order_path, customer_path) = setup_order_and_customer();
+    let result = create_jpa_one_to_one_relationship_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      "customer",
+      "orders",
+      &one_to_one_config(),
+    );
+    assert_eq!(
+      result.err(),
+      Some(format!(
+        "field 'orders' already exists on the inverse side ({})",
+        customer_path.display()
+      ))
+    );
+    assert_eq!(fs::read_to_string(&order_path).unwrap(), ORDER_SOURCE);
+  }
+
+  #[test]
+  fn test_unidirectional_one_to_one_ignores_the_inverse_fields() {
+    let (project, order_path, _customer_path) = setup_order_and_customer();
+    let config = OneToOneFieldConfig {
+      mapping_type: Some(MappingType::UnidirectionalJoinColumn),
+      ..one_to_one_config()
+    };
+    let result = create_jpa_one_to_one_relationship_service::run(
+      project.path(),
+      &read_encoded(&order_path),
+      &order_path,
+      "customer",
+      "orders",
+      &config,
+    );
+    assert!(result.is_ok());
+  }
+}
+
+mod ensure_field_name_available_tests {
+  use super::*;
+
+  fn check(source: &str, field_name: &str) -> Result<(), String> {
+    let ts_file = TSFile::from_source_code(source);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    ensure_field_name_available(&ts_file, class_start_byte, field_name)
+  }
+
+  #[test]
+  fn test_detects_every_declarator_of_a_field() {
+    let source = "public class Point {\n  private int x, y;\n}\n";
+    assert!(check(source, "x").is_err());
+    assert!(check(source, "y").is_err());
+    assert!(check(source, "z").is_ok());
+  }
+
+  #[test]
+  fn test_ignores_fields_of_nested_classes() {
+    let source = "public class Outer {\n  static class Inner {\n    private int value;\n  }\n}\n";
+    assert!(check(source, "value").is_ok());
+  }
+}
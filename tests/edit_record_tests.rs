@@ -0,0 +1,45 @@
+// Edit Record Integration Tests
+// This module contains tests for the undo metadata reported by mutating commands
+
+use syntaxpresso_core::common::ts_file::TSFile;
+use syntaxpresso_core::responses::edit_record_response::EditRecord;
+
+/// Reverses `edits` on `source`, applying the last recorded edit first
+fn undo(source: &str, edits: &[EditRecord]) -> String {
+  let mut restored = source.to_string();
+  for edit in edits.iter().rev() {
+    restored.replace_range(edit.start_byte..edit.start_byte + edit.new_text.len(), &edit.old_text);
+  }
+  restored
+}
+
+#[test]
+fn test_unchanged_file_has_no_edits() {
+  let ts_file = TSFile::from_source_code("public class User {}");
+  assert!(ts_file.edit_records().is_empty());
+}
+
+#[test]
+fn test_edits_round_trip_to_original_source() {
+  let original = "package com.example;\n\npublic class User {\n  private Long id;\n}\n";
+  let mut ts_file = TSFile::from_source_code(original);
+  ts_file.insert_text(21, "\nimport java.util.List;\n");
+  let field_start = ts_file.source_code.find("private").unwrap();
+  ts_file.replace_text_by_range(field_start, field_start + 12, "protected Integer");
+  let edits = ts_file.edit_records();
+  assert_eq!(edits.len(), 1);
+  assert_eq!(edits[0].old_text, original[edits[0].start_byte..edits[0].old_end_byte]);
+  assert_eq!(undo(&ts_file.source_code, &edits), original);
+}
+
+#[test]
+fn test_edits_stay_on_char_boundaries() {
+  let original = "public class Café { String naïve; }";
+  let mut ts_file = TSFile::from_source_code(original);
+  let start = ts_file.source_code.find("Café").unwrap();
+  ts_file.replace_text_by_range(start, start + "Café".len(), "Cafè");
+  let edits = ts_file.edit_records();
+  assert_eq!(edits[0].old_text, "é");
+  assert_eq!(edits[0].new_text, "è");
+  assert_eq!(undo(&ts_file.source_code, &edits), original);
+}
@@ -0,0 +1,86 @@
+// File Util Integration Tests
+// This module contains tests for replacing files atomically through a temporary sibling file
+
+use std::fs;
+use std::path::Path;
+use syntaxpresso_core::common::ts_file::TSFile;
+use syntaxpresso_core::common::utils::file_util::atomic_write;
+use tempfile::TempDir;
+
+/// Names of the entries of `dir`, sorted, to spot leftover temporary files
+fn dir_entries(dir: &Path) -> Vec<String> {
+  let mut names: Vec<String> = fs::read_dir(dir)
+    .unwrap()
+    .flatten()
+    .map(|entry| entry.file_name().to_string_lossy().to_string())
+    .collect();
+  names.sort();
+  names
+}
+
+#[test]
+fn test_creates_missing_file() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let path = temp_dir.path().join("User.java");
+  atomic_write(&path, "public class User {}\n").unwrap();
+  assert_eq!(fs::read_to_string(&path).unwrap(), "public class User {}\n");
+  assert_eq!(dir_entries(temp_dir.path()), vec!["User.java"]);
+}
+
+#[test]
+fn test_replaces_file_with_complete_content() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let path = temp_dir.path().join("User.java");
+  fs::write(&path, "public class User {\n  private Long id;\n}\n").unwrap();
+  let content = format!("public class User {{\n{}}}\n", "  private String name;\n".repeat(10_000));
+  atomic_write(&path, &content).unwrap();
+  assert_eq!(fs::read_to_string(&path).unwrap(), content);
+  assert_eq!(dir_entries(temp_dir.path()), vec!["User.java"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_replaces_file_instead_of_writing_in_place() {
+  use std::os::unix::fs::{MetadataExt, PermissionsExt};
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let path = temp_dir.path().join("User.java");
+  fs::write(&path, "old").unwrap();
+  fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+  let old_inode = fs::metadata(&path).unwrap().ino();
+  atomic_write(&path, "new").unwrap();
+  let metadata = fs::metadata(&path).unwrap();
+  assert_ne!(metadata.ino(), old_inode);
+  assert_eq!(metadata.permissions().mode() & 0o777, 0o640);
+  assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+}
+
+#[test]
+fn test_removes_temp_file_when_rename_fails() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  // A non-empty directory can't be replaced by a file
+  let path = temp_dir.path().join("User.java");
+  fs::create_dir_all(path.join("nested")).unwrap();
+  assert!(atomic_write(&path, "public class User {}\n").is_err());
+  assert!(path.is_dir());
+  assert_eq!(dir_entries(temp_dir.path()), vec!["User.java"]);
+}
+
+#[test]
+fn test_fails_without_parent_directory() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let path = temp_dir.path().join("missing/User.java");
+  assert!(atomic_write(&path, "public class User {}\n").is_err());
+  assert!(dir_entries(temp_dir.path()).is_empty());
+}
+
+#[test]
+fn test_ts_file_save_replaces_file_atomically() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let path = temp_dir.path().join("User.java");
+  fs::write(&path, "public class User {}\n").unwrap();
+  let mut ts_file = TSFile::from_file(&path).unwrap();
+  ts_file.update_source_code("public class User {\n  private Long id;\n}\n");
+  ts_file.save_to_existing_file(&path).unwrap();
+  assert_eq!(fs::read_to_string(&path).unwrap(), "public class User {\n  private Long id;\n}\n");
+  assert_eq!(dir_entries(temp_dir.path()), vec!["User.java"]);
+}
@@ -0,0 +1,75 @@
+// Find JPA Entity Usages Service Integration Tests
+// This module contains tests for locating references to an entity across a project
+
+mod common;
+
+use common::setup_project;
+use syntaxpresso_core::commands::services::find_jpa_entity_usages_service::run;
+
+const ENTITY: (&str, &str) = (
+  "com/example/domain/User.java",
+  "package com.example.domain;\n\n@Entity\npublic class User {\n  private User manager;\n}\n",
+);
+
+const REFERENCING_SERVICE: &str = r#"package com.example.service;
+
+import com.example.domain.User;
+
+public class UserService {
+  private User current;
+
+  public User find(User example) {
+    return example;
+  }
+}
+"#;
+
+const NON_REFERENCING_SERVICE: &str = r#"package com.example.other;
+
+import com.example.security.User;
+
+public class AuthService {
+  private User principal;
+}
+"#;
+
+#[test]
+fn test_finds_usages_in_referencing_file_only() {
+  let project = setup_project(&[
+    ENTITY,
+    ("com/example/service/UserService.java", REFERENCING_SERVICE),
+    ("com/example/other/AuthService.java", NON_REFERENCING_SERVICE),
+  ]);
+  let response = run(project.path(), "com.example.domain.User").unwrap();
+  assert_eq!(response.entity_type, "com.example.domain.User");
+  assert!(response.usages.iter().all(|u| u.file_path.ends_with("UserService.java")));
+  let kinds: Vec<_> = response.usages.iter().map(|u| u.kind.as_str()).collect();
+  assert_eq!(kinds, vec!["import", "field-type", "method-return-type", "method-parameter"]);
+  let import = &response.usages[0];
+  assert_eq!(&REFERENCING_SERVICE[import.start_byte..import.end_byte], "com.example.domain.User");
+}
+
+#[test]
+fn test_same_package_and_fully_qualified_references() {
+  let repository = "package com.example.domain;\n\npublic interface UserRepository extends JpaRepository<User, Long> {}\n";
+  let audit = "package com.example.audit;\n\npublic class Audit {\n  private com.example.domain.User author;\n}\n";
+  let project = setup_project(&[
+    ENTITY,
+    ("com/example/domain/UserRepository.java", repository),
+    ("com/example/audit/Audit.java", audit),
+  ]);
+  let response = run(project.path(), "com.example.domain.User").unwrap();
+  assert_eq!(response.usages.len(), 2);
+  let audit_usage = &response.usages[0];
+  assert!(audit_usage.file_path.ends_with("Audit.java"));
+  assert_eq!(&audit[audit_usage.start_byte..audit_usage.end_byte], "com.example.domain.User");
+  let repository_usage = &response.usages[1];
+  assert!(repository_usage.file_path.ends_with("UserRepository.java"));
+  assert_eq!(repository_usage.kind, "type-reference");
+}
+
+#[test]
+fn test_rejects_unqualified_entity_name() {
+  let project = setup_project(&[ENTITY]);
+  assert!(run(project.path(), "User").is_err());
+}
@@ -0,0 +1,126 @@
+// Formatting Service Integration Tests
+// This module contains tests for blank line normalization of Java sources
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::create_jpa_entity_basic_field_service;
+use syntaxpresso_core::common::services::formatting_service::normalize_blank_lines;
+use syntaxpresso_core::common::ts_file::TSFile;
+use syntaxpresso_core::common::types::basic_field_config::BasicFieldConfig;
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::common::types::field_access::FieldAccess;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+/// Normalizes `source` and returns the resulting code
+fn format(source: &str) -> String {
+  let mut ts_file = TSFile::from_source_code(source);
+  normalize_blank_lines(&mut ts_file);
+  ts_file.source_code
+}
+
+mod normalize_blank_lines_tests {
+  use super::*;
+
+  #[test]
+  fn test_collapses_doubled_blank_lines_between_members() {
+    let source =
+      "public class User {\n\n\n  private Long id;\n\n\n\n  private String name;\n\n\n}\n";
+    assert_eq!(
+      format(source),
+      "public class User {\n\n  private Long id;\n\n  private String name;\n\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_separates_adjacent_members_with_one_blank_line() {
+    let source = "public class User {\n  private Long id;\n  private String name;\n  public Long getId() {\n    return id;\n  }\n}\n";
+    assert_eq!(
+      format(source),
+      "public class User {\n  private Long id;\n\n  private String name;\n\n  public Long getId() {\n    return id;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_keeps_comments_attached_and_method_bodies_untouched() {
+    let source = "public class User {\n  private Long id;\n  /** The name. */\n  private String name; // trailing\n  public void clear() {\n    id = null;\n\n\n    name = null;\n  }\n}\n";
+    assert_eq!(
+      format(source),
+      "public class User {\n  private Long id;\n\n  /** The name. */\n  private String name; // trailing\n  public void clear() {\n    id = null;\n\n\n    name = null;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_normalizes_nested_and_enum_bodies() {
+    let source = "public class Outer {\n  enum Status {\n    ACTIVE;\n\n\n    int code;\n    int rank;\n  }\n  static class Inner {\n    int a;\n\n\n\n    int b;\n  }\n}\n";
+    assert_eq!(
+      format(source),
+      "public class Outer {\n  enum Status {\n    ACTIVE;\n\n    int code;\n\n    int rank;\n  }\n\n  static class Inner {\n    int a;\n\n    int b;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_returns_false_when_already_formatted() {
+    let source = "public class User {\n  private Long id;\n\n  private String name;\n}\n";
+    let mut ts_file = TSFile::from_source_code(source);
+    assert!(!normalize_blank_lines(&mut ts_file));
+    assert_eq!(ts_file.source_code, source);
+  }
+}
+
+mod format_java_option_tests {
+  use super::*;
+
+  const ENTITY_SOURCE: &str = "package com.example.domain;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class User {\n\n\n  private Long id;\n\n\n\n  private String email;\n}\n";
+
+  fn add_field(format_java: bool) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_path = temp_dir.path().join("User.java");
+    fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+    let field_config = BasicFieldConfig {
+      field_name: "name".to_string(),
+      field_type: "String".to_string(),
+      field_type_package_name: Some("java.lang".to_string()),
+      field_length: None,
+      field_precision: None,
+      field_scale: None,
+      field_temporal: None,
+      field_timezone_storage: None,
+      field_unique: false,
+      field_nullable: true,
+      field_large_object: false,
+      basic_fetch: None,
+      field_natural_id: false,
+      field_converter_class: None,
+      column_naming_strategy: ColumnNamingStrategy::default(),
+      explicit_column_name: false,
+      field_access: FieldAccess::default(),
+      after_field: None,
+      at_top: false,
+      javadoc: None,
+      mark_generated: None,
+      default_value: None,
+      column_definition: None,
+      persistence_namespace: PersistenceNamespace::default(),
+      format_java,
+    };
+    create_jpa_entity_basic_field_service::run(&b64_source, &entity_path, &field_config)
+      .expect("Field creation should succeed");
+    fs::read_to_string(&entity_path).unwrap()
+  }
+
+  #[test]
+  fn test_format_java_removes_stray_blank_lines_after_edit() {
+    let source = add_field(true);
+    assert!(!source.contains("\n\n\n"), "unexpected doubled blank line in:\n{}", source);
+    assert!(source.contains("  private Long id;\n\n  private String email;"));
+    assert!(source.contains("  private String name;"));
+  }
+
+  #[test]
+  fn test_without_format_java_blank_lines_are_kept() {
+    let source = add_field(false);
+    assert!(source.contains("\n\n\n"));
+  }
+}
@@ -0,0 +1,106 @@
+// Generate Accessors Service Integration Tests
+// This module contains tests for generating getters and standard or fluent setters
+
+mod common;
+
+use common::{encode, setup_domain_file};
+use std::fs;
+use syntaxpresso_core::commands::services::generate_accessors_service::run;
+use syntaxpresso_core::common::types::fluent_setter_naming::FluentSetterNaming;
+
+const USER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+
+  private static final long serialVersionUID = 1L;
+
+  private final Long id;
+
+  private String name;
+
+  private boolean active;
+}
+"#;
+
+#[test]
+fn test_generates_getters_and_void_setters_by_default() {
+  let (_project, user_path) = setup_domain_file("User.java", USER_SOURCE);
+  let response =
+    run(&encode(USER_SOURCE), &user_path, &[], false, &FluentSetterNaming::default()).unwrap();
+  assert_eq!(
+    response.generated_method_names,
+    vec!["getId", "getName", "setName", "isActive", "setActive"]
+  );
+  let content = fs::read_to_string(&user_path).unwrap();
+  assert!(
+    content
+      .contains("  private boolean active;\n\n  public Long getId() {\n    return id;\n  }\n\n")
+  );
+  assert!(content.contains("  public void setName(String name) {\n    this.name = name;\n  }"));
+  assert!(content.contains("  public boolean isActive() {\n    return active;\n  }"));
+  assert!(!content.contains("setId"));
+  assert!(!content.contains("getSerialVersion"));
+}
+
+#[test]
+fn test_keeps_existing_accessors_and_rejects_unknown_fields() {
+  let source = r#"package com.example.domain;
+
+public class User {
+
+  private String name;
+
+  public String getName() {
+    return name.trim();
+  }
+}
+"#;
+  let (_project, user_path) = setup_domain_file("User.java", source);
+  let response =
+    run(&encode(source), &user_path, &["name".to_string()], false, &FluentSetterNaming::default())
+      .unwrap();
+  assert_eq!(response.generated_method_names, vec!["setName"]);
+  assert_eq!(fs::read_to_string(&user_path).unwrap().matches("getName").count(), 1);
+  let error =
+    run(&encode(source), &user_path, &["email".to_string()], false, &FluentSetterNaming::default())
+      .err()
+      .expect("Expected error");
+  assert_eq!(error, "Field 'email' not found in User");
+}
+
+mod fluent_accessors_tests {
+  use super::*;
+
+  #[test]
+  fn test_fluent_setters_return_this_and_are_named_after_the_field() {
+    let (_project, user_path) = setup_domain_file("User.java", USER_SOURCE);
+    let response = run(
+      &encode(USER_SOURCE),
+      &user_path,
+      &["name".to_string()],
+      true,
+      &FluentSetterNaming::Field,
+    )
+    .unwrap();
+    assert_eq!(response.generated_method_names, vec!["getName", "name"]);
+    let content = fs::read_to_string(&user_path).unwrap();
+    assert!(content.contains(
+      "  public User name(String name) {\n    this.name = name;\n    return this;\n  }\n}"
+    ));
+  }
+
+  #[test]
+  fn test_fluent_setters_can_keep_the_set_prefix() {
+    let source =
+      "package com.example.domain;\n\npublic class Page<T> {\n\n  private T content;\n}\n";
+    let (_project, user_path) = setup_domain_file("User.java", source);
+    run(&encode(source), &user_path, &[], true, &FluentSetterNaming::Set).unwrap();
+    let content = fs::read_to_string(&user_path).unwrap();
+    assert!(content.contains(
+      "  public Page<T> setContent(T content) {\n    this.content = content;\n    return this;\n  }"
+    ));
+  }
+}
@@ -0,0 +1,93 @@
+// Generate Constructors Service Integration Tests
+// This module contains tests for generating no-args and all-args constructors
+
+mod common;
+
+use common::{encode, setup_domain_file};
+use std::fs;
+use syntaxpresso_core::commands::services::generate_constructors_service::run;
+
+const USER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Transient;
+import java.time.LocalDate;
+
+@Entity
+public class User {
+
+  private static final long serialVersionUID = 1L;
+
+  private String name;
+
+  private LocalDate birthDate;
+
+  @Transient
+  private int age;
+
+  public String getName() {
+    return name;
+  }
+}
+"#;
+
+#[test]
+fn test_generates_no_args_and_all_args_constructors_after_the_fields() {
+  let (_project, user_path) = setup_domain_file("User.java", USER_SOURCE);
+  let response = run(&encode(USER_SOURCE), &user_path, true, true).unwrap();
+  assert_eq!(response.generated_constructors, vec!["User()", "User(String, LocalDate)"]);
+  let content = fs::read_to_string(&user_path).unwrap();
+  assert!(content.contains(
+    "  private int age;\n\n  protected User() {\n  }\n\n  public User(String name, LocalDate birthDate) {\n    this.name = name;\n    this.birthDate = birthDate;\n  }\n\n  public String getName() {"
+  ));
+  assert!(!content.contains("this.age"));
+  assert!(!content.contains("this.serialVersionUID"));
+}
+
+#[test]
+fn test_keeps_existing_constructors() {
+  let source = r#"package com.example.domain;
+
+public class User {
+
+  private String name;
+
+  public User() {
+    this.name = "anonymous";
+  }
+}
+"#;
+  let (_project, user_path) = setup_domain_file("User.java", source);
+  let response = run(&encode(source), &user_path, true, true).unwrap();
+  assert_eq!(response.generated_constructors, vec!["User(String)"]);
+  let content = fs::read_to_string(&user_path).unwrap();
+  assert_eq!(content.matches("User() {").count(), 1);
+  assert!(content.contains("  private String name;\n\n  public User(String name) {\n"));
+  let response = run(&encode(&content), &user_path, true, true).unwrap();
+  assert!(response.generated_constructors.is_empty());
+}
+
+#[test]
+fn test_no_args_constructor_is_rejected_with_uninitialized_final_fields() {
+  let source =
+    "package com.example.domain;\n\npublic class User {\n\n  private final Long id;\n}\n";
+  let (_project, user_path) = setup_domain_file("User.java", source);
+  let error = run(&encode(source), &user_path, true, false).err().expect("Expected error");
+  assert_eq!(
+    error,
+    "Cannot generate a no-args constructor for User: final field 'id' is not initialized"
+  );
+  let response = run(&encode(source), &user_path, false, true).unwrap();
+  assert_eq!(response.generated_constructors, vec!["User(Long)"]);
+}
+
+#[test]
+fn test_generates_into_an_empty_class() {
+  let source = "package com.example.domain;\n\npublic class User {}\n";
+  let (_project, user_path) = setup_domain_file("User.java", source);
+  run(&encode(source), &user_path, true, false).unwrap();
+  assert_eq!(
+    fs::read_to_string(&user_path).unwrap(),
+    "package com.example.domain;\n\npublic class User {\n  protected User() {\n  }\n}\n"
+  );
+}
@@ -0,0 +1,71 @@
+// Get All JPA Embeddables Service Integration Tests
+// This module contains tests for listing @Embeddable classes usable as @Embedded field types
+
+mod common;
+
+use common::setup_domain_project;
+use syntaxpresso_core::commands::services::get_all_jpa_embeddables_service::run;
+
+const ADDRESS_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.Transient;
+
+@Embeddable
+public class Address {
+  private static final long serialVersionUID = 1L;
+
+  private String street;
+
+  private String city, zipCode;
+
+  @Transient
+  private String formatted;
+
+  private transient int hash;
+
+  public static class Geo {
+    private double latitude;
+  }
+}
+"#;
+
+const MONEY_SOURCE: &str = r#"package com.example.domain;
+
+public class Money {
+  private long amount;
+}
+"#;
+
+#[test]
+fn test_only_embeddables_are_returned() {
+  let project =
+    setup_domain_project(&[("Address.java", ADDRESS_SOURCE), ("Money.java", MONEY_SOURCE)]);
+  let embeddables = run(project.path(), None).unwrap();
+
+  assert_eq!(embeddables.len(), 1);
+  assert_eq!(embeddables[0].embeddable_type, "Address");
+  assert_eq!(embeddables[0].embeddable_package_name, "com.example.domain");
+  assert!(embeddables[0].file_path.ends_with("Address.java"));
+  assert!(embeddables[0].module.is_none());
+}
+
+#[test]
+fn test_lists_persistent_fields_only() {
+  let project = setup_domain_project(&[("Address.java", ADDRESS_SOURCE)]);
+  let embeddables = run(project.path(), None).unwrap();
+
+  let fields: Vec<(&str, &str)> = embeddables[0]
+    .fields
+    .iter()
+    .map(|field| (field.name.as_str(), field.field_type.as_str()))
+    .collect();
+  assert_eq!(fields, vec![("street", "String"), ("city", "String"), ("zipCode", "String")]);
+}
+
+#[test]
+fn test_entities_are_not_embeddables() {
+  let customer = "package com.example.domain;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Customer {\n  private Address address;\n}\n";
+  let project = setup_domain_project(&[("Customer.java", customer)]);
+  assert!(run(project.path(), None).unwrap().is_empty());
+}
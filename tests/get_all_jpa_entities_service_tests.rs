@@ -0,0 +1,151 @@
+// Get All JPA Entities Service Integration Tests
+// This module contains tests for listing JPA entities, both buffered and streamed as NDJSON
+
+mod common;
+
+use common::setup_domain_project;
+use std::io::{self, Write};
+use syntaxpresso_core::commands::get_all_jpa_entities_command::execute_stream;
+use syntaxpresso_core::commands::services::get_all_jpa_entities_service::{for_each_entity, run};
+use syntaxpresso_core::commands::services::{get_java_files_service, get_jpa_entity_info_service};
+use syntaxpresso_core::common::types::java_file_type::JavaFileType;
+use tempfile::TempDir;
+
+fn entity_source(name: &str) -> String {
+  format!(
+    "package com.example.domain;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class {} {{\n}}\n",
+    name
+  )
+}
+
+fn sample_project() -> TempDir {
+  setup_domain_project(&[
+    ("User.java", &entity_source("User")),
+    ("Order.java", &entity_source("Order")),
+    ("Money.java", "package com.example.domain;\n\npublic class Money {\n}\n"),
+  ])
+}
+
+/// Writer that fails on every write, standing in for a closed pipe
+struct BrokenPipe;
+
+impl Write for BrokenPipe {
+  fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+    Err(io::Error::new(io::ErrorKind::BrokenPipe, "pipe closed"))
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+#[test]
+fn test_run_lists_only_entities() {
+  let temp_dir = sample_project();
+  let mut names: Vec<String> =
+    run(temp_dir.path(), None).unwrap().into_iter().map(|file| file.file_type).collect();
+  names.sort();
+  assert_eq!(names, vec!["Order", "User"]);
+}
+
+#[test]
+fn test_for_each_entity_stops_at_callback_error() {
+  let temp_dir = sample_project();
+  let mut seen = 0;
+  let result = for_each_entity(temp_dir.path(), None, |_| {
+    seen += 1;
+    Err("stop".to_string())
+  });
+  assert_eq!(result, Err("stop".to_string()));
+  assert_eq!(seen, 1);
+}
+
+#[test]
+fn test_stream_writes_one_json_line_per_entity() {
+  let temp_dir = sample_project();
+  let mut out = Vec::new();
+  let response = execute_stream(temp_dir.path(), None, &mut out);
+  let output = String::from_utf8(out).unwrap();
+  let mut names: Vec<String> = output
+    .lines()
+    .map(|line| {
+      let entity: serde_json::Value = serde_json::from_str(line).unwrap();
+      assert_eq!(entity["filePackageName"], "com.example.domain");
+      entity["fileType"].as_str().unwrap().to_string()
+    })
+    .collect();
+  names.sort();
+  assert_eq!(names, vec!["Order", "User"]);
+  assert!(response.is_success());
+  let summary = response.get_data().unwrap();
+  assert_eq!(summary.files_count, 2);
+  assert!(summary.files.is_empty());
+  assert!(!response.to_json().unwrap().contains('\n'));
+}
+
+#[test]
+fn test_stream_without_entities_only_yields_summary() {
+  let temp_dir = setup_domain_project(&[]);
+  let mut out = Vec::new();
+  let response = execute_stream(temp_dir.path(), None, &mut out);
+  assert!(out.is_empty());
+  assert_eq!(response.get_data().unwrap().files_count, 0);
+}
+
+#[test]
+fn test_stream_reports_write_failure_as_error_line() {
+  let temp_dir = sample_project();
+  let response = execute_stream(temp_dir.path(), None, &mut BrokenPipe);
+  assert!(response.is_error());
+  assert!(response.get_error().unwrap().contains("Unable to write entity"));
+}
+
+mod record_tests {
+  use super::*;
+  use base64::Engine;
+
+  /// Record carrying an `@Entity` nested class, which must not make the record an entity
+  const RECORD_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+public record CustomerView(Long id, String name) {
+  @Entity
+  public static class Snapshot {
+  }
+}
+"#;
+
+  #[test]
+  fn test_record_is_not_listed_as_entity() {
+    let temp_dir = setup_domain_project(&[
+      ("User.java", &entity_source("User")),
+      ("CustomerView.java", RECORD_SOURCE),
+    ]);
+    let names: Vec<String> =
+      run(temp_dir.path(), None).unwrap().into_iter().map(|file| file.file_type).collect();
+    assert_eq!(names, vec!["User"]);
+  }
+
+  #[test]
+  fn test_record_is_listed_when_filtering_records() {
+    let temp_dir = setup_domain_project(&[
+      ("User.java", &entity_source("User")),
+      ("CustomerView.java", RECORD_SOURCE),
+    ]);
+    let records = get_java_files_service::run(temp_dir.path(), &JavaFileType::Record, None)
+      .unwrap_or_else(|e| panic!("Listing records should succeed: {}", e));
+    let names: Vec<&str> = records.iter().map(|file| file.file_type.as_str()).collect();
+    assert_eq!(names, vec!["CustomerView"]);
+    assert_eq!(records[0].file_package_name, "com.example.domain");
+  }
+
+  #[test]
+  fn test_entity_info_rejects_record_source() {
+    let b64_source = base64::engine::general_purpose::STANDARD.encode(RECORD_SOURCE);
+    let error = get_jpa_entity_info_service::run(None, Some(&b64_source))
+      .err()
+      .expect("A record is not an entity");
+    assert_eq!(error, "Records cannot be JPA entities");
+  }
+}
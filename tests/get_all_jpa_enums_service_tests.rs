@@ -0,0 +1,48 @@
+// Get All JPA Enums Service Integration Tests
+// This module contains tests for listing enums usable as JPA enum fields
+
+mod common;
+
+use common::setup_domain_project;
+use syntaxpresso_core::commands::services::get_all_jpa_enums_service::run;
+
+#[test]
+fn test_lists_public_top_level_enums_with_constants() {
+  let status = r#"package com.example.domain;
+
+public enum OrderStatus {
+  PENDING,
+  SHIPPED("shipped"),
+  DELIVERED {
+    @Override
+    public boolean isFinal() { return true; }
+  };
+
+  private final String label;
+
+  OrderStatus() { this("pending"); }
+
+  OrderStatus(String label) { this.label = label; }
+
+  public boolean isFinal() { return false; }
+
+  public enum Nested { INNER }
+}
+"#;
+  let project = setup_domain_project(&[("OrderStatus.java", status)]);
+  let enums = run(project.path(), None).unwrap();
+  assert_eq!(enums.len(), 1);
+  assert_eq!(enums[0].enum_type, "OrderStatus");
+  assert_eq!(enums[0].enum_package_name, "com.example.domain");
+  assert_eq!(enums[0].constants, vec!["PENDING", "SHIPPED", "DELIVERED"]);
+  assert!(enums[0].file_path.ends_with("OrderStatus.java"));
+}
+
+#[test]
+fn test_skips_package_private_enums_and_classes() {
+  let hidden = "package com.example.domain;\n\nenum Hidden { A, B }\n";
+  let order =
+    "package com.example.domain;\n\npublic class Order {\n  public enum Type { ONLINE }\n}\n";
+  let project = setup_domain_project(&[("Hidden.java", hidden), ("Order.java", order)]);
+  assert!(run(project.path(), None).unwrap().is_empty());
+}
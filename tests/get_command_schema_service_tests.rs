@@ -0,0 +1,99 @@
+// Get Command Schema Service Integration Tests
+// This module contains tests for the JSON Schema describing command inputs
+
+use serde_json::Value;
+use syntaxpresso_core::commands::services::get_command_schema_service::{run, schema_id};
+
+/// Returns the `oneOf` branch describing the given command
+fn command_branch<'a>(schema: &'a Value, command: &str) -> &'a Value {
+  schema["oneOf"]
+    .as_array()
+    .expect("oneOf should be an array")
+    .iter()
+    .find(|branch| branch["properties"]["command"]["const"] == command)
+    .unwrap_or_else(|| panic!("Missing schema for command {}", command))
+}
+
+#[test]
+fn test_schema_is_versioned() {
+  let schema = run();
+  assert_eq!(schema["$id"], schema_id());
+  assert!(schema_id().ends_with(env!("CARGO_PKG_VERSION")));
+  assert_eq!(schema["version"], env!("CARGO_PKG_VERSION"));
+  assert_eq!(schema["$schema"], "https://json-schema.org/draft/2020-12/schema");
+}
+
+#[test]
+fn test_schema_output_is_stable() {
+  let first = serde_json::to_string(&run()).unwrap();
+  let second = serde_json::to_string(&run()).unwrap();
+  assert_eq!(first, second);
+}
+
+#[test]
+fn test_schema_defines_value_enums_and_configs() {
+  let schema = run();
+  let definitions = &schema["$defs"];
+  let cascade_values = definitions["CascadeType"]["enum"].as_array().unwrap();
+  assert!(cascade_values.contains(&Value::from("all")));
+  assert!(definitions["FetchType"]["enum"].as_array().unwrap().contains(&Value::from("lazy")));
+  assert!(definitions["CollectionType"].is_object());
+  for config in [
+    "BasicFieldConfig",
+    "EnumFieldConfig",
+    "IdFieldConfig",
+    "ManyToOneFieldConfig",
+    "OneToOneFieldConfig",
+  ] {
+    assert_eq!(definitions[config]["type"], "object", "{} should be defined", config);
+  }
+}
+
+#[test]
+fn test_schema_describes_command_arguments() {
+  let schema = run();
+  let arguments =
+    &command_branch(&schema, "create-jpa-entity-basic-field")["properties"]["arguments"];
+  let required = arguments["required"].as_array().unwrap();
+  assert!(required.contains(&Value::from("entity-file-path")));
+  assert!(!required.contains(&Value::from("field-unique")));
+  let properties = &arguments["properties"];
+  assert_eq!(properties["naming-strategy"]["$ref"], "#/$defs/ColumnNamingStrategy");
+  assert_eq!(properties["naming-strategy"]["default"], "snake_case");
+  assert_eq!(properties["field-unique"]["type"], "boolean");
+  assert_eq!(arguments["additionalProperties"], false);
+}
+
+#[test]
+fn test_schema_excludes_interactive_commands() {
+  let schema = run();
+  let commands: Vec<&Value> = schema["oneOf"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|branch| &branch["properties"]["command"]["const"])
+    .collect();
+  assert!(!commands.contains(&&Value::from("ui")));
+  assert!(commands.contains(&&Value::from("schema")));
+}
+
+#[test]
+fn test_schema_builds_on_a_default_sized_thread() {
+  let schema = std::thread::Builder::new()
+    .stack_size(2 * 1024 * 1024)
+    .spawn(run)
+    .expect("Failed to spawn thread")
+    .join()
+    .expect("Building the schema should not overflow a default-sized stack");
+  assert!(!schema["oneOf"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_schema_describes_get_fqcn_arguments() {
+  let schema = run();
+  let arguments = &command_branch(&schema, "get-fqcn")["properties"]["arguments"];
+  assert_eq!(arguments["required"], serde_json::json!(["cwd"]));
+  let properties = &arguments["properties"];
+  assert_eq!(properties["file-path"]["format"], "path");
+  assert_eq!(properties["b64-source-code"]["type"], "string");
+}
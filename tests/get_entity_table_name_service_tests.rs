@@ -0,0 +1,135 @@
+// Get Entity Table Name Service Integration Tests
+// This module contains tests for resolving the table an entity is mapped to, from its own
+// `@Table`, from the root of a single table hierarchy or from the naming strategy
+
+mod common;
+
+use common::{setup_project, source_path};
+use syntaxpresso_core::commands::services::get_entity_table_name_service::run;
+use syntaxpresso_core::common::types::column_naming_strategy::ColumnNamingStrategy;
+use syntaxpresso_core::responses::get_entity_table_name_response::{
+  GetEntityTableNameResponse, TableNameSource,
+};
+use tempfile::TempDir;
+
+fn get_table_name(
+  project: &TempDir,
+  relative_path: &str,
+  naming_strategy: ColumnNamingStrategy,
+) -> GetEntityTableNameResponse {
+  let path = source_path(project, relative_path);
+  run(project.path(), Some(&path), None, &naming_strategy).expect("Table name should resolve")
+}
+
+const ORDER_ITEM: (&str, &str) =
+  ("com/example/OrderItem.java", "package com.example;\n\n@Entity\npublic class OrderItem {\n}\n");
+
+const EXPLICIT_ORDER_ITEM: (&str, &str) = (
+  "com/example/OrderItem.java",
+  "package com.example;\n\n@Entity\n@Table(name = \"order_lines\")\npublic class OrderItem {\n}\n",
+);
+
+const PAYMENT: (&str, &str) = (
+  "com/example/billing/Payment.java",
+  "package com.example.billing;\n\n@Entity\n@Table(name = \"payments\")\npublic class Payment {\n}\n",
+);
+
+const CARD_PAYMENT: (&str, &str) = (
+  "com/example/billing/card/CardPayment.java",
+  "package com.example.billing.card;\n\nimport com.example.billing.Payment;\n\n@Entity\npublic class CardPayment extends Payment {\n}\n",
+);
+
+#[test]
+fn test_returns_explicit_table_name() {
+  let project = setup_project(&[EXPLICIT_ORDER_ITEM]);
+  let response = get_table_name(&project, EXPLICIT_ORDER_ITEM.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.entity_type, "OrderItem");
+  assert_eq!(response.table_name, "order_lines");
+  assert_eq!(response.source, TableNameSource::Explicit);
+  assert_eq!(response.declaring_type, "OrderItem");
+}
+
+#[test]
+fn test_derives_table_name_with_naming_strategy() {
+  let project = setup_project(&[ORDER_ITEM]);
+  let snake_case = get_table_name(&project, ORDER_ITEM.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(snake_case.table_name, "order_item");
+  assert_eq!(snake_case.source, TableNameSource::Derived);
+  let upper = get_table_name(&project, ORDER_ITEM.0, ColumnNamingStrategy::UpperSnakeCase);
+  assert_eq!(upper.table_name, "ORDER_ITEM");
+  let none = get_table_name(&project, ORDER_ITEM.0, ColumnNamingStrategy::None);
+  assert_eq!(none.table_name, "OrderItem");
+}
+
+#[test]
+fn test_derives_table_name_from_entity_name() {
+  let entity = (
+    "com/example/OrderItem.java",
+    "package com.example;\n\n@Entity(name = \"LineItem\")\npublic class OrderItem {\n}\n",
+  );
+  let project = setup_project(&[entity]);
+  let response = get_table_name(&project, entity.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.table_name, "line_item");
+}
+
+#[test]
+fn test_inherits_table_of_single_table_root() {
+  let project = setup_project(&[PAYMENT, CARD_PAYMENT]);
+  let response = get_table_name(&project, CARD_PAYMENT.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.entity_type, "CardPayment");
+  assert_eq!(response.table_name, "payments");
+  assert_eq!(response.source, TableNameSource::Inherited);
+  assert_eq!(response.declaring_type, "Payment");
+}
+
+#[test]
+fn test_derives_table_of_single_table_root_without_table() {
+  let payment = (
+    "com/example/billing/Payment.java",
+    "package com.example.billing;\n\n@Entity\npublic class Payment {\n}\n",
+  );
+  let project = setup_project(&[payment, CARD_PAYMENT]);
+  let response = get_table_name(&project, CARD_PAYMENT.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.table_name, "payment");
+  assert_eq!(response.source, TableNameSource::Derived);
+  assert_eq!(response.declaring_type, "Payment");
+}
+
+#[test]
+fn test_joined_subclass_gets_own_table() {
+  let payment = (
+    "com/example/billing/Payment.java",
+    "package com.example.billing;\n\n@Entity\n@Table(name = \"payments\")\n@Inheritance(strategy = InheritanceType.JOINED)\npublic class Payment {\n}\n",
+  );
+  let project = setup_project(&[payment, CARD_PAYMENT]);
+  let response = get_table_name(&project, CARD_PAYMENT.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.table_name, "card_payment");
+  assert_eq!(response.source, TableNameSource::Derived);
+  assert_eq!(response.declaring_type, "CardPayment");
+}
+
+#[test]
+fn test_ignores_mapped_superclass() {
+  let base = (
+    "com/example/BaseEntity.java",
+    "package com.example;\n\n@MappedSuperclass\n@Table(name = \"ignored\")\npublic abstract class BaseEntity {\n}\n",
+  );
+  let entity = (
+    "com/example/OrderItem.java",
+    "package com.example;\n\n@Entity\npublic class OrderItem extends BaseEntity {\n}\n",
+  );
+  let project = setup_project(&[base, entity]);
+  let response = get_table_name(&project, entity.0, ColumnNamingStrategy::SnakeCase);
+  assert_eq!(response.table_name, "order_item");
+  assert_eq!(response.source, TableNameSource::Derived);
+}
+
+#[test]
+fn test_rejects_non_entity() {
+  let dto = ("com/example/OrderDto.java", "package com.example;\n\npublic class OrderDto {\n}\n");
+  let project = setup_project(&[dto]);
+  let path = source_path(&project, dto.0);
+  let error = run(project.path(), Some(&path), None, &ColumnNamingStrategy::SnakeCase)
+    .expect_err("Non-entities should be rejected");
+  assert!(error.contains("@Entity"));
+}
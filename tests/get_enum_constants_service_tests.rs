@@ -0,0 +1,65 @@
+// Get Enum Constants Service Integration Tests
+// This module contains tests for listing the constants of an enum in declaration order
+
+mod common;
+
+use common::encode;
+use std::fs;
+use syntaxpresso_core::commands::services::get_enum_constants_service::run;
+use tempfile::TempDir;
+
+#[test]
+fn test_lists_plain_enum_constants() {
+  let source = "package com.example.domain;\n\npublic enum Color {\n  RED,\n  GREEN,\n  BLUE\n}\n";
+  let response = run(None, Some(&encode(source))).unwrap();
+  assert_eq!(response.enum_type, "Color");
+  assert_eq!(response.enum_package_name, "com.example.domain");
+  assert_eq!(response.constants, vec!["RED", "GREEN", "BLUE"]);
+}
+
+#[test]
+fn test_lists_constants_with_constructor_arguments() {
+  let source = r#"package com.example.domain;
+
+public enum Planet {
+  MERCURY(3.303e+23, 2.4397e6),
+  @Deprecated
+  PLUTO(1.309e+22, 1.1883e6),
+  EARTH(5.976e+24, 6.37814e6) {
+    @Override
+    public String label() { return "home"; }
+  };
+
+  private final double mass;
+  private final double radius;
+
+  Planet(double mass, double radius) {
+    this.mass = mass;
+    this.radius = radius;
+  }
+
+  public String label() { return name(); }
+}
+"#;
+  let response = run(None, Some(&encode(source))).unwrap();
+  assert_eq!(response.constants, vec!["MERCURY", "PLUTO", "EARTH"]);
+}
+
+#[test]
+fn test_reads_enum_from_file() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let file_path = temp_dir.path().join("Status.java");
+  let source =
+    "package com.example;\n\npublic enum Status { ACTIVE, INACTIVE; }\n\nenum Hidden { A }\n";
+  fs::write(&file_path, source).unwrap();
+  let response = run(Some(&file_path), None).unwrap();
+  assert_eq!(response.enum_type, "Status");
+  assert_eq!(response.constants, vec!["ACTIVE", "INACTIVE"]);
+}
+
+#[test]
+fn test_rejects_file_without_enum() {
+  let source = "package com.example;\n\npublic class Customer {}\n";
+  let error = run(None, Some(&encode(source))).expect_err("Classes should be rejected");
+  assert_eq!(error, "Unable to find a public enum");
+}
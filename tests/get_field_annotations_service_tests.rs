@@ -0,0 +1,127 @@
+// Get Field Annotations Service Integration Tests
+// This module contains tests for reading the annotations of a single entity field
+
+use base64::Engine;
+use serde_json::json;
+use std::fs;
+use syntaxpresso_core::commands::services::get_field_annotations_service::run;
+use syntaxpresso_core::responses::get_field_annotations_response::GetFieldAnnotationsResponse;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.*;
+import java.util.Date;
+import java.util.List;
+
+@Entity
+public class Order {
+  @Id
+  @GeneratedValue(strategy = GenerationType.IDENTITY)
+  private Long id;
+
+  @Column(name = "order_code", length = 255, nullable = false, precision = -1)
+  private String code;
+
+  @Temporal(TemporalType.TIMESTAMP)
+  @Column(nullable = true)
+  private Date placedAt;
+
+  @OneToMany(mappedBy = "order", cascade = {CascadeType.PERSIST, CascadeType.MERGE})
+  @OrderBy("line_no \"asc\"")
+  private List<OrderLine> lines;
+
+  private String notes;
+
+  public static class Line {
+    @Column(length = 10)
+    private String sku;
+  }
+}
+"#;
+
+fn get_annotations(field_name: &str) -> Result<GetFieldAnnotationsResponse, String> {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  run(None, Some(&b64_source), field_name)
+}
+
+#[test]
+fn test_column_attributes_are_parsed() {
+  let response = get_annotations("code").expect("Annotations should be read");
+
+  assert_eq!(response.field_name, "code");
+  assert_eq!(response.field_type, "String");
+  assert_eq!(response.annotations.len(), 1);
+  let column = &response.annotations[0];
+  assert_eq!(column.name, "Column");
+  assert_eq!(
+    serde_json::to_value(&column.attributes).unwrap(),
+    json!({"name": "order_code", "length": 255, "nullable": false, "precision": -1})
+  );
+}
+
+#[test]
+fn test_temporal_single_value_and_column() {
+  let response = get_annotations("placedAt").expect("Annotations should be read");
+
+  assert_eq!(response.field_type, "Date");
+  let names: Vec<&str> = response.annotations.iter().map(|a| a.name.as_str()).collect();
+  assert_eq!(names, vec!["Temporal", "Column"]);
+  assert_eq!(
+    serde_json::to_value(&response.annotations[0].attributes).unwrap(),
+    json!({"value": "TemporalType.TIMESTAMP"})
+  );
+  assert_eq!(
+    serde_json::to_value(&response.annotations[1].attributes).unwrap(),
+    json!({"nullable": true})
+  );
+}
+
+#[test]
+fn test_marker_annotations_have_no_attributes() {
+  let response = get_annotations("id").expect("Annotations should be read");
+
+  assert_eq!(response.annotations[0].name, "Id");
+  assert!(response.annotations[0].attributes.is_empty());
+  assert_eq!(
+    serde_json::to_value(&response.annotations[1].attributes).unwrap(),
+    json!({"strategy": "GenerationType.IDENTITY"})
+  );
+}
+
+#[test]
+fn test_arrays_and_escaped_strings() {
+  let response = get_annotations("lines").expect("Annotations should be read");
+
+  assert_eq!(response.field_type, "List<OrderLine>");
+  assert_eq!(
+    serde_json::to_value(&response.annotations[0].attributes).unwrap(),
+    json!({"mappedBy": "order", "cascade": ["CascadeType.PERSIST", "CascadeType.MERGE"]})
+  );
+  assert_eq!(
+    serde_json::to_value(&response.annotations[1].attributes).unwrap(),
+    json!({"value": "line_no \"asc\""})
+  );
+}
+
+#[test]
+fn test_field_without_annotations() {
+  let response = get_annotations("notes").expect("Annotations should be read");
+  assert!(response.annotations.is_empty());
+}
+
+#[test]
+fn test_missing_field_is_an_error() {
+  let error = get_annotations("total").expect_err("Unknown fields should fail");
+  assert_eq!(error, "Field not found: total");
+}
+
+#[test]
+fn test_reads_entity_from_path() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Order.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+
+  let response = run(Some(&entity_path), None, "code").expect("Annotations should be read");
+  assert_eq!(response.annotations[0].attributes["length"], json!(255));
+}
@@ -0,0 +1,77 @@
+// Get FQCN Service Integration Tests
+// This module contains tests for computing the fully-qualified name of the type declared in a
+// Java file
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::get_fqcn_service::run;
+use syntaxpresso_core::responses::get_fqcn_response::GetFqcnResponse;
+use tempfile::TempDir;
+
+fn resolve(source: &str) -> GetFqcnResponse {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  run(None, Some(&b64_source)).expect("FQCN should resolve")
+}
+
+#[test]
+fn test_packaged_class() {
+  let response = resolve(
+    r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Customer {}
+"#,
+  );
+  assert_eq!(response.fqcn, "com.example.domain.Customer");
+  assert_eq!(response.package_name, "com.example.domain");
+  assert_eq!(response.type_name, "Customer");
+}
+
+#[test]
+fn test_default_package() {
+  let response = resolve("public record Point(int x, int y) {}\n");
+  assert_eq!(response.fqcn, "Point");
+  assert_eq!(response.package_name, "");
+  assert_eq!(response.type_name, "Point");
+}
+
+#[test]
+fn test_multiple_top_level_types_prefers_public_one() {
+  let response = resolve(
+    r#"package com.example.util;
+
+class Helper {}
+
+interface Marker {}
+
+public enum Status {
+  ACTIVE
+}
+"#,
+  );
+  assert_eq!(response.fqcn, "com.example.util.Status");
+}
+
+#[test]
+fn test_multiple_package_private_types_use_first_one() {
+  let response = resolve("package com.example;\n\nclass First {}\n\nclass Second {}\n");
+  assert_eq!(response.fqcn, "com.example.First");
+}
+
+#[test]
+fn test_reads_file_path() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let file_path = temp_dir.path().join("Order.java");
+  fs::write(&file_path, "package com.example.sales;\n\npublic class Order {}\n").unwrap();
+  let response = run(Some(&file_path), None).expect("FQCN should resolve");
+  assert_eq!(response.fqcn, "com.example.sales.Order");
+}
+
+#[test]
+fn test_file_without_type_declaration() {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode("package com.example;\n");
+  let error = run(None, Some(&b64_source)).expect_err("FQCN should not resolve");
+  assert_eq!(error, "Unable to find a top-level type declaration");
+}
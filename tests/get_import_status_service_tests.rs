@@ -0,0 +1,59 @@
+// Get Import Status Service Integration Tests
+// This module contains tests for telling whether a type needs an import in a given file
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::get_import_status_service::run;
+use syntaxpresso_core::responses::get_import_status_response::ImportStatus;
+
+const SOURCE: &str = r#"package com.example.domain;
+
+import java.util.*;
+import java.time.LocalDate;
+import java.time.temporal.*;
+
+public class Customer {
+  private LocalDate birthDate;
+}
+"#;
+
+fn import_status(type_fqcn: &str) -> (ImportStatus, bool) {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(SOURCE);
+  let response = run(None, Some(&b64_source), type_fqcn).expect("Import status should resolve");
+  assert_eq!(response.type_fqcn, type_fqcn);
+  (response.status, response.import_needed)
+}
+
+#[test]
+fn test_single_type_import() {
+  assert_eq!(import_status("java.time.LocalDate"), (ImportStatus::Imported, false));
+}
+
+#[test]
+fn test_wildcard_import() {
+  assert_eq!(import_status("java.util.List"), (ImportStatus::Wildcard, false));
+  assert_eq!(import_status("java.time.temporal.ChronoUnit"), (ImportStatus::Wildcard, false));
+}
+
+#[test]
+fn test_same_package_type() {
+  assert_eq!(import_status("com.example.domain.Order"), (ImportStatus::SamePackage, false));
+}
+
+#[test]
+fn test_java_lang_type() {
+  assert_eq!(import_status("java.lang.String"), (ImportStatus::JavaLang, false));
+}
+
+#[test]
+fn test_type_needing_an_import() {
+  assert_eq!(import_status("java.time.Instant"), (ImportStatus::NotImported, true));
+  assert_eq!(import_status("java.lang.reflect.Method"), (ImportStatus::NotImported, true));
+  assert_eq!(import_status("com.example.domain.sub.Order"), (ImportStatus::NotImported, true));
+}
+
+#[test]
+fn test_rejects_type_without_package() {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(SOURCE);
+  let error = run(None, Some(&b64_source), "Customer").expect_err("A package is required");
+  assert_eq!(error, "'Customer' must include its package (e.g. com.example.Customer)");
+}
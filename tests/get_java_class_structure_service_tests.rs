@@ -0,0 +1,136 @@
+// Get Java Class Structure Service Integration Tests
+// This module contains tests for building the outline of Java types
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::get_java_class_structure_service::run;
+use syntaxpresso_core::responses::get_java_class_structure_response::GetJavaClassStructureResponse;
+
+const ORDER_SERVICE_SOURCE: &str = r#"package com.example.orders;
+
+import java.util.List;
+
+@Service
+public class OrderService {
+  private static final int PAGE_SIZE = 20;
+  private int x, y;
+
+  public OrderService(OrderRepository repository) {
+  }
+
+  public List<Order> find(Long id) {
+    return null;
+  }
+
+  public List<Order> find(String code, int... pages) {
+    return null;
+  }
+
+  @Override
+  protected void cleanup() {
+  }
+
+  static class Page {
+    private int number;
+
+    int next() {
+      return number + 1;
+    }
+  }
+
+  enum Status {
+    ACTIVE, CLOSED;
+
+    boolean isOpen() {
+      return this == ACTIVE;
+    }
+  }
+}
+
+record Line(String product, int quantity) {
+}
+"#;
+
+fn get_structure(source: &str) -> GetJavaClassStructureResponse {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  run(None, Some(&b64_source)).expect("Structure should be built")
+}
+
+#[test]
+fn test_lists_top_level_types_and_package() {
+  let structure = get_structure(ORDER_SERVICE_SOURCE);
+  assert_eq!(structure.package_name, "com.example.orders");
+  let names: Vec<(&str, &str)> =
+    structure.types.iter().map(|t| (t.name.as_str(), t.kind.as_str())).collect();
+  assert_eq!(names, vec![("OrderService", "class"), ("Line", "record")]);
+  let line = &structure.types[1];
+  let components: Vec<&str> = line.fields.iter().map(|f| f.name.as_str()).collect();
+  assert_eq!(components, vec!["product", "quantity"]);
+}
+
+#[test]
+fn test_fields_include_every_declarator_and_modifiers() {
+  let structure = get_structure(ORDER_SERVICE_SOURCE);
+  let service = &structure.types[0];
+  let fields: Vec<(&str, &str)> =
+    service.fields.iter().map(|f| (f.name.as_str(), f.field_type.as_str())).collect();
+  assert_eq!(fields, vec![("PAGE_SIZE", "int"), ("x", "int"), ("y", "int")]);
+  assert_eq!(service.fields[0].modifiers, vec!["private", "static", "final"]);
+  assert_eq!(service.fields[1].start_byte, service.fields[2].start_byte);
+}
+
+#[test]
+fn test_overloaded_methods_and_constructors() {
+  let structure = get_structure(ORDER_SERVICE_SOURCE);
+  let service = &structure.types[0];
+  let methods: Vec<(&str, &str)> =
+    service.methods.iter().map(|m| (m.name.as_str(), m.kind.as_str())).collect();
+  assert_eq!(
+    methods,
+    vec![
+      ("OrderService", "constructor"),
+      ("find", "method"),
+      ("find", "method"),
+      ("cleanup", "method")
+    ]
+  );
+  assert_eq!(service.methods[0].return_type, None);
+  let first_find = &service.methods[1];
+  assert_eq!(first_find.return_type.as_deref(), Some("List<Order>"));
+  assert_eq!(first_find.parameters.len(), 1);
+  assert_eq!(first_find.parameters[0].name, "id");
+  assert_eq!(first_find.parameters[0].parameter_type, "Long");
+  let second_find = &service.methods[2];
+  let parameters: Vec<(&str, &str)> =
+    second_find.parameters.iter().map(|p| (p.name.as_str(), p.parameter_type.as_str())).collect();
+  assert_eq!(parameters, vec![("code", "String"), ("pages", "int...")]);
+  assert_ne!(first_find.start_byte, second_find.start_byte);
+  assert_eq!(service.methods[3].modifiers, vec!["protected"]);
+}
+
+#[test]
+fn test_nested_types_are_outlined_with_byte_ranges() {
+  let structure = get_structure(ORDER_SERVICE_SOURCE);
+  let service = &structure.types[0];
+  let nested: Vec<(&str, &str)> =
+    service.nested_types.iter().map(|t| (t.name.as_str(), t.kind.as_str())).collect();
+  assert_eq!(nested, vec![("Page", "class"), ("Status", "enum")]);
+  let page = &service.nested_types[0];
+  assert_eq!(page.modifiers, vec!["static"]);
+  assert_eq!(page.fields[0].name, "number");
+  assert_eq!(page.methods[0].name, "next");
+  assert!(service.start_byte < page.start_byte && page.end_byte < service.end_byte);
+  assert_eq!(
+    &ORDER_SERVICE_SOURCE[page.start_byte..page.end_byte],
+    "static class Page {\n    private int number;\n\n    int next() {\n      return number + 1;\n    }\n  }"
+  );
+  let status = &service.nested_types[1];
+  assert_eq!(status.methods[0].name, "isOpen");
+  assert!(status.fields.is_empty());
+}
+
+#[test]
+fn test_file_without_types_is_an_error() {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode("package com.example;\n");
+  assert!(run(None, Some(&b64_source)).is_err());
+  assert!(run(None, None).is_err());
+}
@@ -0,0 +1,120 @@
+// Get Java File Type Service Integration Tests
+// This module contains tests for classifying the top-level type declared in a Java file
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::get_java_file_type_service::run;
+use syntaxpresso_core::responses::get_java_file_type_response::{
+  GetJavaFileTypeResponse, JavaFileKind,
+};
+
+fn classify(source: &str) -> GetJavaFileTypeResponse {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  run(None, Some(&b64_source)).expect("File type should resolve")
+}
+
+#[test]
+fn test_record() {
+  let response = classify(
+    r#"package com.example.dto;
+
+public record CustomerDto(Long id, String name) {}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Record);
+  assert_eq!(response.type_name, "CustomerDto");
+  assert_eq!(response.package_name, "com.example.dto");
+}
+
+#[test]
+fn test_annotation_type() {
+  let response = classify(
+    r#"package com.example.validation;
+
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+
+@Retention(RetentionPolicy.RUNTIME)
+public @interface ValidEmail {
+  String message() default "Invalid email";
+}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Annotation);
+  assert_eq!(response.type_name, "ValidEmail");
+}
+
+#[test]
+fn test_entity() {
+  let response = classify(
+    r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+
+@Entity
+public class Customer {
+  @Id
+  private Long id;
+}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Entity);
+  assert_eq!(response.type_name, "Customer");
+  assert_eq!(response.package_name, "com.example.domain");
+}
+
+#[test]
+fn test_qualified_jpa_annotations() {
+  let response = classify(
+    r#"package com.example.domain;
+
+@jakarta.persistence.MappedSuperclass
+public abstract class BaseEntity {}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::MappedSuperclass);
+
+  let response = classify(
+    r#"package com.example.domain;
+
+@jakarta.persistence.Embeddable
+public class Address {}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Embeddable);
+}
+
+#[test]
+fn test_member_annotations_do_not_change_plain_class() {
+  let response = classify(
+    r#"package com.example.domain;
+
+public class Holder {
+  @Embedded
+  private Address address;
+}
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Class);
+}
+
+#[test]
+fn test_prefers_public_type() {
+  let response = classify(
+    r#"package com.example.service;
+
+interface Helper {}
+
+public enum Status { ACTIVE }
+"#,
+  );
+  assert_eq!(response.kind, JavaFileKind::Enum);
+  assert_eq!(response.type_name, "Status");
+}
+
+#[test]
+fn test_rejects_file_without_type() {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode("package com.example;\n");
+  let error = run(None, Some(&b64_source)).expect_err("A type declaration is required");
+  assert_eq!(error, "Unable to find a top-level type declaration");
+}
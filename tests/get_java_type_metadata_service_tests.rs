@@ -0,0 +1,43 @@
+// Get Java Type Metadata Service Integration Tests
+// This module contains tests for listing the basic types with all their capability flags
+
+use syntaxpresso_core::commands::services::get_java_type_metadata_service::run;
+use syntaxpresso_core::common::types::java_basic_types::JavaBasicType;
+use syntaxpresso_core::responses::java_type_metadata_response::JavaTypeMetadataResponse;
+
+fn metadata_of(type_id: &str) -> JavaTypeMetadataResponse {
+  run().unwrap().into_iter().find(|java_type| java_type.id == type_id).expect("Expected type")
+}
+
+#[test]
+fn test_string_supports_length_and_lob() {
+  let string = metadata_of("java.lang.String");
+  assert!(string.supports_length);
+  assert!(string.supports_lob);
+  assert!(!string.supports_precision_scale);
+  assert!(!string.supports_temporal);
+}
+
+#[test]
+fn test_big_decimal_supports_precision_and_scale() {
+  let big_decimal = metadata_of("java.math.BigDecimal");
+  assert!(big_decimal.supports_precision_scale);
+  assert!(!big_decimal.supports_length);
+  assert!(!big_decimal.supports_lob);
+}
+
+#[test]
+fn test_lists_every_basic_type_in_order() {
+  let ids: Vec<String> = run().unwrap().into_iter().map(|java_type| java_type.id).collect();
+  let all_ids: Vec<String> =
+    JavaBasicType::AllTypes.get_all_types().into_iter().map(|java_type| java_type.id).collect();
+  assert_eq!(ids, all_ids);
+}
+
+#[test]
+fn test_flags_are_serialized_in_camel_case() {
+  let json = serde_json::to_value(metadata_of("java.util.Calendar")).unwrap();
+  assert_eq!(json["supportsTemporal"], true);
+  assert_eq!(json["supportsPrecisionScale"], false);
+  assert_eq!(json["packagePath"], "java.util");
+}
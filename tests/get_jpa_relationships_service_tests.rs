@@ -0,0 +1,74 @@
+// Get JPA Relationships Service Integration Tests
+// This module contains tests for extracting relationship metadata from JPA entities
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::get_jpa_relationships_service::run;
+use syntaxpresso_core::responses::get_jpa_relationships_response::GetJpaRelationshipsResponse;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+@Entity
+public class Order {
+  @Id
+  private Long id;
+
+  @ManyToOne(fetch = FetchType.LAZY)
+  @JoinColumn(name = "customer_id")
+  private Customer customer;
+
+  @OneToMany(mappedBy = "order", cascade = {CascadeType.PERSIST, CascadeType.MERGE})
+  private List<OrderLine> lines;
+
+  @OneToOne(cascade = CascadeType.ALL, targetEntity = InvoiceImpl.class)
+  private Invoice invoice;
+
+  @ManyToMany
+  private Set<Tag> tags;
+}
+"#;
+
+fn get_relationships(source: &str) -> GetJpaRelationshipsResponse {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  run(None, Some(&b64_source)).expect("Relationship extraction should succeed")
+}
+
+#[test]
+fn test_extracts_relationships_in_declaration_order() {
+  let response = get_relationships(ENTITY_SOURCE);
+  assert_eq!(response.entity_type, "Order");
+  assert_eq!(response.entity_package_name, "com.example.domain");
+  let fields: Vec<_> = response.relationships.iter().map(|r| r.field_name.as_str()).collect();
+  assert_eq!(fields, vec!["customer", "lines", "invoice", "tags"]);
+}
+
+#[test]
+fn test_parses_relationship_attributes() {
+  let response = get_relationships(ENTITY_SOURCE);
+  let customer = &response.relationships[0];
+  assert_eq!(customer.kind, "ManyToOne");
+  assert_eq!(customer.target_type, "Customer");
+  assert_eq!(customer.fetch_type, "LAZY");
+  assert!(customer.cascades.is_empty());
+  let lines = &response.relationships[1];
+  assert_eq!(lines.target_type, "OrderLine");
+  assert_eq!(lines.mapped_by.as_deref(), Some("order"));
+  assert_eq!(lines.cascades, vec!["PERSIST", "MERGE"]);
+  let invoice = &response.relationships[2];
+  assert_eq!(invoice.target_type, "InvoiceImpl");
+  assert_eq!(invoice.fetch_type, "EAGER");
+  assert_eq!(invoice.cascades, vec!["ALL"]);
+  let tags = &response.relationships[3];
+  assert_eq!(tags.kind, "ManyToMany");
+  assert_eq!(tags.target_type, "Tag");
+  assert_eq!(tags.fetch_type, "LAZY");
+}
+
+#[test]
+fn test_json_output_is_stable() {
+  let first = serde_json::to_string(&get_relationships(ENTITY_SOURCE)).unwrap();
+  let second = serde_json::to_string(&get_relationships(ENTITY_SOURCE)).unwrap();
+  assert_eq!(first, second);
+  assert!(first.contains(
+    r#"{"fieldName":"customer","kind":"ManyToOne","targetType":"Customer","mappedBy":null,"fetchType":"LAZY","cascades":[]}"#
+  ));
+}
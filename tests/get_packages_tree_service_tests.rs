@@ -0,0 +1,95 @@
+// Get Packages Tree Service Integration Tests
+// This module contains tests for listing the project packages as a nested tree
+
+mod common;
+
+use common::setup_project;
+use std::fs;
+use syntaxpresso_core::commands::get_packages_tree_command::execute;
+use syntaxpresso_core::commands::services::get_packages_tree_service::run;
+use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use syntaxpresso_core::responses::get_packages_tree_response::PackageTreeNodeResponse;
+use tempfile::TempDir;
+
+/// Creates a project with an empty public class for each `(package name, class name)` pair
+fn setup_classes(classes: &[(&str, &str)]) -> TempDir {
+  let files: Vec<(String, String)> = classes
+    .iter()
+    .map(|(package_name, class_name)| {
+      (
+        format!("{}/{}.java", package_name.replace('.', "/"), class_name),
+        format!("package {};\n\npublic class {} {{\n}}\n", package_name, class_name),
+      )
+    })
+    .collect();
+  let files: Vec<(&str, &str)> =
+    files.iter().map(|(path, source)| (path.as_str(), source.as_str())).collect();
+  setup_project(&files)
+}
+
+fn sample_project() -> TempDir {
+  setup_classes(&[
+    ("com.example", "Application"),
+    ("com.example.service", "UserService"),
+    ("com.example.service", "OrderService"),
+    ("com.example.model", "User"),
+    ("com.example.model.dto", "UserDto"),
+  ])
+}
+
+fn child<'a>(node: &'a PackageTreeNodeResponse, name: &str) -> &'a PackageTreeNodeResponse {
+  node
+    .children
+    .iter()
+    .find(|child| child.name == name)
+    .unwrap_or_else(|| panic!("Missing child package '{}' of {}", name, node.package_name))
+}
+
+#[test]
+fn test_nests_sub_packages_under_root_package() {
+  let temp_dir = sample_project();
+  let trees = run(temp_dir.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+  assert_eq!(trees.len(), 1);
+  let root = &trees[0];
+  assert_eq!(root.name, "example");
+  assert_eq!(root.package_name, "com.example");
+  assert_eq!(root.file_count, 1);
+  let child_names: Vec<&str> = root.children.iter().map(|child| child.name.as_str()).collect();
+  assert_eq!(child_names, vec!["model", "service"]);
+
+  let service = child(root, "service");
+  assert_eq!(service.package_name, "com.example.service");
+  assert_eq!(service.file_count, 2);
+  assert!(service.children.is_empty());
+
+  let model = child(root, "model");
+  assert_eq!(model.file_count, 1);
+  let dto = child(model, "dto");
+  assert_eq!(dto.package_name, "com.example.model.dto");
+  assert_eq!(dto.file_count, 1);
+}
+
+#[test]
+fn test_package_without_files_has_zero_count() {
+  let temp_dir = sample_project();
+  fs::create_dir_all(temp_dir.path().join("src/main/java/com/example/config")).unwrap();
+  let trees = run(temp_dir.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+  let config = child(&trees[0], "config");
+  assert_eq!(config.file_count, 0);
+  assert!(config.children.is_empty());
+}
+
+#[test]
+fn test_command_counts_every_nested_package() {
+  let temp_dir = sample_project();
+  let response = execute(temp_dir.path(), &JavaSourceDirectoryType::Main, None);
+  let data = response.get_data().expect("Command should succeed");
+  assert_eq!(data.packages_count, 4);
+}
+
+#[test]
+fn test_empty_project_has_no_packages() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let trees = run(temp_dir.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+  assert!(trees.is_empty());
+}
@@ -0,0 +1,129 @@
+// Get Project Info Service Integration Tests
+// This module contains tests for detecting build tool, Java version, JPA namespace and source
+// roots from Maven and Gradle build files
+
+mod common;
+
+use common::setup_build_project;
+use std::fs;
+use syntaxpresso_core::commands::services::get_project_info_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use syntaxpresso_core::common::utils::project_layout_util::{
+  find_gradle_java_version, find_maven_java_version, find_persistence_namespace,
+};
+use tempfile::TempDir;
+
+const MAVEN_JAKARTA_POM: &str = r#"<project>
+  <modelVersion>4.0.0</modelVersion>
+  <groupId>com.example</groupId>
+  <artifactId>shop</artifactId>
+  <properties>
+    <java.version>21</java.version>
+    <maven.compiler.release>${java.version}</maven.compiler.release>
+  </properties>
+  <dependencies>
+    <dependency>
+      <groupId>jakarta.persistence</groupId>
+      <artifactId>jakarta.persistence-api</artifactId>
+      <version>3.1.0</version>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+const GRADLE_JAVAX_BUILD: &str = r#"plugins {
+  id 'java'
+}
+
+sourceCompatibility = JavaVersion.VERSION_1_8
+
+dependencies {
+  implementation 'javax.persistence:javax.persistence-api:2.2'
+}
+"#;
+
+#[test]
+fn test_maven_project_declaring_jakarta() {
+  let project = setup_build_project(Some(("pom.xml", MAVEN_JAKARTA_POM)));
+  let info = run(project.path()).unwrap();
+  assert_eq!(info.build_tool, "maven");
+  assert_eq!(info.java_version.as_deref(), Some("21"));
+  assert_eq!(info.persistence_namespace.as_deref(), Some("jakarta"));
+  let roots: Vec<(&str, String)> =
+    info.source_roots.iter().map(|root| (root.source_set.as_str(), root.path.clone())).collect();
+  assert_eq!(
+    roots,
+    vec![
+      ("main", project.path().join("src/main/java").to_string_lossy().to_string()),
+      ("test", project.path().join("src/test/java").to_string_lossy().to_string()),
+    ]
+  );
+}
+
+#[test]
+fn test_gradle_project_declaring_javax() {
+  let project = setup_build_project(Some(("build.gradle", GRADLE_JAVAX_BUILD)));
+  let info = run(project.path()).unwrap();
+  assert_eq!(info.build_tool, "gradle");
+  assert_eq!(info.java_version.as_deref(), Some("1.8"));
+  assert_eq!(info.persistence_namespace.as_deref(), Some("javax"));
+  assert_eq!(info.source_roots.len(), 2);
+}
+
+#[test]
+fn test_namespace_is_read_from_sub_module() {
+  let project = setup_build_project(Some(("settings.gradle", "include 'domain'")));
+  fs::write(project.path().join("build.gradle"), "plugins { id 'java' }").unwrap();
+  let module_dir = project.path().join("domain");
+  fs::create_dir_all(module_dir.join("src/main/java")).unwrap();
+  fs::write(module_dir.join("build.gradle"), GRADLE_JAVAX_BUILD).unwrap();
+  let info = run(project.path()).unwrap();
+  assert_eq!(info.persistence_namespace.as_deref(), Some("javax"));
+  assert!(
+    info.source_roots.iter().any(|root| root.module.as_deref() == Some("domain")
+      && root.path.ends_with("domain/src/main/java"))
+  );
+}
+
+#[test]
+fn test_project_without_build_file() {
+  let temp_dir = TempDir::new().unwrap();
+  let info = run(temp_dir.path()).unwrap();
+  assert_eq!(info.build_tool, "unknown");
+  assert_eq!(info.java_version, None);
+  assert_eq!(info.persistence_namespace, None);
+  assert!(info.source_roots.is_empty());
+}
+
+mod build_file_parsing_tests {
+  use super::*;
+
+  #[test]
+  fn test_gradle_toolchain_version() {
+    let build = "java {\n  toolchain {\n    languageVersion = JavaLanguageVersion.of(17)\n  }\n}";
+    assert_eq!(find_gradle_java_version(build).as_deref(), Some("17"));
+  }
+
+  #[test]
+  fn test_gradle_kotlin_source_compatibility() {
+    let build = "java {\n  sourceCompatibility = JavaVersion.VERSION_11\n}";
+    assert_eq!(find_gradle_java_version(build).as_deref(), Some("11"));
+    assert_eq!(find_gradle_java_version("sourceCompatibility = '17'").as_deref(), Some("17"));
+  }
+
+  #[test]
+  fn test_maven_compiler_source() {
+    let pom = "<properties><maven.compiler.source>17</maven.compiler.source></properties>";
+    assert_eq!(find_maven_java_version(pom).as_deref(), Some("17"));
+    assert_eq!(find_maven_java_version("<project></project>"), None);
+  }
+
+  #[test]
+  fn test_spring_boot_version_decides_namespace() {
+    let boot3 = "<parent>\n  <artifactId>spring-boot-starter-parent</artifactId>\n  <version>3.2.1</version>\n</parent>";
+    let boot2 = "plugins {\n  id 'org.springframework.boot' version '2.7.18'\n}";
+    assert_eq!(find_persistence_namespace(boot3), Some(PersistenceNamespace::Jakarta));
+    assert_eq!(find_persistence_namespace(boot2), Some(PersistenceNamespace::Javax));
+    assert_eq!(find_persistence_namespace("plugins { id 'java' }"), None);
+  }
+}
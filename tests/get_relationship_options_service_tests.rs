@@ -0,0 +1,31 @@
+// Get Relationship Options Service Integration Tests
+// This module contains tests for the option values each relationship kind accepts
+
+use syntaxpresso_core::commands::services::get_relationship_options_service::run;
+
+#[test]
+fn test_one_to_one_options() {
+  let options = run().unwrap().one_to_one;
+  assert_eq!(options.cascades, vec!["persist", "merge", "remove", "refresh", "detach"]);
+  assert_eq!(options.fetch_types, vec!["lazy", "eager"]);
+  assert!(options.collection_types.is_empty());
+  assert_eq!(options.owning_side_other, vec!["mandatory", "unique", "orphan_removal"]);
+  assert_eq!(options.inverse_side_other, vec!["mandatory", "orphan_removal"]);
+}
+
+#[test]
+fn test_many_to_one_options() {
+  let options = run().unwrap().many_to_one;
+  assert_eq!(options.cascades, vec!["persist", "merge", "remove", "refresh", "detach"]);
+  assert_eq!(options.fetch_types, vec!["lazy", "eager"]);
+  assert_eq!(options.collection_types, vec!["list", "set", "collection"]);
+  assert_eq!(options.owning_side_other, vec!["mandatory", "unique"]);
+  assert_eq!(options.inverse_side_other, vec!["orphan_removal"]);
+}
+
+#[test]
+fn test_options_are_keyed_by_relationship_kind() {
+  let json = serde_json::to_value(run().unwrap()).unwrap();
+  assert_eq!(json["oneToOne"]["owningSideOther"][2], "orphan_removal");
+  assert_eq!(json["manyToOne"]["collectionTypes"][0], "list");
+}
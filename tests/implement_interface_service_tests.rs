@@ -0,0 +1,206 @@
+// Implement Interface Service Integration Tests
+// This module contains tests for adding interfaces to the implements clause of a class
+
+mod common;
+
+use common::{encode, setup_project, source_path};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::implement_interface_service::run;
+use tempfile::TempDir;
+
+const USER_SERVICE_SOURCE: &str = r#"package com.example.service;
+
+public class UserService {
+
+  private String name;
+}
+"#;
+
+const AUDITED_SOURCE: &str = r#"package com.example.common;
+
+import java.time.Instant;
+import java.util.List;
+
+public interface Audited {
+
+  Instant getCreatedAt();
+
+  boolean isArchived();
+
+  int revision();
+
+  void touch(String reason) throws AuditException;
+
+  List<AuditEntry> history(int limit);
+
+  default String describe() {
+    return "audited";
+  }
+
+  static Audited none() {
+    return null;
+  }
+}
+"#;
+
+/// Creates a project with `UserService` in `com.example.service` and the `Audited` interface
+/// with its `AuditEntry` and `AuditException` siblings in `com.example.common`
+fn setup_user_service(user_service_source: &str) -> (TempDir, PathBuf) {
+  let project = setup_project(&[
+    ("com/example/service/UserService.java", user_service_source),
+    ("com/example/common/Audited.java", AUDITED_SOURCE),
+    (
+      "com/example/common/AuditEntry.java",
+      "package com.example.common;\n\npublic class AuditEntry {}\n",
+    ),
+    (
+      "com/example/common/AuditException.java",
+      "package com.example.common;\n\npublic class AuditException extends Exception {}\n",
+    ),
+  ]);
+  let user_service_path = source_path(&project, "com/example/service/UserService.java");
+  (project, user_service_path)
+}
+
+#[test]
+fn test_adds_first_interface_and_import() {
+  let (project, user_service_path) = setup_user_service(USER_SERVICE_SOURCE);
+  let response = run(
+    project.path(),
+    &encode(USER_SERVICE_SOURCE),
+    &user_service_path,
+    "java.io.Serializable",
+    false,
+  )
+  .unwrap();
+  assert_eq!(response.interface_name, "Serializable");
+  assert!(response.stubbed_method_names.is_empty());
+  let content = fs::read_to_string(&user_service_path).unwrap();
+  assert!(content.contains("import java.io.Serializable;"));
+  assert!(content.contains("public class UserService implements Serializable {"));
+}
+
+#[test]
+fn test_appends_second_interface_to_existing_list() {
+  let source = r#"package com.example.service;
+
+import java.io.Serializable;
+
+public class UserService extends BaseService<String> implements Serializable {
+}
+"#;
+  let (project, user_service_path) = setup_user_service(source);
+  run(project.path(), &encode(source), &user_service_path, "java.lang.Comparable", false).unwrap();
+  let content = fs::read_to_string(&user_service_path).unwrap();
+  assert!(content.contains(
+    "public class UserService extends BaseService<String> implements Serializable, Comparable {"
+  ));
+  assert!(!content.contains("import java.lang.Comparable;"));
+}
+
+#[test]
+fn test_adds_clause_after_superclass() {
+  let source =
+    "package com.example.service;\n\npublic class UserService extends BaseService {\n}\n";
+  let (project, user_service_path) = setup_user_service(source);
+  run(project.path(), &encode(source), &user_service_path, "java.lang.Runnable", false).unwrap();
+  let content = fs::read_to_string(&user_service_path).unwrap();
+  assert!(content.contains("public class UserService extends BaseService implements Runnable {"));
+}
+
+#[test]
+fn test_rejects_interface_already_implemented() {
+  let source =
+    "package com.example.service;\n\npublic class UserService implements Runnable {\n}\n";
+  let (project, user_service_path) = setup_user_service(source);
+  let result =
+    run(project.path(), &encode(source), &user_service_path, "java.lang.Runnable", false);
+  assert_eq!(result.err().expect("Expected error"), "UserService already implements Runnable");
+}
+
+mod stub_methods_tests {
+  use super::*;
+
+  #[test]
+  fn test_stubs_abstract_methods_with_default_bodies() {
+    let (project, user_service_path) = setup_user_service(USER_SERVICE_SOURCE);
+    let response = run(
+      project.path(),
+      &encode(USER_SERVICE_SOURCE),
+      &user_service_path,
+      "com.example.common.Audited",
+      true,
+    )
+    .unwrap();
+    assert_eq!(
+      response.stubbed_method_names,
+      vec!["getCreatedAt", "isArchived", "revision", "touch", "history"]
+    );
+    assert!(response.warnings.is_empty());
+    let content = fs::read_to_string(&user_service_path).unwrap();
+    assert!(content.contains("public class UserService implements Audited {"));
+    assert!(content.contains(
+      "  private String name;\n\n  @Override\n  public Instant getCreatedAt() {\n    return null;\n  }\n\n"
+    ));
+    assert!(content.contains("  public boolean isArchived() {\n    return false;\n  }"));
+    assert!(content.contains("  public int revision() {\n    return 0;\n  }"));
+    assert!(content.contains("  public void touch(String reason) throws AuditException {\n  }"));
+    assert!(
+      content.contains("  public List<AuditEntry> history(int limit) {\n    return null;\n  }\n}")
+    );
+    assert!(!content.contains("describe"));
+    assert!(!content.contains("none()"));
+    for import in [
+      "com.example.common.Audited",
+      "com.example.common.AuditEntry",
+      "com.example.common.AuditException",
+      "java.time.Instant",
+      "java.util.List",
+    ] {
+      assert!(content.contains(&format!("import {};", import)), "missing import {}", import);
+    }
+  }
+
+  #[test]
+  fn test_skips_methods_already_declared() {
+    let source = r#"package com.example.service;
+
+public class UserService {
+
+  public boolean isArchived() {
+    return true;
+  }
+}
+"#;
+    let (project, user_service_path) = setup_user_service(source);
+    let response =
+      run(project.path(), &encode(source), &user_service_path, "com.example.common.Audited", true)
+        .unwrap();
+    assert!(!response.stubbed_method_names.contains(&"isArchived".to_string()));
+    let content = fs::read_to_string(&user_service_path).unwrap();
+    assert_eq!(content.matches("isArchived").count(), 1);
+  }
+
+  #[test]
+  fn test_warns_when_interface_source_is_missing() {
+    let (project, user_service_path) = setup_user_service(USER_SERVICE_SOURCE);
+    let response = run(
+      project.path(),
+      &encode(USER_SERVICE_SOURCE),
+      &user_service_path,
+      "java.util.function.Supplier",
+      true,
+    )
+    .unwrap();
+    assert!(response.stubbed_method_names.is_empty());
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Source of java.util.function.Supplier not found in the project; no methods were stubbed"
+      ]
+    );
+    let content = fs::read_to_string(&user_service_path).unwrap();
+    assert!(content.contains("public class UserService implements Supplier {"));
+  }
+}
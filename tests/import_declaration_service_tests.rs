@@ -638,6 +638,160 @@ public class Test {}"#;
     }
   }
 
+  // Tests for ImportInsertionPosition::Alphabetical
+  mod add_import_alphabetical_tests {
+    use super::*;
+
+    const SORTED_IMPORTS: &str = "package com.example;\n\nimport jakarta.persistence.Column;\nimport jakarta.persistence.Id;\nimport java.util.List;\n\npublic class Test {}";
+
+    fn add_sorted(package_scope: &str, class_name: &str) -> String {
+      let mut ts_file = TSFile::from_source_code(SORTED_IMPORTS);
+      let result =
+        add_import(&mut ts_file, &ImportInsertionPosition::Alphabetical, package_scope, class_name);
+      assert!(result.is_some(), "Should add the import");
+      ts_file.source_code
+    }
+
+    fn import_lines(source: &str) -> Vec<&str> {
+      source.lines().filter(|line| line.starts_with("import")).collect()
+    }
+
+    #[test]
+    fn test_inserts_into_middle_of_sorted_block() {
+      let source = add_sorted("jakarta.persistence", "Entity");
+      assert_eq!(
+        import_lines(&source),
+        vec![
+          "import jakarta.persistence.Column;",
+          "import jakarta.persistence.Entity;",
+          "import jakarta.persistence.Id;",
+          "import java.util.List;",
+        ]
+      );
+    }
+
+    #[test]
+    fn test_inserts_at_beginning_of_sorted_block() {
+      let source = add_sorted("com.example.domain", "User");
+      assert_eq!(import_lines(&source)[0], "import com.example.domain.User;");
+      assert!(source.contains("package com.example;\n\nimport com.example.domain.User;\n"));
+    }
+
+    #[test]
+    fn test_inserts_at_end_of_sorted_block() {
+      let source = add_sorted("java.util", "Set");
+      assert_eq!(import_lines(&source).last(), Some(&"import java.util.Set;"));
+      assert!(source.contains("import java.util.List;\nimport java.util.Set;\n\npublic class"));
+    }
+
+    #[test]
+    fn test_ignores_static_imports() {
+      let java_code = "package com.example;\n\nimport java.util.List;\nimport static org.junit.Assert.assertTrue;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+      add_import(&mut ts_file, &ImportInsertionPosition::Alphabetical, "java.util", "Map");
+      assert_eq!(
+        import_lines(&ts_file.source_code),
+        vec![
+          "import java.util.List;",
+          "import java.util.Map;",
+          "import static org.junit.Assert.assertTrue;",
+        ]
+      );
+    }
+  }
+
+  // Tests for remove_import function
+  mod remove_import_tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_single_type_import() {
+      let java_code = "package com.example;\n\nimport java.util.List;\nimport java.util.Map;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      let result = remove_import(&mut ts_file, "java.util", "List");
+
+      assert!(result.is_some(), "Should remove the import");
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport java.util.Map;\n\npublic class Test {}"
+      );
+    }
+
+    #[test]
+    fn test_does_not_remove_wildcard_import() {
+      let java_code = "package com.example;\n\nimport java.util.*;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      let result = remove_import(&mut ts_file, "java.util", "List");
+
+      assert!(result.is_none(), "Wildcard imports must be kept");
+      assert!(ts_file.source_code.contains("import java.util.*;"));
+    }
+  }
+
+  // Tests for deduplicate_imports function
+  mod deduplicate_imports_tests {
+    use super::*;
+
+    #[test]
+    fn test_removes_repeated_imports() {
+      let java_code = "package com.example;\n\nimport java.util.List;\nimport java.util.Map;\nimport java.util.List;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      assert_eq!(deduplicate_imports(&mut ts_file), 1);
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport java.util.List;\nimport java.util.Map;\n\npublic class Test {}"
+      );
+    }
+
+    #[test]
+    fn test_keeps_static_and_regular_imports_of_the_same_name() {
+      let java_code = "import java.util.Collections;\nimport static java.util.Collections;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      assert_eq!(deduplicate_imports(&mut ts_file), 0);
+      assert_eq!(ts_file.source_code, java_code);
+    }
+  }
+
+  // Tests for organize_imports function
+  mod organize_imports_tests {
+    use super::*;
+
+    #[test]
+    fn test_sorts_imports_with_static_imports_first() {
+      let java_code = "package com.example;\n\nimport java.util.Map;\nimport static org.junit.Assert.*;\nimport jakarta.persistence.Entity;\nimport java.util.Map;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      assert!(organize_imports(&mut ts_file));
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport static org.junit.Assert.*;\n\nimport jakarta.persistence.Entity;\nimport java.util.Map;\n\npublic class Test {}"
+      );
+    }
+
+    #[test]
+    fn test_sorted_imports_are_left_untouched() {
+      let java_code =
+        "import jakarta.persistence.Entity;\nimport java.util.List;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      assert!(!organize_imports(&mut ts_file));
+      assert_eq!(ts_file.source_code, java_code);
+    }
+
+    #[test]
+    fn test_imports_interleaved_with_comments_are_left_untouched() {
+      let java_code = "import java.util.Map;\n// Persistence\nimport jakarta.persistence.Entity;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code);
+
+      assert!(!organize_imports(&mut ts_file));
+      assert_eq!(ts_file.source_code, java_code);
+    }
+  }
+
   // Integration tests combining multiple functions
   mod integration_tests {
     use super::*;
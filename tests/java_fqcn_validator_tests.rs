@@ -0,0 +1,51 @@
+// Java FQCN Validator Integration Tests
+// This module contains tests for the validation of fully qualified class name arguments, such as
+// `--converter-class`, including the Java reserved word checks
+
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// Runs `find-jpa-entity-usages`, whose only other argument is the project, with `entity_type`
+fn run_with_fqcn(entity_type: &str) -> Output {
+  let project = TempDir::new().expect("Failed to create temp directory");
+  Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .args(["find-jpa-entity-usages", "--cwd", project.path().to_str().unwrap()])
+    .args(["--entity-type", entity_type])
+    .output()
+    .expect("Failed to run syntaxpresso-core")
+}
+
+fn assert_valid(fqcn: &str) {
+  let output = run_with_fqcn(fqcn);
+  assert!(output.status.success(), "'{}' should be valid: {:?}", fqcn, output);
+}
+
+fn assert_invalid(fqcn: &str, reason: &str) {
+  let output = run_with_fqcn(fqcn);
+  assert!(!output.status.success(), "'{}' should be invalid", fqcn);
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(stderr.contains(reason), "Expected reason containing '{}', got '{}'", reason, stderr);
+}
+
+#[test]
+fn test_accepts_fully_qualified_class_names() {
+  assert_valid("com.example.converter.MoneyConverter");
+  assert_valid("com.example.Order$Line");
+  assert_valid("com.exämple._internal.Converter2");
+}
+
+#[test]
+fn test_rejects_malformed_names() {
+  assert_invalid(" ", "cannot be empty");
+  assert_invalid("MoneyConverter", "must include its package");
+  assert_invalid("com..MoneyConverter", "not a well-formed");
+  assert_invalid("com.example.1Converter", "not a well-formed");
+  assert_invalid("com.example.Money-Converter", "not a well-formed");
+}
+
+#[test]
+fn test_rejects_reserved_word_segments() {
+  assert_invalid("com.example.class.MoneyConverter", "'class' conflicts with a Java reserved word");
+  assert_invalid("com.example.null", "'null' conflicts with a Java reserved word");
+  assert_invalid("com.enum.Status", "'enum' conflicts with a Java reserved word");
+}
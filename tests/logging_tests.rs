@@ -0,0 +1,101 @@
+// Logging Integration Tests
+// This module contains tests for the --verbose and --quiet options of the binary, which must
+// keep stdout a single JSON response
+
+mod common;
+
+use base64::Engine;
+use common::setup_domain_file;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  private Long id;
+}
+"#;
+
+fn run_binary(args: &[&str]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .args(args)
+    .output()
+    .expect("Failed to run syntaxpresso-core")
+}
+
+fn parse_stdout(output: &Output) -> serde_json::Value {
+  serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+    panic!("stdout is not valid JSON ({}):\n{}", e, String::from_utf8_lossy(&output.stdout))
+  })
+}
+
+fn add_field_args<'a>(cwd: &'a Path, entity_path: &'a Path, b64_source: &'a str) -> Vec<String> {
+  vec![
+    "create-jpa-entity-basic-field".to_string(),
+    "--cwd".to_string(),
+    cwd.display().to_string(),
+    "--entity-file-path".to_string(),
+    entity_path.display().to_string(),
+    "--entity-file-b64-src".to_string(),
+    b64_source.to_string(),
+    "--field-name".to_string(),
+    "createdAt".to_string(),
+    "--field-type".to_string(),
+    "LocalDateTime".to_string(),
+    "--field-type-package-name".to_string(),
+    "java.time".to_string(),
+  ]
+}
+
+#[test]
+fn test_verbose_keeps_stdout_valid_json() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  let mut args = add_field_args(temp_dir.path(), &entity_path, &b64_source);
+  args.push("--verbose".to_string());
+  let output = run_binary(&args.iter().map(String::as_str).collect::<Vec<_>>());
+
+  let response = parse_stdout(&output);
+  assert_eq!(response["succeed"], true);
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(stderr.contains("adding import"), "stderr:\n{}", stderr);
+  assert!(stderr.contains("class=\"LocalDateTime\""), "stderr:\n{}", stderr);
+  assert!(stderr.contains("saved file"), "stderr:\n{}", stderr);
+  assert!(stderr.contains("command finished"), "stderr:\n{}", stderr);
+}
+
+#[test]
+fn test_verbose_logs_scanned_files() {
+  let (temp_dir, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let cwd = temp_dir.path().display().to_string();
+  let output = run_binary(&["get-all-jpa-entities", "--cwd", &cwd, "--verbose"]);
+
+  let response = parse_stdout(&output);
+  assert_eq!(response["data"]["filesCount"], 1);
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(stderr.contains("scanning file"), "stderr:\n{}", stderr);
+  assert!(stderr.contains("User.java"), "stderr:\n{}", stderr);
+}
+
+#[test]
+fn test_quiet_and_default_write_nothing_to_stderr() {
+  let (temp_dir, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let cwd = temp_dir.path().display().to_string();
+  for extra_args in [vec!["--quiet"], vec![]] {
+    let mut args = vec!["get-all-jpa-entities", "--cwd", &cwd];
+    args.extend(extra_args);
+    let output = run_binary(&args);
+    parse_stdout(&output);
+    assert!(output.stderr.is_empty(), "stderr:\n{}", String::from_utf8_lossy(&output.stderr));
+  }
+}
+
+#[test]
+fn test_verbose_and_quiet_conflict() {
+  let output = run_binary(&["get-relationship-options", "--verbose", "--quiet"]);
+  assert!(!output.status.success());
+  assert!(output.stdout.is_empty());
+}
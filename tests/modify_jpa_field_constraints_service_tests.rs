@@ -0,0 +1,68 @@
+// Modify JPA Field Constraints Service Integration Tests
+// This module contains tests for updating nullable and unique on existing entity fields
+
+use base64::Engine;
+use std::fs;
+use syntaxpresso_core::commands::services::modify_jpa_field_constraints_service::run;
+use syntaxpresso_core::common::types::persistence_namespace::PersistenceNamespace;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.catalog;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+
+@Entity
+public class Product {
+
+  @Column(name = "name", nullable = true)
+  private String name;
+
+  @Column(length = 20)
+  private String code;
+
+  private String description;
+}
+"#;
+
+/// Runs the service against a temporary copy of the entity and returns the new source
+fn modify_constraints(
+  field_name: &str,
+  nullable: Option<bool>,
+  unique: Option<bool>,
+) -> Result<String, String> {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let entity_path = temp_dir.path().join("Product.java");
+  fs::write(&entity_path, ENTITY_SOURCE).unwrap();
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  let response =
+    run(&b64_source, &entity_path, field_name, nullable, unique, &PersistenceNamespace::default())?;
+  assert_eq!(response.file_package_name, "com.example.catalog");
+  Ok(fs::read_to_string(&entity_path).unwrap())
+}
+
+#[test]
+fn test_flips_nullable_in_place() {
+  let source = modify_constraints("name", Some(false), None).unwrap();
+  assert!(source.contains("  @Column(name = \"name\", nullable = false)\n  private String name;"));
+}
+
+#[test]
+fn test_adds_unique_next_to_length() {
+  let source = modify_constraints("code", None, Some(true)).unwrap();
+  assert!(source.contains("  @Column(length = 20, unique = true)\n  private String code;"));
+}
+
+#[test]
+fn test_adds_column_when_absent() {
+  let source = modify_constraints("description", Some(false), Some(true)).unwrap();
+  assert!(
+    source.contains("  @Column(nullable = false, unique = true)\n  private String description;")
+  );
+}
+
+#[test]
+fn test_requires_a_constraint() {
+  let error = modify_constraints("name", None, None).expect_err("Change should fail");
+  assert_eq!(error, "At least one of nullable or unique must be given");
+}
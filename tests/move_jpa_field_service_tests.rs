@@ -0,0 +1,235 @@
+// Move JPA Field Service Integration Tests
+// This module contains tests for moving entity fields and their accessors into a mapped superclass
+
+mod common;
+
+use common::{encode, setup_project, source_path};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::move_jpa_field_service::run;
+use syntaxpresso_core::common::services::formatting_service::set_member_spacing;
+use tempfile::TempDir;
+
+const USER_SOURCE: &str = r#"package com.example.domain;
+
+import com.example.common.BaseEntity;
+import jakarta.persistence.Entity;
+import jakarta.persistence.GeneratedValue;
+import jakarta.persistence.Id;
+
+@Entity
+public class User extends BaseEntity {
+
+  /** Primary key */
+  @Id
+  @GeneratedValue
+  private Long id;
+
+  private String name;
+
+  public Long getId() {
+    return id;
+  }
+
+  public void setId(Long id) {
+    this.id = id;
+  }
+
+  public String getName() {
+    return name;
+  }
+}
+"#;
+
+const BASE_ENTITY_SOURCE: &str = r#"package com.example.common;
+
+import jakarta.persistence.MappedSuperclass;
+import java.time.Instant;
+
+@MappedSuperclass
+public abstract class BaseEntity {
+
+  private Instant createdAt;
+}
+"#;
+
+/// Creates a project with `User` in `com.example.domain` extending `BaseEntity` from
+/// `com.example.common`
+fn setup_user_and_base_entity(
+  user_source: &str,
+  base_entity_source: &str,
+) -> (TempDir, PathBuf, PathBuf) {
+  let project = setup_project(&[
+    ("com/example/domain/User.java", user_source),
+    ("com/example/common/BaseEntity.java", base_entity_source),
+  ]);
+  let user_path = source_path(&project, "com/example/domain/User.java");
+  let base_entity_path = source_path(&project, "com/example/common/BaseEntity.java");
+  (project, user_path, base_entity_path)
+}
+
+#[test]
+fn test_moves_id_field_with_accessors_and_imports() {
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(USER_SOURCE, BASE_ENTITY_SOURCE);
+  let response =
+    run(project.path(), &encode(USER_SOURCE), &user_path, &base_entity_path, "id", false).unwrap();
+  assert_eq!(response.moved_field_name, "id");
+  assert_eq!(response.moved_method_names, vec!["getId", "setId"]);
+  assert!(!response.entity.edits.is_empty());
+  assert!(!response.superclass.edits.is_empty());
+  assert_eq!(
+    fs::read_to_string(&user_path).unwrap(),
+    r#"package com.example.domain;
+
+import com.example.common.BaseEntity;
+import jakarta.persistence.Entity;
+
+@Entity
+public class User extends BaseEntity {
+
+  private String name;
+
+  public String getName() {
+    return name;
+  }
+}
+"#
+  );
+  assert_eq!(
+    fs::read_to_string(&base_entity_path).unwrap(),
+    r#"package com.example.common;
+
+import jakarta.persistence.GeneratedValue;
+import jakarta.persistence.Id;
+import jakarta.persistence.MappedSuperclass;
+import java.time.Instant;
+
+@MappedSuperclass
+public abstract class BaseEntity {
+
+  private Instant createdAt;
+
+  /** Primary key */
+  @Id
+  @GeneratedValue
+  private Long id;
+
+  public Long getId() {
+    return id;
+  }
+
+  public void setId(Long id) {
+    this.id = id;
+  }
+}
+"#
+  );
+}
+
+#[test]
+fn test_moves_into_an_empty_superclass() {
+  let base_entity_source = "package com.example.common;\n\nimport jakarta.persistence.MappedSuperclass;\n\n@MappedSuperclass\npublic abstract class BaseEntity {}\n";
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(USER_SOURCE, base_entity_source);
+  run(project.path(), &encode(USER_SOURCE), &user_path, &base_entity_path, "name", false).unwrap();
+  let base_entity = fs::read_to_string(&base_entity_path).unwrap();
+  assert!(
+    base_entity.ends_with(
+      "public abstract class BaseEntity {\n  private String name;\n\n  public String getName() {\n    return name;\n  }\n}\n"
+    ),
+    "Superclass:\n{}",
+    base_entity
+  );
+  assert!(!fs::read_to_string(&user_path).unwrap().contains("getName"));
+}
+
+#[test]
+fn test_imports_classes_of_the_entity_package() {
+  let user_source = "package com.example.domain;\n\nimport com.example.common.BaseEntity;\n\npublic class User extends BaseEntity {\n\n  private Status status;\n}\n";
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(user_source, BASE_ENTITY_SOURCE);
+  fs::write(
+    user_path.with_file_name("Status.java"),
+    "package com.example.domain;\n\npublic enum Status { ACTIVE }\n",
+  )
+  .unwrap();
+  run(project.path(), &encode(user_source), &user_path, &base_entity_path, "status", false)
+    .unwrap();
+  let base_entity = fs::read_to_string(&base_entity_path).unwrap();
+  assert!(
+    base_entity.contains("import com.example.domain.Status;"),
+    "Superclass:\n{}",
+    base_entity
+  );
+  assert!(base_entity.contains("  private Status status;\n}"));
+}
+
+#[test]
+fn test_entity_must_extend_the_superclass() {
+  let user_source = USER_SOURCE.replace(" extends BaseEntity", "");
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(&user_source, BASE_ENTITY_SOURCE);
+  let error =
+    run(project.path(), &encode(&user_source), &user_path, &base_entity_path, "id", false)
+      .err()
+      .expect("Move should be rejected");
+  assert_eq!(error, "User does not extend BaseEntity");
+  assert_eq!(fs::read_to_string(&user_path).unwrap(), user_source);
+  assert_eq!(fs::read_to_string(&base_entity_path).unwrap(), BASE_ENTITY_SOURCE);
+}
+
+#[test]
+fn test_superclass_of_another_package_is_rejected() {
+  let user_source = USER_SOURCE.replace("import com.example.common.BaseEntity;\n", "");
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(&user_source, BASE_ENTITY_SOURCE);
+  let error =
+    run(project.path(), &encode(&user_source), &user_path, &base_entity_path, "id", false)
+      .err()
+      .expect("Move should be rejected");
+  assert_eq!(error, "User does not extend BaseEntity");
+}
+
+#[test]
+fn test_field_already_declared_in_superclass_is_rejected() {
+  let base_entity_source = BASE_ENTITY_SOURCE.replace("createdAt", "name");
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(USER_SOURCE, &base_entity_source);
+  let error =
+    run(project.path(), &encode(USER_SOURCE), &user_path, &base_entity_path, "name", false)
+      .err()
+      .expect("Move should be rejected");
+  assert_eq!(error, "field 'name' already exists");
+  assert_eq!(fs::read_to_string(&user_path).unwrap(), USER_SOURCE);
+}
+
+#[test]
+fn test_unknown_field_is_rejected() {
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(USER_SOURCE, BASE_ENTITY_SOURCE);
+  let error =
+    run(project.path(), &encode(USER_SOURCE), &user_path, &base_entity_path, "email", false)
+      .err()
+      .expect("Move should be rejected");
+  assert_eq!(error, "Field not found: email");
+}
+
+#[test]
+fn test_member_spacing_applies_to_moved_members() {
+  let (project, user_path, base_entity_path) =
+    setup_user_and_base_entity(USER_SOURCE, BASE_ENTITY_SOURCE);
+  set_member_spacing(0);
+  let result =
+    run(project.path(), &encode(USER_SOURCE), &user_path, &base_entity_path, "id", false);
+  set_member_spacing(1);
+  result.unwrap();
+  let base_entity_source = fs::read_to_string(&base_entity_path).unwrap();
+  assert!(
+    base_entity_source.contains(
+      "  private Instant createdAt;\n  /** Primary key */\n  @Id\n  @GeneratedValue\n  private Long id;\n  public Long getId() {\n    return id;\n  }\n  public void setId(Long id) {\n    this.id = id;\n  }\n}"
+    ),
+    "Moved members:\n{}",
+    base_entity_source
+  );
+}
@@ -0,0 +1,102 @@
+// Naming Util Integration Tests
+// This module contains tests for the name case conversions shared by the generators and the TUI
+
+use syntaxpresso_core::commands::services::convert_name_service::run;
+use syntaxpresso_core::common::types::name_case::NameCase;
+use syntaxpresso_core::common::utils::naming_util::*;
+
+#[test]
+fn test_split_words_keeps_acronyms_together() {
+  assert_eq!(split_words("HTTPServer"), vec!["HTTP", "Server"]);
+  assert_eq!(split_words("userIDs"), vec!["user", "IDs"]);
+  assert_eq!(split_words("parsedURLValue"), vec!["parsed", "URL", "Value"]);
+  assert_eq!(split_words("order_item-id"), vec!["order", "item", "id"]);
+  assert!(split_words("__").is_empty());
+}
+
+#[test]
+fn test_to_snake_case() {
+  assert_eq!(to_snake_case("HTTPServer"), "http_server");
+  assert_eq!(to_snake_case("firstName"), "first_name");
+  assert_eq!(to_snake_case("OrderItem"), "order_item");
+  assert_eq!(to_snake_case("address2Line"), "address2_line");
+  assert_eq!(to_snake_case("already_snake"), "already_snake");
+}
+
+#[test]
+fn test_to_screaming_snake_case() {
+  assert_eq!(to_screaming_snake_case("HTTPServer"), "HTTP_SERVER");
+  assert_eq!(to_screaming_snake_case("maxRetryCount"), "MAX_RETRY_COUNT");
+}
+
+#[test]
+fn test_to_kebab_case() {
+  assert_eq!(to_kebab_case("HTTPServer"), "http-server");
+  assert_eq!(to_kebab_case("order_item"), "order-item");
+}
+
+#[test]
+fn test_to_pascal_case() {
+  assert_eq!(to_pascal_case("http_server"), "HttpServer");
+  assert_eq!(to_pascal_case("HTTPServer"), "HttpServer");
+  assert_eq!(to_pascal_case("orderItem"), "OrderItem");
+  assert_eq!(to_pascal_case("ORDER_ITEM"), "OrderItem");
+}
+
+#[test]
+fn test_to_camel_case() {
+  assert_eq!(to_camel_case("HTTPServer"), "httpServer");
+  assert_eq!(to_camel_case("order_item"), "orderItem");
+  assert_eq!(to_camel_case("OrderItem"), "orderItem");
+  assert_eq!(to_camel_case("URL"), "url");
+}
+
+#[test]
+fn test_ensure_camel_case_keeps_camel_case_names() {
+  assert_eq!(ensure_camel_case("userID"), "userID");
+  assert_eq!(ensure_camel_case("email"), "email");
+  assert_eq!(ensure_camel_case("first_name"), "firstName");
+  assert_eq!(ensure_camel_case("FirstName"), "firstName");
+}
+
+#[test]
+fn test_ensure_pascal_case_keeps_pascal_case_names() {
+  assert_eq!(ensure_pascal_case("HTTPServer"), "HTTPServer");
+  assert_eq!(ensure_pascal_case("order_item"), "OrderItem");
+  assert_eq!(ensure_pascal_case("orderItem"), "OrderItem");
+  assert_eq!(ensure_pascal_case("ORDER"), "Order");
+}
+
+#[test]
+fn test_field_names_for_types() {
+  assert_eq!(field_name_for_type("UserProfile"), "userProfile");
+  assert_eq!(field_name_for_type("URLMapping"), "urlMapping");
+  assert_eq!(field_name_for_type(""), "");
+  assert_eq!(collection_field_name_for_type("Order", "List"), "orderList");
+  assert_eq!(collection_field_name_for_type("", "List"), "");
+}
+
+#[test]
+fn test_table_name_for_entity() {
+  assert_eq!(table_name_for_entity("OrderItem"), "order_item");
+  assert_eq!(table_name_for_entity("HTTPServer"), "http_server");
+}
+
+mod convert_name_service_tests {
+  use super::*;
+
+  #[test]
+  fn test_converts_to_target_case() {
+    let response = run("HTTPServer", &NameCase::Snake).unwrap();
+    assert_eq!(response.converted_name, "http_server");
+    assert_eq!(response.words, vec!["HTTP", "Server"]);
+    assert_eq!(run("http_server", &NameCase::Pascal).unwrap().converted_name, "HttpServer");
+    assert_eq!(run("http_server", &NameCase::Camel).unwrap().converted_name, "httpServer");
+  }
+
+  #[test]
+  fn test_rejects_names_without_words() {
+    let error = run(" _ ", &NameCase::Camel).expect_err("Empty name should be rejected");
+    assert_eq!(error, "Name must contain at least one word");
+  }
+}
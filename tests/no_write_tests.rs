@@ -0,0 +1,108 @@
+// No-Write Integration Tests
+// This module contains tests for the global --no-write option, which runs a command's full
+// validation without touching the project or returning generated content
+
+mod common;
+
+use base64::Engine;
+use common::setup_domain_file;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  private Long id;
+
+  private String email;
+}
+"#;
+
+fn run_binary(args: &[String]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .args(args)
+    .output()
+    .expect("Failed to run syntaxpresso-core")
+}
+
+fn parse_stdout(output: &Output) -> serde_json::Value {
+  serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+    panic!("stdout is not valid JSON ({}):\n{}", e, String::from_utf8_lossy(&output.stdout))
+  })
+}
+
+fn add_field_args(cwd: &Path, entity_path: &Path, field_name: &str) -> Vec<String> {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  vec![
+    "create-jpa-entity-basic-field".to_string(),
+    "--cwd".to_string(),
+    cwd.display().to_string(),
+    "--entity-file-path".to_string(),
+    entity_path.display().to_string(),
+    "--entity-file-b64-src".to_string(),
+    b64_source,
+    "--field-name".to_string(),
+    field_name.to_string(),
+    "--field-type".to_string(),
+    "String".to_string(),
+    "--field-type-package-name".to_string(),
+    "java.lang".to_string(),
+    "--no-write".to_string(),
+  ]
+}
+
+fn directory_entries(dir: &Path) -> Vec<String> {
+  let mut entries: Vec<String> = fs::read_dir(dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+    .collect();
+  entries.sort();
+  entries
+}
+
+#[test]
+fn test_no_write_validates_valid_field_without_saving() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary(&add_field_args(temp_dir.path(), &entity_path, "firstName"));
+
+  let response = parse_stdout(&output);
+  assert_eq!(response["succeed"], true);
+  assert!(response.get("data").is_none(), "response: {}", response);
+  assert!(response["warnings"].is_array());
+  assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+  assert_eq!(directory_entries(entity_path.parent().unwrap()), vec!["User.java"]);
+}
+
+#[test]
+fn test_no_write_reports_duplicate_field() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary(&add_field_args(temp_dir.path(), &entity_path, "email"));
+
+  let response = parse_stdout(&output);
+  assert_eq!(response["succeed"], false);
+  let error = response["errorReason"].as_str().unwrap();
+  assert!(error.contains("field 'email' already exists"), "error: {}", error);
+  assert_eq!(fs::read_to_string(&entity_path).unwrap(), ENTITY_SOURCE);
+}
+
+#[test]
+fn test_no_write_does_not_delete_files() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let args = vec![
+    "delete-java-file".to_string(),
+    "--cwd".to_string(),
+    temp_dir.path().display().to_string(),
+    "--file-path".to_string(),
+    entity_path.display().to_string(),
+    "--no-write".to_string(),
+  ];
+  let output = run_binary(&args);
+
+  let response = parse_stdout(&output);
+  assert_eq!(response["succeed"], true, "response: {}", response);
+  assert!(entity_path.exists());
+}
@@ -318,6 +318,119 @@ mod path_security_attack_tests {
   }
 }
 
+mod path_normalization_tests {
+  use super::*;
+
+  #[test]
+  fn test_normalize_strips_verbatim_prefix() {
+    assert_eq!(normalize_windows_path(r"\\?\C:\Users\dev\project"), r"C:\Users\dev\project");
+  }
+
+  #[test]
+  fn test_normalize_strips_verbatim_unc_prefix() {
+    assert_eq!(normalize_windows_path(r"\\?\UNC\server\share\project"), r"\\server\share\project");
+  }
+
+  #[test]
+  fn test_normalize_uppercases_drive_letter() {
+    assert_eq!(normalize_windows_path(r"c:\Users\dev"), r"C:\Users\dev");
+  }
+
+  #[test]
+  fn test_normalize_converts_forward_slashes() {
+    assert_eq!(normalize_windows_path("C:/Users/dev/src/file.txt"), r"C:\Users\dev\src\file.txt");
+  }
+
+  #[test]
+  fn test_normalize_leaves_relative_paths_untouched() {
+    assert_eq!(normalize_windows_path(r"src\file.txt"), r"src\file.txt");
+    assert_eq!(normalize_windows_path(r"..\root_file.txt"), r"..\root_file.txt");
+  }
+}
+
+#[cfg(windows)]
+mod path_security_windows_tests {
+  use super::*;
+
+  #[test]
+  fn test_prevent_backslash_parent_directory_traversal() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(&temp_dir.path().join("src")).unwrap();
+
+    let result = validator.validate_path_containment(Path::new(r"..\root_file.txt"));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("outside allowed directory"));
+  }
+
+  #[test]
+  fn test_prevent_backslash_multiple_parent_directory_traversal() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(&temp_dir.path().join("src\\nested")).unwrap();
+
+    let result = validator.validate_path_containment(Path::new(r"..\..\root_file.txt"));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("outside allowed directory"));
+  }
+
+  #[test]
+  fn test_prevent_mixed_separator_traversal_attack() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(&temp_dir.path().join("src")).unwrap();
+
+    let result = validator.validate_path_containment(Path::new(r"nested/..\..\root_file.txt"));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("outside allowed directory"));
+  }
+
+  #[test]
+  fn test_prevent_absolute_drive_path_outside_base() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(temp_dir.path()).unwrap();
+
+    let result =
+      validator.validate_path_containment(Path::new(r"C:\Windows\System32\drivers\etc\hosts"));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("outside allowed directory"));
+  }
+
+  #[test]
+  fn test_allow_absolute_path_with_lowercase_drive_letter() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(temp_dir.path()).unwrap();
+
+    let canonical = fs::canonicalize(temp_dir.path().join("src\\file.txt")).unwrap();
+    let normalized = normalize_windows_path(&canonical.to_string_lossy());
+    let lowercase = format!("{}{}", normalized[..1].to_lowercase(), &normalized[1..]);
+    let result = validator.validate_path_containment(Path::new(&lowercase));
+    assert!(result.is_ok(), "Lowercase drive letter should be allowed: {}", lowercase);
+  }
+
+  #[test]
+  fn test_allow_verbatim_prefixed_path_within_base() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(temp_dir.path()).unwrap();
+
+    let verbatim = fs::canonicalize(temp_dir.path().join("src\\file.txt")).unwrap();
+    assert!(verbatim.to_string_lossy().starts_with(r"\\?\"));
+    let result = validator.validate_path_containment(&verbatim);
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn test_allow_legitimate_backslash_relative_paths() {
+    let temp_dir = setup_test_directory();
+    let validator = PathSecurityValidator::new(temp_dir.path()).unwrap();
+
+    let safe_paths =
+      vec![r"src\file.txt", r"src\nested\deep.txt", r".\src\file.txt", r"src\nested\..\file.txt"];
+
+    for path in safe_paths {
+      let result = validator.validate_path_containment(Path::new(path));
+      assert!(result.is_ok(), "Safe path should be allowed: {}", path);
+    }
+  }
+}
+
 #[cfg(unix)]
 mod path_security_symlink_tests {
   use super::*;
@@ -0,0 +1,337 @@
+// Project Layout Util Integration Tests
+// This module contains tests for build tool detection and source root resolution
+
+mod common;
+
+use common::setup_build_project;
+use std::fs;
+use syntaxpresso_core::common::types::build_tool_type::BuildToolType;
+use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use syntaxpresso_core::common::utils::project_layout_util::*;
+use tempfile::TempDir;
+
+mod build_tool_detection_tests {
+  use super::*;
+
+  #[test]
+  fn test_detects_maven_project() {
+    let project = setup_build_project(Some(("pom.xml", "<project></project>")));
+    assert_eq!(BuildToolType::detect(project.path()), BuildToolType::Maven);
+  }
+
+  #[test]
+  fn test_detects_gradle_groovy_project() {
+    let project = setup_build_project(Some(("build.gradle", "plugins { id 'java' }")));
+    assert_eq!(BuildToolType::detect(project.path()), BuildToolType::Gradle);
+  }
+
+  #[test]
+  fn test_detects_gradle_kotlin_project() {
+    let project = setup_build_project(Some(("build.gradle.kts", "plugins { java }")));
+    assert_eq!(BuildToolType::detect(project.path()), BuildToolType::GradleKotlin);
+  }
+
+  #[test]
+  fn test_unknown_without_build_file() {
+    let project = setup_build_project(None);
+    assert_eq!(BuildToolType::detect(project.path()), BuildToolType::Unknown);
+  }
+}
+
+mod resolve_source_root_tests {
+  use super::*;
+
+  #[test]
+  fn test_falls_back_to_standard_layout_without_build_file() {
+    let project = setup_build_project(None);
+    let main_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    let test_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Test);
+    assert_eq!(main_root, project.path().join("src/main/java"));
+    assert_eq!(test_root, project.path().join("src/test/java"));
+  }
+
+  #[test]
+  fn test_maven_standard_layout() {
+    let project = setup_build_project(Some(("pom.xml", "<project><build></build></project>")));
+    let root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    assert_eq!(root, project.path().join("src/main/java"));
+  }
+
+  #[test]
+  fn test_maven_custom_source_directories() {
+    let pom = r#"<project>
+  <build>
+    <sourceDirectory>${project.basedir}/src/java</sourceDirectory>
+    <testSourceDirectory>tests/java</testSourceDirectory>
+  </build>
+</project>"#;
+    let project = setup_build_project(Some(("pom.xml", pom)));
+    let main_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    let test_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Test);
+    assert_eq!(main_root, project.path().join("src/java"));
+    assert_eq!(test_root, project.path().join("tests/java"));
+  }
+
+  #[test]
+  fn test_gradle_standard_layout() {
+    let build =
+      "plugins {\n  id 'java'\n}\n\ndependencies {\n  testImplementation 'junit:junit:4.13'\n}\n";
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    assert_eq!(root, project.path().join("src/main/java"));
+  }
+
+  #[test]
+  fn test_gradle_groovy_source_sets_block() {
+    let build = r#"
+sourceSets {
+  main {
+    java {
+      srcDirs = ['src/java']
+    }
+    resources {
+      srcDirs = ['src/resources']
+    }
+  }
+  test {
+    java.srcDir 'test/java'
+  }
+}
+"#;
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let main_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    let test_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Test);
+    assert_eq!(main_root, project.path().join("src/java"));
+    assert_eq!(test_root, project.path().join("test/java"));
+  }
+
+  #[test]
+  fn test_gradle_kotlin_named_source_set() {
+    let build = r#"
+sourceSets {
+  named("main") {
+    java.setSrcDirs(listOf("app/java"))
+  }
+}
+"#;
+    let project = setup_build_project(Some(("build.gradle.kts", build)));
+    let root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    assert_eq!(root, project.path().join("app/java"));
+  }
+
+  #[test]
+  fn test_gradle_dotted_source_set() {
+    let build = "sourceSets.main.java.srcDirs = ['java']\n";
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let main_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    let test_root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Test);
+    assert_eq!(main_root, project.path().join("java"));
+    assert_eq!(test_root, project.path().join("src/test/java"));
+  }
+
+  #[test]
+  fn test_gradle_source_set_with_non_ascii_text() {
+    let build =
+      "sourceSets {\n  main {\n    java { /* İİİİİİİİİİİİ */ srcDirs = ['src/java'] }\n  }\n}\n";
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let root = resolve_source_root(project.path(), &JavaSourceDirectoryType::Main);
+    assert_eq!(root, project.path().join("src/java"));
+  }
+
+  #[test]
+  fn test_all_source_directory_ignores_build_file() {
+    let build = "sourceSets.main.java.srcDirs = ['java']\n";
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let root = resolve_source_root(project.path(), &JavaSourceDirectoryType::All);
+    assert_eq!(root, project.path().join("src"));
+  }
+}
+
+mod source_root_consumers_tests {
+  use super::*;
+  use syntaxpresso_core::commands::services::{create_java_file_service, get_all_packages_service};
+  use syntaxpresso_core::common::types::java_file_type::JavaFileType;
+
+  #[test]
+  fn test_get_all_packages_uses_custom_source_root() {
+    let build = "sourceSets { main { java { srcDirs = ['src/java'] } } }\n";
+    let project = setup_build_project(Some(("build.gradle", build)));
+    let package_dir = project.path().join("src/java/com/example/app");
+    fs::create_dir_all(package_dir.join("domain")).unwrap();
+    fs::write(package_dir.join("App.java"), "package com.example.app;\n\npublic class App {}")
+      .unwrap();
+    let packages =
+      get_all_packages_service::run(project.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+    let mut names: Vec<_> = packages.into_iter().map(|p| p.package_name).collect();
+    names.sort();
+    assert_eq!(names, vec!["com.example.app", "com.example.app.domain"]);
+  }
+
+  #[test]
+  fn test_create_java_file_uses_custom_source_root() {
+    let pom = "<project><build><sourceDirectory>src/java</sourceDirectory></build></project>";
+    let project = setup_build_project(Some(("pom.xml", pom)));
+    let response = create_java_file_service::run(
+      project.path(),
+      "com.example",
+      "Customer",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      false,
+    )
+    .unwrap();
+    assert!(response.file_path.ends_with("src/java/com/example/Customer.java"));
+    assert!(project.path().join("src/java/com/example/Customer.java").exists());
+  }
+}
+
+mod project_modules_tests {
+  use super::*;
+  use syntaxpresso_core::commands::services::{
+    get_all_jpa_entities_service, get_all_jpa_mapped_superclasses, get_all_packages_service,
+    get_java_files_service,
+  };
+  use syntaxpresso_core::common::types::java_file_type::JavaFileType;
+
+  /// Creates a multi-module Maven project with `api`, `core` and `web` modules
+  fn setup_multi_module_project() -> TempDir {
+    let project = setup_build_project(Some(("pom.xml", "<project><modules></modules></project>")));
+    fs::remove_dir_all(project.path().join("src")).unwrap();
+    for module in ["api", "core", "web"] {
+      let module_dir = project.path().join(module);
+      let package_dir = module_dir.join(format!("src/main/java/com/example/{}", module));
+      fs::create_dir_all(&package_dir).unwrap();
+      fs::write(module_dir.join("pom.xml"), "<project></project>").unwrap();
+      let class_name = format!("{}Service", module.to_uppercase());
+      fs::write(
+        package_dir.join(format!("{}.java", class_name)),
+        format!("package com.example.{};\n\npublic class {} {{}}", module, class_name),
+      )
+      .unwrap();
+    }
+    // Build output must never be mistaken for a module
+    fs::create_dir_all(project.path().join("web/target/classes")).unwrap();
+    fs::write(project.path().join("web/target/classes/pom.xml"), "<project></project>").unwrap();
+    project
+  }
+
+  #[test]
+  fn test_find_project_modules() {
+    let project = setup_multi_module_project();
+    let modules = find_project_modules(project.path());
+    let names: Vec<_> = modules.iter().filter_map(|m| m.name.as_deref()).collect();
+    assert_eq!(names, vec!["api", "core", "web"]);
+    assert_eq!(modules[0].path, project.path().join("api"));
+  }
+
+  #[test]
+  fn test_single_module_project_has_no_sub_modules() {
+    let project = setup_build_project(Some(("pom.xml", "<project></project>")));
+    assert!(find_project_modules(project.path()).is_empty());
+    let selected = select_project_modules(project.path(), None).unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].name, None);
+  }
+
+  #[test]
+  fn test_select_project_modules_with_filter() {
+    let project = setup_multi_module_project();
+    let selected = select_project_modules(project.path(), Some(":core")).unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].name.as_deref(), Some("core"));
+    let missing = select_project_modules(project.path(), Some("batch"));
+    assert_eq!(missing.unwrap_err(), "Module not found: batch");
+  }
+
+  #[test]
+  fn test_get_all_packages_aggregates_modules() {
+    let project = setup_multi_module_project();
+    let packages =
+      get_all_packages_service::run(project.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+    let mut entries: Vec<_> =
+      packages.into_iter().map(|p| (p.module.unwrap_or_default(), p.package_name)).collect();
+    entries.sort();
+    assert_eq!(
+      entries,
+      vec![
+        ("api".to_string(), "com.example.api".to_string()),
+        ("core".to_string(), "com.example.core".to_string()),
+        ("web".to_string(), "com.example.web".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_get_java_files_scoped_to_module() {
+    let project = setup_multi_module_project();
+    let files =
+      get_java_files_service::run(project.path(), &JavaFileType::Class, Some("web")).unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].file_type, "WEBService");
+    assert_eq!(files[0].module.as_deref(), Some("web"));
+  }
+
+  /// Adds an `@Entity` and a `@MappedSuperclass` to the `core` and `web` modules
+  fn add_jpa_classes(project: &TempDir) {
+    for module in ["core", "web"] {
+      let package_dir =
+        project.path().join(format!("{}/src/main/java/com/example/{}", module, module));
+      let entity_name = format!("{}Entity", module.to_uppercase());
+      fs::write(
+        package_dir.join(format!("{}.java", entity_name)),
+        format!("package com.example.{};\n\n@Entity\npublic class {} {{}}", module, entity_name),
+      )
+      .unwrap();
+      let superclass_name = format!("{}Base", module.to_uppercase());
+      fs::write(
+        package_dir.join(format!("{}.java", superclass_name)),
+        format!(
+          "package com.example.{};\n\n@MappedSuperclass\npublic abstract class {} {{}}",
+          module, superclass_name
+        ),
+      )
+      .unwrap();
+    }
+  }
+
+  #[test]
+  fn test_get_all_jpa_entities_aggregates_modules() {
+    let project = setup_multi_module_project();
+    add_jpa_classes(&project);
+    let mut entities: Vec<_> = get_all_jpa_entities_service::run(project.path(), None)
+      .unwrap()
+      .into_iter()
+      .map(|f| (f.module.unwrap_or_default(), f.file_type))
+      .collect();
+    entities.sort();
+    assert_eq!(
+      entities,
+      vec![
+        ("core".to_string(), "COREEntity".to_string()),
+        ("web".to_string(), "WEBEntity".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_get_all_jpa_entities_scoped_to_module() {
+    let project = setup_multi_module_project();
+    add_jpa_classes(&project);
+    let entities = get_all_jpa_entities_service::run(project.path(), Some("web")).unwrap();
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].file_type, "WEBEntity");
+    assert_eq!(entities[0].module.as_deref(), Some("web"));
+  }
+
+  #[test]
+  fn test_get_all_jpa_mapped_superclasses_scoped_to_module() {
+    let project = setup_multi_module_project();
+    add_jpa_classes(&project);
+    let superclasses = get_all_jpa_mapped_superclasses::run(project.path(), Some("core")).unwrap();
+    assert_eq!(superclasses.len(), 1);
+    assert_eq!(superclasses[0].file_type, "COREBase");
+    assert_eq!(superclasses[0].module.as_deref(), Some("core"));
+    let missing = get_all_jpa_mapped_superclasses::run(project.path(), Some("batch"));
+    assert_eq!(missing.err().as_deref(), Some("Module not found: batch"));
+  }
+}
@@ -0,0 +1,31 @@
+// Query Cache Integration Tests
+// This module contains tests for the cache of compiled Tree-sitter queries. It holds a single
+// test because the compilation counter is shared by every test of the binary.
+
+use syntaxpresso_core::common::query::compiled_query_count;
+use syntaxpresso_core::common::services::import_declaration_service::get_all_import_declaration_nodes;
+use syntaxpresso_core::common::ts_file::TSFile;
+
+const SOURCE: &str = r#"package com.example;
+
+import java.util.List;
+import java.util.Set;
+
+public class Customer {
+  private List<String> names;
+  private Set<Long> ids;
+}
+"#;
+
+#[test]
+fn test_repeated_lookups_compile_the_query_once() {
+  let ts_file = TSFile::from_source_code(SOURCE);
+  assert_eq!(get_all_import_declaration_nodes(&ts_file).len(), 2);
+  let compiled_after_first_lookup = compiled_query_count();
+  assert!(compiled_after_first_lookup > 0);
+  for _ in 0..100 {
+    let other_file = TSFile::from_source_code(SOURCE);
+    assert_eq!(get_all_import_declaration_nodes(&other_file).len(), 2);
+  }
+  assert_eq!(compiled_query_count(), compiled_after_first_lookup);
+}
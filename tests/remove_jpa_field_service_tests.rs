@@ -0,0 +1,102 @@
+// Remove JPA Field Service Integration Tests
+// This module contains tests for removing entity fields and their inverse relationship side
+
+mod common;
+
+use common::{domain_path, encode, setup_domain_project};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::remove_jpa_field_service::run;
+use tempfile::TempDir;
+
+const ORDER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import jakarta.persistence.JoinColumn;
+import jakarta.persistence.ManyToOne;
+
+@Entity
+public class Order {
+
+  @Id
+  private Long id;
+
+  @ManyToOne
+  @JoinColumn(name = "customer_id")
+  private Customer customer;
+}
+"#;
+
+const CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import jakarta.persistence.OneToMany;
+import java.util.List;
+
+@Entity
+public class Customer {
+
+  @Id
+  private Long id;
+
+  @OneToMany(mappedBy = "customer")
+  private List<Order> orders;
+}
+"#;
+
+/// Creates a project with the bidirectional `Order` -> `Customer` relationship
+fn setup_order_and_customer() -> (TempDir, PathBuf, PathBuf) {
+  let project =
+    setup_domain_project(&[("Order.java", ORDER_SOURCE), ("Customer.java", CUSTOMER_SOURCE)]);
+  let order_path = domain_path(&project, "Order.java");
+  let customer_path = domain_path(&project, "Customer.java");
+  (project, order_path, customer_path)
+}
+
+#[test]
+fn test_removes_field_and_unused_imports() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let response =
+    run(project.path(), &encode(ORDER_SOURCE), &order_path, "customer", false, false).unwrap();
+  assert_eq!(response.removed_field_name, "customer");
+  assert!(response.inverse_entity.is_none());
+  let order = fs::read_to_string(&order_path).unwrap();
+  assert_eq!(
+    order,
+    "package com.example.domain;\n\nimport jakarta.persistence.Entity;\nimport jakarta.persistence.Id;\n\n@Entity\npublic class Order {\n\n  @Id\n  private Long id;\n}\n"
+  );
+  assert_eq!(fs::read_to_string(&customer_path).unwrap(), CUSTOMER_SOURCE);
+}
+
+#[test]
+fn test_cleanup_inverse_removes_both_sides() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let response =
+    run(project.path(), &encode(ORDER_SOURCE), &order_path, "customer", true, false).unwrap();
+  assert_eq!(response.inverse_field_name.as_deref(), Some("orders"));
+  assert_eq!(response.inverse_entity.unwrap().file_type, "Customer");
+  assert!(!fs::read_to_string(&order_path).unwrap().contains("customer"));
+  let customer = fs::read_to_string(&customer_path).unwrap();
+  assert!(!customer.contains("orders"));
+  assert!(!customer.contains("import jakarta.persistence.OneToMany;"));
+  assert!(!customer.contains("import java.util.List;"));
+  assert!(customer.contains("  @Id\n  private Long id;\n}\n"));
+}
+
+#[test]
+fn test_cleanup_inverse_from_the_inverse_side() {
+  let (project, order_path, customer_path) = setup_order_and_customer();
+  let response =
+    run(project.path(), &encode(CUSTOMER_SOURCE), &customer_path, "orders", true, false).unwrap();
+  assert_eq!(response.inverse_field_name.as_deref(), Some("customer"));
+  assert!(!fs::read_to_string(&order_path).unwrap().contains("private Customer customer;"));
+}
+
+#[test]
+fn test_unknown_field_is_rejected() {
+  let (project, order_path, _) = setup_order_and_customer();
+  let result = run(project.path(), &encode(ORDER_SOURCE), &order_path, "missing", true, false);
+  assert_eq!(result.err().unwrap(), "Field not found: missing");
+}
@@ -0,0 +1,86 @@
+// Remove Unused Imports Service Integration Tests
+// This module contains tests for removing imports whose simple name is not referenced in the file
+
+mod common;
+
+use common::encode;
+use std::fs;
+use syntaxpresso_core::commands::services::remove_unused_imports_service::run;
+use tempfile::TempDir;
+
+const SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import java.math.BigDecimal;
+import java.time.Instant;
+import java.util.*;
+import static java.util.Objects.requireNonNull;
+
+/**
+ * Keeps the totals, see {@link Instant} for the timestamps.
+ */
+@Entity
+public class Invoice {
+  private BigDecimal total;
+}
+"#;
+
+#[test]
+fn test_removes_unused_import_and_keeps_used_one() {
+  let response = run(None, Some(&encode(SOURCE))).unwrap();
+  assert_eq!(response.removed_imports, vec!["java.time.Instant".to_string()]);
+  assert!(!response.file.edits.is_empty());
+}
+
+#[test]
+fn test_import_only_referenced_from_javadoc_link_is_unused() {
+  let response = run(None, Some(&encode(SOURCE))).unwrap();
+  assert!(response.removed_imports.contains(&"java.time.Instant".to_string()));
+  assert!(!response.removed_imports.contains(&"java.math.BigDecimal".to_string()));
+  assert!(!response.removed_imports.contains(&"jakarta.persistence.Entity".to_string()));
+}
+
+#[test]
+fn test_wildcard_and_static_imports_are_kept() {
+  let response = run(None, Some(&encode(SOURCE))).unwrap();
+  assert!(response.removed_imports.iter().all(|import| !import.starts_with("java.util")));
+}
+
+#[test]
+fn test_file_is_saved_without_unused_imports() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let file_path = temp_dir.path().join("Invoice.java");
+  fs::write(&file_path, SOURCE).unwrap();
+  let response = run(Some(&file_path), None).unwrap();
+  assert_eq!(response.removed_imports, vec!["java.time.Instant".to_string()]);
+  let source = fs::read_to_string(&file_path).unwrap();
+  assert!(!source.contains("import java.time.Instant;"));
+  assert!(source.contains("import java.math.BigDecimal;\nimport java.util.*;\n"));
+  assert!(source.contains("import static java.util.Objects.requireNonNull;"));
+  assert!(source.contains("{@link Instant}"));
+}
+
+#[test]
+fn test_file_without_unused_imports_is_unchanged() {
+  let temp_dir = TempDir::new().expect("Failed to create temp directory");
+  let file_path = temp_dir.path().join("Invoice.java");
+  let source = SOURCE.replace("import java.time.Instant;\n", "");
+  fs::write(&file_path, &source).unwrap();
+  let response = run(Some(&file_path), None).unwrap();
+  assert!(response.removed_imports.is_empty());
+  assert!(response.file.edits.is_empty());
+  assert_eq!(fs::read_to_string(&file_path).unwrap(), source);
+}
+
+#[test]
+fn test_unused_import_from_package_named_static_is_removed() {
+  let source = SOURCE.replace(
+    "import java.time.Instant;\n",
+    "import java.time.Instant;\nimport com.acme.staticdata.Country;\nimport com.acme.staticdata.Currency;\n",
+  ).replace("private BigDecimal total;", "private BigDecimal total;\n  private Currency currency;");
+  let response = run(None, Some(&encode(&source))).unwrap();
+  assert_eq!(
+    response.removed_imports,
+    vec!["java.time.Instant".to_string(), "com.acme.staticdata.Country".to_string()]
+  );
+}
@@ -0,0 +1,135 @@
+// Rename JPA Entity Service Integration Tests
+// This module contains tests for renaming JPA entities and their repositories
+
+mod common;
+
+use common::{domain_path, setup_domain_project};
+use std::fs;
+use std::path::PathBuf;
+use syntaxpresso_core::commands::services::rename_jpa_entity_service::run;
+use tempfile::TempDir;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Table;
+
+@Entity
+@Table(name = "user_account")
+public class UserAccount {
+
+  private UserAccount parent;
+
+  public UserAccount() {}
+
+  public UserAccount(UserAccount parent) {
+    this.parent = parent;
+  }
+}
+"#;
+
+const REPOSITORY_SOURCE: &str = r#"package com.example.domain;
+
+import org.springframework.data.jpa.repository.JpaRepository;
+
+public interface UserAccountRepository extends JpaRepository<UserAccount, Long> {}
+"#;
+
+/// Creates a project with the `UserAccount` entity and, when asked, its repository
+fn setup_user_account(with_repository: bool) -> (TempDir, PathBuf) {
+  let mut files = vec![("UserAccount.java", ENTITY_SOURCE)];
+  if with_repository {
+    files.push(("UserAccountRepository.java", REPOSITORY_SOURCE));
+  }
+  let project = setup_domain_project(&files);
+  let entity_path = domain_path(&project, "UserAccount.java");
+  (project, entity_path)
+}
+
+fn package_dir(project: &TempDir) -> PathBuf {
+  project.path().join("src/main/java/com/example/domain")
+}
+
+mod entity_rename_tests {
+  use super::*;
+
+  #[test]
+  fn test_renames_class_constructors_and_file() {
+    let (project, entity_path) = setup_user_account(false);
+    let response = run(project.path(), &entity_path, "Customer", false).unwrap();
+    assert_eq!(response.old_entity_name, "UserAccount");
+    assert_eq!(response.new_entity_name, "Customer");
+    assert!(!entity_path.exists());
+    let source = fs::read_to_string(package_dir(&project).join("Customer.java")).unwrap();
+    assert!(source.contains("public class Customer {"));
+    assert!(source.contains("public Customer() {}"));
+    assert!(source.contains("public Customer(Customer parent)"));
+    assert!(source.contains("private Customer parent;"));
+    assert!(!source.contains("UserAccount"));
+    assert!(response.repository.is_none());
+    assert_eq!(response.touched_files.len(), 2);
+  }
+
+  #[test]
+  fn test_updates_derived_table_name() {
+    let (project, entity_path) = setup_user_account(false);
+    run(project.path(), &entity_path, "Customer", false).unwrap();
+    let source = fs::read_to_string(package_dir(&project).join("Customer.java")).unwrap();
+    assert!(source.contains("@Table(name = \"customer\")"));
+  }
+
+  #[test]
+  fn test_keeps_explicit_table_name() {
+    let (project, entity_path) = setup_user_account(false);
+    fs::write(&entity_path, ENTITY_SOURCE.replace("\"user_account\"", "\"accounts\"")).unwrap();
+    run(project.path(), &entity_path, "Customer", false).unwrap();
+    let source = fs::read_to_string(package_dir(&project).join("Customer.java")).unwrap();
+    assert!(source.contains("@Table(name = \"accounts\")"));
+  }
+
+  #[test]
+  fn test_rejects_name_collision() {
+    let (project, entity_path) = setup_user_account(false);
+    fs::write(package_dir(&project).join("Customer.java"), "public class Customer {}").unwrap();
+    let result = run(project.path(), &entity_path, "Customer", false);
+    assert_eq!(result.err().unwrap(), "A class named Customer already exists");
+    assert!(entity_path.exists());
+  }
+}
+
+mod repository_rename_tests {
+  use super::*;
+
+  #[test]
+  fn test_updates_repository_type_argument_without_renaming() {
+    let (project, entity_path) = setup_user_account(true);
+    let response = run(project.path(), &entity_path, "Customer", false).unwrap();
+    let repository_path = package_dir(&project).join("UserAccountRepository.java");
+    let source = fs::read_to_string(&repository_path).unwrap();
+    assert!(
+      source.contains("interface UserAccountRepository extends JpaRepository<Customer, Long>")
+    );
+    assert_eq!(response.touched_files.len(), 3);
+  }
+
+  #[test]
+  fn test_renames_repository() {
+    let (project, entity_path) = setup_user_account(true);
+    let response = run(project.path(), &entity_path, "Customer", true).unwrap();
+    assert!(!package_dir(&project).join("UserAccountRepository.java").exists());
+    let repository_path = package_dir(&project).join("CustomerRepository.java");
+    let source = fs::read_to_string(&repository_path).unwrap();
+    assert!(source.contains("interface CustomerRepository extends JpaRepository<Customer, Long>"));
+    assert_eq!(response.repository.unwrap().file_type, "CustomerRepository");
+    assert_eq!(response.touched_files.len(), 4);
+  }
+
+  #[test]
+  fn test_rejects_repository_name_collision() {
+    let (project, entity_path) = setup_user_account(true);
+    fs::write(package_dir(&project).join("CustomerRepository.java"), "interface X {}").unwrap();
+    let result = run(project.path(), &entity_path, "Customer", true);
+    assert_eq!(result.err().unwrap(), "A class named CustomerRepository already exists");
+    assert!(entity_path.exists());
+  }
+}
@@ -0,0 +1,107 @@
+// Scan Cache Integration Tests
+// This module contains tests for reusing entity and package scans while no source changed
+
+mod common;
+
+use common::{domain_path, setup_domain_project};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syntaxpresso_core::commands::services::{
+  get_all_jpa_entities_service, get_all_packages_service,
+};
+use syntaxpresso_core::common::ts_file::loaded_file_count;
+use syntaxpresso_core::common::types::java_source_directory_type::JavaSourceDirectoryType;
+use syntaxpresso_core::common::utils::scan_cache_util::set_scan_cache;
+use tempfile::TempDir;
+
+const USER_SOURCE: &str = "package com.example.domain;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class User {}\n";
+
+/// Creates a project holding the `User` entity in `com.example.domain`
+fn setup_user_project() -> (TempDir, PathBuf) {
+  let project = setup_domain_project(&[("User.java", USER_SOURCE)]);
+  let package_dir = domain_path(&project, "");
+  (project, package_dir)
+}
+
+/// Runs the entity scan and returns the entity names and the number of files it loaded
+fn scan_entities(cwd: &Path, cache: bool) -> (Vec<String>, usize) {
+  set_scan_cache(cache);
+  let loaded_before = loaded_file_count();
+  let result = get_all_jpa_entities_service::run(cwd, None);
+  let loaded = loaded_file_count() - loaded_before;
+  set_scan_cache(false);
+  let mut names: Vec<String> = result.unwrap().into_iter().map(|entity| entity.file_type).collect();
+  names.sort();
+  (names, loaded)
+}
+
+#[test]
+fn test_unchanged_project_skips_parsing() {
+  let (project, _) = setup_user_project();
+  assert_eq!(scan_entities(project.path(), true), (vec!["User".to_string()], 1));
+  assert_eq!(scan_entities(project.path(), true), (vec!["User".to_string()], 0));
+}
+
+#[test]
+fn test_changed_java_file_triggers_rescan() {
+  let (project, package_dir) = setup_user_project();
+  scan_entities(project.path(), true);
+  fs::write(
+    package_dir.join("Order.java"),
+    "package com.example.domain;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Order {}\n",
+  )
+  .unwrap();
+  assert_eq!(
+    scan_entities(project.path(), true),
+    (vec!["Order".to_string(), "User".to_string()], 2)
+  );
+  fs::write(package_dir.join("User.java"), USER_SOURCE.replace("@Entity\n", "")).unwrap();
+  assert_eq!(scan_entities(project.path(), true), (vec!["Order".to_string()], 2));
+}
+
+#[test]
+fn test_scans_are_not_cached_without_the_option() {
+  let (project, _) = setup_user_project();
+  scan_entities(project.path(), false);
+  assert_eq!(scan_entities(project.path(), false), (vec!["User".to_string()], 1));
+}
+
+#[test]
+fn test_new_package_directory_invalidates_package_scan() {
+  let (project, package_dir) = setup_user_project();
+  let scan_packages = || {
+    set_scan_cache(true);
+    let packages =
+      get_all_packages_service::run(project.path(), &JavaSourceDirectoryType::Main, None).unwrap();
+    set_scan_cache(false);
+    let mut names: Vec<String> = packages.into_iter().map(|p| p.package_name).collect();
+    names.sort();
+    names
+  };
+  assert_eq!(scan_packages(), vec!["com.example.domain"]);
+  fs::create_dir_all(package_dir.join("model")).unwrap();
+  assert_eq!(scan_packages(), vec!["com.example.domain", "com.example.domain.model"]);
+}
+
+#[test]
+fn test_changes_in_packages_named_like_build_output_trigger_rescan() {
+  let (project, package_dir) = setup_user_project();
+  for package in ["build", "out", "target"] {
+    let nested_dir = package_dir.join(package);
+    fs::create_dir_all(&nested_dir).unwrap();
+    fs::write(
+      nested_dir.join("Marker.java"),
+      format!("package com.example.domain.{};\n\npublic class Marker {{}}\n", package),
+    )
+    .unwrap();
+  }
+  scan_entities(project.path(), true);
+  fs::write(
+    package_dir.join("out/Order.java"),
+    "package com.example.domain.out;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Order {}\n",
+  )
+  .unwrap();
+  let (names, loaded) = scan_entities(project.path(), true);
+  assert_eq!(names, vec!["Order".to_string(), "User".to_string()]);
+  assert_eq!(loaded, 5);
+}
@@ -0,0 +1,141 @@
+// Stdin Source Integration Tests
+// This module contains tests for the --stdin option, which reads the Java source of a command
+// from standard input instead of a base64 argument
+
+mod common;
+
+use common::setup_domain_file;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import java.util.UUID;
+
+@Entity
+public class User {
+  @Id
+  private UUID id;
+
+  private String email;
+}
+"#;
+
+fn run_binary_with_stdin(args: &[&str], stdin: &str) -> Output {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("Failed to run syntaxpresso-core");
+  child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+  child.wait_with_output().expect("Failed to wait for syntaxpresso-core")
+}
+
+fn parse_stdout(output: &Output) -> serde_json::Value {
+  serde_json::from_slice(&output.stdout).expect("Expected JSON on stdout")
+}
+
+fn path_arg(path: &Path) -> &str {
+  path.to_str().unwrap()
+}
+
+#[test]
+fn test_entity_info_is_parsed_from_stdin() {
+  let (project, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary_with_stdin(
+    &["get-jpa-entity-info", "--cwd", path_arg(project.path()), "--stdin"],
+    ENTITY_SOURCE,
+  );
+  let json = parse_stdout(&output);
+  assert_eq!(json["succeed"], true);
+  assert_eq!(json["data"]["isJpaEntity"], true);
+  assert_eq!(json["data"]["entityType"], "User");
+  assert_eq!(json["data"]["entityPackageName"], "com.example.domain");
+  assert_eq!(json["data"]["idFieldType"], "UUID");
+  assert_eq!(json["data"]["idFieldPackageName"], "java.util");
+}
+
+#[test]
+fn test_manipulation_commands_edit_the_source_from_stdin() {
+  let (project, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary_with_stdin(
+    &[
+      "generate-accessors",
+      "--cwd",
+      path_arg(project.path()),
+      "--entity-file-path",
+      path_arg(&entity_path),
+      "--field-name",
+      "email",
+      "--stdin",
+    ],
+    ENTITY_SOURCE,
+  );
+  let json = parse_stdout(&output);
+  assert_eq!(json["succeed"], true);
+  assert_eq!(json["data"]["generatedMethodNames"], serde_json::json!(["getEmail", "setEmail"]));
+  assert!(fs::read_to_string(&entity_path).unwrap().contains("public String getEmail()"));
+}
+
+#[test]
+fn test_stdin_conflicts_with_the_other_sources() {
+  let (project, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary_with_stdin(
+    &[
+      "get-jpa-entity-info",
+      "--cwd",
+      path_arg(project.path()),
+      "--entity-file-path",
+      path_arg(&entity_path),
+      "--stdin",
+    ],
+    ENTITY_SOURCE,
+  );
+  assert!(!output.status.success());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+  let output = run_binary_with_stdin(
+    &["dump-ast", "--cwd", path_arg(project.path()), "--b64-source-code", "", "--stdin"],
+    ENTITY_SOURCE,
+  );
+  assert!(!output.status.success());
+}
+
+#[test]
+fn test_dash_is_a_literal_base64_source() {
+  let (project, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary_with_stdin(
+    &["dump-ast", "--cwd", path_arg(project.path()), "--b64-source-code", "-"],
+    ENTITY_SOURCE,
+  );
+  let json = parse_stdout(&output);
+  assert_eq!(json["succeed"], false);
+}
+
+#[test]
+fn test_source_is_required_without_a_file_path() {
+  let (project, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let output = run_binary_with_stdin(&["dump-ast", "--cwd", path_arg(project.path())], "");
+  assert!(!output.status.success());
+  let output = run_binary_with_stdin(
+    &["dump-ast", "--cwd", path_arg(project.path()), "--stdin"],
+    ENTITY_SOURCE,
+  );
+  assert_eq!(parse_stdout(&output)["succeed"], true);
+}
+
+#[test]
+fn test_source_docs_do_not_become_command_summaries() {
+  let output = Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .arg("--help")
+    .output()
+    .expect("Failed to run syntaxpresso-core");
+  let help = String::from_utf8(output.stdout).unwrap();
+  assert!(help.contains("create-jpa-repository"));
+  assert!(!help.contains("Source of"), "unexpected summary in:\n{}", help);
+}
@@ -0,0 +1,93 @@
+// Timing Integration Tests
+// This module contains tests for the global --with-timing option, which reports the time a
+// command spent parsing, scanning and writing files
+
+mod common;
+
+use base64::Engine;
+use common::setup_domain_file;
+use std::path::Path;
+use std::process::Command;
+
+const ENTITY_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class User {
+  private Long id;
+}
+"#;
+
+/// Runs the binary and parses the JSON it prints
+fn run_binary(args: &[String]) -> serde_json::Value {
+  let output = Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+    .args(args)
+    .output()
+    .expect("Failed to run syntaxpresso-core");
+  serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+    panic!("stdout is not valid JSON ({}):\n{}", e, String::from_utf8_lossy(&output.stdout))
+  })
+}
+
+fn add_field_args(cwd: &Path, entity_path: &Path) -> Vec<String> {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(ENTITY_SOURCE);
+  [
+    "create-jpa-entity-basic-field",
+    "--cwd",
+    &cwd.display().to_string(),
+    "--entity-file-path",
+    &entity_path.display().to_string(),
+    "--entity-file-b64-src",
+    &b64_source,
+    "--field-name",
+    "email",
+    "--field-type",
+    "String",
+  ]
+  .iter()
+  .map(|arg| arg.to_string())
+  .collect()
+}
+
+fn assert_timing_keys(response: &serde_json::Value) {
+  let timing = response["timingMs"].as_object().expect("timingMs should be an object");
+  for key in ["parse", "scan", "write"] {
+    let milliseconds = timing.get(key).and_then(|value| value.as_f64());
+    assert!(milliseconds.is_some_and(|ms| ms >= 0.0), "{} missing in {:?}", key, timing);
+  }
+}
+
+#[test]
+fn test_with_timing_reports_phases_of_a_mutating_command() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let mut args = add_field_args(temp_dir.path(), &entity_path);
+  args.push("--with-timing".to_string());
+  let response = run_binary(&args);
+  assert_eq!(response["succeed"], true);
+  assert_timing_keys(&response);
+  assert!(response["timingMs"]["write"].as_f64().unwrap() > 0.0);
+}
+
+#[test]
+fn test_with_timing_reports_phases_of_a_scan() {
+  let (temp_dir, _) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let response = run_binary(&[
+    "get-all-jpa-entities".to_string(),
+    "--cwd".to_string(),
+    temp_dir.path().display().to_string(),
+    "--with-timing".to_string(),
+  ]);
+  assert_eq!(response["succeed"], true);
+  assert_timing_keys(&response);
+  assert!(response["timingMs"]["parse"].as_f64().unwrap() > 0.0);
+  assert_eq!(response["timingMs"]["write"], 0.0);
+}
+
+#[test]
+fn test_timing_is_absent_without_the_flag() {
+  let (temp_dir, entity_path) = setup_domain_file("User.java", ENTITY_SOURCE);
+  let response = run_binary(&add_field_args(temp_dir.path(), &entity_path));
+  assert_eq!(response["succeed"], true);
+  assert!(response.get("timingMs").is_none());
+}
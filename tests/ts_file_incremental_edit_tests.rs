@@ -0,0 +1,100 @@
+// TSFile Incremental Edit Integration Tests
+// This module contains tests for batching edits with apply_edit and flushing them with reparse
+
+use syntaxpresso_core::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+const SOURCE: &str = r#"package com.example;
+
+public class User {
+  private Long id;
+
+  private String name;
+}
+"#;
+
+/// Flattens a tree into `(kind, start_byte, end_byte)` triples in document order
+fn describe_tree(ts_file: &TSFile) -> Vec<(String, usize, usize)> {
+  fn visit(node: Node, nodes: &mut Vec<(String, usize, usize)>) {
+    nodes.push((node.kind().to_string(), node.start_byte(), node.end_byte()));
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+      visit(child, nodes);
+    }
+  }
+  let mut nodes = Vec::new();
+  visit(ts_file.tree.as_ref().expect("File should be parsed").root_node(), &mut nodes);
+  nodes
+}
+
+#[test]
+fn test_three_edits_then_single_reparse_match_fresh_parse() {
+  let mut ts_file = TSFile::from_source_code(SOURCE);
+  // Rename the class
+  let class_name_start = SOURCE.find("User").unwrap();
+  ts_file.apply_edit(class_name_start, class_name_start + "User".len(), "Customer").unwrap();
+  // Change the type of `id`, offsets now account for the rename
+  let long_start = ts_file.source_code.find("Long").unwrap();
+  ts_file.apply_edit(long_start, long_start + "Long".len(), "java.util.UUID").unwrap();
+  // Append a field before the closing brace
+  let closing_brace = ts_file.source_code.rfind('}').unwrap();
+  ts_file.apply_edit(closing_brace, closing_brace, "\n  private String email;\n").unwrap();
+  assert!(ts_file.is_tree_dirty());
+
+  ts_file.reparse();
+
+  assert!(!ts_file.is_tree_dirty());
+  let fresh = TSFile::from_source_code(&ts_file.source_code);
+  assert_eq!(describe_tree(&ts_file), describe_tree(&fresh));
+  assert!(!ts_file.tree.as_ref().unwrap().root_node().has_error());
+  assert!(ts_file.source_code.contains("public class Customer {"));
+  assert!(ts_file.source_code.contains("private java.util.UUID id;"));
+  assert!(ts_file.source_code.contains("private String email;"));
+}
+
+#[test]
+fn test_batched_edits_are_reported_as_edit_records() {
+  let mut ts_file = TSFile::from_source_code(SOURCE);
+  let class_name_start = SOURCE.find("User").unwrap();
+  ts_file.apply_edit(class_name_start, class_name_start + "User".len(), "Person").unwrap();
+  ts_file.reparse();
+  let edits = ts_file.edit_records();
+  assert_eq!(edits.len(), 1);
+  assert_eq!(edits[0].old_text, "User");
+  assert_eq!(edits[0].new_text, "Person");
+  assert!(ts_file.is_modified());
+}
+
+#[test]
+fn test_reparse_without_pending_edits_keeps_tree() {
+  let mut ts_file = TSFile::from_source_code(SOURCE);
+  let before = describe_tree(&ts_file);
+  ts_file.reparse();
+  assert!(!ts_file.is_tree_dirty());
+  assert_eq!(describe_tree(&ts_file), before);
+}
+
+#[test]
+fn test_internal_edit_flushes_pending_edits() {
+  let mut ts_file = TSFile::from_source_code(SOURCE);
+  let class_name_start = SOURCE.find("User").unwrap();
+  ts_file.apply_edit(class_name_start, class_name_start + "User".len(), "Account").unwrap();
+  let name_start = ts_file.source_code.find("name").unwrap();
+  ts_file.replace_text_by_range(name_start, name_start + "name".len(), "login");
+  assert!(!ts_file.is_tree_dirty());
+  let fresh = TSFile::from_source_code(&ts_file.source_code);
+  assert_eq!(describe_tree(&ts_file), describe_tree(&fresh));
+}
+
+#[test]
+fn test_invalid_ranges_are_rejected() {
+  let mut ts_file = TSFile::from_source_code("class Café {}");
+  let len = ts_file.source_code.len();
+  assert!(ts_file.apply_edit(4, 2, "x").is_err());
+  assert!(ts_file.apply_edit(0, len + 1, "x").is_err());
+  let accent = ts_file.source_code.find('é').unwrap();
+  let error = ts_file.apply_edit(accent + 1, accent + 2, "e").unwrap_err();
+  assert!(error.contains("splits a character"));
+  assert!(!ts_file.is_tree_dirty());
+  assert_eq!(ts_file.source_code, "class Café {}");
+}
@@ -0,0 +1,64 @@
+// TSFile Syntax Error Integration Tests
+// This module contains tests for locating syntax errors and refusing to edit classes that have them
+
+use syntaxpresso_core::common::services::class_declaration_service::get_public_class_node;
+use syntaxpresso_core::common::services::field_declaration_service::ensure_class_has_no_syntax_errors;
+use syntaxpresso_core::common::ts_file::TSFile;
+
+const UNCLOSED_CLASS_SOURCE: &str =
+  "package com.example;\n\npublic class User {\n  private Long id;\n";
+
+/// Runs the syntax error check against the public class of `source`
+fn check_public_class(source: &str) -> Result<(), String> {
+  let ts_file = TSFile::from_source_code(source);
+  let class_start_byte = get_public_class_node(&ts_file).expect("Public class").start_byte();
+  ensure_class_has_no_syntax_errors(&ts_file, class_start_byte)
+}
+
+#[test]
+fn test_valid_source_has_no_errors() {
+  let ts_file = TSFile::from_source_code("package com.example;\n\npublic class User {}\n");
+  assert!(!ts_file.has_errors());
+  assert!(ts_file.error_ranges().is_empty());
+}
+
+#[test]
+fn test_unclosed_brace_is_reported_after_the_last_token() {
+  let ts_file = TSFile::from_source_code(UNCLOSED_CLASS_SOURCE);
+  assert!(ts_file.has_errors());
+  let end = UNCLOSED_CLASS_SOURCE.trim_end().len();
+  assert_eq!(ts_file.error_ranges(), vec![end..end]);
+}
+
+#[test]
+fn test_error_ranges_are_in_source_order() {
+  let source = "public class User {\n  private Long id\n  private String name\n}\n";
+  let ts_file = TSFile::from_source_code(source);
+  let ranges = ts_file.error_ranges();
+  assert_eq!(ranges.len(), 2, "Ranges: {:?}", ranges);
+  assert!(ranges[0].start < ranges[1].start);
+}
+
+#[test]
+fn test_unclosed_class_cannot_be_edited() {
+  assert_eq!(
+    check_public_class(UNCLOSED_CLASS_SOURCE),
+    Err("cannot edit: source has syntax errors near line 4, column 19".to_string())
+  );
+}
+
+#[test]
+fn test_missing_semicolon_is_located() {
+  let source = "package com.example;\n\npublic class User {\n  private Long id\n}\n";
+  assert_eq!(
+    check_public_class(source),
+    Err("cannot edit: source has syntax errors near line 4, column 18".to_string())
+  );
+}
+
+#[test]
+fn test_errors_outside_the_class_are_ignored() {
+  let source =
+    "package com.example;\n\npublic class User {\n  private Long id;\n}\n\nclass Broken {\n";
+  assert!(check_public_class(source).is_ok());
+}
@@ -0,0 +1,59 @@
+// UI Id Field Form Integration Tests
+// This module contains tests for the default field name of the id field form. It only builds
+// with the `ui` feature.
+
+#![cfg(feature = "ui")]
+
+mod common;
+
+use base64::Engine;
+use common::setup_domain_file;
+use syntaxpresso_core::common::types::id_field_naming::IdFieldNaming;
+use syntaxpresso_core::ui::forms::create_id_field::CreateIdFieldForm;
+use tempfile::TempDir;
+
+const ORDER_ITEM_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class OrderItem {
+}
+"#;
+
+fn build_form(id_field_naming: &IdFieldNaming) -> CreateIdFieldForm {
+  let (project, entity_path) = setup_domain_file("OrderItem.java", ORDER_ITEM_SOURCE);
+  CreateIdFieldForm::new(
+    project.path().to_path_buf(),
+    base64::engine::general_purpose::STANDARD.encode(ORDER_ITEM_SOURCE),
+    entity_path,
+    id_field_naming,
+  )
+}
+
+#[test]
+fn test_defaults_to_id() {
+  let form = build_form(&IdFieldNaming::Id);
+  assert_eq!(form.field_name(), "id");
+  assert_eq!(form.field_name_cursor(), 2);
+}
+
+#[test]
+fn test_entity_id_naming_derives_the_name_from_the_entity() {
+  let form = build_form(&IdFieldNaming::EntityId);
+  assert_eq!(form.field_name(), "orderItemId");
+  assert_eq!(form.field_name_cursor(), 11);
+}
+
+#[test]
+fn test_falls_back_to_id_when_the_entity_cannot_be_read() {
+  let project = TempDir::new().unwrap();
+  let form = CreateIdFieldForm::new(
+    project.path().to_path_buf(),
+    String::new(),
+    project.path().join("Missing.java"),
+    &IdFieldNaming::EntityId,
+  );
+  assert_eq!(form.field_name(), "id");
+  assert_eq!(form.field_name_cursor(), 2);
+}
@@ -0,0 +1,97 @@
+// UI Text Input Integration Tests
+// This module contains tests for the cursor handling of the TUI text inputs with multi-byte and
+// wide characters. It only builds with the `ui` feature.
+#![cfg(feature = "ui")]
+
+use crossterm::event::KeyCode;
+use syntaxpresso_core::ui::form_trait::{InputMode, helpers};
+
+/// Types each key into `text` through the shared text input handler
+fn type_keys(text: &mut String, cursor: &mut usize, keys: &[KeyCode]) {
+  let mut mode = InputMode::Insert;
+  for key in keys {
+    helpers::handle_text_input(*key, text, cursor, &mut mode);
+  }
+}
+
+#[test]
+fn test_inserts_multi_byte_character() {
+  let mut text = String::from("nme");
+  let mut cursor = 1;
+  type_keys(&mut text, &mut cursor, &[KeyCode::Char('ä')]);
+  assert_eq!(text, "näme");
+  assert_eq!(cursor, 2);
+  type_keys(&mut text, &mut cursor, &[KeyCode::Right, KeyCode::Char('ß')]);
+  assert_eq!(text, "nämße");
+  assert_eq!(cursor, 4);
+  assert_eq!(helpers::cursor_column(&text, cursor), 4);
+}
+
+#[test]
+fn test_removes_multi_byte_characters() {
+  let mut text = String::from("größe");
+  let mut cursor = helpers::text_len(&text);
+  assert_eq!(cursor, 5);
+  type_keys(&mut text, &mut cursor, &[KeyCode::Left, KeyCode::Left, KeyCode::Backspace]);
+  assert_eq!(text, "grße");
+  assert_eq!(cursor, 2);
+  type_keys(&mut text, &mut cursor, &[KeyCode::Delete]);
+  assert_eq!(text, "gre");
+  type_keys(&mut text, &mut cursor, &[KeyCode::End, KeyCode::Delete, KeyCode::Right]);
+  assert_eq!(text, "gre");
+  assert_eq!(cursor, 3);
+}
+
+#[test]
+fn test_combining_mark_joins_previous_character() {
+  let mut text = String::from("cafe");
+  let mut cursor = 4;
+  type_keys(&mut text, &mut cursor, &[KeyCode::Char('\u{301}')]);
+  assert_eq!(text, "cafe\u{301}");
+  assert_eq!(cursor, 4);
+  type_keys(&mut text, &mut cursor, &[KeyCode::Backspace]);
+  assert_eq!(text, "caf");
+  assert_eq!(cursor, 3);
+}
+
+#[test]
+fn test_cursor_column_counts_wide_characters() {
+  let text = "名前x";
+  assert_eq!(helpers::text_len(text), 3);
+  assert_eq!(helpers::cursor_column(text, 1), 2);
+  assert_eq!(helpers::cursor_column(text, 3), 5);
+}
+
+#[test]
+fn test_numeric_input_ignores_non_digits() {
+  let mut text = String::from("12");
+  let mut cursor = 2;
+  let mut mode = InputMode::Insert;
+  helpers::handle_numeric_input(KeyCode::Char('é'), &mut text, &mut cursor, &mut mode);
+  helpers::handle_numeric_input(KeyCode::Char('3'), &mut text, &mut cursor, &mut mode);
+  assert_eq!(text, "123");
+  assert_eq!(cursor, 3);
+}
+
+#[test]
+fn test_qualified_name_input_accepts_multi_byte_identifiers() {
+  let mut text = String::new();
+  let mut cursor = 0;
+  let mut mode = InputMode::Insert;
+  for c in "com.exämple.Geld€Converter$1 ".chars() {
+    helpers::handle_qualified_name_input(KeyCode::Char(c), &mut text, &mut cursor, &mut mode);
+  }
+  assert_eq!(text, "com.exämple.GeldConverter$1");
+  assert_eq!(cursor, 27);
+  for key in [KeyCode::Home, KeyCode::Right, KeyCode::Right, KeyCode::Right, KeyCode::Delete] {
+    helpers::handle_qualified_name_input(key, &mut text, &mut cursor, &mut mode);
+  }
+  assert_eq!(text, "comexämple.GeldConverter$1");
+  for key in [KeyCode::Right, KeyCode::Right, KeyCode::Right, KeyCode::Backspace] {
+    helpers::handle_qualified_name_input(key, &mut text, &mut cursor, &mut mode);
+  }
+  assert_eq!(text, "comexmple.GeldConverter$1");
+  assert_eq!(cursor, 5);
+  helpers::handle_qualified_name_input(KeyCode::Enter, &mut text, &mut cursor, &mut mode);
+  assert_eq!(mode, InputMode::Normal);
+}
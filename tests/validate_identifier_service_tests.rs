@@ -0,0 +1,114 @@
+// Validate Identifier Service Integration Tests
+// This module contains tests for validating class, package, field and method names, including
+// the Java reserved word checks
+
+use syntaxpresso_core::commands::services::validate_identifier_service::run;
+use syntaxpresso_core::common::types::java_identifier_kind::JavaIdentifierKind;
+
+fn assert_valid(kind: JavaIdentifierKind, value: &str) {
+  let response = run(&kind, value).expect("Validation should not fail");
+  assert!(response.valid, "{:?} '{}' should be valid: {:?}", kind, value, response.reason);
+  assert!(response.reason.is_none());
+}
+
+fn assert_invalid(kind: JavaIdentifierKind, value: &str, reason: &str) {
+  let response = run(&kind, value).expect("Validation should not fail");
+  assert!(!response.valid, "{:?} '{}' should be invalid", kind, value);
+  let actual = response.reason.expect("Invalid names should carry a reason");
+  assert!(actual.contains(reason), "Expected reason containing '{}', got '{}'", reason, actual);
+}
+
+mod class_tests {
+  use super::*;
+
+  #[test]
+  fn test_accepts_class_names() {
+    assert_valid(JavaIdentifierKind::Class, "User");
+    assert_valid(JavaIdentifierKind::Class, "OrderItem2");
+    assert_valid(JavaIdentifierKind::Class, "order-item");
+  }
+
+  #[test]
+  fn test_rejects_invalid_class_names() {
+    assert_invalid(JavaIdentifierKind::Class, "", "cannot be empty");
+    assert_invalid(JavaIdentifierKind::Class, "Order Item", "can only contain");
+    assert_invalid(JavaIdentifierKind::Class, "_User", "cannot start or end with underscore");
+  }
+
+  #[test]
+  fn test_rejects_reserved_class_names() {
+    assert_invalid(JavaIdentifierKind::Class, "Class", "reserved word");
+    assert_invalid(JavaIdentifierKind::Class, "null", "reserved word");
+  }
+}
+
+mod package_tests {
+  use super::*;
+
+  #[test]
+  fn test_accepts_package_names() {
+    assert_valid(JavaIdentifierKind::Package, "com.example.shop");
+    assert_valid(JavaIdentifierKind::Package, "com.example.order_items");
+  }
+
+  #[test]
+  fn test_rejects_invalid_package_names() {
+    assert_invalid(JavaIdentifierKind::Package, "", "cannot be empty");
+    assert_invalid(JavaIdentifierKind::Package, "com..example", "consecutive dots");
+    assert_invalid(JavaIdentifierKind::Package, "com.example-shop", "can only contain");
+  }
+
+  #[test]
+  fn test_rejects_reserved_package_segments() {
+    assert_invalid(JavaIdentifierKind::Package, "com.example.new", "'new'");
+    assert_invalid(JavaIdentifierKind::Package, "int.example", "'int'");
+  }
+}
+
+mod field_tests {
+  use super::*;
+
+  #[test]
+  fn test_accepts_field_names() {
+    assert_valid(JavaIdentifierKind::Field, "firstName");
+    assert_valid(JavaIdentifierKind::Field, "_count");
+    assert_valid(JavaIdentifierKind::Field, "$value2");
+    assert_valid(JavaIdentifierKind::Field, "Class");
+  }
+
+  #[test]
+  fn test_rejects_invalid_field_names() {
+    assert_invalid(JavaIdentifierKind::Field, "", "cannot be empty");
+    assert_invalid(JavaIdentifierKind::Field, "2ndLine", "must start with");
+    assert_invalid(JavaIdentifierKind::Field, "first-name", "can only contain");
+    assert_invalid(JavaIdentifierKind::Field, "_", "keyword");
+  }
+
+  #[test]
+  fn test_rejects_reserved_field_names() {
+    assert_invalid(JavaIdentifierKind::Field, "class", "reserved word");
+    assert_invalid(JavaIdentifierKind::Field, "true", "reserved word");
+  }
+}
+
+mod method_tests {
+  use super::*;
+
+  #[test]
+  fn test_accepts_method_names() {
+    assert_valid(JavaIdentifierKind::Method, "getName");
+    assert_valid(JavaIdentifierKind::Method, "toString");
+  }
+
+  #[test]
+  fn test_rejects_invalid_method_names() {
+    assert_invalid(JavaIdentifierKind::Method, "get name", "can only contain");
+    assert_invalid(JavaIdentifierKind::Method, "1st", "must start with");
+  }
+
+  #[test]
+  fn test_rejects_reserved_method_names() {
+    assert_invalid(JavaIdentifierKind::Method, "return", "reserved word");
+    assert_invalid(JavaIdentifierKind::Method, "synchronized", "reserved word");
+  }
+}
@@ -0,0 +1,96 @@
+// Validate JPA Entity Service Integration Tests
+// This module contains tests for the JPA entity modeling diagnostics
+
+use base64::Engine;
+use syntaxpresso_core::commands::services::validate_jpa_entity_service::run;
+use syntaxpresso_core::responses::validate_jpa_entity_response::{
+  DiagnosticSeverity, ValidateJpaEntityResponse,
+};
+
+fn validate(source: &str) -> ValidateJpaEntityResponse {
+  let b64_source = base64::engine::general_purpose::STANDARD.encode(source);
+  run(None, Some(&b64_source)).expect("Validation should succeed")
+}
+
+fn codes(response: &ValidateJpaEntityResponse) -> Vec<&str> {
+  response.diagnostics.iter().map(|d| d.code.as_str()).collect()
+}
+
+mod id_tests {
+  use super::*;
+
+  #[test]
+  fn test_entity_missing_id() {
+    let source = "@Entity\npublic class User {\n  private String name;\n}";
+    let response = validate(source);
+    assert!(!response.is_valid);
+    assert_eq!(codes(&response), vec!["missing-id"]);
+    let diagnostic = &response.diagnostics[0];
+    assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+    assert_eq!(&source[diagnostic.start_byte..diagnostic.end_byte], "User");
+  }
+
+  #[test]
+  fn test_entity_with_two_ids() {
+    let source = "@Entity\npublic class Line {\n  @Id\n  private Long orderId;\n\n  @Id\n  private Long lineId;\n}";
+    let response = validate(source);
+    assert!(!response.is_valid);
+    assert_eq!(codes(&response), vec!["multiple-ids"]);
+    let diagnostic = &response.diagnostics[0];
+    assert!(diagnostic.message.contains("lineId"));
+    assert!(source[diagnostic.start_byte..diagnostic.end_byte].contains("private Long lineId;"));
+  }
+
+  #[test]
+  fn test_two_ids_with_id_class_are_valid() {
+    let source = "@Entity\n@IdClass(LineId.class)\npublic class Line {\n  @Id\n  private Long orderId;\n\n  @Id\n  private Long lineId;\n}";
+    let response = validate(source);
+    assert!(response.is_valid);
+    assert!(response.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn test_id_may_be_inherited_from_superclass() {
+    let response = validate("@Entity\npublic class User extends BaseEntity {}");
+    assert!(response.diagnostics.is_empty());
+  }
+}
+
+mod field_tests {
+  use super::*;
+
+  #[test]
+  fn test_relationship_and_field_warnings() {
+    let source = r#"@Entity
+public class Order {
+  @Id
+  private Long id;
+
+  @ManyToOne
+  private Customer customer;
+
+  @OneToMany
+  private List<Line> lines;
+
+  @OneToMany(mappedBy = "order")
+  private List<Payment> payments;
+
+  public String note;
+
+  public static final String TYPE = "order";
+}"#;
+    let response = validate(source);
+    assert!(response.is_valid, "Warnings must not invalidate the entity");
+    assert_eq!(
+      codes(&response),
+      vec!["missing-join-strategy", "missing-mapped-by", "public-mutable-field"]
+    );
+    assert!(response.diagnostics.iter().all(|d| d.severity == DiagnosticSeverity::Warning));
+  }
+
+  #[test]
+  fn test_rejects_non_entity() {
+    let b64_source = base64::engine::general_purpose::STANDARD.encode("public class Plain {}");
+    assert_eq!(run(None, Some(&b64_source)).err().unwrap(), "File is not a JPA entity");
+  }
+}